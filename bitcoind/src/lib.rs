@@ -16,7 +16,7 @@ use std::ffi::OsStr;
 use std::net::{Ipv4Addr, SocketAddrV4, TcpListener};
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, ExitStatus, Stdio};
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use std::{env, fmt, fs, thread};
 
 use anyhow::Context;
@@ -143,6 +143,13 @@ pub enum Error {
     /// Returned when bitcoind could not be reached after multiple attempts.
     /// The attached string, if present, contains the error encountered when trying to connect.
     NoBitcoindInstance(String),
+    /// Returned when the running bitcoind is not the version selected by the enabled feature.
+    VersionMismatch {
+        /// The version the client was compiled for.
+        expected: &'static str,
+        /// The version reported by the running bitcoind e.g., `280100`.
+        got: usize,
+    },
 }
 
 impl fmt::Debug for Error {
@@ -160,6 +167,7 @@ impl fmt::Debug for Error {
             RpcUserAndPasswordUsed => write!(f, "`-rpcuser` and `-rpcpassword` cannot be used, it will be deprecated soon and it's recommended to use `-rpcauth` instead which works alongside with the default cookie authentication"),
             SkipDownload => write!(f, "expecting an auto-downloaded executable but `BITCOIND_SKIP_DOWNLOAD` env var is set"),
             NoBitcoindInstance(msg) => write!(f, "it appears that bitcoind is not reachable: {}", msg),
+            VersionMismatch { expected, got } => write!(f, "the running bitcoind reports version {} but the enabled feature selects {}, check `BITCOIND_EXE` and the `bitcoind` in `PATH`", got, expected),
         }
     }
 }
@@ -182,7 +190,8 @@ impl std::error::Error for Error {
             | BothDirsSpecified
             | RpcUserAndPasswordUsed
             | SkipDownload
-            | NoBitcoindInstance(_) => None,
+            | NoBitcoindInstance(_)
+            | VersionMismatch { .. } => None,
         }
     }
 }
@@ -372,19 +381,18 @@ impl BitcoinD {
                 }
             };
             let client = match &conf.wallet {
-                Some(wallet) =>
-                    match Self::create_client_wallet(&client_base, &rpc_url, &auth, wallet) {
-                        Ok(client) => client,
-                        Err(e) => {
-                            // If the wallet cannot be created or loaded, there might be an issue
-                            // with the work_dir or process. Kill the process and retry.
-                            Self::terminate_process(&mut process);
-                            if attempt == conf.attempts - 1 {
-                                return Err(e);
-                            }
-                            continue;
+                Some(wallet) => match Self::create_client_wallet(&client_base, wallet) {
+                    Ok(client) => client,
+                    Err(e) => {
+                        // If the wallet cannot be created or loaded, there might be an issue
+                        // with the work_dir or process. Kill the process and retry.
+                        Self::terminate_process(&mut process);
+                        if attempt == conf.attempts - 1 {
+                            return Err(e);
                         }
-                    },
+                        continue;
+                    }
+                },
                 None => client_base,
             };
             if Self::wait_for_client(&client, CLIENT_WAIT_TIMEOUT).is_err() {
@@ -486,7 +494,7 @@ impl BitcoinD {
 
     /// Returns `Ok` once the cookie file is accessible, or an error if it times out.
     fn wait_for_cookie_file(cookie_file: &Path, timeout: Duration) -> anyhow::Result<()> {
-        let start = std::time::Instant::now();
+        let start = Instant::now();
         while start.elapsed() < timeout {
             if cookie_file.exists() {
                 return Ok(());
@@ -496,17 +504,18 @@ impl BitcoinD {
         Err(anyhow::anyhow!("timeout waiting for cookie file: {}", cookie_file.display()))
     }
 
-    /// Returns `Ok` once the client can successfully call, or an error if it times out.
+    /// Returns `Ok` once the client can successfully call, or the last error if it times out.
     fn wait_for_client(client: &Client, timeout: Duration) -> anyhow::Result<()> {
-        let start = std::time::Instant::now();
-        while start.elapsed() < timeout {
-            // Test calling GetBlockchainInfo. Use serde value to be resilient to upstream changes.
-            if client.call::<serde_json::Value>("getblockchaininfo", &[]).is_ok() {
-                return Ok(());
+        let start = Instant::now();
+        loop {
+            // Retry any error, e.g., the credentials are rejected while the cookie file is written.
+            match client.wait_for_ready(timeout.saturating_sub(start.elapsed())) {
+                Ok(()) => return Ok(()),
+                Err(e) if start.elapsed() >= timeout =>
+                    return Err(e).context("timeout waiting for client to be ready"),
+                Err(_) => thread::sleep(CLIENT_RETRY_DELAY),
             }
-            thread::sleep(Duration::from_millis(200));
         }
-        Err(anyhow::anyhow!("timeout waiting for client to be ready"))
     }
 
     /// Create a new RPC client connected to the given `rpc_url` with the provided `auth`.
@@ -529,18 +538,13 @@ impl BitcoinD {
     /// If the wallet already exists, it will load it.
     ///
     /// The client or wallet may not be immediately available, so retry up to 10 times.
-    fn create_client_wallet(
-        client_base: &Client,
-        rpc_url: &str,
-        auth: &Auth,
-        wallet: &str,
-    ) -> anyhow::Result<Client> {
+    fn create_client_wallet(client_base: &Client, wallet: &str) -> anyhow::Result<Client> {
         for _ in 0..CLIENT_CREATE_RETRIES {
             // Try to create the wallet, or if that fails it might already exist so try to load it.
             if client_base.create_wallet(wallet).is_ok() || client_base.load_wallet(wallet).is_ok()
             {
-                let url = format!("{}/wallet/{}", rpc_url, wallet);
-                return Client::new_with_auth(&url, auth.clone())
+                return client_base
+                    .for_wallet(wallet)
                     .map_err(|e| Error::NoBitcoindInstance(e.to_string()).into());
             }
             thread::sleep(CLIENT_RETRY_DELAY);
@@ -548,6 +552,20 @@ impl BitcoinD {
         Err(Error::NoBitcoindInstance("Could not create or load wallet".to_string()).into())
     }
 
+    /// Returns the RPC client after checking it matches the version of the running bitcoind.
+    ///
+    /// The client version is fixed at compile time by the enabled feature while the executable may
+    /// come from `BITCOIND_EXE` or `PATH`. Use this to fail early instead of hitting confusing
+    /// deserialization errors when the two disagree.
+    pub fn versioned_client(&self) -> anyhow::Result<&Client> {
+        match self.client.check_expected_server_version() {
+            Ok(()) => Ok(&self.client),
+            Err(client_sync::Error::ServerVersion(e)) =>
+                Err(Error::VersionMismatch { expected: VERSION, got: e.got }.into()),
+            Err(e) => Err(Error::Rpc(e).into()),
+        }
+    }
+
     /// Returns the rpc URL including the schema eg. http://127.0.0.1:44842.
     pub fn rpc_url(&self) -> String { format!("http://{}", self.params.rpc_socket) }
 
@@ -575,6 +593,11 @@ impl BitcoinD {
     /// created wallet.
     pub fn create_wallet<T: AsRef<str>>(&self, wallet: T) -> anyhow::Result<Client> {
         let _ = self.client.create_wallet(wallet.as_ref())?;
+        self.wallet_client(wallet)
+    }
+
+    /// Return an RPC client connected to the already loaded `wallet`.
+    pub fn wallet_client<T: AsRef<str>>(&self, wallet: T) -> anyhow::Result<Client> {
         Ok(Client::new_with_auth(
             &self.rpc_url_with_wallet(wallet),
             Auth::CookieFile(self.params.cookie_file.clone()),
@@ -709,6 +732,14 @@ mod test {
         assert_eq!(0, info.blocks);
     }
 
+    #[test]
+    fn test_versioned_client() {
+        let exe = init();
+        let node = BitcoinD::new(exe).unwrap();
+        let client = node.versioned_client().unwrap();
+        assert!(client.server_version().unwrap() > 0);
+    }
+
     #[test]
     fn test_node() {
         let exe = init();
@@ -286,7 +286,8 @@ impl AsyncConnection {
                 )))]
                 return Err(Error::HttpsFeatureNotEnabled);
                 #[cfg(any(feature = "async-https-rustls", feature = "async-https-rustls-probe"))]
-                rustls_stream::wrap_async_stream(socket, params.host).await
+                rustls_stream::wrap_async_stream(socket, params.host, params.root_certificates)
+                    .await
             } else {
                 Ok(AsyncHttpStream::Unsecured(socket))
             }
@@ -355,6 +356,10 @@ impl AsyncConnection {
             Some(proxy) => {
                 // do proxy things
                 let mut tcp = Self::tcp_connect(&proxy.server, proxy.port).await?;
+                if proxy.is_socks5() {
+                    Self::socks5_handshake(&mut tcp, proxy, params.host, params.port).await?;
+                    return Ok(tcp);
+                }
 
                 let proxy_request = proxy.connect(params.host, params.port);
                 tcp.write_all(proxy_request.as_bytes()).await?;
@@ -392,6 +397,33 @@ impl AsyncConnection {
         Self::tcp_connect(&params.host, params.port).await
     }
 
+    /// Asks the SOCKS5 `proxy` connected to by `tcp` to open a connection to `host:port`.
+    #[cfg(feature = "proxy")]
+    async fn socks5_handshake(
+        tcp: &mut AsyncTcpStream,
+        proxy: &crate::Proxy,
+        host: &str,
+        port: u16,
+    ) -> Result<(), Error> {
+        tcp.write_all(&proxy.socks5_greeting()).await?;
+        let mut method = [0; 2];
+        tcp.read_exact(&mut method).await?;
+        if proxy.socks5_verify_method(method)? {
+            tcp.write_all(&proxy.socks5_auth()).await?;
+            let mut status = [0; 2];
+            tcp.read_exact(&mut status).await?;
+            crate::Proxy::socks5_verify_auth(status)?;
+        }
+
+        tcp.write_all(&crate::Proxy::socks5_connect(host, port)?).await?;
+        let mut header = [0; 5];
+        tcp.read_exact(&mut header).await?;
+        // The bound address and port are not needed, but must be drained.
+        let mut rest = vec![0; crate::Proxy::socks5_verify_reply(header)?];
+        tcp.read_exact(&mut rest).await?;
+        Ok(())
+    }
+
     async fn timeout<O, F: Future<Output = O>>(timeout: Option<Instant>, f: F) -> Result<O, Error> {
         if let Some(time) = timeout {
             tokio::time::timeout_at(time.into(), f).await.map_err(|_| Error::IoError(timeout_err()))
@@ -675,7 +707,8 @@ impl Connection {
             return Err(Error::HttpsFeatureNotEnabled);
             #[cfg(feature = "rustls")]
             {
-                let tls = rustls_stream::wrap_stream(socket, params.host)?;
+                let tls =
+                    rustls_stream::wrap_stream(socket, params.host, params.root_certificates)?;
                 HttpStream::Secured(Box::new(tls), timeout_at)
             }
         } else {
@@ -730,6 +763,10 @@ impl Connection {
             Some(proxy) => {
                 // do proxy things
                 let mut tcp = Self::tcp_connect(&proxy.server, proxy.port, timeout_at)?;
+                if proxy.is_socks5() {
+                    Self::socks5_handshake(&mut tcp, proxy, params.host, params.port)?;
+                    return Ok(tcp);
+                }
 
                 write!(tcp, "{}", proxy.connect(params.host, params.port))?;
                 tcp.flush()?;
@@ -766,6 +803,33 @@ impl Connection {
         Self::tcp_connect(params.host, params.port, timeout_at)
     }
 
+    /// Asks the SOCKS5 `proxy` connected to by `tcp` to open a connection to `host:port`.
+    #[cfg(feature = "proxy")]
+    fn socks5_handshake(
+        tcp: &mut TcpStream,
+        proxy: &crate::Proxy,
+        host: &str,
+        port: u16,
+    ) -> Result<(), Error> {
+        tcp.write_all(&proxy.socks5_greeting())?;
+        let mut method = [0; 2];
+        tcp.read_exact(&mut method)?;
+        if proxy.socks5_verify_method(method)? {
+            tcp.write_all(&proxy.socks5_auth())?;
+            let mut status = [0; 2];
+            tcp.read_exact(&mut status)?;
+            crate::Proxy::socks5_verify_auth(status)?;
+        }
+
+        tcp.write_all(&crate::Proxy::socks5_connect(host, port)?)?;
+        let mut header = [0; 5];
+        tcp.read_exact(&mut header)?;
+        // The bound address and port are not needed, but must be drained.
+        let mut rest = vec![0; crate::Proxy::socks5_verify_reply(header)?];
+        tcp.read_exact(&mut rest)?;
+        Ok(())
+    }
+
     /// Sends the [`Request`](struct.Request.html), consumes this
     /// connection, and returns a [`Response`](struct.Response.html).
     pub(crate) fn send(mut self, request: ParsedRequest) -> Result<ResponseLazy, Error> {
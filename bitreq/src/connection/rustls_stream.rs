@@ -3,8 +3,12 @@
 
 #[cfg(feature = "rustls")]
 use alloc::sync::Arc;
+#[cfg(feature = "rustls")]
+use std::collections::HashMap;
 use std::io;
 use std::net::TcpStream;
+#[cfg(feature = "rustls")]
+use std::sync::Mutex;
 use std::sync::OnceLock;
 
 #[cfg(all(feature = "native-tls", not(feature = "rustls")))]
@@ -32,10 +36,30 @@ pub type SecuredStream = StreamOwned<ClientConnection, TcpStream>;
 #[cfg(feature = "rustls")]
 static CONFIG: OnceLock<Arc<ClientConfig>> = OnceLock::new();
 
+/// The configs built so far, keyed by the DER encoded extra roots they trust.
+#[cfg(feature = "rustls")]
+type ConfigCache = Mutex<HashMap<Vec<Vec<u8>>, Arc<ClientConfig>>>;
+
+/// The configs built for each set of extra roots, see [`client_config`].
+#[cfg(feature = "rustls")]
+static EXTRA_ROOTS_CONFIGS: OnceLock<ConfigCache> = OnceLock::new();
+
 #[cfg(feature = "rustls")]
 fn build_client_config() -> Arc<ClientConfig> {
+    build_client_config_with(&[]).expect("no extra roots to add")
+}
+
+/// Builds a config trusting the default roots plus the DER encoded `extra_roots`.
+///
+/// Fails with [`Error::InvalidRootCertificate`] if one of `extra_roots` is not a valid certificate.
+#[cfg(feature = "rustls")]
+fn build_client_config_with(extra_roots: &[Vec<u8>]) -> Result<Arc<ClientConfig>, Error> {
     let mut root_certificates = RootCertStore::empty();
 
+    for der in extra_roots {
+        root_certificates.add(der.clone().into()).map_err(Error::InvalidRootCertificate)?;
+    }
+
     #[cfg(feature = "https-rustls-probe")]
     for cert in rustls_native_certs::load_native_certs().certs {
         let _ = root_certificates.add(cert);
@@ -46,17 +70,38 @@ fn build_client_config() -> Arc<ClientConfig> {
 
     let config =
         ClientConfig::builder().with_root_certificates(root_certificates).with_no_client_auth();
-    Arc::new(config)
+    Ok(Arc::new(config))
 }
 
+/// Returns the config to use, building it on first use of each set of `extra_roots`.
 #[cfg(feature = "rustls")]
-pub(super) fn wrap_stream(tcp: TcpStream, host: &str) -> Result<SecuredStream, Error> {
+fn client_config(extra_roots: &[Vec<u8>]) -> Result<Arc<ClientConfig>, Error> {
+    if extra_roots.is_empty() {
+        return Ok(CONFIG.get_or_init(build_client_config).clone());
+    }
+
+    let configs = EXTRA_ROOTS_CONFIGS.get_or_init(Default::default);
+    let mut configs = configs.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(config) = configs.get(extra_roots) {
+        return Ok(config.clone());
+    }
+    let config = build_client_config_with(extra_roots)?;
+    configs.insert(extra_roots.to_vec(), config.clone());
+    Ok(config)
+}
+
+#[cfg(feature = "rustls")]
+pub(super) fn wrap_stream(
+    tcp: TcpStream,
+    host: &str,
+    extra_roots: &[Vec<u8>],
+) -> Result<SecuredStream, Error> {
     #[cfg(feature = "log")]
     log::trace!("Setting up TLS parameters for {host}.");
     let dns_name = ServerName::try_from(host)
         .map(|name| name.to_owned())
         .map_err(|err| Error::IoError(io::Error::new(io::ErrorKind::Other, err)))?;
-    let sess = ClientConnection::new(CONFIG.get_or_init(build_client_config).clone(), dns_name)
+    let sess = ClientConnection::new(client_config(extra_roots)?, dns_name)
         .map_err(Error::RustlsCreateConnection)?;
 
     #[cfg(feature = "log")]
@@ -73,6 +118,7 @@ pub type AsyncSecuredStream = TlsStream<tokio::net::TcpStream>;
 pub(super) async fn wrap_async_stream(
     tcp: AsyncTcpStream,
     host: &str,
+    extra_roots: &[Vec<u8>],
 ) -> Result<AsyncHttpStream, Error> {
     #[cfg(feature = "log")]
     log::trace!("Setting up TLS parameters for {host}.");
@@ -80,7 +126,7 @@ pub(super) async fn wrap_async_stream(
         .map(|name| name.to_owned())
         .map_err(|err| Error::IoError(io::Error::new(io::ErrorKind::Other, err)))?;
 
-    let connector = TlsConnector::from(CONFIG.get_or_init(build_client_config).clone());
+    let connector = TlsConnector::from(client_config(extra_roots)?);
 
     #[cfg(feature = "log")]
     log::trace!("Establishing TLS session to {host}.");
@@ -149,3 +195,15 @@ pub(super) async fn wrap_async_stream(
 
     Ok(AsyncHttpStream::Secured(Box::new(tls)))
 }
+
+#[cfg(test)]
+#[cfg(feature = "rustls")]
+mod tests {
+    use super::{client_config, Error};
+
+    #[test]
+    fn test_invalid_root_certificate() {
+        let err = client_config(&[vec![0x30, 0x03, 0x02, 0x01, 0x00]]).unwrap_err();
+        assert!(matches!(err, Error::InvalidRootCertificate(_)));
+    }
+}
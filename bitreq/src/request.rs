@@ -97,6 +97,8 @@ pub struct Request {
     max_redirects: usize,
     #[cfg(feature = "proxy")]
     pub(crate) proxy: Option<Proxy>,
+    #[cfg(feature = "rustls")]
+    pub(crate) root_certificates: Vec<Vec<u8>>,
 }
 
 impl Request {
@@ -127,6 +129,8 @@ impl Request {
             max_redirects: 100,
             #[cfg(feature = "proxy")]
             proxy: None,
+            #[cfg(feature = "rustls")]
+            root_certificates: Vec::new(),
         }
     }
 
@@ -268,6 +272,16 @@ impl Request {
         self
     }
 
+    /// Adds a DER encoded certificate to trust as a root when connecting over HTTPS.
+    ///
+    /// The certificate is trusted in addition to the default roots, useful when the server uses a
+    /// self-signed certificate or one issued by a private CA.
+    #[cfg(feature = "rustls")]
+    pub fn with_root_certificate<T: Into<Vec<u8>>>(mut self, der: T) -> Request {
+        self.root_certificates.push(der.into());
+        self
+    }
+
     /// Enables HTTP request pipelining for this request.
     ///
     /// Note that because pipelined requests may be replayed in case of failure, you should only
@@ -538,6 +552,8 @@ pub(crate) struct ConnectionParams<'a> {
     pub(crate) port: u16,
     #[cfg(feature = "proxy")]
     pub(crate) proxy: Option<&'a Proxy>,
+    #[cfg(feature = "rustls")]
+    pub(crate) root_certificates: &'a [Vec<u8>],
 }
 
 #[cfg(feature = "std")]
@@ -549,6 +565,8 @@ impl<'a> ConnectionParams<'a> {
             port: request.url.port(),
             #[cfg(feature = "proxy")]
             proxy: request.config.proxy.as_ref(),
+            #[cfg(feature = "rustls")]
+            root_certificates: &request.config.root_certificates,
         }
     }
 }
@@ -562,6 +580,8 @@ pub(crate) struct OwnedConnectionParams {
     pub(crate) port: u16,
     #[cfg(feature = "proxy")]
     pub(crate) proxy: Option<Proxy>,
+    #[cfg(feature = "rustls")]
+    pub(crate) root_certificates: Vec<Vec<u8>>,
 }
 
 #[cfg(feature = "std")]
@@ -570,6 +590,10 @@ impl PartialEq<ConnectionParams<'_>> for OwnedConnectionParams {
         if self.https != other.https || self.host != other.host || self.port != other.port {
             return false;
         }
+        #[cfg(feature = "rustls")]
+        if self.root_certificates != other.root_certificates {
+            return false;
+        }
         #[cfg(feature = "proxy")]
         {
             self.proxy.as_ref() == other.proxy
@@ -590,6 +614,8 @@ impl From<ConnectionParams<'_>> for OwnedConnectionParams {
             port: other.port,
             #[cfg(feature = "proxy")]
             proxy: other.proxy.cloned(),
+            #[cfg(feature = "rustls")]
+            root_certificates: other.root_certificates.to_vec(),
         }
     }
 }
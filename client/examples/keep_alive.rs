@@ -0,0 +1,66 @@
+//! Compares the time taken by sequential calls with and without keep-alive.
+//!
+//! Builds a client without and then one with keep-alive, and times `calls` (default 1000)
+//! sequential `getblockcount` calls made with each. Run against a running node, for example a
+//! regtest node started with `bitcoind -regtest`:
+//!
+//! ```text
+//! cargo run --release --example keep_alive --features client-sync -- \
+//!     http://127.0.0.1:18443 ~/.bitcoin/regtest/.cookie 1000
+//! ```
+//!
+//! The numbers below were not measured against a node. Three runs of a release build on a single
+//! core Linux VM, against a stub HTTP/1.1 server on loopback that answers each request immediately
+//! without doing any work, took:
+//!
+//! ```text
+//! keep-alive false: 1000 calls in 896ms to 963ms (about 920µs per call)
+//! keep-alive true : 1000 calls in 161ms to 199ms (about 185µs per call)
+//! ```
+//!
+//! That is each call took about a fifth of the time with keep-alive. The stub answering
+//! immediately over loopback leaves mostly the cost of opening a connection, against a node the
+//! time taken to answer is added to both and the saving per call grows with the round trip time.
+
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use corepc_client::client_sync::v17::Client;
+use corepc_client::client_sync::{Auth, Result};
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let (url, cookie) = match (args.next(), args.next()) {
+        (Some(url), Some(cookie)) => (url, PathBuf::from(cookie)),
+        _ => {
+            eprintln!("usage: keep_alive <url> <cookie file> [calls]");
+            std::process::exit(1);
+        }
+    };
+    let calls = args.next().and_then(|n| n.parse().ok()).unwrap_or(1000);
+
+    for keep_alive in [false, true] {
+        let client = Client::builder(&url)
+            .auth(Auth::CookieFile(cookie.clone()))
+            .keep_alive(keep_alive)
+            .build()?;
+        let elapsed = time_calls(&client, calls)?;
+        println!(
+            "keep-alive {:<5}: {} calls in {:?} ({:?} per call)",
+            keep_alive,
+            calls,
+            elapsed,
+            elapsed / calls
+        );
+    }
+    Ok(())
+}
+
+/// Returns the time taken to call `getblockcount` `calls` times.
+fn time_calls(client: &Client, calls: u32) -> Result<Duration> {
+    let start = Instant::now();
+    for _ in 0..calls {
+        client.get_block_count()?;
+    }
+    Ok(start.elapsed())
+}
@@ -0,0 +1,38 @@
+//! Makes calls using a `reqwest` client in place of the built-in HTTP transport.
+//!
+//! Run against a running node, for example a regtest node started with `bitcoind -regtest`:
+//!
+//! ```text
+//! cargo run --example reqwest --features transport-reqwest -- \
+//!     http://127.0.0.1:18443 ~/.bitcoin/regtest/.cookie
+//! ```
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use corepc_client::client_sync::v17::Client;
+use corepc_client::client_sync::{Auth, ReqwestTransport, Result};
+
+fn main() -> Result<()> {
+    let mut args = std::env::args().skip(1);
+    let (url, cookie) = match (args.next(), args.next()) {
+        (Some(url), Some(cookie)) => (url, PathBuf::from(cookie)),
+        _ => {
+            eprintln!("usage: reqwest <url> <cookie file>");
+            std::process::exit(1);
+        }
+    };
+
+    // Configure the `reqwest` client as the rest of the application does.
+    let http = reqwest::blocking::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .user_agent("corepc-example")
+        .build()
+        .expect("failed to build reqwest client");
+    let transport = ReqwestTransport::with_client(http, &url)?.auth(Auth::CookieFile(cookie))?;
+    let client = Client::with_transport(transport);
+
+    println!("block count: {}", client.get_block_count()?.0);
+    println!("best block: {}", client.get_best_block_hash()?.0);
+    Ok(())
+}
@@ -0,0 +1,379 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Async JSON-RPC clients for testing against specific versions of Bitcoin Core.
+//!
+//! Each version module exposes a `Client` with the same methods as the blocking client of the
+//! same version in `client_sync` but as `async fn`s. Both clients are implemented by the same
+//! `impl_client_vXX__*` macros, and share the argument, [`Auth`] and [`Error`] types.
+//!
+//! The client does not support the options of `client_sync::ClientBuilder`: calls are not
+//! retried, there is no middleware and a cookie file is only read once. Use
+//! `Client::with_transport` to configure the transport, known-slow methods (see
+//! [`method_timeout`]) then use the timeout of `transport`.
+//!
+//! [`method_timeout`]: crate::client_sync::method_timeout
+
+pub mod v17;
+pub mod v18;
+pub mod v19;
+pub mod v20;
+pub mod v21;
+pub mod v22;
+pub mod v23;
+pub mod v24;
+pub mod v25;
+pub mod v26;
+pub mod v27;
+pub mod v28;
+pub mod v29;
+pub mod v30;
+pub mod v31;
+
+pub use crate::client_sync::{Auth, Error, Result};
+
+/// Defines an async `jsonrpc::client_async::Client` using `bitreq`.
+#[macro_export]
+macro_rules! define_jsonrpc_bitreq_async_client {
+    ($version:literal) => {
+        use std::fmt;
+
+        use $crate::client_async::{log_response, Auth, Error, Result};
+
+        /// Client implements an async JSON-RPC client for the Bitcoin Core daemon or compatible APIs.
+        pub struct Client {
+            inner: jsonrpc::client_async::Client,
+            /// Builds the transport used for known-slow methods, `None` for a custom transport.
+            builder: Option<jsonrpc::bitreq_http_async::Builder>,
+        }
+
+        impl fmt::Debug for Client {
+            fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
+                write!(
+                    f,
+                    "corepc_client::client_async::{}::Client({:?})", $version, self.inner
+                )
+            }
+        }
+
+        impl Client {
+            /// Creates a client to a bitcoind JSON-RPC server without authentication.
+            pub fn new(url: &str) -> Self {
+                let builder = jsonrpc::bitreq_http_async::Builder::new()
+                    .url(url)
+                    .expect("jsonrpc v0.19, this function does not error")
+                    .timeout($crate::client_sync::DEFAULT_TIMEOUT);
+                Self::with_builder(builder)
+            }
+
+            /// Creates a client to a bitcoind JSON-RPC server with authentication.
+            pub fn new_with_auth(url: &str, auth: Auth) -> Result<Self> {
+                if matches!(auth, Auth::None) {
+                    return Err(Error::MissingUserPassword);
+                }
+                let (user, pass) = auth.get_user_pass()?;
+
+                let builder = jsonrpc::bitreq_http_async::Builder::new()
+                    .url(url)
+                    .expect("jsonrpc v0.19, this function does not error")
+                    .timeout($crate::client_sync::DEFAULT_TIMEOUT)
+                    .basic_auth(user.unwrap(), pass);
+                Ok(Self::with_builder(builder))
+            }
+
+            /// Creates a client that sends requests using `transport`.
+            ///
+            /// For example a `jsonrpc::bitreq_http_async::BitreqHttpTransport` with a custom
+            /// timeout or cookie authentication.
+            pub fn with_transport<T: jsonrpc::client_async::Transport>(transport: T) -> Self {
+                let inner = jsonrpc::client_async::Client::with_transport(transport);
+                Self { inner, builder: None }
+            }
+
+            fn with_builder(builder: jsonrpc::bitreq_http_async::Builder) -> Self {
+                let inner = jsonrpc::client_async::Client::with_transport(builder.clone().build());
+                Self { inner, builder: Some(builder) }
+            }
+
+            /// Call an RPC `method` with given `args` list.
+            ///
+            /// Known-slow methods (see [`method_timeout`]) use a longer timeout than the client
+            /// default.
+            ///
+            /// [`method_timeout`]: crate::client_sync::method_timeout
+            pub async fn call<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<T> {
+                let raw = serde_json::value::to_raw_value(args)?;
+                match $crate::client_sync::method_timeout(method) {
+                    Some(timeout) => self.call_with_timeout_raw(method, &raw, timeout).await,
+                    None => self.call_with_client(method, &raw, &self.inner).await,
+                }
+            }
+
+            /// Call an RPC `method` passing `args` by name.
+            ///
+            /// Arguments that are not passed take their default value, so unlike [`Self::call`] there
+            /// is no need to fill in the optional arguments preceding the ones being set.
+            pub async fn call_named<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[(&str, serde_json::Value)],
+            ) -> Result<T> {
+                let args = args
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.clone()))
+                    .collect::<serde_json::Map<_, _>>();
+                let raw = serde_json::value::to_raw_value(&args)?;
+                self.call_with_client(method, &raw, &self.inner).await
+            }
+
+            /// Call an RPC `method` with given `args` list, returning the result as untyped JSON.
+            ///
+            /// Use this for methods this client does not wrap, e.g., ones added in a newer release of
+            /// Bitcoin Core or by a patched node.
+            pub async fn call_json(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<serde_json::Value> {
+                self.call(method, args).await
+            }
+
+            /// Call an RPC `method` with given `args` list, aborting if no response arrives within `timeout`.
+            ///
+            /// The timeout only applies to this call. A client created with [`Self::with_transport`]
+            /// uses the timeout of its transport.
+            pub async fn call_with_timeout<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+                timeout: std::time::Duration,
+            ) -> Result<T> {
+                let raw = serde_json::value::to_raw_value(args)?;
+                self.call_with_timeout_raw(method, &raw, timeout).await
+            }
+
+            /// Calls `method` once for each of the `args` lists, sending the calls in a single batch
+            /// request.
+            ///
+            /// Returns the results in the same order as `args`.
+            pub async fn call_batch<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[Vec<serde_json::Value>],
+            ) -> Result<Vec<T>> {
+                if args.is_empty() {
+                    return Ok(vec![]);
+                }
+                let raws = args
+                    .iter()
+                    .map(serde_json::value::to_raw_value)
+                    .collect::<std::result::Result<Vec<_>, _>>()?;
+                let requests = raws
+                    .iter()
+                    .map(|raw| self.inner.build_request(method, Some(raw)))
+                    .collect::<Vec<_>>();
+                if log::log_enabled!(log::Level::Debug) {
+                    log::debug!(target: "corepc", "batch request: {} calls", requests.len());
+                }
+
+                let responses = self.inner.send_batch(&requests).await?;
+                responses
+                    .into_iter()
+                    .map(|resp| {
+                        let resp = resp.ok_or(Error::UnexpectedStructure)?;
+                        $crate::client_sync::deserialize_result(method, resp)
+                    })
+                    .collect()
+            }
+
+            async fn call_with_timeout_raw<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                raw: &serde_json::value::RawValue,
+                timeout: std::time::Duration,
+            ) -> Result<T> {
+                match self.builder {
+                    Some(ref builder) => {
+                        let transport = builder.clone().timeout(timeout).build();
+                        let client = jsonrpc::client_async::Client::with_transport(transport);
+                        self.call_with_client(method, raw, &client).await
+                    }
+                    None => self.call_with_client(method, raw, &self.inner).await,
+                }
+            }
+
+            async fn call_with_client<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                raw: &serde_json::value::RawValue,
+                client: &jsonrpc::client_async::Client,
+            ) -> Result<T> {
+                $crate::client_async::instrument(method, async {
+                    let req = client.build_request(&method, Some(raw));
+                    if log::log_enabled!(log::Level::Debug) {
+                        let params = $crate::redact::params(method, raw.get());
+                        log::debug!(target: "corepc", "request: {} {}", method, params);
+                    }
+
+                    let resp = client.send_request(req).await.map_err(Error::from);
+                    log_response(method, raw, &resp);
+                    $crate::client_sync::deserialize_result(method, resp?)
+                })
+                .await
+            }
+        }
+    }
+}
+
+/// Implements the methods `$items` on the async `Client` as `async fn`s.
+///
+/// Awaits the calls made on `self` in the body of each method. Calls must be at the top level of
+/// the body, calls nested within a block, a closure or an argument list would not be awaited and
+/// fail to compile:
+///
+/// ```compile_fail
+/// struct Client;
+///
+/// impl Client {
+///     async fn call(&self) -> u64 { 42 }
+/// }
+///
+/// corepc_client::impl_async_client_methods! {
+///     pub fn nested(&self) -> u64 { drop(self.call()); 0 }
+/// }
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_async_client_methods {
+    ($(
+        $(#[$attr:meta])*
+        pub fn $name:ident($($params:tt)*) -> $ret:ty { $($body:tt)* }
+    )*) => {
+        impl Client {
+            $(
+                $(#[$attr])*
+                pub async fn $name($($params)*) -> $ret {
+                    $crate::await_self_calls! { @top $($body)* }
+                    $crate::await_self_calls! { [] $($body)* }
+                }
+            )*
+        }
+    };
+}
+
+/// Expands to the statements in the input, appending `.await` to each call made on `self`.
+///
+/// With `@top`, expands to nothing if all the calls made on `self` are awaited and to a compile
+/// error otherwise.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! await_self_calls {
+    // Checks the calls at the top level, only a plain method call is awaited.
+    (@top) => {};
+    (@top self . $method:ident ($($args:tt)*) $($rest:tt)*) => {
+        $crate::await_self_calls! { @nested $($args)* }
+        $crate::await_self_calls! { @top $($rest)* }
+    };
+    (@top self . $method:ident :: $($rest:tt)*) => {
+        compile_error!(concat!("`self.", stringify!($method), "` with turbofish is not awaited"));
+    };
+    (@top $group:tt $($rest:tt)*) => {
+        $crate::await_self_calls! { @nested $group }
+        $crate::await_self_calls! { @top $($rest)* }
+    };
+    // Checks that there are no calls within blocks, closures and argument lists.
+    (@nested) => {};
+    (@nested self . $method:ident ($($args:tt)*) $($rest:tt)*) => {
+        compile_error!(concat!("`self.", stringify!($method), "` is not awaited, call it at the top level"));
+    };
+    (@nested self . $method:ident :: $($rest:tt)*) => {
+        compile_error!(concat!("`self.", stringify!($method), "` is not awaited, call it at the top level"));
+    };
+    (@nested ($($inner:tt)*) $($rest:tt)*) => {
+        $crate::await_self_calls! { @nested $($inner)* }
+        $crate::await_self_calls! { @nested $($rest)* }
+    };
+    (@nested [$($inner:tt)*] $($rest:tt)*) => {
+        $crate::await_self_calls! { @nested $($inner)* }
+        $crate::await_self_calls! { @nested $($rest)* }
+    };
+    (@nested {$($inner:tt)*} $($rest:tt)*) => {
+        $crate::await_self_calls! { @nested $($inner)* }
+        $crate::await_self_calls! { @nested $($rest)* }
+    };
+    (@nested $token:tt $($rest:tt)*) => {
+        $crate::await_self_calls! { @nested $($rest)* }
+    };
+    ([$($out:tt)*]) => { $($out)* };
+    // `self` is matched as an ident and compared in `@call` to keep the hygiene of the caller.
+    ([$($out:tt)*] $recv:ident . $method:ident ($($args:tt)*) $($rest:tt)*) => {
+        $crate::await_self_calls! { @call [$($out)*] $recv $recv.$method($($args)*) $($rest)* }
+    };
+    ([$($out:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::await_self_calls! { [$($out)* $token] $($rest)* }
+    };
+    (@call [$($out:tt)*] self $call:ident . $method:ident $args:tt $($rest:tt)*) => {
+        $crate::await_self_calls! { [$($out)* $call.$method $args .await] $($rest)* }
+    };
+    (@call [$($out:tt)*] $other:ident $call:ident . $method:ident $args:tt $($rest:tt)*) => {
+        $crate::await_self_calls! { [$($out)* $call.$method $args] $($rest)* }
+    };
+}
+
+/// Awaits `f`, making the call to `method` within a span if the `tracing` feature is enabled.
+#[cfg(feature = "tracing")]
+async fn instrument<T>(method: &str, f: impl std::future::Future<Output = Result<T>>) -> Result<T> {
+    use tracing::Instrument as _;
+
+    let span = crate::span::call(method);
+    let start = std::time::Instant::now();
+    let result = f.instrument(span.clone()).await;
+    let error_code = match result {
+        Err(Error::JsonRpc(jsonrpc::error::Error::Rpc(ref e))) => Some(e.code),
+        _ => None,
+    };
+    crate::span::record(&span, start, &result, error_code);
+    result
+}
+
+/// Awaits `f`, making the call to `method` within a span if the `tracing` feature is enabled.
+#[cfg(not(feature = "tracing"))]
+async fn instrument<T>(
+    _method: &str,
+    f: impl std::future::Future<Output = Result<T>>,
+) -> Result<T> {
+    f.await
+}
+
+/// Helper to log an RPC response.
+fn log_response(
+    method: &str,
+    params: &serde_json::value::RawValue,
+    resp: &Result<jsonrpc::Response>,
+) {
+    use log::Level::{Debug, Trace, Warn};
+
+    if log::log_enabled!(Warn) || log::log_enabled!(Debug) || log::log_enabled!(Trace) {
+        match resp {
+            Err(ref e) =>
+                if log::log_enabled!(Debug) {
+                    log::debug!(target: "corepc", "error: {}: {:?}", method, e);
+                },
+            Ok(ref resp) =>
+                if let Some(ref e) = resp.error {
+                    if log::log_enabled!(Debug) {
+                        log::debug!(target: "corepc", "response error for {}: {:?}", method, e);
+                    }
+                } else if log::log_enabled!(Trace) {
+                    let def =
+                        serde_json::value::to_raw_value(&serde_json::value::Value::Null).unwrap();
+                    let result = resp.result.as_ref().unwrap_or(&def);
+                    let result = crate::redact::result(method, params.get(), result.get());
+                    log::trace!(target: "corepc", "response for {}: {}", method, result);
+                },
+        }
+    }
+}
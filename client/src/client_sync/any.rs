@@ -0,0 +1,292 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A client that works with any supported version of Bitcoin Core.
+
+use bitcoin::address::NetworkChecked;
+use bitcoin::{Address, Amount, Block, BlockHash, Transaction, Txid};
+use types::model;
+
+use crate::client_sync::traits::into_model;
+use crate::client_sync::v17::{HashOrHeight, StatField};
+use crate::client_sync::{
+    Auth, BlockchainRpc, ClientBuilder, Error, NetworkRpc, RawTransactionsRpc, Result,
+    VersionedClient, WalletRpc,
+};
+
+/// Calls `$body` with the version specific client as `$client`.
+///
+/// If a list of variants is given, returns [`Error::UnsupportedByServer`] for any other version.
+macro_rules! dispatch {
+    ($self:ident, |$client:ident| $body:expr) => {
+        dispatch!(
+            $self,
+            "",
+            [V17, V18, V19, V20, V21, V22, V23, V24, V25, V26, V27, V28, V29, V30, V31],
+            |$client| $body
+        )
+    };
+    ($self:ident, $method:literal, [$($variant:ident),*], |$client:ident| $body:expr) => {
+        match &$self.0 {
+            $(VersionedClient::$variant($client) => $body,)*
+            #[allow(unreachable_patterns)]
+            _ => Err(Error::UnsupportedByServer {
+                method: $method,
+                major_version: $self.0.major_version(),
+            }),
+        }
+    };
+}
+
+/// A client exposing the methods common to all supported versions of Bitcoin Core.
+///
+/// Results are returned as the version nonspecific `model` types. Methods that were added in a
+/// later version return [`Error::UnsupportedByServer`] when the server is older. Implements the
+/// section traits e.g., [`WalletRpc`], use [`AnyClient::versioned`] to make other calls.
+#[derive(Debug)]
+pub struct AnyClient(VersionedClient);
+
+impl AnyClient {
+    /// Connects to the server at `url`, see [`VersionedClient::detect`].
+    pub fn detect(url: &str, auth: Auth) -> Result<Self> {
+        Ok(AnyClient(VersionedClient::detect(url, auth)?))
+    }
+
+    /// Connects to the server configured by `builder`, see [`VersionedClient::detect_with`].
+    pub fn detect_with<C>(builder: ClientBuilder<C>) -> Result<Self> {
+        Ok(AnyClient(VersionedClient::detect_with(builder)?))
+    }
+
+    /// Returns the version specific client.
+    pub fn versioned(&self) -> &VersionedClient { &self.0 }
+
+    /// Returns the major version of the server, e.g., 17 for v0.17.2 and 29 for v29.0.
+    pub fn major_version(&self) -> u32 { self.0.major_version() }
+
+    /// Gets the hash of the best (tip) block in the most-work fully-validated chain.
+    pub fn get_best_block_hash(&self) -> Result<model::GetBestBlockHash> {
+        BlockchainRpc::get_best_block_hash(self)
+    }
+
+    /// Gets the block with hash `hash`.
+    pub fn get_block(&self, hash: BlockHash) -> Result<Block> {
+        BlockchainRpc::get_block(self, hash)
+    }
+
+    /// Gets the number of blocks in the longest blockchain.
+    pub fn get_block_count(&self) -> Result<model::GetBlockCount> {
+        BlockchainRpc::get_block_count(self)
+    }
+
+    /// Gets the hash of the block at `height` in the best chain.
+    pub fn get_block_hash(&self, height: u64) -> Result<model::GetBlockHash> {
+        BlockchainRpc::get_block_hash(self, height)
+    }
+
+    /// Gets information about the state of the blockchain.
+    pub fn get_blockchain_info(&self) -> Result<model::GetBlockchainInfo> {
+        BlockchainRpc::get_blockchain_info(self)
+    }
+
+    /// Gets the chainstates, including the one validating the blocks below a loaded snapshot.
+    ///
+    /// Requires Bitcoin Core v26 or later.
+    pub fn get_chain_states(&self) -> Result<model::GetChainStates> {
+        dispatch!(self, "getchainstates", [V26, V27, V28, V29, V30, V31], |client| into_model(
+            client.get_chain_states()?.into_model()
+        ))
+    }
+
+    /// Gets the state of the deployments at the chain tip.
+    ///
+    /// Requires Bitcoin Core v23 or later.
+    pub fn get_deployment_info(&self) -> Result<model::GetDeploymentInfo> {
+        dispatch!(
+            self,
+            "getdeploymentinfo",
+            [V23, V24, V25, V26, V27, V28, V29, V30, V31],
+            |client| into_model(client.get_deployment_info_tip()?.into_model())
+        )
+    }
+
+    /// Gets the state of the deployments at the block with hash `block_hash`.
+    ///
+    /// Requires Bitcoin Core v23 or later.
+    pub fn get_deployment_info_at(
+        &self,
+        block_hash: &BlockHash,
+    ) -> Result<model::GetDeploymentInfo> {
+        dispatch!(
+            self,
+            "getdeploymentinfo",
+            [V23, V24, V25, V26, V27, V28, V29, V30, V31],
+            |client| into_model(client.get_deployment_info(block_hash)?.into_model())
+        )
+    }
+
+    /// Gets information about the state of the mempool.
+    pub fn get_mempool_info(&self) -> Result<model::GetMempoolInfo> {
+        BlockchainRpc::get_mempool_info(self)
+    }
+
+    /// Gets information about the node's P2P networking state.
+    pub fn get_network_info(&self) -> Result<model::GetNetworkInfo> {
+        NetworkRpc::get_network_info(self)
+    }
+
+    /// Gets the IDs of all transactions in the mempool.
+    pub fn get_raw_mempool(&self) -> Result<model::GetRawMempool> {
+        BlockchainRpc::get_raw_mempool(self)
+    }
+
+    /// Gets the IDs of all transactions in the mempool and the mempool sequence number.
+    ///
+    /// Requires Bitcoin Core v0.21 or later.
+    pub fn get_raw_mempool_sequence(&self) -> Result<model::GetRawMempoolSequence> {
+        dispatch!(
+            self,
+            "getrawmempool",
+            [V21, V22, V23, V24, V25, V26, V27, V28, V29, V30, V31],
+            |client| into_model(client.get_raw_mempool_sequence()?.into_model())
+        )
+    }
+
+    /// Dumps the mempool to disk, the file written is only returned by v23 and later.
+    pub fn save_mempool(&self) -> Result<model::SaveMempool> {
+        if self.major_version() < 23 {
+            dispatch!(self, "savemempool", [V17, V18, V19, V20, V21, V22], |client| {
+                client.save_mempool()?;
+                Ok(model::SaveMempool { filename: None })
+            })
+        } else {
+            dispatch!(
+                self,
+                "savemempool",
+                [V23, V24, V25, V26, V27, V28, V29, V30, V31],
+                |client| Ok(client.save_mempool()?.into_model())
+            )
+        }
+    }
+
+    /// Submits `tx` to the node and broadcasts it to the network.
+    pub fn send_raw_transaction(&self, tx: &Transaction) -> Result<model::SendRawTransaction> {
+        RawTransactionsRpc::send_raw_transaction(self, tx)
+    }
+}
+
+impl BlockchainRpc for AnyClient {
+    fn get_best_block_hash(&self) -> Result<model::GetBestBlockHash> {
+        dispatch!(self, |client| BlockchainRpc::get_best_block_hash(client))
+    }
+
+    fn get_block(&self, hash: BlockHash) -> Result<Block> {
+        dispatch!(self, |client| BlockchainRpc::get_block(client, hash))
+    }
+
+    fn get_block_count(&self) -> Result<model::GetBlockCount> {
+        dispatch!(self, |client| BlockchainRpc::get_block_count(client))
+    }
+
+    fn get_block_hash(&self, height: u64) -> Result<model::GetBlockHash> {
+        dispatch!(self, |client| BlockchainRpc::get_block_hash(client, height))
+    }
+
+    fn get_block_header(&self, hash: &BlockHash) -> Result<model::GetBlockHeader> {
+        dispatch!(self, |client| BlockchainRpc::get_block_header(client, hash))
+    }
+
+    fn get_block_stats(
+        &self,
+        block: HashOrHeight,
+        stats: Option<&[StatField]>,
+    ) -> Result<model::GetBlockStats> {
+        dispatch!(self, |client| BlockchainRpc::get_block_stats(client, block, stats))
+    }
+
+    fn get_blockchain_info(&self) -> Result<model::GetBlockchainInfo> {
+        dispatch!(self, |client| BlockchainRpc::get_blockchain_info(client))
+    }
+
+    fn get_chain_tips(&self) -> Result<model::GetChainTips> {
+        dispatch!(self, |client| BlockchainRpc::get_chain_tips(client))
+    }
+
+    fn get_difficulty(&self) -> Result<model::GetDifficulty> {
+        dispatch!(self, |client| BlockchainRpc::get_difficulty(client))
+    }
+
+    fn get_mempool_info(&self) -> Result<model::GetMempoolInfo> {
+        dispatch!(self, |client| BlockchainRpc::get_mempool_info(client))
+    }
+
+    fn get_mempool_entry(&self, txid: Txid) -> Result<model::GetMempoolEntry> {
+        dispatch!(self, |client| BlockchainRpc::get_mempool_entry(client, txid))
+    }
+
+    fn get_raw_mempool(&self) -> Result<model::GetRawMempool> {
+        dispatch!(self, |client| BlockchainRpc::get_raw_mempool(client))
+    }
+
+    fn get_tx_out(&self, txid: Txid, vout: u64) -> Result<model::GetTxOut> {
+        dispatch!(self, |client| BlockchainRpc::get_tx_out(client, txid, vout))
+    }
+}
+
+impl NetworkRpc for AnyClient {
+    fn get_network_info(&self) -> Result<model::GetNetworkInfo> {
+        dispatch!(self, |client| NetworkRpc::get_network_info(client))
+    }
+
+    fn get_peer_info(&self) -> Result<model::GetPeerInfo> {
+        dispatch!(self, |client| NetworkRpc::get_peer_info(client))
+    }
+
+    fn ping(&self) -> Result<()> { dispatch!(self, |client| NetworkRpc::ping(client)) }
+}
+
+impl RawTransactionsRpc for AnyClient {
+    fn get_raw_transaction(&self, txid: Txid) -> Result<model::GetRawTransaction> {
+        dispatch!(self, |client| RawTransactionsRpc::get_raw_transaction(client, txid))
+    }
+
+    fn send_raw_transaction(&self, tx: &Transaction) -> Result<model::SendRawTransaction> {
+        dispatch!(self, |client| RawTransactionsRpc::send_raw_transaction(client, tx))
+    }
+}
+
+impl WalletRpc for AnyClient {
+    fn get_balance(&self) -> Result<model::GetBalance> {
+        dispatch!(self, |client| WalletRpc::get_balance(client))
+    }
+
+    fn get_new_address(&self) -> Result<model::GetNewAddress> {
+        dispatch!(self, |client| WalletRpc::get_new_address(client))
+    }
+
+    fn get_transaction(&self, txid: Txid) -> Result<model::GetTransaction> {
+        dispatch!(self, |client| WalletRpc::get_transaction(client, txid))
+    }
+
+    fn get_wallet_info(&self) -> Result<model::GetWalletInfo> {
+        dispatch!(self, |client| WalletRpc::get_wallet_info(client))
+    }
+
+    fn list_unspent(&self) -> Result<model::ListUnspent> {
+        dispatch!(self, |client| WalletRpc::list_unspent(client))
+    }
+
+    fn list_wallets(&self) -> Result<model::ListWallets> {
+        dispatch!(self, |client| WalletRpc::list_wallets(client))
+    }
+
+    fn send_to_address(
+        &self,
+        address: &Address<NetworkChecked>,
+        amount: Amount,
+    ) -> Result<model::SendToAddress> {
+        dispatch!(self, |client| WalletRpc::send_to_address(client, address, amount))
+    }
+}
+
+impl From<VersionedClient> for AnyClient {
+    fn from(client: VersionedClient) -> Self { AnyClient(client) }
+}
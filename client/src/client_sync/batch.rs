@@ -0,0 +1,210 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Support for sending multiple calls as a single JSON-RPC batch request.
+
+use std::marker::PhantomData;
+
+use serde_json::value::RawValue;
+
+use crate::client_sync::{deserialize_result, Error, JsonRpcVersion, Result};
+
+/// A batch of JSON-RPC calls, sent to the server in a single HTTP request.
+///
+/// Create one with `Client::batch`, queue calls with [`Batch::push`] then send them with
+/// [`Batch::send`].
+pub struct Batch<'a> {
+    client: &'a jsonrpc::client::Client,
+    version: JsonRpcVersion,
+    calls: Vec<(String, Box<RawValue>)>,
+}
+
+impl<'a> Batch<'a> {
+    /// Creates an empty batch that will be sent using `client`.
+    pub(crate) fn new(client: &'a jsonrpc::client::Client, version: JsonRpcVersion) -> Self {
+        Batch { client, version, calls: vec![] }
+    }
+
+    /// Queues a call to `method` with given `args` list.
+    ///
+    /// The returned handle is used to get the typed result out of the [`BatchResponse`].
+    pub fn push<T>(&mut self, method: &str, args: &[serde_json::Value]) -> Result<BatchCall<T>> {
+        let raw = serde_json::value::to_raw_value(args)?;
+        self.calls.push((method.to_owned(), raw));
+        Ok(BatchCall { index: self.calls.len() - 1, marker: PhantomData })
+    }
+
+    /// Returns the number of queued calls.
+    pub fn len(&self) -> usize { self.calls.len() }
+
+    /// Returns true if no calls have been queued.
+    pub fn is_empty(&self) -> bool { self.calls.is_empty() }
+
+    /// Sends all queued calls in a single request.
+    pub fn send(self) -> Result<BatchResponse> {
+        if self.calls.is_empty() {
+            return Ok(BatchResponse { methods: vec![], responses: vec![] });
+        }
+
+        let requests = self
+            .calls
+            .iter()
+            .map(|(method, raw)| {
+                let mut req = self.client.build_request(method, Some(raw));
+                req.jsonrpc = Some(self.version.as_str());
+                req
+            })
+            .collect::<Vec<_>>();
+        if log::log_enabled!(log::Level::Debug) {
+            log::debug!(target: "corepc", "batch request: {} calls", requests.len());
+        }
+
+        let responses = self.client.send_batch(&requests)?;
+        let methods = self.calls.into_iter().map(|(method, _)| method).collect();
+        Ok(BatchResponse { methods, responses })
+    }
+}
+
+/// Handle to a call queued in a [`Batch`], used to get its result from the [`BatchResponse`].
+pub struct BatchCall<T> {
+    index: usize,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for BatchCall<T> {
+    fn clone(&self) -> Self { *self }
+}
+
+impl<T> Copy for BatchCall<T> {}
+
+/// The responses to a [`Batch`], in the same order the calls were queued.
+#[derive(Debug)]
+pub struct BatchResponse {
+    methods: Vec<String>,
+    responses: Vec<Option<jsonrpc::Response>>,
+}
+
+impl BatchResponse {
+    /// Takes the typed result of `call` out of the response.
+    ///
+    /// Errors with `UnexpectedStructure` if the server did not respond to the call, or if the
+    /// result has already been taken.
+    pub fn take<T>(&mut self, call: BatchCall<T>) -> Result<T>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        let resp = self.responses.get_mut(call.index).and_then(Option::take);
+        let resp = resp.ok_or(Error::UnexpectedStructure)?;
+        deserialize_result(&self.methods[call.index], resp)
+    }
+
+    /// Returns the result of every call, in order, deserialized as the same type `T`.
+    ///
+    /// Useful when the batch is many calls to the same method e.g., `getrawtransaction`.
+    pub fn into_results<T>(self) -> Result<Vec<T>>
+    where
+        T: for<'de> serde::de::Deserialize<'de>,
+    {
+        self.methods
+            .iter()
+            .zip(self.responses)
+            .map(|(method, resp)| {
+                deserialize_result(method, resp.ok_or(Error::UnexpectedStructure)?)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    use jsonrpc::{Request, Response};
+
+    use super::*;
+
+    /// A transport answering each call of a batch with its first argument, in reverse order.
+    #[derive(Clone, Default)]
+    struct EchoTransport {
+        /// The IDs of the requests sent, in order.
+        ids: Arc<Mutex<Vec<serde_json::Value>>>,
+        /// Whether to leave out the response to the last request.
+        drop_last: bool,
+    }
+
+    impl jsonrpc::Transport for EchoTransport {
+        fn send_request(&self, _: Request) -> std::result::Result<Response, jsonrpc::Error> {
+            unimplemented!("only batches are sent")
+        }
+
+        fn send_batch(
+            &self,
+            reqs: &[Request],
+        ) -> std::result::Result<Vec<Response>, jsonrpc::Error> {
+            let mut responses = Vec::new();
+            for req in reqs {
+                self.ids.lock().unwrap().push(req.id.clone());
+                let params: Vec<serde_json::Value> =
+                    serde_json::from_str(req.params.unwrap().get())?;
+                let result = serde_json::value::to_raw_value(&params[0])?;
+                let id = req.id.clone();
+                responses.push(Response { result: Some(result), error: None, id, jsonrpc: None });
+            }
+            if self.drop_last {
+                responses.pop();
+            }
+            responses.reverse();
+            Ok(responses)
+        }
+
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "echo") }
+    }
+
+    #[test]
+    fn requests_have_distinct_ids() {
+        let transport = EchoTransport::default();
+        let client = jsonrpc::client::Client::with_transport(transport.clone());
+        let mut batch = Batch::new(&client, JsonRpcVersion::V2);
+        for i in 0..3 {
+            batch.push::<u64>("getblockhash", &[i.into()]).unwrap();
+        }
+        batch.send().unwrap();
+
+        let ids = transport.ids.lock().unwrap().clone();
+        assert_eq!(ids.len(), 3);
+        let distinct = ids.iter().map(ToString::to_string).collect::<HashSet<_>>();
+        assert_eq!(distinct.len(), 3, "duplicate request IDs: {:?}", ids);
+    }
+
+    #[test]
+    fn responses_are_in_queued_order() {
+        let client = jsonrpc::client::Client::with_transport(EchoTransport::default());
+        let mut batch = Batch::new(&client, JsonRpcVersion::V2);
+        for i in 0..5 {
+            batch.push::<u64>("getblockhash", &[i.into()]).unwrap();
+        }
+        let results: Vec<u64> = batch.send().unwrap().into_results().unwrap();
+        assert_eq!(results, [0, 1, 2, 3, 4]);
+
+        let mut batch = Batch::new(&client, JsonRpcVersion::V2);
+        let first = batch.push::<String>("echo", &["first".into()]).unwrap();
+        let second = batch.push::<u64>("echo", &[2.into()]).unwrap();
+        let mut response = batch.send().unwrap();
+        assert_eq!(response.take(second).unwrap(), 2);
+        assert_eq!(response.take(first).unwrap(), "first");
+        assert!(matches!(response.take(first), Err(Error::UnexpectedStructure)));
+    }
+
+    #[test]
+    fn missing_response() {
+        let transport = EchoTransport { drop_last: true, ..Default::default() };
+        let client = jsonrpc::client::Client::with_transport(transport);
+        let mut batch = Batch::new(&client, JsonRpcVersion::V2);
+        let answered = batch.push::<u64>("echo", &[1.into()]).unwrap();
+        let dropped = batch.push::<u64>("echo", &[2.into()]).unwrap();
+        let mut response = batch.send().unwrap();
+        assert_eq!(response.take(answered).unwrap(), 1);
+        assert!(matches!(response.take(dropped), Err(Error::UnexpectedStructure)));
+    }
+}
@@ -0,0 +1,506 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A builder for configuring how a `Client` connects to the server.
+
+use std::fmt;
+#[cfg(feature = "client-sync")]
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[cfg(feature = "client-sync")]
+use jsonrpc::http::bitreq_http::{self, BitreqHttpTransport};
+#[cfg(feature = "client-sync")]
+use jsonrpc::http::simple_http::{self, SimpleHttpTransport};
+
+use crate::client_sync::stats::StatsRecorder;
+#[cfg(feature = "client-sync")]
+use crate::client_sync::{
+    wallet_path, Auth, Error, FailoverTransport, HttpTransport, Result, Transport,
+};
+use crate::client_sync::{Middleware, RetryPolicy};
+
+/// The default timeout for a JSON-RPC request.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// The default maximum size of a response, 1 GiB.
+pub const DEFAULT_MAX_RESPONSE_SIZE: usize = 1024 * 1024 * 1024;
+
+/// Builder for a version specific `Client`, create one with `Client::builder`.
+#[cfg(feature = "client-sync")]
+#[derive(Debug)]
+pub struct ClientBuilder<C> {
+    url: String,
+    fallback_urls: Vec<String>,
+    wallet: Option<String>,
+    auth: Auth,
+    timeout: Duration,
+    keep_alive: bool,
+    headers: Vec<(String, String)>,
+    max_response_size: usize,
+    #[cfg(feature = "tls")]
+    root_certificates: Vec<Vec<u8>>,
+    #[cfg(feature = "proxy")]
+    proxy: Option<Proxy>,
+    options: ClientOptions,
+    client: PhantomData<fn() -> C>,
+}
+
+// Not derived, which would require `C: Clone`.
+#[cfg(feature = "client-sync")]
+impl<C> Clone for ClientBuilder<C> {
+    fn clone(&self) -> Self {
+        ClientBuilder {
+            url: self.url.clone(),
+            fallback_urls: self.fallback_urls.clone(),
+            wallet: self.wallet.clone(),
+            auth: self.auth.clone(),
+            timeout: self.timeout,
+            keep_alive: self.keep_alive,
+            headers: self.headers.clone(),
+            max_response_size: self.max_response_size,
+            #[cfg(feature = "tls")]
+            root_certificates: self.root_certificates.clone(),
+            #[cfg(feature = "proxy")]
+            proxy: self.proxy.clone(),
+            options: self.options.clone(),
+            client: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "client-sync")]
+impl<C> ClientBuilder<C> {
+    /// Creates a builder for a client connecting to `url` without authentication.
+    pub(crate) fn new(url: &str) -> Self {
+        ClientBuilder {
+            url: url.to_owned(),
+            fallback_urls: Vec::new(),
+            wallet: None,
+            auth: Auth::None,
+            timeout: DEFAULT_TIMEOUT,
+            keep_alive: false,
+            headers: Vec::new(),
+            max_response_size: DEFAULT_MAX_RESPONSE_SIZE,
+            #[cfg(feature = "tls")]
+            root_certificates: Vec::new(),
+            #[cfg(feature = "proxy")]
+            proxy: None,
+            options: ClientOptions::default(),
+            client: PhantomData,
+        }
+    }
+
+    /// Sets the authentication method, defaults to [`Auth::None`].
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Adds a node to send requests to if connecting to the previously added ones fails.
+    ///
+    /// Requests that time out are not sent to the next node, see [`FailoverTransport`].
+    ///
+    /// May be called multiple times, nodes are tried in the order they were added, starting with
+    /// the `url` the builder was created with. All nodes use the same options, including the
+    /// credentials, so use [`Auth::UserPass`] with credentials set by `rpcauth` on every node. A
+    /// cookie file is only reloaded for the primary node.
+    pub fn fallback_url(mut self, url: &str) -> Self {
+        self.fallback_urls.push(url.to_owned());
+        self
+    }
+
+    /// Sends requests to the endpoint of the loaded wallet `name`, defaults to the node endpoint.
+    ///
+    /// Same as calling `Client::for_wallet` on the built client.
+    pub fn wallet(mut self, name: &str) -> Self {
+        self.wallet = Some(name.to_owned());
+        self
+    }
+
+    /// Sets the timeout after which a request is aborted, defaults to [`DEFAULT_TIMEOUT`].
+    ///
+    /// The timeout covers the whole request, from connecting to reading the last byte of the
+    /// response. Use `Client::call_with_timeout` to override it for a single call.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the maximum size of a response in bytes, defaults to [`DEFAULT_MAX_RESPONSE_SIZE`].
+    ///
+    /// Calls with a larger response fail with [`Error::ResponseTooLarge`] instead of buffering it,
+    /// e.g., `getrawmempool` with `verbose` set on a node with a large mempool.
+    pub fn max_response_size(mut self, max: usize) -> Self {
+        self.max_response_size = max;
+        self
+    }
+
+    /// Adds an HTTP header sent with each request, e.g., a token required by an API gateway in
+    /// front of the node.
+    ///
+    /// May be called multiple times to add several headers.
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_owned(), value.to_owned()));
+        self
+    }
+
+    /// Trusts the DER encoded CA certificate `der` when connecting to an `https://` URL.
+    ///
+    /// The certificate is trusted in addition to the default root certificates.
+    #[cfg(feature = "tls")]
+    pub fn root_certificate(mut self, der: Vec<u8>) -> Self {
+        self.root_certificates.push(der);
+        self
+    }
+
+    /// Routes requests through `proxy`, defaults to connecting directly.
+    ///
+    /// Use a [`Proxy::Socks5`] pointing at a Tor client to reach a node only listening on an onion
+    /// address.
+    #[cfg(feature = "proxy")]
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Reuses a single connection for sequential calls, defaults to opening one per call.
+    ///
+    /// Saves a TCP handshake per call, which adds up when making many calls e.g., `getblock` for
+    /// every block in the chain. Measured against a stub server on loopback that answers each
+    /// request immediately, not against a node, a call took about a fifth of the time. The saving
+    /// against a node is smaller relative to the time the node takes to answer, run the
+    /// `keep_alive` example to measure it. Only supported for `http://` URLs without a proxy.
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+
+    /// Sets the JSON-RPC version of the requests, defaults to [`JsonRpcVersion::V2`].
+    pub fn jsonrpc_version(mut self, version: JsonRpcVersion) -> Self {
+        self.options.jsonrpc_version = version;
+        self
+    }
+
+    /// Retries calls that fail with a transient error according to `policy`, defaults to no retry.
+    ///
+    /// Batches are never retried.
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.options.retry = Some(policy);
+        self
+    }
+
+    /// Returns a builder with the same options for a client of type `D`.
+    pub(crate) fn for_client<D>(self) -> ClientBuilder<D> {
+        ClientBuilder {
+            url: self.url,
+            fallback_urls: self.fallback_urls,
+            wallet: self.wallet,
+            auth: self.auth,
+            timeout: self.timeout,
+            keep_alive: self.keep_alive,
+            headers: self.headers,
+            max_response_size: self.max_response_size,
+            #[cfg(feature = "tls")]
+            root_certificates: self.root_certificates,
+            #[cfg(feature = "proxy")]
+            proxy: self.proxy,
+            options: self.options,
+            client: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "client-sync")]
+impl<C: FromTransport> ClientBuilder<C> {
+    /// Builds the client, reading the cookie file if [`Auth::CookieFile`] is used.
+    ///
+    /// The cookie changes each time the server restarts, so the client re-reads the cookie file
+    /// and retries once when a call fails because the server rejected the credentials.
+    pub fn build(self) -> Result<C> {
+        let (transport, options) = self.into_transport()?;
+        Ok(C::from_transport(transport, options))
+    }
+
+    /// Returns the transport and options the client is built with.
+    pub(crate) fn into_transport(mut self) -> Result<(HttpTransport, ClientOptions)> {
+        for (name, value) in &self.headers {
+            check_header(name, value)?;
+        }
+        let (user, pass) = self.auth.clone().get_user_pass()?;
+        if let Auth::CookieFile(ref path) = self.auth {
+            self.options.cookie_file = Some(path.clone());
+        }
+        let mut transport = self.http_transport(&self.url, &user, &pass)?;
+        if !self.fallback_urls.is_empty() {
+            let mut failover = FailoverTransport::new(transport);
+            for url in &self.fallback_urls {
+                failover = failover.fallback(self.http_transport(url, &user, &pass)?);
+            }
+            transport = HttpTransport::Failover(failover);
+        }
+        if let Some(ref wallet) = self.wallet {
+            transport = Transport::with_url_path(&transport, &wallet_path(wallet))
+                .ok_or(Error::UnsupportedByTransport("wallet endpoints"))?;
+        }
+        Ok((transport, self.options))
+    }
+
+    fn http_transport(
+        &self,
+        url: &str,
+        user: &Option<String>,
+        pass: &Option<String>,
+    ) -> Result<HttpTransport> {
+        let (user, pass) = (user.clone(), pass.clone());
+        if self.keep_alive {
+            Ok(HttpTransport::KeepAlive(self.keep_alive_transport(url, user, pass)?))
+        } else {
+            Ok(HttpTransport::Bitreq(self.bitreq_transport(url, user, pass)?))
+        }
+    }
+
+    fn bitreq_transport(
+        &self,
+        url: &str,
+        user: Option<String>,
+        pass: Option<String>,
+    ) -> Result<BitreqHttpTransport> {
+        let mut builder = bitreq_http::Builder::new()
+            .url(url)
+            .expect("jsonrpc v0.19, this function does not error")
+            .timeout(self.timeout)
+            .max_response_size(self.max_response_size);
+        if let Some(user) = user {
+            builder = builder.basic_auth(user, pass);
+        }
+        for (name, value) in &self.headers {
+            builder = builder.header(name.clone(), value.clone());
+        }
+        #[cfg(feature = "tls")]
+        for der in &self.root_certificates {
+            builder = builder.root_certificate(der.clone());
+        }
+        #[cfg(feature = "proxy")]
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone().into_bitreq()?);
+        }
+        Ok(builder.build())
+    }
+
+    fn keep_alive_transport(
+        &self,
+        url: &str,
+        user: Option<String>,
+        pass: Option<String>,
+    ) -> Result<SimpleHttpTransport> {
+        if url.starts_with("https://") {
+            return Err(Error::IncompatibleOptions("keep-alive does not support HTTPS"));
+        }
+        #[cfg(feature = "tls")]
+        if !self.root_certificates.is_empty() {
+            return Err(Error::IncompatibleOptions("keep-alive does not support HTTPS"));
+        }
+        #[cfg(feature = "proxy")]
+        if self.proxy.is_some() {
+            return Err(Error::IncompatibleOptions("keep-alive does not support a proxy"));
+        }
+
+        let mut builder = simple_http::Builder::new()
+            .url(url)
+            .map_err(jsonrpc::Error::from)?
+            .timeout(self.timeout)
+            .max_response_size(self.max_response_size as u64);
+        if let Some(user) = user {
+            builder = builder.auth(user, pass);
+        }
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        Ok(builder.build())
+    }
+}
+
+/// Checks that `name` is a valid HTTP header name and `value` does not contain a line break.
+#[cfg(feature = "client-sync")]
+fn check_header(name: &str, value: &str) -> Result<()> {
+    let valid_name = !name.is_empty()
+        && name.bytes().all(|b| b.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&b));
+    if !valid_name || value.bytes().any(|b| b == b'\r' || b == b'\n') {
+        return Err(Error::InvalidHeader(name.to_owned()));
+    }
+    Ok(())
+}
+
+/// A proxy to route requests to the server through.
+#[cfg(feature = "proxy")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Proxy {
+    /// A SOCKS5 proxy listening on `addr` (`host:port`), e.g., `127.0.0.1:9050` for Tor.
+    ///
+    /// The server's host name is resolved by the proxy, so onion addresses can be used in the URL.
+    Socks5 {
+        /// Address of the proxy.
+        addr: String,
+        /// Username and password to authenticate with the proxy, if it requires them.
+        ///
+        /// Tor uses these to isolate streams, any values are accepted.
+        auth: Option<(String, String)>,
+    },
+}
+
+#[cfg(feature = "proxy")]
+impl Proxy {
+    /// Converts to the proxy configuration used by the transport.
+    fn into_bitreq(self) -> Result<jsonrpc::bitreq::Proxy> {
+        let proxy = match self {
+            Proxy::Socks5 { addr, auth: None } => format!("socks5h://{}", addr),
+            Proxy::Socks5 { addr, auth: Some((user, pass)) } =>
+                format!("socks5h://{}:{}@{}", user, pass, addr),
+        };
+        jsonrpc::bitreq::Proxy::new_socks5(proxy).map_err(|_| Error::InvalidProxy)
+    }
+}
+
+/// The JSON-RPC version of the requests sent by a `Client`.
+///
+/// Bitcoin Core v28 and later follow the JSON-RPC 2.0 spec for 2.0 requests: a response contains
+/// either a result or an error, errors are returned with HTTP status 200 and notifications are
+/// supported. Older versions treat all requests as legacy 1.0 requests, the client handles the
+/// responses to both.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum JsonRpcVersion {
+    /// Legacy JSON-RPC 1.0, as used by Bitcoin Core before v28.
+    V1,
+    /// JSON-RPC 2.0.
+    #[default]
+    V2,
+}
+
+impl JsonRpcVersion {
+    /// Returns the value of the `jsonrpc` field of a request.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            JsonRpcVersion::V1 => "1.0",
+            JsonRpcVersion::V2 => "2.0",
+        }
+    }
+}
+
+/// Options that change how a `Client` makes calls, independent of the transport.
+#[doc(hidden)]
+#[derive(Clone, Default)]
+pub struct ClientOptions {
+    pub(crate) retry: Option<RetryPolicy>,
+    pub(crate) middleware: Vec<Arc<dyn Middleware>>,
+    pub(crate) stats: StatsRecorder,
+    pub(crate) cookie_file: Option<PathBuf>,
+    pub(crate) jsonrpc_version: JsonRpcVersion,
+}
+
+impl fmt::Debug for ClientOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ClientOptions")
+            .field("retry", &self.retry)
+            .field("middleware", &self.middleware.len())
+            .field("stats", &self.stats)
+            .field("cookie_file", &self.cookie_file)
+            .field("jsonrpc_version", &self.jsonrpc_version)
+            .finish()
+    }
+}
+
+/// Constructs a client from a configured transport.
+///
+/// Implemented for each version specific `Client` by `define_jsonrpc_bitreq_client!`.
+#[cfg(feature = "client-sync")]
+#[doc(hidden)]
+pub trait FromTransport {
+    /// Creates a client that sends requests using `transport`.
+    fn from_transport(transport: HttpTransport, options: ClientOptions) -> Self;
+}
+
+#[cfg(all(test, feature = "client-sync"))]
+mod tests {
+    use super::*;
+    use crate::client_sync::test_server::serve_one;
+    use crate::client_sync::v17::Client;
+
+    #[test]
+    fn timeout() {
+        let timeout = Duration::from_secs(5);
+        let (transport, _) = Client::builder("http://127.0.0.1:18443").into_transport().unwrap();
+        assert_eq!(Transport::timeout(&transport), Some(DEFAULT_TIMEOUT));
+
+        let builder = Client::builder("http://127.0.0.1:18443").timeout(timeout);
+        let (transport, _) = builder.into_transport().unwrap();
+        assert_eq!(Transport::timeout(&transport), Some(timeout));
+
+        let builder = Client::builder("http://127.0.0.1:18443").timeout(timeout).keep_alive(true);
+        let (transport, _) = builder.into_transport().unwrap();
+        assert!(matches!(transport, HttpTransport::KeepAlive(_)));
+        assert_eq!(Transport::timeout(&transport), Some(timeout));
+
+        // A timeout longer than the slow method timeout is kept.
+        let timeout = Duration::from_secs(30 * 60);
+        let builder = Client::builder("http://127.0.0.1:18443").timeout(timeout);
+        let (transport, _) = builder.into_transport().unwrap();
+        assert_eq!(crate::client_sync::slow_method_timeout(&transport, "dumptxoutset"), None);
+    }
+
+    #[test]
+    fn user_pass_auth_and_wallet_path() {
+        let (url, server) = serve_one(serde_json::json!(7));
+        let client = Client::builder(&url)
+            .auth(Auth::UserPass("user".to_owned(), "pass".to_owned()))
+            .wallet("my wallet")
+            .header("X-Api-Key", "secret")
+            .build()
+            .unwrap();
+        assert_eq!(client.call::<u64>("getblockcount", &[]).unwrap(), 7);
+
+        let head = server.join().unwrap();
+        assert!(head.starts_with("POST /wallet/my%20wallet HTTP/1.1\r\n"), "{}", head);
+        // The base64 encoding of `user:pass`.
+        assert!(head.contains("Authorization: Basic dXNlcjpwYXNz\r\n"), "{}", head);
+        assert!(head.contains("X-Api-Key: secret\r\n"), "{}", head);
+    }
+
+    #[test]
+    #[cfg(feature = "tls")]
+    fn root_certificate() {
+        use crate::client_sync::test_server::{ca_certificate, serve_one_tls};
+
+        let (url, server) = serve_one_tls(serde_json::json!(7));
+        let client = Client::builder(&url).root_certificate(ca_certificate()).build().unwrap();
+        assert_eq!(client.call::<u64>("getblockcount", &[]).unwrap(), 7);
+
+        let head = server.join().unwrap();
+        assert!(head.starts_with("POST / HTTP/1.1\r\n"), "{}", head);
+    }
+
+    #[test]
+    fn cookie_file_auth() {
+        let path =
+            std::env::temp_dir().join(format!("corepc-builder-{}.cookie", std::process::id()));
+        std::fs::write(&path, "__cookie__:secret\n").unwrap();
+        let builder =
+            Client::builder("http://127.0.0.1:18443").auth(Auth::CookieFile(path.clone()));
+        let (_, options) = builder.into_transport().unwrap();
+        assert_eq!(options.cookie_file, Some(path.clone()));
+
+        std::fs::remove_file(&path).unwrap();
+        let builder = Client::builder("http://127.0.0.1:18443").auth(Auth::CookieFile(path));
+        assert!(matches!(builder.into_transport(), Err(Error::Io(_))));
+    }
+
+    #[test]
+    fn invalid_options() {
+        let builder = Client::builder("http://127.0.0.1:18443").header("X-Api-Key", "a\r\nb");
+        assert!(matches!(builder.into_transport(), Err(Error::InvalidHeader(_))));
+
+        let builder = Client::builder("https://127.0.0.1:18443").keep_alive(true);
+        assert!(matches!(builder.into_transport(), Err(Error::IncompatibleOptions(_))));
+    }
+}
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Selecting the version specific `Client` for a server at runtime.
+
+use crate::client_sync::{
+    v17, v18, v19, v20, v21, v22, v23, v24, v25, v26, v27, v28, v29, v30, v31, Auth, ClientBuilder,
+    Error, Result,
+};
+
+/// Defines `VersionedClient` with a variant for each supported major version.
+macro_rules! define_versioned_client {
+    ($($variant:ident => $module:ident, $major:literal;)*) => {
+        /// A version specific `Client`, selected to match the server by [`VersionedClient::detect`].
+        ///
+        /// Lets tools work against whatever version of Bitcoin Core the user runs without picking
+        /// the version at compile time.
+        #[derive(Debug)]
+        pub enum VersionedClient {
+            $(
+                #[doc = concat!("A client for Bitcoin Core v", stringify!($major), ".")]
+                $variant($module::Client),
+            )*
+        }
+
+        impl VersionedClient {
+            /// Connects to the server at `url` and returns the client matching its version.
+            ///
+            /// The version is read from `getnetworkinfo`, any release of a supported major version
+            /// is accepted. Use [`Self::detect_with`] to configure the client.
+            pub fn detect(url: &str, auth: Auth) -> Result<Self> {
+                Self::detect_with(v17::Client::builder(url).auth(auth))
+            }
+
+            /// Connects to the server configured by `builder` and returns the client matching its
+            /// version, built with the options of `builder`.
+            ///
+            /// The version is probed with the same options, e.g., proxy and timeout, the client
+            /// type of `builder` does not matter.
+            ///
+            /// # Examples
+            ///
+            /// ```no_run
+            /// # use std::time::Duration;
+            /// # use corepc_client::client_sync::{v17, Auth, VersionedClient};
+            /// let builder = v17::Client::builder("http://127.0.0.1:8332")
+            ///     .auth(Auth::CookieFile("/home/user/.bitcoin/.cookie".into()))
+            ///     .timeout(Duration::from_secs(10));
+            /// let client = VersionedClient::detect_with(builder)?;
+            /// # Ok::<(), corepc_client::client_sync::Error>(())
+            /// ```
+            pub fn detect_with<C>(builder: ClientBuilder<C>) -> Result<Self> {
+                let probe = builder.clone().for_client::<v17::Client>().build()?;
+                let info: serde_json::Value = probe.call("getnetworkinfo", &[])?;
+                let version = info
+                    .get("version")
+                    .and_then(serde_json::Value::as_u64)
+                    .ok_or(Error::UnexpectedStructure)?;
+
+                match version / 10_000 {
+                    $($major => Ok(VersionedClient::$variant(
+                        builder.for_client::<$module::Client>().build()?,
+                    )),)*
+                    _ => Err(Error::UnsupportedServerVersion(version)),
+                }
+            }
+
+            /// Returns the major version of the server, e.g., 17 for v0.17.2 and 29 for v29.0.
+            pub fn major_version(&self) -> u32 {
+                match self {
+                    $(VersionedClient::$variant(_) => $major,)*
+                }
+            }
+        }
+    };
+}
+
+define_versioned_client! {
+    V17 => v17, 17;
+    V18 => v18, 18;
+    V19 => v19, 19;
+    V20 => v20, 20;
+    V21 => v21, 21;
+    V22 => v22, 22;
+    V23 => v23, 23;
+    V24 => v24, 24;
+    V25 => v25, 25;
+    V26 => v26, 26;
+    V27 => v27, 27;
+    V28 => v28, 28;
+    V29 => v29, 29;
+    V30 => v30, 30;
+    V31 => v31, 31;
+}
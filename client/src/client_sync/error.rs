@@ -4,6 +4,9 @@ use std::{error, fmt, io};
 
 use bitcoin::hex;
 
+#[cfg(feature = "client-sync")]
+use crate::client_sync::RestError;
+
 /// The error type for errors produced in this library.
 #[derive(Debug)]
 pub enum Error {
@@ -22,12 +25,71 @@ pub enum Error {
     ServerVersion(UnexpectedServerVersionError),
     /// Missing user/password.
     MissingUserPassword,
+    /// Failed to deserialize the result of a JSON-RPC call.
+    Deserialize(DeserializeError),
+    /// The proxy address or credentials are malformed.
+    InvalidProxy,
+    /// The client options cannot be used together.
+    IncompatibleOptions(&'static str),
+    /// The HTTP header name or value is malformed.
+    InvalidHeader(String),
+    /// The response exceeded the maximum size set with `ClientBuilder::max_response_size`.
+    ResponseTooLarge,
+    /// The transport does not support the requested feature.
+    UnsupportedByTransport(&'static str),
+    /// The server reported a version that no client supports.
+    UnsupportedServerVersion(u64),
+    /// The method is not available in the version of the server.
+    UnsupportedByServer {
+        /// The RPC method.
+        method: &'static str,
+        /// The major version of the server.
+        major_version: u32,
+    },
+    /// Failed to convert the result into a version nonspecific model type.
+    IntoModel(Box<dyn error::Error + Send + Sync>),
+    /// The call did not complete before its deadline, see `Client::call_with_deadline`.
+    DeadlineExceeded,
+    /// A request to the REST interface failed, see `RestClient`.
+    #[cfg(feature = "client-sync")]
+    Rest(RestError),
 }
 
 impl From<jsonrpc::error::Error> for Error {
-    fn from(e: jsonrpc::error::Error) -> Error { Error::JsonRpc(e) }
+    fn from(e: jsonrpc::error::Error) -> Error {
+        if is_response_too_large(&e) {
+            return Error::ResponseTooLarge;
+        }
+        Error::JsonRpc(e)
+    }
+}
+
+/// Returns true if `e` is a transport error caused by the response exceeding the maximum size.
+#[cfg(feature = "client-sync")]
+fn is_response_too_large(e: &jsonrpc::error::Error) -> bool {
+    use jsonrpc::http::{bitreq_http, simple_http};
+
+    let e = match e {
+        jsonrpc::error::Error::Transport(e) => e,
+        _ => return false,
+    };
+    if let Some(e) = e.downcast_ref::<simple_http::Error>() {
+        return matches!(
+            e,
+            simple_http::Error::HttpResponseContentLengthTooLarge { .. }
+                | simple_http::Error::HttpResponseTooLarge { .. }
+        );
+    }
+    matches!(
+        e.downcast_ref::<bitreq_http::Error>(),
+        Some(bitreq_http::Error::Bitreq(jsonrpc::bitreq::Error::BodyOverflow))
+    )
 }
 
+/// Only the built-in HTTP transports limit the size of a response.
+#[cfg(not(feature = "client-sync"))]
+fn is_response_too_large(_: &jsonrpc::error::Error) -> bool { false }
+
 impl From<hex::HexToArrayError> for Error {
     fn from(e: hex::HexToArrayError) -> Self { Self::HexToArray(e) }
 }
@@ -64,6 +126,19 @@ impl fmt::Display for Error {
             Returned(ref s) => write!(f, "the daemon returned an error string: {}", s),
             ServerVersion(ref e) => write!(f, "server version: {}", e),
             MissingUserPassword => write!(f, "missing user and/or password"),
+            Deserialize(ref e) => write!(f, "deserialize: {}", e),
+            InvalidProxy => write!(f, "the proxy address or credentials are malformed"),
+            IncompatibleOptions(s) => write!(f, "incompatible client options: {}", s),
+            InvalidHeader(ref name) => write!(f, "invalid HTTP header: {}", name),
+            ResponseTooLarge => write!(f, "the response exceeded the maximum size"),
+            UnsupportedByTransport(s) => write!(f, "not supported by the transport: {}", s),
+            UnsupportedServerVersion(v) => write!(f, "unsupported bitcoind version: {}", v),
+            UnsupportedByServer { method, major_version } =>
+                write!(f, "{} is not supported by bitcoind v{}", method, major_version),
+            IntoModel(ref e) => write!(f, "into model: {}", e),
+            DeadlineExceeded => write!(f, "the call did not complete before its deadline"),
+            #[cfg(feature = "client-sync")]
+            Rest(ref e) => write!(f, "REST: {}", e),
         }
     }
 }
@@ -80,9 +155,165 @@ impl error::Error for Error {
             BitcoinSerialization(ref e) => Some(e),
             Io(ref e) => Some(e),
             ServerVersion(ref e) => Some(e),
-            InvalidCookieFile | UnexpectedStructure | Returned(_) | MissingUserPassword => None,
+            Deserialize(ref e) => Some(e),
+            IntoModel(ref e) => Some(&**e),
+            #[cfg(feature = "client-sync")]
+            Rest(ref e) => Some(e),
+            InvalidCookieFile
+            | UnexpectedStructure
+            | Returned(_)
+            | MissingUserPassword
+            | InvalidProxy
+            | IncompatibleOptions(_)
+            | InvalidHeader(_)
+            | ResponseTooLarge
+            | UnsupportedByTransport(_)
+            | UnsupportedServerVersion(_)
+            | UnsupportedByServer { .. }
+            | DeadlineExceeded => None,
+        }
+    }
+}
+
+impl Error {
+    /// Returns the Bitcoin Core error code if the server responded with an RPC error.
+    pub fn rpc_code(&self) -> Option<RpcErrorCode> {
+        match self {
+            Error::JsonRpc(jsonrpc::error::Error::Rpc(e)) => Some(RpcErrorCode::from_code(e.code)),
+            _ => None,
         }
     }
+
+    /// Returns true if the node is still starting up (`RPC_IN_WARMUP`).
+    pub fn is_warmup(&self) -> bool { self.rpc_code() == Some(RpcErrorCode::InWarmup) }
+
+    /// Returns true if the server does not know the called method (`RPC_METHOD_NOT_FOUND`).
+    pub fn is_method_not_found(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::MethodNotFound)
+    }
+
+    /// Returns true if the requested wallet is not loaded (`RPC_WALLET_NOT_FOUND`).
+    pub fn is_wallet_not_found(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::WalletNotFound)
+    }
+
+    /// Returns true if the transaction or block was rejected by the node (`RPC_VERIFY_REJECTED`).
+    pub fn is_verify_rejected(&self) -> bool {
+        self.rpc_code() == Some(RpcErrorCode::VerifyRejected)
+    }
+}
+
+/// Defines `RpcErrorCode` with a variant for each error code.
+macro_rules! define_rpc_error_code {
+    ($($(#[$doc:meta])* $variant:ident = $code:literal,)*) => {
+        /// An error code returned by Bitcoin Core, as defined in `src/rpc/protocol.h`.
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum RpcErrorCode {
+            $($(#[$doc])* $variant,)*
+            /// A code not known to this library.
+            Other(i32),
+        }
+
+        impl RpcErrorCode {
+            /// Returns the error code for `code`.
+            pub fn from_code(code: i32) -> Self {
+                match code {
+                    $($code => RpcErrorCode::$variant,)*
+                    code => RpcErrorCode::Other(code),
+                }
+            }
+
+            /// Returns the numeric value of the error code.
+            pub fn code(self) -> i32 {
+                match self {
+                    $(RpcErrorCode::$variant => $code,)*
+                    RpcErrorCode::Other(code) => code,
+                }
+            }
+        }
+    };
+}
+
+define_rpc_error_code! {
+    /// `RPC_INVALID_REQUEST`: the request is not a valid JSON-RPC request.
+    InvalidRequest = -32600,
+    /// `RPC_METHOD_NOT_FOUND`: the method does not exist or is disabled.
+    MethodNotFound = -32601,
+    /// `RPC_INVALID_PARAMS`: the params are malformed.
+    InvalidParams = -32602,
+    /// `RPC_INTERNAL_ERROR`: an internal error occurred.
+    InternalError = -32603,
+    /// `RPC_PARSE_ERROR`: the request is not valid JSON.
+    ParseError = -32700,
+    /// `RPC_MISC_ERROR`: an exception was thrown while handling the request.
+    MiscError = -1,
+    /// `RPC_TYPE_ERROR`: an argument has an unexpected type.
+    TypeError = -3,
+    /// `RPC_INVALID_ADDRESS_OR_KEY`: the address, key or hash is invalid or unknown.
+    InvalidAddressOrKey = -5,
+    /// `RPC_OUT_OF_MEMORY`: ran out of memory during the operation.
+    OutOfMemory = -7,
+    /// `RPC_INVALID_PARAMETER`: an argument is invalid, missing or duplicated.
+    InvalidParameter = -8,
+    /// `RPC_DATABASE_ERROR`: a database error occurred.
+    DatabaseError = -20,
+    /// `RPC_DESERIALIZATION_ERROR`: failed to decode a transaction or block.
+    DeserializationError = -22,
+    /// `RPC_VERIFY_ERROR`: general error during transaction or block submission.
+    VerifyError = -25,
+    /// `RPC_VERIFY_REJECTED`: the transaction or block was rejected by the network rules.
+    VerifyRejected = -26,
+    /// `RPC_VERIFY_ALREADY_IN_UTXO_SET` (`RPC_VERIFY_ALREADY_IN_CHAIN` before v28): the
+    /// transaction is already confirmed.
+    VerifyAlreadyInUtxoSet = -27,
+    /// `RPC_IN_WARMUP`: the node is still starting up.
+    InWarmup = -28,
+    /// `RPC_METHOD_DEPRECATED`: the method is deprecated.
+    MethodDeprecated = -32,
+    /// `RPC_CLIENT_NOT_CONNECTED`: the node is not connected to any peers.
+    ClientNotConnected = -9,
+    /// `RPC_CLIENT_IN_INITIAL_DOWNLOAD`: the node is still downloading the initial blocks.
+    ClientInInitialDownload = -10,
+    /// `RPC_CLIENT_NODE_ALREADY_ADDED`: the node is already added.
+    ClientNodeAlreadyAdded = -23,
+    /// `RPC_CLIENT_NODE_NOT_ADDED`: the node has not been added.
+    ClientNodeNotAdded = -24,
+    /// `RPC_CLIENT_NODE_NOT_CONNECTED`: the node to disconnect is not connected.
+    ClientNodeNotConnected = -29,
+    /// `RPC_CLIENT_INVALID_IP_OR_SUBNET`: the IP address or subnet is invalid.
+    ClientInvalidIpOrSubnet = -30,
+    /// `RPC_CLIENT_P2P_DISABLED`: P2P networking is disabled.
+    ClientP2pDisabled = -31,
+    /// `RPC_CLIENT_MEMPOOL_DISABLED`: the mempool is disabled.
+    ClientMempoolDisabled = -33,
+    /// `RPC_CLIENT_NODE_CAPACITY_REACHED`: the maximum number of connections was reached.
+    ClientNodeCapacityReached = -34,
+    /// `RPC_WALLET_ERROR`: unspecified problem with the wallet.
+    WalletError = -4,
+    /// `RPC_WALLET_INSUFFICIENT_FUNDS`: not enough funds in the wallet or account.
+    WalletInsufficientFunds = -6,
+    /// `RPC_WALLET_INVALID_LABEL_NAME`: the label name is invalid.
+    WalletInvalidLabelName = -11,
+    /// `RPC_WALLET_KEYPOOL_RAN_OUT`: the keypool ran out.
+    WalletKeypoolRanOut = -12,
+    /// `RPC_WALLET_UNLOCK_NEEDED`: the wallet passphrase must be entered first.
+    WalletUnlockNeeded = -13,
+    /// `RPC_WALLET_PASSPHRASE_INCORRECT`: the wallet passphrase is incorrect.
+    WalletPassphraseIncorrect = -14,
+    /// `RPC_WALLET_WRONG_ENC_STATE`: the wallet is not in the required encryption state.
+    WalletWrongEncState = -15,
+    /// `RPC_WALLET_ENCRYPTION_FAILED`: failed to encrypt the wallet.
+    WalletEncryptionFailed = -16,
+    /// `RPC_WALLET_ALREADY_UNLOCKED`: the wallet is already unlocked.
+    WalletAlreadyUnlocked = -17,
+    /// `RPC_WALLET_NOT_FOUND`: the wallet does not exist or is not loaded.
+    WalletNotFound = -18,
+    /// `RPC_WALLET_NOT_SPECIFIED`: multiple wallets are loaded and none was specified.
+    WalletNotSpecified = -19,
+    /// `RPC_WALLET_ALREADY_LOADED`: the wallet is already loaded.
+    WalletAlreadyLoaded = -35,
+    /// `RPC_WALLET_ALREADY_EXISTS`: a wallet with the same name already exists.
+    WalletAlreadyExists = -36,
 }
 
 /// Error returned when RPC client expects a different version than bitcoind reports.
@@ -110,3 +341,79 @@ impl error::Error for UnexpectedServerVersionError {}
 impl From<UnexpectedServerVersionError> for Error {
     fn from(e: UnexpectedServerVersionError) -> Self { Self::ServerVersion(e) }
 }
+
+/// Error returned when the result of a JSON-RPC call does not match the expected type.
+#[derive(Debug)]
+pub struct DeserializeError {
+    /// The JSON-RPC method called.
+    pub method: String,
+    /// Path to the offending field within the result e.g., `vin[3].prevout.value`.
+    pub path: String,
+    /// The underlying deserialization error.
+    pub error: serde_json::Error,
+}
+
+impl fmt::Display for DeserializeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "failed to deserialize result of {} at {}: {}",
+            self.method, self.path, self.error
+        )
+    }
+}
+
+impl error::Error for DeserializeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> { Some(&self.error) }
+}
+
+impl From<DeserializeError> for Error {
+    fn from(e: DeserializeError) -> Self { Self::Deserialize(e) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpc_error(code: i32) -> Error {
+        let e = jsonrpc::error::RpcError { code, message: "error".to_owned(), data: None };
+        Error::JsonRpc(jsonrpc::error::Error::Rpc(e))
+    }
+
+    #[test]
+    fn rpc_error_code_from_code() {
+        assert_eq!(RpcErrorCode::from_code(-32601), RpcErrorCode::MethodNotFound);
+        assert_eq!(RpcErrorCode::from_code(-8), RpcErrorCode::InvalidParameter);
+        assert_eq!(RpcErrorCode::from_code(-27), RpcErrorCode::VerifyAlreadyInUtxoSet);
+        assert_eq!(RpcErrorCode::from_code(-28), RpcErrorCode::InWarmup);
+        assert_eq!(RpcErrorCode::from_code(-36), RpcErrorCode::WalletAlreadyExists);
+        assert_eq!(RpcErrorCode::from_code(-1000), RpcErrorCode::Other(-1000));
+        assert_eq!(RpcErrorCode::from_code(0), RpcErrorCode::Other(0));
+    }
+
+    #[test]
+    fn rpc_error_code_round_trips() {
+        for code in -32800..=0 {
+            assert_eq!(RpcErrorCode::from_code(code).code(), code);
+        }
+    }
+
+    #[test]
+    fn error_rpc_code() {
+        let err = rpc_error(-28);
+        assert_eq!(err.rpc_code(), Some(RpcErrorCode::InWarmup));
+        assert!(err.is_warmup());
+        assert!(!err.is_method_not_found());
+
+        let err = rpc_error(-8);
+        assert_eq!(err.rpc_code(), Some(RpcErrorCode::InvalidParameter));
+        assert!(!err.is_warmup());
+
+        assert!(rpc_error(-32601).is_method_not_found());
+        assert!(rpc_error(-18).is_wallet_not_found());
+        assert!(rpc_error(-26).is_verify_rejected());
+
+        assert_eq!(Error::UnexpectedStructure.rpc_code(), None);
+        assert!(!Error::UnexpectedStructure.is_warmup());
+    }
+}
@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Failing over to another node when the current one cannot be reached.
+
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use jsonrpc::{Request, Response};
+
+use crate::client_sync::retry::is_connect_error;
+use crate::client_sync::transport::DefaultTransport;
+use crate::client_sync::Transport;
+
+/// A transport that sends requests to the first of a prioritized list of nodes that can be reached.
+///
+/// Each request is sent using the transports in order, moving on to the next one only if
+/// connecting to the previous one failed, e.g., connection refused. Timeouts are returned as is
+/// since the node may have received the request, as are the errors returned by a node, including
+/// HTTP errors. Create one with `ClientBuilder::fallback_url` or pass one to
+/// `Client::with_transport`.
+///
+/// Each node keeps its own credentials. When the client reloads the cookie file after a node
+/// rejected the credentials, only the primary node is updated and only if it was the one that
+/// rejected them, so fallback nodes must be configured with `rpcauth` credentials.
+#[derive(Clone, Debug)]
+pub struct FailoverTransport<Tr = DefaultTransport> {
+    transports: Vec<Tr>,
+    /// The index of the transport that handled the last request, shared by all copies.
+    last: Arc<AtomicUsize>,
+}
+
+impl<Tr: Transport> FailoverTransport<Tr> {
+    /// Creates a transport sending requests using `primary`.
+    pub fn new(primary: Tr) -> Self {
+        FailoverTransport { transports: vec![primary], last: Arc::new(AtomicUsize::new(0)) }
+    }
+
+    /// Adds `transport` to use if all the previously added ones cannot be reached.
+    pub fn fallback(mut self, transport: Tr) -> Self {
+        self.transports.push(transport);
+        self
+    }
+
+    /// Calls `f` with each transport in order until it does not fail to connect.
+    fn send<T>(
+        &self,
+        mut f: impl FnMut(&Tr) -> Result<T, jsonrpc::Error>,
+    ) -> Result<T, jsonrpc::Error> {
+        let (last, transports) = self.transports.split_last().expect("there is a primary");
+        for (i, transport) in transports.iter().enumerate() {
+            match f(transport) {
+                Err(e) if is_connect_error(&e) => {
+                    log::debug!(target: "corepc", "failing over after error: {}", e);
+                }
+                result => {
+                    self.last.store(i, Ordering::Relaxed);
+                    return result;
+                }
+            }
+        }
+        self.last.store(transports.len(), Ordering::Relaxed);
+        f(last)
+    }
+
+    /// Returns a copy with `f` applied to each transport.
+    fn map(&self, f: impl FnMut(&Tr) -> Option<Tr>) -> Option<Self> {
+        Some(FailoverTransport {
+            transports: self.transports.iter().map(f).collect::<Option<_>>()?,
+            last: self.last.clone(),
+        })
+    }
+}
+
+impl<Tr: Transport> jsonrpc::Transport for FailoverTransport<Tr> {
+    fn send_request(&self, req: Request) -> Result<Response, jsonrpc::Error> {
+        self.send(|tp| tp.send_request(req.clone()))
+    }
+
+    fn send_batch(&self, reqs: &[Request]) -> Result<Vec<Response>, jsonrpc::Error> {
+        self.send(|tp| tp.send_batch(reqs))
+    }
+
+    fn send_notification(&self, req: Request) -> Result<(), jsonrpc::Error> {
+        self.send(|tp| tp.send_notification(req.clone()))
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, transport) in self.transports.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            transport.fmt_target(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<Tr: Transport> Transport for FailoverTransport<Tr> {
+    /// Returns the timeout of the primary node, all nodes use the same timeout.
+    fn timeout(&self) -> Option<Duration> { self.transports[0].timeout() }
+
+    fn with_timeout(&self, timeout: Duration) -> Self {
+        self.map(|tp| Some(tp.with_timeout(timeout))).expect("all transports are mapped")
+    }
+
+    fn with_new_connection(&self) -> Self {
+        self.map(|tp| Some(tp.with_new_connection())).expect("all transports are mapped")
+    }
+
+    fn with_url_path(&self, path: &str) -> Option<Self> { self.map(|tp| tp.with_url_path(path)) }
+
+    /// Sets the credentials of the primary node, if it handled the last request.
+    ///
+    /// Returns false if a fallback node handled it, since the credentials are those of the primary
+    /// node e.g., read from its cookie file.
+    fn set_basic_auth(&self, user: String, pass: Option<String>) -> bool {
+        if self.last.load(Ordering::Relaxed) != 0 {
+            return false;
+        }
+        self.transports[0].set_basic_auth(user, pass)
+    }
+}
+
+#[cfg(all(test, feature = "client-sync"))]
+mod tests {
+    use std::io;
+    use std::sync::Mutex;
+
+    use jsonrpc::http::simple_http;
+
+    use super::*;
+
+    /// A node answering each request with its name, or failing with `error`.
+    #[derive(Clone, Debug)]
+    struct Node {
+        name: &'static str,
+        error: Option<io::ErrorKind>,
+        /// The names of the nodes sent a request, shared by all nodes of a test.
+        sent: Arc<Mutex<Vec<&'static str>>>,
+        /// The user set with `set_basic_auth`.
+        user: Arc<Mutex<Option<String>>>,
+    }
+
+    impl jsonrpc::Transport for Node {
+        fn send_request(&self, req: Request) -> Result<Response, jsonrpc::Error> {
+            self.sent.lock().unwrap().push(self.name);
+            if let Some(kind) = self.error {
+                return Err(simple_http::Error::SocketError(kind.into()).into());
+            }
+            let result = serde_json::value::to_raw_value(self.name)?;
+            Ok(Response { result: Some(result), error: None, id: req.id, jsonrpc: None })
+        }
+
+        fn send_batch(&self, _: &[Request]) -> Result<Vec<Response>, jsonrpc::Error> {
+            unimplemented!("only requests are sent")
+        }
+
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.name) }
+    }
+
+    impl Transport for Node {
+        fn set_basic_auth(&self, user: String, _: Option<String>) -> bool {
+            *self.user.lock().unwrap() = Some(user);
+            true
+        }
+    }
+
+    /// Returns a failover transport over nodes named `a`, `b`, ... failing with `errors`.
+    fn failover(errors: &[Option<io::ErrorKind>]) -> (FailoverTransport<Node>, Vec<Node>) {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let names = ["a", "b", "c"];
+        let nodes: Vec<_> = errors
+            .iter()
+            .zip(names)
+            .map(|(&error, name)| Node { name, error, sent: sent.clone(), user: Arc::default() })
+            .collect();
+        let mut transport = FailoverTransport::new(nodes[0].clone());
+        for node in &nodes[1..] {
+            transport = transport.fallback(node.clone());
+        }
+        (transport, nodes)
+    }
+
+    fn send(transport: &FailoverTransport<Node>) -> Result<String, jsonrpc::Error> {
+        let req = Request { method: "getblockcount", params: None, id: 1.into(), jsonrpc: None };
+        let response = jsonrpc::Transport::send_request(transport, req)?;
+        Ok(serde_json::from_str(response.result.unwrap().get())?)
+    }
+
+    fn sent(nodes: &[Node]) -> Vec<&'static str> { nodes[0].sent.lock().unwrap().clone() }
+
+    const REFUSED: Option<io::ErrorKind> = Some(io::ErrorKind::ConnectionRefused);
+
+    #[test]
+    fn sends_to_primary() {
+        let (transport, nodes) = failover(&[None, None]);
+        assert_eq!(send(&transport).unwrap(), "a");
+        assert_eq!(sent(&nodes), ["a"]);
+    }
+
+    #[test]
+    fn fails_over_in_order() {
+        let (transport, nodes) = failover(&[REFUSED, REFUSED, None]);
+        assert_eq!(send(&transport).unwrap(), "c");
+        assert_eq!(sent(&nodes), ["a", "b", "c"]);
+
+        let (transport, nodes) = failover(&[REFUSED, REFUSED]);
+        assert!(send(&transport).is_err());
+        assert_eq!(sent(&nodes), ["a", "b"]);
+    }
+
+    #[test]
+    fn does_not_fail_over_on_timeout() {
+        let (transport, nodes) = failover(&[Some(io::ErrorKind::TimedOut), None]);
+        assert!(send(&transport).is_err());
+        assert_eq!(sent(&nodes), ["a"]);
+    }
+
+    #[test]
+    fn set_basic_auth_only_updates_primary() {
+        let (transport, nodes) = failover(&[None, None]);
+        send(&transport).unwrap();
+        assert!(transport.set_basic_auth("user".to_owned(), None));
+        assert_eq!(*nodes[0].user.lock().unwrap(), Some("user".to_owned()));
+        assert_eq!(*nodes[1].user.lock().unwrap(), None);
+
+        let (transport, nodes) = failover(&[REFUSED, None]);
+        send(&transport).unwrap();
+        assert!(!transport.set_basic_auth("user".to_owned(), None));
+        assert!(nodes.iter().all(|node| node.user.lock().unwrap().is_none()));
+    }
+}
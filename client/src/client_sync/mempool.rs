@@ -0,0 +1,192 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Tracking the transactions in the mempool using the ZMQ `sequence` notifications.
+
+use std::collections::{HashSet, VecDeque};
+use std::net::ToSocketAddrs;
+use std::time::Duration;
+use std::{error, fmt};
+
+use bitcoin::Txid;
+
+use crate::client_sync::{AnyClient, Error};
+use crate::zmq::{self, Event, SequenceEvent, Subscriber, Topic};
+
+/// A change to the mempool, yielded by [`MempoolTracker`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MempoolEvent {
+    /// The transaction was added to the mempool.
+    Added(Txid),
+    /// The transaction was removed from the mempool e.g., it was mined, replaced or evicted.
+    Removed(Txid),
+}
+
+/// A local copy of the set of transactions in the mempool of a node, kept up to date with the
+/// notifications published on the ZMQ `sequence` topic (`-zmqpubsequence`).
+///
+/// Starts with the mempool returned by `getrawmempool` with `mempool_sequence` set, and applies the
+/// notifications received after it. Transactions in connected blocks are removed, fetching each
+/// block from the node. If notifications were missed, e.g., because the node dropped them, the
+/// mempool is fetched again and the differences yielded as events.
+///
+/// Iterating over a tracker yields the changes to the mempool. Requires Bitcoin Core v0.21 or
+/// later.
+#[derive(Debug)]
+pub struct MempoolTracker<'a> {
+    client: &'a AnyClient,
+    subscriber: Subscriber,
+    txids: HashSet<Txid>,
+    /// The mempool sequence number of the last change applied.
+    mempool_sequence: u64,
+    /// The sequence number of the last notification received, to find missed notifications.
+    last_notification: Option<u32>,
+    pending: VecDeque<MempoolEvent>,
+}
+
+impl<'a> MempoolTracker<'a> {
+    /// Subscribes to the `sequence` topic at the ZMQ address `addr` e.g., `"127.0.0.1:28332"`,
+    /// and fetches the mempool using `client`.
+    ///
+    /// Changes made between the subscription and the node processing it may only be seen after
+    /// the next [`MempoolTracker::resync`].
+    pub fn connect<A: ToSocketAddrs>(
+        client: &'a AnyClient,
+        addr: A,
+    ) -> Result<Self, MempoolTrackerError> {
+        let subscriber = Subscriber::connect(addr, &[Topic::Sequence])?;
+        let mut tracker = MempoolTracker {
+            client,
+            subscriber,
+            txids: HashSet::new(),
+            mempool_sequence: 0,
+            last_notification: None,
+            pending: VecDeque::new(),
+        };
+        tracker.resync()?;
+        tracker.pending.clear();
+        Ok(tracker)
+    }
+
+    /// Returns the IDs of the transactions currently in the mempool.
+    pub fn mempool(&self) -> &HashSet<Txid> { &self.txids }
+
+    /// Returns the mempool sequence number of the last change applied.
+    pub fn mempool_sequence(&self) -> u64 { self.mempool_sequence }
+
+    /// Sets the timeout waiting for a notification, see [`Subscriber::set_read_timeout`].
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<(), MempoolTrackerError> {
+        Ok(self.subscriber.set_read_timeout(timeout)?)
+    }
+
+    /// Fetches the mempool from the node, queueing an event for each difference to the local copy.
+    pub fn resync(&mut self) -> Result<(), MempoolTrackerError> {
+        let snapshot = self.client.get_raw_mempool_sequence()?;
+        let txids: HashSet<Txid> = snapshot.txids.into_iter().collect();
+        self.pending.extend(self.txids.difference(&txids).map(|txid| MempoolEvent::Removed(*txid)));
+        self.pending.extend(txids.difference(&self.txids).map(|txid| MempoolEvent::Added(*txid)));
+        self.txids = txids;
+        self.mempool_sequence = snapshot.mempool_sequence;
+        Ok(())
+    }
+
+    /// Waits for and returns the next change to the mempool.
+    pub fn next_event(&mut self) -> Result<MempoolEvent, MempoolTrackerError> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(event);
+            }
+
+            let notification = self.subscriber.receive()?;
+            let missed = self
+                .last_notification
+                .is_some_and(|last| notification.sequence != last.wrapping_add(1));
+            self.last_notification = Some(notification.sequence);
+            if missed {
+                // The mempool fetched includes the change of this notification.
+                self.resync()?;
+                continue;
+            }
+            if let Event::Sequence(event) = notification.event {
+                self.apply(event)?;
+            }
+        }
+    }
+
+    /// Applies `event` to the local copy, queueing the resulting changes.
+    fn apply(&mut self, event: SequenceEvent) -> Result<(), MempoolTrackerError> {
+        match event {
+            // Changes with a sequence number not above the current one are already applied.
+            SequenceEvent::TxAdded { txid, mempool_sequence }
+                if mempool_sequence > self.mempool_sequence =>
+            {
+                self.mempool_sequence = mempool_sequence;
+                if self.txids.insert(txid) {
+                    self.pending.push_back(MempoolEvent::Added(txid));
+                }
+            }
+            SequenceEvent::TxRemoved { txid, mempool_sequence }
+                if mempool_sequence > self.mempool_sequence =>
+            {
+                self.mempool_sequence = mempool_sequence;
+                if self.txids.remove(&txid) {
+                    self.pending.push_back(MempoolEvent::Removed(txid));
+                }
+            }
+            // No notification is published for the transactions removed by a connected block,
+            // transactions returned to the mempool by a disconnected block are published as added.
+            SequenceEvent::BlockConnected(hash) => {
+                let block = self.client.get_block(hash)?;
+                for tx in &block.txdata {
+                    let txid = tx.compute_txid();
+                    if self.txids.remove(&txid) {
+                        self.pending.push_back(MempoolEvent::Removed(txid));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+impl Iterator for MempoolTracker<'_> {
+    type Item = Result<MempoolEvent, MempoolTrackerError>;
+
+    fn next(&mut self) -> Option<Self::Item> { Some(self.next_event()) }
+}
+
+/// An error returned by a [`MempoolTracker`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum MempoolTrackerError {
+    /// A call to the node failed.
+    Rpc(Error),
+    /// Receiving a notification failed.
+    Zmq(zmq::Error),
+}
+
+impl From<Error> for MempoolTrackerError {
+    fn from(e: Error) -> Self { MempoolTrackerError::Rpc(e) }
+}
+
+impl From<zmq::Error> for MempoolTrackerError {
+    fn from(e: zmq::Error) -> Self { MempoolTrackerError::Zmq(e) }
+}
+
+impl fmt::Display for MempoolTrackerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MempoolTrackerError::Rpc(e) => write!(f, "RPC: {}", e),
+            MempoolTrackerError::Zmq(e) => write!(f, "ZMQ: {}", e),
+        }
+    }
+}
+
+impl error::Error for MempoolTrackerError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            MempoolTrackerError::Rpc(e) => Some(e),
+            MempoolTrackerError::Zmq(e) => Some(e),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Hooks called around every request a `Client` sends.
+
+use std::time::Duration;
+
+use serde_json::value::RawValue;
+
+use crate::client_sync::{ClientOptions, Result};
+
+/// Intercepts the calls made by a `Client`, add one with `Client::with_middleware`.
+///
+/// Both hooks are called for each attempt when a call is retried. Calls sent in a batch are not
+/// intercepted.
+pub trait Middleware: Send + Sync {
+    /// Called before a request to `method` is sent, `params` may be modified in place.
+    fn on_request(&self, method: &str, params: &mut serde_json::Value) { let _ = (method, params); }
+
+    /// Called with the response to a request to `method`, received `elapsed` after it was sent.
+    fn on_response(&self, method: &str, response: &Result<jsonrpc::Response>, elapsed: Duration) {
+        let _ = (method, response, elapsed);
+    }
+}
+
+impl ClientOptions {
+    /// Runs the `on_request` hooks, returns the new params if there are any hooks.
+    pub(crate) fn on_request(
+        &self,
+        method: &str,
+        params: &RawValue,
+    ) -> Result<Option<Box<RawValue>>> {
+        if self.middleware.is_empty() {
+            return Ok(None);
+        }
+
+        let mut value = serde_json::from_str(params.get())?;
+        for middleware in &self.middleware {
+            middleware.on_request(method, &mut value);
+        }
+        Ok(Some(serde_json::value::to_raw_value(&value)?))
+    }
+
+    /// Runs the `on_response` hooks.
+    pub(crate) fn on_response(
+        &self,
+        method: &str,
+        response: &Result<jsonrpc::Response>,
+        elapsed: Duration,
+    ) {
+        for middleware in &self.middleware {
+            middleware.on_response(method, response, elapsed);
+        }
+    }
+}
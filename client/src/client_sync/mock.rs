@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A transport returning canned responses, for testing code that uses a `Client`.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use jsonrpc::error::RpcError;
+use jsonrpc::{Request, Response};
+
+use crate::client_sync::{RpcErrorCode, Transport};
+
+/// A transport that answers each method with a registered fixture instead of calling a server.
+///
+/// Create a client with `Client::with_transport(mock.clone())` for the version being tested, it
+/// has the same methods as a client connected to a node. Clones share fixtures and recorded
+/// calls, so the original can be used to set up and inspect the mock after the client is created.
+/// Calls to methods without a fixture fail with `RPC_METHOD_NOT_FOUND` (-32601).
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    fixtures: Arc<Mutex<HashMap<String, Result<serde_json::Value, RpcError>>>>,
+    calls: Arc<Mutex<Vec<(String, serde_json::Value)>>>,
+}
+
+impl MockTransport {
+    /// Creates a mock without any fixtures.
+    pub fn new() -> Self { MockTransport::default() }
+
+    /// Answers calls to `method` with `result`, replacing any previous fixture.
+    ///
+    /// `result` is the JSON the node would return e.g., `json!(42)` for `getblockcount`.
+    pub fn respond(&self, method: &str, result: serde_json::Value) {
+        self.lock_fixtures().insert(method.to_owned(), Ok(result));
+    }
+
+    /// Answers calls to `method` with the RPC error `code`, replacing any previous fixture.
+    pub fn respond_error(&self, method: &str, code: i32, message: &str) {
+        let error = RpcError { code, message: message.to_owned(), data: None };
+        self.lock_fixtures().insert(method.to_owned(), Err(error));
+    }
+
+    /// Returns the method and params of each call made so far, in the order they were made.
+    pub fn calls(&self) -> Vec<(String, serde_json::Value)> {
+        self.calls.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn lock_fixtures(
+        &self,
+    ) -> std::sync::MutexGuard<'_, HashMap<String, Result<serde_json::Value, RpcError>>> {
+        self.fixtures.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Records the call made by `req` and returns the response for its fixture.
+    fn respond_to(&self, req: &Request) -> Result<Response, jsonrpc::Error> {
+        let params = match req.params {
+            Some(params) => serde_json::from_str(params.get())?,
+            None => serde_json::Value::Null,
+        };
+        self.calls.lock().unwrap_or_else(|e| e.into_inner()).push((req.method.to_owned(), params));
+
+        let (result, error) = match self.lock_fixtures().get(req.method) {
+            Some(Ok(result)) => (Some(serde_json::value::to_raw_value(result)?), None),
+            Some(Err(error)) => (None, Some(error.clone())),
+            None => {
+                let message = "Method not found".to_owned();
+                let code = RpcErrorCode::MethodNotFound.code();
+                (None, Some(RpcError { code, message, data: None }))
+            }
+        };
+        Ok(Response { result, error, id: req.id.clone(), jsonrpc: req.jsonrpc.map(str::to_owned) })
+    }
+}
+
+impl jsonrpc::Transport for MockTransport {
+    fn send_request(&self, req: Request) -> Result<Response, jsonrpc::Error> {
+        self.respond_to(&req)
+    }
+
+    fn send_batch(&self, reqs: &[Request]) -> Result<Vec<Response>, jsonrpc::Error> {
+        reqs.iter().map(|req| self.respond_to(req)).collect()
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "mock") }
+}
+
+impl Transport for MockTransport {}
+
+impl fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MockTransport")
+            .field("methods", &self.lock_fixtures().keys().collect::<Vec<_>>())
+            .field("calls", &self.calls().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::client_sync::v17::Client;
+    use crate::client_sync::Error;
+
+    #[test]
+    fn responds_with_fixtures() {
+        let hash = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+        let mock = MockTransport::new();
+        mock.respond("getblockhash", json!(hash));
+        mock.respond_error("getblockcount", -28, "Loading block index...");
+
+        let client = Client::with_transport(mock.clone());
+        assert_eq!(client.get_block_hash(0).unwrap().0, hash);
+
+        match client.get_block_count() {
+            Err(Error::JsonRpc(jsonrpc::Error::Rpc(e))) => assert_eq!(e.code, -28),
+            res => panic!("unexpected result: {:?}", res),
+        }
+        match client.get_difficulty() {
+            Err(Error::JsonRpc(jsonrpc::Error::Rpc(e))) => assert_eq!(e.code, -32601),
+            res => panic!("unexpected result: {:?}", res),
+        }
+
+        let methods: Vec<_> = mock.calls().into_iter().map(|(method, _)| method).collect();
+        assert_eq!(methods, ["getblockhash", "getblockcount", "getdifficulty"]);
+        assert_eq!(mock.calls()[0].1, json!([0]));
+    }
+
+    #[test]
+    fn respond_replaces_fixture() {
+        let mock = MockTransport::new();
+        mock.respond_error("getblockcount", -28, "Loading block index...");
+        mock.respond("getblockcount", json!(42));
+
+        let client = Client::with_transport(mock);
+        assert_eq!(client.get_block_count().unwrap().0, 42);
+    }
+}
@@ -2,7 +2,31 @@
 
 //! JSON-RPC clients for testing against specific versions of Bitcoin Core.
 
+#[cfg(feature = "client-sync")]
+mod any;
+mod batch;
+mod builder;
+#[cfg(feature = "client-sync")]
+mod detect;
 mod error;
+mod failover;
+#[cfg(all(feature = "zmq", feature = "client-sync"))]
+mod mempool;
+mod middleware;
+mod mock;
+mod pipeline;
+mod record;
+mod replace;
+#[cfg(feature = "transport-reqwest")]
+mod reqwest_http;
+#[cfg(feature = "client-sync")]
+mod rest;
+mod retry;
+mod stats;
+#[cfg(test)]
+mod test_server;
+mod traits;
+mod transport;
 pub mod v17;
 pub mod v18;
 pub mod v19;
@@ -18,12 +42,52 @@ pub mod v28;
 pub mod v29;
 pub mod v30;
 pub mod v31;
+pub mod wallet;
+mod watcher;
 
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
-pub use crate::client_sync::error::Error;
+#[cfg(feature = "client-sync")]
+pub use crate::client_sync::any::AnyClient;
+pub use crate::client_sync::batch::{Batch, BatchCall, BatchResponse};
+#[cfg(feature = "proxy")]
+pub use crate::client_sync::builder::Proxy;
+#[cfg(feature = "client-sync")]
+pub use crate::client_sync::builder::{ClientBuilder, FromTransport};
+pub use crate::client_sync::builder::{
+    ClientOptions, JsonRpcVersion, DEFAULT_MAX_RESPONSE_SIZE, DEFAULT_TIMEOUT,
+};
+#[cfg(feature = "client-sync")]
+pub use crate::client_sync::detect::VersionedClient;
+pub use crate::client_sync::error::{
+    DeserializeError, Error, RpcErrorCode, UnexpectedServerVersionError,
+};
+pub use crate::client_sync::failover::FailoverTransport;
+#[cfg(all(feature = "zmq", feature = "client-sync"))]
+pub use crate::client_sync::mempool::{MempoolEvent, MempoolTracker, MempoolTrackerError};
+pub use crate::client_sync::middleware::Middleware;
+pub use crate::client_sync::mock::MockTransport;
+use crate::client_sync::pipeline::run_concurrently;
+pub use crate::client_sync::record::RecordReplayTransport;
+pub use crate::client_sync::replace::{build_replacement, Replacement, ReplacementError};
+#[cfg(feature = "transport-reqwest")]
+pub use crate::client_sync::reqwest_http::{ReqwestError, ReqwestTransport};
+#[cfg(feature = "client-sync")]
+pub use crate::client_sync::rest::{GetUtxos, RestClient, RestError, Utxo};
+pub use crate::client_sync::retry::RetryPolicy;
+use crate::client_sync::retry::{poll_until_ready, with_cookie_reload, with_retry};
+pub use crate::client_sync::stats::{ClientStats, LatencyHistogram, MethodStats, LATENCY_BUCKETS};
+pub use crate::client_sync::traits::{
+    BlockchainRpc, DescriptorWalletRpc, NetworkRpc, RawTransactionsRpc, WalletRpc,
+};
+use crate::client_sync::transport::DefaultTransport;
+#[cfg(feature = "client-sync")]
+pub use crate::client_sync::transport::HttpTransport;
+pub use crate::client_sync::transport::Transport;
+pub use crate::client_sync::watcher::BlockWatcher;
 
 /// Crate-specific Result type.
 ///
@@ -62,15 +126,23 @@ macro_rules! define_jsonrpc_bitreq_client {
     ($version:literal) => {
         use std::fmt;
 
-        use $crate::client_sync::{log_response, Auth, Result};
+        use $crate::client_sync::{log_response, Result};
         use $crate::client_sync::error::Error;
 
         /// Client implements a JSON-RPC client for the Bitcoin Core daemon or compatible APIs.
-        pub struct Client {
+        ///
+        /// Requests are sent using `bitreq` by default, or a `ReqwestTransport` if only the
+        /// `transport-reqwest` feature is enabled. Use [`Client::with_transport`] to send them with
+        /// any other [`Transport`].
+        ///
+        /// [`Transport`]: crate::client_sync::Transport
+        pub struct Client<Tr = $crate::client_sync::DefaultTransport> {
             inner: jsonrpc::client::Client,
+            transport: Tr,
+            options: $crate::client_sync::ClientOptions,
         }
 
-        impl fmt::Debug for Client {
+        impl<Tr> fmt::Debug for Client<Tr> {
             fn fmt(&self, f: &mut fmt::Formatter) -> core::fmt::Result {
                 write!(
                     f,
@@ -79,57 +151,303 @@ macro_rules! define_jsonrpc_bitreq_client {
             }
         }
 
+        #[cfg(feature = "client-sync")]
+        impl $crate::client_sync::FromTransport for Client {
+            fn from_transport(
+                transport: $crate::client_sync::HttpTransport,
+                options: $crate::client_sync::ClientOptions,
+            ) -> Self {
+                let inner = jsonrpc::client::Client::with_transport(transport.clone());
+                Self { inner, transport, options }
+            }
+        }
+
+        #[cfg(feature = "client-sync")]
         impl Client {
             /// Creates a client to a bitcoind JSON-RPC server without authentication.
             pub fn new(url: &str) -> Self {
-                let transport = jsonrpc::http::bitreq_http::Builder::new()
-                    .url(url)
-                    .expect("jsonrpc v0.19, this function does not error")
-                    .timeout(std::time::Duration::from_secs(60))
-                    .build();
-                let inner = jsonrpc::client::Client::with_transport(transport);
-
-                Self { inner }
+                Self::builder(url).build().expect("Auth::None does not error")
             }
 
             /// Creates a client to a bitcoind JSON-RPC server with authentication.
-            pub fn new_with_auth(url: &str, auth: Auth) -> Result<Self> {
-                if matches!(auth, Auth::None) {
+            pub fn new_with_auth(url: &str, auth: $crate::client_sync::Auth) -> Result<Self> {
+                if matches!(auth, $crate::client_sync::Auth::None) {
                     return Err(Error::MissingUserPassword);
                 }
-                let (user, pass) = auth.get_user_pass()?;
+                Self::builder(url).auth(auth).build()
+            }
 
-                let transport = jsonrpc::http::bitreq_http::Builder::new()
-                    .url(url)
-                    .expect("jsonrpc v0.19, this function does not error")
-                    .timeout(std::time::Duration::from_secs(60))
-                    .basic_auth(user.unwrap(), pass)
-                    .build();
-                let inner = jsonrpc::client::Client::with_transport(transport);
+            /// Returns a builder for a client to a bitcoind JSON-RPC server at `url`.
+            pub fn builder(url: &str) -> $crate::client_sync::ClientBuilder<Self> {
+                $crate::client_sync::ClientBuilder::new(url)
+            }
+        }
+
+        impl<Tr: $crate::client_sync::Transport> Client<Tr> {
+            /// Creates a client that sends requests using `transport`.
+            pub fn with_transport(transport: Tr) -> Self {
+                let inner = jsonrpc::client::Client::with_transport(transport.clone());
+                Self { inner, transport, options: Default::default() }
+            }
+
+            /// Returns a client for the loaded wallet `name`, sending requests to `/wallet/<name>`.
+            ///
+            /// Wallet calls must be sent to a wallet endpoint when more than one wallet is loaded.
+            /// The returned client shares the retry policy, middleware and statistics of this one.
+            pub fn for_wallet(&self, name: &str) -> Result<Self> {
+                let path = $crate::client_sync::wallet_path(name);
+                let transport = $crate::client_sync::Transport::with_url_path(&self.transport, &path)
+                    .ok_or(Error::UnsupportedByTransport("wallet endpoints"))?;
+                let inner = jsonrpc::client::Client::with_transport(transport.clone());
+                Ok(Self { inner, transport, options: self.options.clone() })
+            }
 
-                Ok(Self { inner })
+            /// Adds `middleware` to intercept the calls made by this client.
+            ///
+            /// Middleware is called in the order it was added.
+            pub fn with_middleware(
+                mut self,
+                middleware: impl $crate::client_sync::Middleware + 'static,
+            ) -> Self {
+                self.options.middleware.push(std::sync::Arc::new(middleware));
+                self
+            }
+
+            /// Returns the per-method statistics of the calls made by this client so far.
+            ///
+            /// Calls sent in a batch are not included.
+            pub fn stats(&self) -> $crate::client_sync::ClientStats { self.options.stats.snapshot() }
+
+            /// Clears the statistics returned by [`Self::stats`].
+            pub fn reset_stats(&self) { self.options.stats.reset() }
+
+            /// Returns a new batch, calls queued on it are sent in a single JSON-RPC request.
+            pub fn batch(&self) -> $crate::client_sync::Batch<'_> {
+                $crate::client_sync::Batch::new(&self.inner, self.options.jsonrpc_version)
+            }
+
+            /// Waits for the server to be ready to handle calls, polling `getblockchaininfo`.
+            ///
+            /// Retries while the server cannot be reached or is still starting up e.g., responds
+            /// with `RPC_IN_WARMUP` (-28) while loading the block index. Returns the last error if
+            /// the server is not ready after `timeout`, other errors are returned immediately.
+            pub fn wait_for_ready(&self, timeout: std::time::Duration) -> Result<()> {
+                $crate::client_sync::poll_until_ready(timeout, || {
+                    self.call::<serde_json::Value>("getblockchaininfo", &[]).map(|_| ())
+                })
+            }
+
+            /// Calls `method` once for each of the `args` lists, sending the calls in a single batch
+            /// request.
+            ///
+            /// Returns the results in the same order as `args`, see [`Self::batch`] to queue calls to
+            /// different methods.
+            pub fn call_batch<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[Vec<serde_json::Value>],
+            ) -> Result<Vec<T>> {
+                let mut batch = self.batch();
+                for args in args {
+                    batch.push::<T>(method, args)?;
+                }
+                batch.send()?.into_results()
+            }
+
+            /// Calls `method` once for each of the `args` lists, sending up to `connections` calls
+            /// concurrently.
+            ///
+            /// Each call is sent on its own request over one of `connections` connections, unlike a
+            /// [`Self::batch`] which the server handles sequentially. Returns the results in the
+            /// same order as `args`, e.g., to fetch many block headers with `getblockheader`.
+            pub fn pipeline<T>(
+                &self,
+                method: &str,
+                args: &[Vec<serde_json::Value>],
+                connections: usize,
+            ) -> Vec<Result<T>>
+            where
+                T: for<'a> serde::de::Deserialize<'a> + std::marker::Send,
+                Tr: std::marker::Send + std::marker::Sync,
+            {
+                let new_transport = || {
+                    let transport =
+                        $crate::client_sync::Transport::with_new_connection(&self.transport);
+                    match $crate::client_sync::slow_method_timeout(&transport, method) {
+                        Some(timeout) =>
+                            $crate::client_sync::Transport::with_timeout(&transport, timeout),
+                        None => transport,
+                    }
+                };
+                $crate::client_sync::run_concurrently(args.len(), connections, new_transport, |transport, i| {
+                    let raw = serde_json::value::to_raw_value(&args[i])?;
+                    self.observe(method, || self.call_with_transport(method, &raw, transport, None))
+                })
             }
 
             /// Call an RPC `method` with given `args` list.
+            ///
+            /// Known-slow methods (see [`method_timeout`]) use a longer timeout than the client default,
+            /// unless the client is configured with an even longer one.
+            ///
+            /// [`method_timeout`]: crate::client_sync::method_timeout
             pub fn call<T: for<'a> serde::de::Deserialize<'a>>(
                 &self,
                 method: &str,
                 args: &[serde_json::Value],
             ) -> Result<T> {
                 let raw = serde_json::value::to_raw_value(args)?;
-                let req = self.inner.build_request(&method, Some(&*raw));
-                if log::log_enabled!(log::Level::Debug) {
-                    log::debug!(target: "corepc", "request: {} {}", method, serde_json::Value::from(args));
-                }
+                self.call_raw(method, &raw)
+            }
+
+            /// Call an RPC `method` passing `args` by name.
+            ///
+            /// Arguments that are not passed take their default value, so unlike [`Self::call`] there
+            /// is no need to fill in the optional arguments preceding the ones being set.
+            pub fn call_named<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[(&str, serde_json::Value)],
+            ) -> Result<T> {
+                let args = args
+                    .iter()
+                    .map(|(name, value)| (name.to_string(), value.clone()))
+                    .collect::<serde_json::Map<_, _>>();
+                let raw = serde_json::value::to_raw_value(&args)?;
+                self.call_raw(method, &raw)
+            }
+
+            /// Call an RPC `method` with given `args` list, returning the result as untyped JSON.
+            ///
+            /// Use this for methods this client does not wrap, e.g., ones added in a newer release of
+            /// Bitcoin Core or by a patched node. The call goes through the same transport, auth,
+            /// retries and middleware as the wrapped methods.
+            pub fn call_json(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+            ) -> Result<serde_json::Value> {
+                self.call(method, args)
+            }
+
+            /// Sends a JSON-RPC 2.0 notification calling `method` with `args`, without waiting for a
+            /// result.
+            ///
+            /// The server runs the method but does not respond, so failures are not reported.
+            /// Notifications are sent once, without retries or middleware. Bitcoin Core before v28
+            /// treats a notification as a request and its response is ignored.
+            pub fn notify(&self, method: &str, args: &[serde_json::Value]) -> Result<()> {
+                let raw = serde_json::value::to_raw_value(args)?;
+                let mut req = self.inner.build_request(method, Some(&raw));
+                req.id = serde_json::Value::Null;
+                req.jsonrpc = Some("2.0");
+                self.observe(method, || Ok(self.transport.send_notification(req)?))
+            }
+
+            /// Call an RPC `method` with given `args` list, aborting if no response arrives within `timeout`.
+            ///
+            /// The timeout only applies to this call, other calls made with this client are unaffected.
+            pub fn call_with_timeout<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+                timeout: std::time::Duration,
+            ) -> Result<T> {
+                let raw = serde_json::value::to_raw_value(args)?;
+                let transport = $crate::client_sync::Transport::with_timeout(&self.transport, timeout);
+                self.observe(method, || self.call_with_transport(method, &raw, &transport, None))
+            }
+
+            /// Call an RPC `method` with given `args` list, giving up if it does not complete within `deadline`.
+            ///
+            /// Unlike [`Self::call_with_timeout`], which limits each request sent to the server, the
+            /// deadline covers the whole call including any retries, see `ClientBuilder::retry`. Fails
+            /// with [`Error::DeadlineExceeded`] once the deadline passes. The server is not notified,
+            /// e.g., use the `abort` action of `scantxoutset` to stop a scan on the server.
+            ///
+            /// [`Error::DeadlineExceeded`]: crate::client_sync::Error::DeadlineExceeded
+            pub fn call_with_deadline<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                args: &[serde_json::Value],
+                deadline: std::time::Duration,
+            ) -> Result<T> {
+                let raw = serde_json::value::to_raw_value(args)?;
+                let deadline = std::time::Instant::now() + deadline;
+                self.observe(method, || {
+                    self.call_with_transport(method, &raw, &self.transport, Some(deadline))
+                })
+            }
+
+            fn call_raw<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                raw: &serde_json::value::RawValue,
+            ) -> Result<T> {
+                let timeout = $crate::client_sync::slow_method_timeout(&self.transport, method);
+                self.observe(method, || match timeout {
+                    Some(timeout) => {
+                        let transport =
+                            $crate::client_sync::Transport::with_timeout(&self.transport, timeout);
+                        self.call_with_transport(method, raw, &transport, None)
+                    }
+                    None => self.call_with_transport(method, raw, &self.transport, None),
+                })
+            }
 
-                let resp = self.inner.send_request(req).map_err(Error::from);
-                log_response(method, &resp);
-                Ok(resp?.result()?)
+            /// Calls `f`, recording the call to `method` in the client statistics.
+            fn observe<T>(&self, method: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+                self.options.stats.record(method, || $crate::client_sync::instrument(method, f))
+            }
+
+            fn call_with_transport<T: for<'a> serde::de::Deserialize<'a>>(
+                &self,
+                method: &str,
+                raw: &serde_json::value::RawValue,
+                transport: &Tr,
+                deadline: Option<std::time::Instant>,
+            ) -> Result<T> {
+                let retry = self.options.retry.as_ref();
+                $crate::client_sync::with_cookie_reload(&self.options, transport, || {
+                    $crate::client_sync::with_retry(retry, method, deadline, || {
+                        let params = self.options.on_request(method, raw)?;
+                        let params = params.as_deref().unwrap_or(raw);
+                        let mut req = self.inner.build_request(&method, Some(params));
+                        req.jsonrpc = Some(self.options.jsonrpc_version.as_str());
+                        if log::log_enabled!(log::Level::Debug) {
+                            let params = $crate::redact::params(method, params.get());
+                            log::debug!(target: "corepc", "request: {} {}", method, params);
+                        }
+
+                        let start = std::time::Instant::now();
+                        let resp = $crate::client_sync::send_request(transport, req, deadline);
+                        log_response(method, params, &resp);
+                        self.options.on_response(method, &resp, start.elapsed());
+                        $crate::client_sync::deserialize_result(method, resp?)
+                    })
+                })
             }
         }
     }
 }
 
+/// Implements the methods `$items` on `Client`, as `async fn`s if `$mode` is `async`.
+///
+/// Used by the `impl_client_vXX__*` macros so that the blocking and the async clients are
+/// implemented by the same macros.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! impl_client_methods {
+    ([] $($items:tt)*) => {
+        impl<Tr: $crate::client_sync::Transport> Client<Tr> {
+            $($items)*
+        }
+    };
+    ([async] $($items:tt)*) => {
+        $crate::impl_async_client_methods! { $($items)* }
+    };
+}
+
 /// Implements the `check_expected_server_version()` on `Client`.
 ///
 /// Requires `Client` to be in scope and implement `server_version()`.
@@ -138,15 +456,16 @@ macro_rules! define_jsonrpc_bitreq_client {
 /// # Parameters
 ///
 /// - `$expected_versions`: An vector of expected server versions e.g., `[230100, 230200]`.
+/// - `$mode`: Pass `async` to implement the method on the async client.
 #[macro_export]
 macro_rules! impl_client_check_expected_server_version {
-    ($expected_versions:expr) => {
-        impl Client {
+    ($expected_versions:expr $(, $mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Checks that the JSON-RPC endpoint is for a `bitcoind` instance with the expected version.
             pub fn check_expected_server_version(&self) -> Result<()> {
                 let server_version = self.server_version()?;
                 if !$expected_versions.contains(&server_version) {
-                    return Err($crate::client_sync::error::UnexpectedServerVersionError {
+                    return Err($crate::client_sync::UnexpectedServerVersionError {
                         got: server_version,
                         expected: $expected_versions.to_vec(),
                     })?;
@@ -157,16 +476,154 @@ macro_rules! impl_client_check_expected_server_version {
     };
 }
 
+/// Timeout used for RPC methods that may take minutes to complete on mainnet.
+pub const SLOW_METHOD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Timeout used for `getblocktemplate` long polling, which waits for the template to change.
+pub const LONGPOLL_TIMEOUT: Duration = Duration::from_secs(30 * 60);
+
+/// Returns the timeout override for `method`, if it is known to be slow.
+///
+/// `Client::call` uses this timeout for the returned methods if the client default is shorter.
+pub fn method_timeout(method: &str) -> Option<Duration> {
+    match method {
+        "dumptxoutset" | "gettxoutsetinfo" | "importdescriptors" | "loadtxoutset"
+        | "rescanblockchain" | "scanblocks" | "scantxoutset" | "verifychain" =>
+            Some(SLOW_METHOD_TIMEOUT),
+        _ => None,
+    }
+}
+
+/// Returns the timeout to call `method` with using `transport`, if it must be extended.
+///
+/// The timeout of `transport` is kept if it is at least the [`method_timeout`] of `method`.
+fn slow_method_timeout<Tr: Transport>(transport: &Tr, method: &str) -> Option<Duration> {
+    let timeout = method_timeout(method)?;
+    match transport.timeout() {
+        Some(configured) if configured >= timeout => None,
+        _ => Some(timeout),
+    }
+}
+
+/// Time allowed for the response of a `waitfor*` call, beyond the timeout passed to the node.
+const WAIT_FOR_MARGIN: Duration = Duration::from_secs(10);
+
+/// Returns the `timeout` argument, in milliseconds, of a `waitfor*` method and the request timeout.
+pub(crate) fn wait_for_timeout(timeout: Duration) -> (serde_json::Value, Duration) {
+    // The node waits indefinitely for 0 and parses the argument as a 32 bit integer.
+    let millis = timeout.as_millis().clamp(1, i32::MAX as u128) as u64;
+    (millis.into(), Duration::from_millis(millis) + WAIT_FOR_MARGIN)
+}
+
+/// Returns the URL path of the endpoint for wallet `name`, percent-encoding the name.
+fn wallet_path(name: &str) -> String {
+    let mut path = String::from("/wallet/");
+    for byte in name.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' =>
+                path.push(char::from(byte)),
+            _ => path.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    path
+}
+
+/// Sends `req` using `transport`, failing with [`Error::DeadlineExceeded`] if `deadline` passes.
+///
+/// Fails with `NonceMismatch` if the response is not for `req`.
+fn send_request<Tr: Transport>(
+    transport: &Tr,
+    req: jsonrpc::Request,
+    deadline: Option<Instant>,
+) -> Result<jsonrpc::Response> {
+    let id = req.id.clone();
+    let resp = match deadline {
+        Some(deadline) => {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(Error::DeadlineExceeded);
+            }
+            match transport.with_timeout(remaining).send_request(req) {
+                Err(_) if Instant::now() >= deadline => return Err(Error::DeadlineExceeded),
+                resp => resp?,
+            }
+        }
+        None => transport.send_request(req)?,
+    };
+
+    // Check the response is for this request, as `jsonrpc::Client::call` does.
+    if resp.id != id {
+        return Err(jsonrpc::error::Error::NonceMismatch.into());
+    }
+    Ok(resp)
+}
+
+/// Converts `fee_rate` to BTC/kvB, the unit of the fee rate arguments of most methods.
+///
+/// Exact, unlike going through sat/vB which rounds rates below 1 sat/vB down to 0 (no limit).
+pub(crate) fn fee_rate_to_btc_per_kvb(fee_rate: bitcoin::FeeRate) -> f64 {
+    bitcoin::Amount::from_sat(fee_rate.to_sat_per_kwu() * 4).to_btc()
+}
+
 /// Shorthand for converting a variable into a `serde_json::Value`.
-fn into_json<T>(val: T) -> Result<serde_json::Value>
+pub(crate) fn into_json<T>(val: T) -> Result<serde_json::Value>
 where
     T: serde::ser::Serialize,
 {
     Ok(serde_json::to_value(val)?)
 }
 
+/// Extracts the result from an RPC response, recording the JSON path of any deserialization error.
+#[cfg(feature = "serde-path-to-error")]
+pub(crate) fn deserialize_result<T>(method: &str, resp: jsonrpc::Response) -> Result<T>
+where
+    T: for<'a> serde::de::Deserialize<'a>,
+{
+    if let Some(e) = resp.error {
+        return Err(jsonrpc::error::Error::Rpc(e).into());
+    }
+
+    let raw = resp.result.as_ref().map(|res| res.get()).unwrap_or("null");
+    let de = &mut serde_json::Deserializer::from_str(raw);
+    serde_path_to_error::deserialize(de).map_err(|e| {
+        let path = e.path().to_string();
+        error::DeserializeError { method: method.to_owned(), path, error: e.into_inner() }.into()
+    })
+}
+
+/// Extracts the result from an RPC response.
+#[cfg(not(feature = "serde-path-to-error"))]
+pub(crate) fn deserialize_result<T>(_method: &str, resp: jsonrpc::Response) -> Result<T>
+where
+    T: for<'a> serde::de::Deserialize<'a>,
+{
+    Ok(resp.result()?)
+}
+
+/// Calls `f`, making the call to `method` within a span if the `tracing` feature is enabled.
+#[cfg(feature = "tracing")]
+fn instrument<T>(method: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let span = crate::span::call(method);
+    let start = std::time::Instant::now();
+    let result = span.in_scope(f);
+    let error_code = match result {
+        Err(Error::JsonRpc(jsonrpc::error::Error::Rpc(ref e))) => Some(e.code),
+        _ => None,
+    };
+    crate::span::record(&span, start, &result, error_code);
+    result
+}
+
+/// Calls `f`, making the call to `method` within a span if the `tracing` feature is enabled.
+#[cfg(not(feature = "tracing"))]
+fn instrument<T>(_method: &str, f: impl FnOnce() -> Result<T>) -> Result<T> { f() }
+
 /// Helper to log an RPC response.
-fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
+fn log_response(
+    method: &str,
+    params: &serde_json::value::RawValue,
+    resp: &Result<jsonrpc::Response>,
+) {
     use log::Level::{Debug, Trace, Warn};
 
     if log::log_enabled!(Warn) || log::log_enabled!(Debug) || log::log_enabled!(Trace) {
@@ -184,8 +641,148 @@ fn log_response(method: &str, resp: &Result<jsonrpc::Response>) {
                     let def =
                         serde_json::value::to_raw_value(&serde_json::value::Value::Null).unwrap();
                     let result = resp.result.as_ref().unwrap_or(&def);
+                    let result = crate::redact::result(method, params.get(), result.get());
                     log::trace!(target: "corepc", "response for {}: {}", method, result);
                 },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    use jsonrpc::{Request, Response};
+
+    use super::*;
+
+    /// A mock transport recording the timeout each request is sent with.
+    #[derive(Clone, Debug)]
+    struct TimedTransport {
+        mock: MockTransport,
+        timeout: Duration,
+        sent: Arc<Mutex<Vec<Duration>>>,
+    }
+
+    impl TimedTransport {
+        fn new(timeout: Duration) -> Self {
+            TimedTransport { mock: MockTransport::new(), timeout, sent: Arc::default() }
+        }
+    }
+
+    impl jsonrpc::Transport for TimedTransport {
+        fn send_request(&self, req: Request) -> std::result::Result<Response, jsonrpc::Error> {
+            self.sent.lock().unwrap().push(self.timeout);
+            self.mock.send_request(req)
+        }
+
+        fn send_batch(
+            &self,
+            reqs: &[Request],
+        ) -> std::result::Result<Vec<Response>, jsonrpc::Error> {
+            self.mock.send_batch(reqs)
+        }
+
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "timed") }
+    }
+
+    impl Transport for TimedTransport {
+        fn timeout(&self) -> Option<Duration> { Some(self.timeout) }
+
+        fn with_timeout(&self, timeout: Duration) -> Self {
+            TimedTransport { timeout, ..self.clone() }
+        }
+    }
+
+    #[test]
+    fn slow_method_timeout_extends_shorter_timeout() {
+        let transport = TimedTransport::new(DEFAULT_TIMEOUT);
+        transport.mock.respond("verifychain", serde_json::json!(true));
+        transport.mock.respond("getblockcount", serde_json::json!(1));
+        let client = v17::Client::with_transport(transport.clone());
+
+        client.call::<bool>("verifychain", &[]).unwrap();
+        client.call::<u64>("getblockcount", &[]).unwrap();
+        assert_eq!(*transport.sent.lock().unwrap(), [SLOW_METHOD_TIMEOUT, DEFAULT_TIMEOUT]);
+    }
+
+    #[test]
+    fn slow_method_timeout_keeps_longer_timeout() {
+        let timeout = Duration::from_secs(30 * 60);
+        let transport = TimedTransport::new(timeout);
+        transport.mock.respond("dumptxoutset", serde_json::json!({}));
+        let client = v17::Client::with_transport(transport.clone());
+
+        client.call::<serde_json::Value>("dumptxoutset", &[]).unwrap();
+        client.pipeline::<serde_json::Value>("dumptxoutset", &[vec![]], 1).pop().unwrap().unwrap();
+        assert_eq!(*transport.sent.lock().unwrap(), [timeout, timeout]);
+    }
+
+    /// Responds to every request with a result of 42.
+    #[derive(Clone, Debug)]
+    struct FixedTransport;
+
+    impl jsonrpc::Transport for FixedTransport {
+        fn send_request(&self, req: Request) -> std::result::Result<Response, jsonrpc::Error> {
+            let result = serde_json::value::to_raw_value(&42).unwrap();
+            Ok(Response { result: Some(result), error: None, id: req.id, jsonrpc: None })
+        }
+
+        fn send_batch(
+            &self,
+            reqs: &[Request],
+        ) -> std::result::Result<Vec<Response>, jsonrpc::Error> {
+            reqs.iter().map(|req| self.send_request(req.clone())).collect()
+        }
+
+        fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "fixed") }
+    }
+
+    // Uses the default implementations of all the `Transport` methods.
+    impl Transport for FixedTransport {}
+
+    #[test]
+    #[cfg(feature = "serde-path-to-error")]
+    fn malformed_result_has_path() {
+        let mock = MockTransport::new();
+        mock.respond(
+            "getblockchaininfo",
+            serde_json::json!({ "chain": "regtest", "blocks": "ten" }),
+        );
+        let client = v17::Client::with_transport(mock);
+
+        match client.get_blockchain_info() {
+            Err(Error::Deserialize(e)) => {
+                assert_eq!(e.method, "getblockchaininfo");
+                assert_eq!(e.path, "blocks");
+                assert!(e.error.is_data());
+            }
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    #[cfg(not(feature = "serde-path-to-error"))]
+    fn malformed_result() {
+        let mock = MockTransport::new();
+        mock.respond(
+            "getblockchaininfo",
+            serde_json::json!({ "chain": "regtest", "blocks": "ten" }),
+        );
+        let client = v17::Client::with_transport(mock);
+
+        match client.get_blockchain_info() {
+            Err(Error::JsonRpc(jsonrpc::Error::Json(e))) => assert!(e.is_data()),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn custom_transport() {
+        let client = v17::Client::with_transport(FixedTransport);
+        assert_eq!(client.get_block_count().unwrap().0, 42);
+        assert_eq!(client.call::<u64>("getblockcount", &[]).unwrap(), 42);
+        assert!(matches!(client.for_wallet("w"), Err(Error::UnsupportedByTransport(_))));
+    }
+}
@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Sending calls concurrently, see `Client::pipeline`.
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+use crate::client_sync::Result;
+
+/// Calls `f` for each index in `0..len` using up to `threads` threads, returning the results in
+/// index order.
+///
+/// Each thread calls `init` once and passes its result to each of its calls to `f`, e.g., to use a
+/// connection per thread.
+pub(crate) fn run_concurrently<S, T, I, F>(
+    len: usize,
+    threads: usize,
+    init: I,
+    f: F,
+) -> Vec<Result<T>>
+where
+    T: Send,
+    I: Fn() -> S + Sync,
+    F: Fn(&S, usize) -> Result<T> + Sync,
+{
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new((0..len).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, len.max(1)) {
+            scope.spawn(|| {
+                let state = init();
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    if i >= len {
+                        break;
+                    }
+                    let result = f(&state, i);
+                    results.lock().unwrap_or_else(|e| e.into_inner())[i] = Some(result);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap_or_else(|e| e.into_inner())
+        .into_iter()
+        .map(|result| result.expect("all calls are made"))
+        .collect()
+}
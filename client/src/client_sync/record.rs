@@ -0,0 +1,271 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Recording the calls made by a `Client` to a file and replaying them later.
+
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use jsonrpc::{Request, Response};
+use serde::{Deserialize, Serialize};
+
+use crate::client_sync::transport::DefaultTransport;
+use crate::client_sync::{Result, Transport};
+
+/// A transport that records calls to a JSONL file, or answers calls from such a recording.
+///
+/// In record mode requests are sent using the wrapped transport and each request and response
+/// pair is written to the file as it completes. In replay mode no server is needed, a call is
+/// answered with the first response recorded for the same method and params that has not yet been
+/// replayed. Calls without a matching recording fail with a transport error.
+///
+/// Recordings of a real node make for deterministic tests and are a source of fixtures for the
+/// `types` crate. The URL path of a call, e.g., the wallet endpoint, is not recorded. Passphrases
+/// and private keys are redacted the same way as when calls are logged, calls passing a secret
+/// are matched by their redacted params and results that are a secret are replayed redacted.
+#[derive(Clone)]
+pub struct RecordReplayTransport<Tr = DefaultTransport>(Mode<Tr>);
+
+#[derive(Clone)]
+enum Mode<Tr> {
+    /// Sends requests using `inner`, recording them to `file`.
+    Record { inner: Tr, file: Arc<Mutex<File>> },
+    /// Answers requests from the recorded `calls` that have not been replayed yet.
+    Replay { calls: Arc<Mutex<Vec<RecordedCall>>> },
+}
+
+/// A request and response pair, as written to a line of the recording.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct RecordedCall {
+    method: String,
+    params: serde_json::Value,
+    response: Response,
+}
+
+impl RecordReplayTransport {
+    /// Replays the calls recorded to the file at `path`.
+    pub fn replay<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut calls = Vec::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if !line.trim().is_empty() {
+                calls.push(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(RecordReplayTransport(Mode::Replay { calls: Arc::new(Mutex::new(calls)) }))
+    }
+}
+
+impl<Tr: Transport> RecordReplayTransport<Tr> {
+    /// Records the calls sent using `inner` to the file at `path`, replacing any existing file.
+    pub fn record<P: AsRef<Path>>(inner: Tr, path: P) -> Result<Self> {
+        let file = File::create(path)?;
+        Ok(RecordReplayTransport(Mode::Record { inner, file: Arc::new(Mutex::new(file)) }))
+    }
+
+    /// Appends the call made by `req` to the recording.
+    fn write(file: &Mutex<File>, req: &Request, response: &Response) -> io::Result<()> {
+        let params = params(req)?;
+        let mut response = response.clone();
+        if response.result.is_some() && crate::redact::is_sensitive_result(req.method, &params) {
+            response.result = Some(serde_json::value::to_raw_value(crate::redact::REDACTED)?);
+        }
+        let call = RecordedCall { method: req.method.to_owned(), params, response };
+        let line = serde_json::to_string(&call)?;
+        let mut file = file.lock().unwrap_or_else(|e| e.into_inner());
+        writeln!(file, "{}", line)
+    }
+
+    /// Removes the first recorded call matching `req` and returns its response.
+    fn take(calls: &Mutex<Vec<RecordedCall>>, req: &Request) -> io::Result<Response> {
+        let params = params(req)?;
+        let mut calls = calls.lock().unwrap_or_else(|e| e.into_inner());
+        let pos = calls.iter().position(|call| call.method == req.method && call.params == params);
+        match pos {
+            Some(pos) => {
+                let mut response = calls.remove(pos).response;
+                response.id = req.id.clone();
+                Ok(response)
+            }
+            None => Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no recorded call to {} with params {}", req.method, params),
+            )),
+        }
+    }
+}
+
+impl<Tr: Transport> jsonrpc::Transport for RecordReplayTransport<Tr> {
+    fn send_request(&self, req: Request) -> std::result::Result<Response, jsonrpc::Error> {
+        match &self.0 {
+            Mode::Record { inner, file } => {
+                let response = inner.send_request(req.clone())?;
+                Self::write(file, &req, &response).map_err(transport_error)?;
+                Ok(response)
+            }
+            Mode::Replay { calls } => Self::take(calls, &req).map_err(transport_error),
+        }
+    }
+
+    fn send_batch(&self, reqs: &[Request]) -> std::result::Result<Vec<Response>, jsonrpc::Error> {
+        match &self.0 {
+            Mode::Record { inner, file } => {
+                let responses = inner.send_batch(reqs)?;
+                for response in &responses {
+                    if let Some(req) = reqs.iter().find(|req| req.id == response.id) {
+                        Self::write(file, req, response).map_err(transport_error)?;
+                    }
+                }
+                Ok(responses)
+            }
+            Mode::Replay { calls } =>
+                reqs.iter().map(|req| Self::take(calls, req).map_err(transport_error)).collect(),
+        }
+    }
+
+    /// Notifications have no response, they are sent but not recorded.
+    fn send_notification(&self, req: Request) -> std::result::Result<(), jsonrpc::Error> {
+        match &self.0 {
+            Mode::Record { inner, .. } => inner.send_notification(req),
+            Mode::Replay { .. } => Ok(()),
+        }
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.0 {
+            Mode::Record { inner, .. } => inner.fmt_target(f),
+            Mode::Replay { .. } => write!(f, "replay"),
+        }
+    }
+}
+
+impl<Tr: Transport> Transport for RecordReplayTransport<Tr> {
+    fn with_timeout(&self, timeout: Duration) -> Self {
+        match &self.0 {
+            Mode::Record { inner, file } => RecordReplayTransport(Mode::Record {
+                inner: inner.with_timeout(timeout),
+                file: Arc::clone(file),
+            }),
+            Mode::Replay { .. } => self.clone(),
+        }
+    }
+
+    fn with_new_connection(&self) -> Self {
+        match &self.0 {
+            Mode::Record { inner, file } => RecordReplayTransport(Mode::Record {
+                inner: inner.with_new_connection(),
+                file: Arc::clone(file),
+            }),
+            Mode::Replay { .. } => self.clone(),
+        }
+    }
+
+    fn with_url_path(&self, path: &str) -> Option<Self> {
+        match &self.0 {
+            Mode::Record { inner, file } => Some(RecordReplayTransport(Mode::Record {
+                inner: inner.with_url_path(path)?,
+                file: Arc::clone(file),
+            })),
+            Mode::Replay { .. } => Some(self.clone()),
+        }
+    }
+
+    fn set_basic_auth(&self, user: String, pass: Option<String>) -> bool {
+        match &self.0 {
+            Mode::Record { inner, .. } => inner.set_basic_auth(user, pass),
+            Mode::Replay { .. } => false,
+        }
+    }
+}
+
+impl<Tr> fmt::Debug for RecordReplayTransport<Tr> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.0 {
+            Mode::Record { .. } => f.write_str("RecordReplayTransport::Record"),
+            Mode::Replay { calls } => f
+                .debug_struct("RecordReplayTransport::Replay")
+                .field("remaining", &calls.lock().unwrap_or_else(|e| e.into_inner()).len())
+                .finish(),
+        }
+    }
+}
+
+/// Returns the params of `req` with the secrets redacted, `null` if there are none.
+fn params(req: &Request) -> io::Result<serde_json::Value> {
+    match req.params {
+        Some(params) => {
+            let mut params = serde_json::from_str(params.get())?;
+            crate::redact::redact_params(req.method, &mut params);
+            Ok(params)
+        }
+        None => Ok(serde_json::Value::Null),
+    }
+}
+
+fn transport_error(e: io::Error) -> jsonrpc::Error { jsonrpc::Error::Transport(Box::new(e)) }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::client_sync::v17::Client;
+    use crate::client_sync::MockTransport;
+
+    /// Returns a path in the temporary directory unique to this process and `name`.
+    fn recording(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("corepc-record-{}-{}.jsonl", name, std::process::id()))
+    }
+
+    #[test]
+    fn record_then_replay() {
+        let path = recording("replay");
+        let genesis = "000000000019d6689c085ae165831e934ff763ae46a2a6c172b3f1b60a8ce26f";
+
+        let mock = MockTransport::new();
+        mock.respond("getblockhash", json!(genesis));
+        let recorder = RecordReplayTransport::record(mock, &path).unwrap();
+        let client = Client::with_transport(recorder);
+        assert_eq!(client.get_block_hash(0).unwrap().0, genesis);
+
+        let replay = RecordReplayTransport::replay(&path).unwrap();
+        let client = Client::with_transport(replay);
+        assert_eq!(client.get_block_hash(0).unwrap().0, genesis);
+        // Each recorded call is replayed once, and only for the same params.
+        assert!(client.get_block_hash(0).is_err());
+        assert!(client.get_block_hash(1).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn secrets_are_redacted() {
+        let path = recording("redact");
+        let privkey = "cVpF924EspNh8KjYsfhgY96mmxvT6DgdWiTYMtMjuM74hJaU5psW";
+
+        let mock = MockTransport::new();
+        mock.respond("walletpassphrase", json!(null));
+        mock.respond("dumpprivkey", json!(privkey));
+        let recorder = RecordReplayTransport::record(mock, &path).unwrap();
+        let client = Client::with_transport(recorder);
+        client.call::<()>("walletpassphrase", &[json!("hunter2"), json!(60)]).unwrap();
+        let address = [json!("bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080")];
+        assert_eq!(client.call::<String>("dumpprivkey", &address).unwrap(), privkey);
+
+        let recorded = std::fs::read_to_string(&path).unwrap();
+        assert!(!recorded.contains("hunter2"), "{}", recorded);
+        assert!(!recorded.contains(privkey), "{}", recorded);
+
+        // Calls passing a secret are matched by their redacted params.
+        let replay = RecordReplayTransport::replay(&path).unwrap();
+        let client = Client::with_transport(replay);
+        client.call::<()>("walletpassphrase", &[json!("other"), json!(60)]).unwrap();
+        let replayed = client.call::<String>("dumpprivkey", &address).unwrap();
+        assert_eq!(replayed, crate::redact::REDACTED);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Building replace-by-fee (BIP 125) replacements of mempool transactions.
+
+use std::{error, fmt};
+
+use bitcoin::{Amount, FeeRate, ScriptBuf, Transaction, Txid, Witness};
+
+use crate::client_sync::{BlockchainRpc, Error, RawTransactionsRpc};
+
+/// The incremental relay fee of nodes that do not report it, v23 and earlier.
+const DEFAULT_INCREMENTAL_RELAY_FEE: FeeRate = FeeRate::from_sat_per_vb_unchecked(1);
+
+/// An unsigned replacement of a mempool transaction, returned by [`build_replacement`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Replacement {
+    /// The replacement with the signatures removed, ready for signing.
+    pub tx: Transaction,
+    /// The fee paid by the replacement.
+    pub fee: Amount,
+    /// The fee paid by the replaced transaction.
+    pub replaced_fee: Amount,
+}
+
+/// Builds a replacement of the mempool transaction `txid` paying at least `fee_rate`.
+///
+/// The replacement spends the same inputs to the same outputs, the fee is increased by taking it
+/// from output `change`. The fee also pays for the replaced transaction and its descendants plus
+/// the incremental relay fee of the node, as required by the replacement rules. It is computed
+/// for the virtual size of the replaced transaction, which signatures of the same type keep.
+///
+/// Useful when the keys are not held by the node wallet, otherwise see `bumpfee`. The replaced
+/// transaction must signal replaceability unless the node accepts full replace-by-fee.
+pub fn build_replacement<C: BlockchainRpc + RawTransactionsRpc>(
+    client: &C,
+    txid: Txid,
+    change: usize,
+    fee_rate: FeeRate,
+) -> Result<Replacement, ReplacementError> {
+    let mut tx = client.get_raw_transaction(txid)?.0;
+    let entry = client.get_mempool_entry(txid)?.0;
+    // Before v0.19 `size` is the virtual size.
+    let vsize = u64::from(entry.vsize.or(entry.size).ok_or(Error::UnexpectedStructure)?);
+    let incremental_relay_fee =
+        client.get_mempool_info()?.incremental_relay_fee.unwrap_or(DEFAULT_INCREMENTAL_RELAY_FEE);
+
+    // An overflowing fee can not be paid by the change output either.
+    let min_fee = incremental_relay_fee
+        .fee_vb(vsize)
+        .and_then(|fee| fee.checked_add(entry.fees.descendant))
+        .unwrap_or(Amount::MAX);
+    let fee = fee_rate.fee_vb(vsize).unwrap_or(Amount::MAX).max(min_fee).max(entry.fees.base);
+    let increase = fee - entry.fees.base;
+
+    let output = tx.output.get_mut(change).ok_or(ReplacementError::NoChange(change))?;
+    let dust = output.script_pubkey.minimal_non_dust();
+    output.value = match output.value.checked_sub(increase) {
+        Some(value) if value >= dust => value,
+        _ =>
+            return Err(ReplacementError::InsufficientChange {
+                required: increase.checked_add(dust).unwrap_or(Amount::MAX),
+            }),
+    };
+    for input in &mut tx.input {
+        input.script_sig = ScriptBuf::new();
+        input.witness = Witness::new();
+    }
+
+    Ok(Replacement { tx, fee, replaced_fee: entry.fees.base })
+}
+
+/// An error returned by [`build_replacement`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReplacementError {
+    /// A call to the node failed.
+    Rpc(Error),
+    /// The transaction has no output with the given index.
+    NoChange(usize),
+    /// The change output is below the `required` value, the fee increase plus the dust limit.
+    InsufficientChange {
+        /// The minimum value of the change output.
+        required: Amount,
+    },
+}
+
+impl From<Error> for ReplacementError {
+    fn from(e: Error) -> Self { ReplacementError::Rpc(e) }
+}
+
+impl fmt::Display for ReplacementError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReplacementError::Rpc(e) => write!(f, "RPC: {}", e),
+            ReplacementError::NoChange(index) => write!(f, "no output with index {}", index),
+            ReplacementError::InsufficientChange { required } =>
+                write!(f, "change output is less than the required {}", required),
+        }
+    }
+}
+
+impl error::Error for ReplacementError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ReplacementError::Rpc(e) => Some(e),
+            ReplacementError::NoChange(_) | ReplacementError::InsufficientChange { .. } => None,
+        }
+    }
+}
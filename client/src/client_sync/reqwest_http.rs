@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Sending requests with a blocking `reqwest` client.
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use std::{error, fmt};
+
+use jsonrpc::{Request, Response};
+use reqwest::blocking::Client as HttpClient;
+use reqwest::{StatusCode, Url};
+
+use crate::client_sync::{Auth, Result, Transport};
+
+/// A username and optional password.
+type Credentials = (String, Option<String>);
+
+/// A transport sending requests with a blocking [`reqwest`] client.
+///
+/// For applications that already depend on `reqwest` and want to share its configuration e.g.,
+/// TLS, proxies or default headers. Create a client with `Client::with_transport`, it has the same
+/// methods as a client created with `Client::builder`. Clones share the credentials.
+#[derive(Clone, Debug)]
+pub struct ReqwestTransport {
+    client: HttpClient,
+    url: Url,
+    basic_auth: Arc<RwLock<Option<Credentials>>>,
+    timeout: Option<Duration>,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport sending requests to `url` with a default `reqwest` client.
+    pub fn new(url: &str) -> Result<Self> { Self::with_client(HttpClient::new(), url) }
+
+    /// Creates a transport sending requests to `url` with `client`.
+    ///
+    /// Requests use the timeout `client` was built with, unless a call sets its own.
+    pub fn with_client(client: HttpClient, url: &str) -> Result<Self> {
+        let url = Url::parse(url).map_err(|e| ReqwestError::Url(e.to_string()))?;
+        Ok(ReqwestTransport { client, url, basic_auth: Arc::default(), timeout: None })
+    }
+
+    /// Authenticates requests using `auth`, reading the cookie file if there is one.
+    pub fn auth(self, auth: Auth) -> Result<Self> {
+        let (user, pass) = auth.get_user_pass()?;
+        if let Some(user) = user {
+            *self.basic_auth.write().expect("poisoned lock") = Some((user, pass));
+        }
+        Ok(self)
+    }
+
+    /// Sends `body` as the JSON body of a POST request.
+    fn send(
+        &self,
+        body: impl serde::Serialize,
+    ) -> std::result::Result<reqwest::blocking::Response, ReqwestError> {
+        let body = serde_json::to_vec(&body).map_err(ReqwestError::Json)?;
+        let mut http = self
+            .client
+            .post(self.url.clone())
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body);
+        if let Some((user, pass)) = &*self.basic_auth.read().expect("poisoned lock") {
+            http = http.basic_auth(user, pass.as_ref());
+        }
+        if let Some(timeout) = self.timeout {
+            http = http.timeout(timeout);
+        }
+        Ok(http.send()?)
+    }
+
+    fn request<R>(&self, body: impl serde::Serialize) -> std::result::Result<R, ReqwestError>
+    where
+        R: for<'a> serde::de::Deserialize<'a>,
+    {
+        // Bitcoin Core returns RPC errors with an HTTP error status, only return the status if
+        // the body is not a JSON-RPC response e.g., when the work queue depth is exceeded.
+        let resp = self.send(body)?;
+        let status = resp.status();
+        let bytes = resp.bytes()?;
+        match serde_json::from_slice(&bytes) {
+            Ok(json) => Ok(json),
+            Err(e) =>
+                if status != StatusCode::OK {
+                    Err(http_error(status, &bytes))
+                } else {
+                    Err(ReqwestError::Json(e))
+                },
+        }
+    }
+
+    /// Sends a notification, accepting either no response (HTTP 204) or any JSON response.
+    fn notify(&self, body: impl serde::Serialize) -> std::result::Result<(), ReqwestError> {
+        let resp = self.send(body)?;
+        let status = resp.status();
+        let bytes = resp.bytes()?;
+        if status == StatusCode::NO_CONTENT
+            || serde_json::from_slice::<serde_json::Value>(&bytes).is_ok()
+        {
+            return Ok(());
+        }
+        Err(http_error(status, &bytes))
+    }
+}
+
+impl jsonrpc::Transport for ReqwestTransport {
+    fn send_request(&self, req: Request) -> std::result::Result<Response, jsonrpc::Error> {
+        Ok(self.request(req)?)
+    }
+
+    fn send_batch(&self, reqs: &[Request]) -> std::result::Result<Vec<Response>, jsonrpc::Error> {
+        Ok(self.request(reqs)?)
+    }
+
+    fn send_notification(&self, req: Request) -> std::result::Result<(), jsonrpc::Error> {
+        Ok(self.notify(req)?)
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.url) }
+}
+
+impl Transport for ReqwestTransport {
+    fn timeout(&self) -> Option<Duration> { self.timeout }
+
+    fn with_timeout(&self, timeout: Duration) -> Self {
+        ReqwestTransport { timeout: Some(timeout), ..self.clone() }
+    }
+
+    fn with_url_path(&self, path: &str) -> Option<Self> {
+        let mut url = self.url.clone();
+        url.set_path(path);
+        Some(ReqwestTransport { url, ..self.clone() })
+    }
+
+    fn set_basic_auth(&self, user: String, pass: Option<String>) -> bool {
+        *self.basic_auth.write().expect("poisoned lock") = Some((user, pass));
+        true
+    }
+}
+
+fn http_error(status: StatusCode, body: &[u8]) -> ReqwestError {
+    ReqwestError::Http { status_code: status.as_u16(), body: String::from_utf8_lossy(body).into() }
+}
+
+/// An error returned by [`ReqwestTransport`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ReqwestError {
+    /// The URL is malformed.
+    Url(String),
+    /// The request failed e.g., the server could not be reached or timed out.
+    Reqwest(reqwest::Error),
+    /// The server returned an HTTP error status without a JSON-RPC response.
+    Http {
+        /// The HTTP status code.
+        status_code: u16,
+        /// The body of the response.
+        body: String,
+    },
+    /// Serializing the request or parsing the response failed.
+    Json(serde_json::Error),
+}
+
+impl ReqwestError {
+    /// Returns true if connecting to the server failed, before the request was sent.
+    pub(crate) fn is_connect_error(&self) -> bool {
+        matches!(self, ReqwestError::Reqwest(e) if e.is_connect())
+    }
+
+    /// Returns the HTTP error status code, if the server returned one.
+    pub(crate) fn status_code(&self) -> Option<u16> {
+        match self {
+            ReqwestError::Http { status_code, .. } => Some(*status_code),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for ReqwestError {
+    fn from(e: reqwest::Error) -> Self { ReqwestError::Reqwest(e) }
+}
+
+impl From<ReqwestError> for jsonrpc::Error {
+    fn from(e: ReqwestError) -> Self { jsonrpc::Error::Transport(Box::new(e)) }
+}
+
+impl From<ReqwestError> for crate::client_sync::Error {
+    fn from(e: ReqwestError) -> Self { jsonrpc::Error::from(e).into() }
+}
+
+impl fmt::Display for ReqwestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ReqwestError::Url(e) => write!(f, "invalid URL: {}", e),
+            ReqwestError::Reqwest(e) => write!(f, "reqwest: {}", e),
+            ReqwestError::Http { status_code, body } =>
+                write!(f, "status: {}, body: {}", status_code, body),
+            ReqwestError::Json(e) => write!(f, "JSON error: {}", e),
+        }
+    }
+}
+
+impl error::Error for ReqwestError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ReqwestError::Reqwest(e) => Some(e),
+            ReqwestError::Json(e) => Some(e),
+            ReqwestError::Url(_) | ReqwestError::Http { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client_sync::test_server::serve_one;
+    use crate::client_sync::v17::Client;
+
+    #[test]
+    fn client_makes_call() {
+        let (url, server) = serve_one(serde_json::json!(7));
+        let transport = ReqwestTransport::new(&url)
+            .unwrap()
+            .auth(Auth::UserPass("user".to_owned(), "pass".to_owned()))
+            .unwrap();
+        let client: Client<ReqwestTransport> = Client::with_transport(transport);
+        assert_eq!(client.get_block_count().unwrap().0, 7);
+
+        let head = server.join().unwrap().to_ascii_lowercase();
+        assert!(head.starts_with("post / http/1.1\r\n"), "{}", head);
+        // The base64 encoding of `user:pass`.
+        assert!(head.contains("authorization: basic dxnlcjpwyxnz\r\n"), "{}", head);
+    }
+}
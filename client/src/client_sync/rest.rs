@@ -0,0 +1,309 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! A client for the REST interface of Bitcoin Core.
+
+use std::time::Duration;
+use std::{error, fmt};
+
+use bitcoin::consensus::encode::{self, Decodable, VarInt};
+use bitcoin::{block, Amount, Block, BlockHash, OutPoint, ScriptBuf, Transaction, TxOut, Txid};
+use jsonrpc::bitreq;
+use types::model;
+
+use crate::client_sync::traits::into_model;
+use crate::client_sync::{Error, Result, DEFAULT_TIMEOUT};
+
+/// The maximum number of headers the node returns for one request.
+const MAX_HEADERS_PER_REQUEST: u32 = 2000;
+
+/// The start of the error message of nodes before v24 for a headers request without a count in
+/// the path.
+const NO_HEADER_COUNT: &str = "No header count specified";
+
+/// A client for the unauthenticated REST interface of a node started with `-rest`.
+///
+/// Blocks, headers and transactions are downloaded in their binary encoding, which is much faster
+/// than over JSON-RPC e.g., when downloading many blocks. Results are returned as the same types
+/// as the JSON-RPC client.
+#[derive(Clone, Debug)]
+pub struct RestClient {
+    url: String,
+    timeout: Duration,
+}
+
+impl RestClient {
+    /// Creates a client for the node at `url` e.g., `"http://127.0.0.1:8332"`.
+    pub fn new(url: &str) -> Self {
+        RestClient { url: url.trim_end_matches('/').to_owned(), timeout: DEFAULT_TIMEOUT }
+    }
+
+    /// Sets the timeout of each request, rounded up to whole seconds.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Gets the block with hash `hash`.
+    pub fn get_block(&self, hash: BlockHash) -> Result<Block> {
+        self.get_bin(&format!("block/{}.bin", hash))
+    }
+
+    /// Gets up to `count` headers of the best chain, starting with the header of block `hash`.
+    ///
+    /// Nodes before v24 only support the deprecated `headers/<count>/<hash>` path, a request with
+    /// it is made if the node rejects the `count` query parameter.
+    pub fn get_block_headers(&self, hash: BlockHash, count: u32) -> Result<Vec<block::Header>> {
+        let body = match self.get(&format!("headers/{}.bin?count={}", hash, count)) {
+            Err(Error::Rest(RestError::Http { status_code: 400, body }))
+                if body.starts_with(NO_HEADER_COUNT) =>
+                self.get(&format!("headers/{}/{}.bin", count, hash))?,
+            res => res?,
+        };
+        // The response is the headers one after another, without a length prefix.
+        body.chunks(block::Header::SIZE)
+            .map(|header| Ok(encode::deserialize(header).map_err(RestError::Decode)?))
+            .collect()
+    }
+
+    /// Gets up to `count` headers of the best chain starting with the header of block `hash`,
+    /// making as many requests as needed.
+    ///
+    /// The node returns at most 2000 headers per request, each subsequent request starts at the
+    /// last header already downloaded. Fewer than `count` headers are returned if the tip of the
+    /// best chain is reached.
+    pub fn headers_from(&self, hash: BlockHash, count: u32) -> Result<Vec<block::Header>> {
+        let mut headers = Vec::new();
+        let mut start = hash;
+        while (headers.len() as u32) < count {
+            // Only the hash of the last header is known, so subsequent requests overlap by one.
+            let overlap = u32::from(!headers.is_empty());
+            let requested = (count - headers.len() as u32 + overlap).min(MAX_HEADERS_PER_REQUEST);
+            let page = self.get_block_headers(start, requested)?;
+            if overlap == 1 && page.first().map(|header| header.block_hash()) != Some(start) {
+                return Err(Error::UnexpectedStructure);
+            }
+
+            let full = page.len() as u32 == requested;
+            headers.extend(page.into_iter().skip(overlap as usize));
+            if !full {
+                break;
+            }
+            start = headers.last().expect("a full page is not empty").block_hash();
+        }
+        Ok(headers)
+    }
+
+    /// Gets the hash of the block at `height` in the best chain.
+    pub fn get_block_hash(&self, height: u64) -> Result<model::GetBlockHash> {
+        Ok(model::GetBlockHash(self.get_bin(&format!("blockhashbyheight/{}.bin", height))?))
+    }
+
+    /// Gets the mempool or, if the node has a transaction index, any transaction with ID `txid`.
+    pub fn get_raw_transaction(&self, txid: Txid) -> Result<model::GetRawTransaction> {
+        Ok(model::GetRawTransaction(self.get_bin::<Transaction>(&format!("tx/{}.bin", txid))?))
+    }
+
+    /// Gets information about the state of the mempool.
+    pub fn get_mempool_info(&self) -> Result<model::GetMempoolInfo> {
+        let json: serde_json::Value = serde_json::from_slice(&self.get("mempool/info.json")?)?;
+
+        // The REST interface does not report the node version, use the type of the newest version
+        // that the response deserializes into.
+        macro_rules! try_versions {
+            ($($module:ident),*) => {
+                $(
+                    if let Ok(info) = types::$module::GetMempoolInfo::deserialize(&json) {
+                        return into_model(info.into_model());
+                    }
+                )*
+            };
+        }
+        use serde::Deserialize as _;
+        try_versions!(v31, v30, v24, v22, v21, v19, v17);
+        Err(Error::UnexpectedStructure)
+    }
+
+    /// Gets the unspent transaction outputs `outpoints` from the UTXO set of the node.
+    ///
+    /// If `check_mempool` is true outputs spent by mempool transactions are excluded and outputs
+    /// created by them are included. The node accepts at most 15 outpoints per request.
+    pub fn get_utxos(&self, outpoints: &[OutPoint], check_mempool: bool) -> Result<GetUtxos> {
+        let mut path = String::from("getutxos");
+        if check_mempool {
+            path.push_str("/checkmempool");
+        }
+        for outpoint in outpoints {
+            path.push_str(&format!("/{}-{}", outpoint.txid, outpoint.vout));
+        }
+        path.push_str(".bin");
+
+        let body = self.get(&path)?;
+        Ok(decode_utxos(&body, outpoints.len()).map_err(RestError::Decode)?)
+    }
+
+    /// Gets `path` and decodes the body of the response.
+    fn get_bin<T: encode::Decodable>(&self, path: &str) -> Result<T> {
+        Ok(encode::deserialize(&self.get(path)?).map_err(RestError::Decode)?)
+    }
+
+    /// Gets `path` relative to `/rest/`, returning the body of the response.
+    fn get(&self, path: &str) -> Result<Vec<u8>> {
+        let url = format!("{}/rest/{}", self.url, path);
+        log::debug!(target: "corepc", "REST request: {}", url);
+        let timeout = self.timeout.as_secs() + u64::from(self.timeout.subsec_nanos() > 0);
+        let resp = bitreq::get(url).with_timeout(timeout).send().map_err(RestError::Bitreq)?;
+        if resp.status_code != 200 {
+            return Err(RestError::Http {
+                status_code: resp.status_code,
+                body: String::from_utf8_lossy(resp.as_bytes()).trim_end().to_owned(),
+            }
+            .into());
+        }
+        Ok(resp.into_bytes())
+    }
+}
+
+/// Result of [`RestClient::get_utxos`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GetUtxos {
+    /// The height of the tip of the best chain when the UTXO set was queried.
+    pub chain_height: u32,
+    /// The hash of the tip of the best chain when the UTXO set was queried.
+    pub chain_tip_hash: BlockHash,
+    /// The output for each requested outpoint, in the same order, `None` if it is not unspent.
+    pub utxos: Vec<Option<Utxo>>,
+}
+
+/// An unspent transaction output returned by [`RestClient::get_utxos`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Utxo {
+    /// The height of the block containing the transaction, `0x7FFFFFFF` if it is in the mempool.
+    pub height: u32,
+    /// The value of the output.
+    pub value: Amount,
+    /// The script of the output.
+    pub script_pubkey: ScriptBuf,
+}
+
+/// Decodes the binary response of `getutxos` for `count` requested outpoints.
+fn decode_utxos(mut body: &[u8], count: usize) -> core::result::Result<GetUtxos, encode::Error> {
+    let r = &mut body;
+    let chain_height = u32::consensus_decode(r)?;
+    let chain_tip_hash = BlockHash::consensus_decode(r)?;
+    // One bit for each requested outpoint, set if it is unspent.
+    let bitmap = Vec::<u8>::consensus_decode(r)?;
+    if bitmap.len() != count.div_ceil(8) {
+        return Err(encode::Error::ParseFailed("bitmap length does not match outpoints"));
+    }
+
+    let mut found = Vec::new();
+    for _ in 0..VarInt::consensus_decode(r)?.0 {
+        // A transaction version field that is always zero.
+        let _ = u32::consensus_decode(r)?;
+        let height = u32::consensus_decode(r)?;
+        let TxOut { value, script_pubkey } = TxOut::consensus_decode(r)?;
+        found.push(Utxo { height, value, script_pubkey });
+    }
+    if !r.is_empty() {
+        return Err(encode::Error::ParseFailed("data not consumed entirely"));
+    }
+
+    let mut found = found.into_iter();
+    let utxos = (0..count)
+        .map(|i| match bitmap[i / 8] & (1 << (i % 8)) {
+            0 => Ok(None),
+            _ => found.next().map(Some).ok_or(encode::Error::ParseFailed("too few outputs")),
+        })
+        .collect::<core::result::Result<_, _>>()?;
+    if found.next().is_some() {
+        return Err(encode::Error::ParseFailed("too many outputs"));
+    }
+    Ok(GetUtxos { chain_height, chain_tip_hash, utxos })
+}
+
+/// An error returned by a [`RestClient`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RestError {
+    /// The request failed e.g., the node could not be reached or timed out.
+    Bitreq(bitreq::Error),
+    /// The node returned an HTTP error status e.g., 404 for an unknown block.
+    Http {
+        /// The HTTP status code.
+        status_code: i32,
+        /// The body of the response, an error message.
+        body: String,
+    },
+    /// Decoding the response failed.
+    Decode(encode::Error),
+}
+
+impl From<RestError> for Error {
+    fn from(e: RestError) -> Self { Error::Rest(e) }
+}
+
+impl fmt::Display for RestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RestError::Bitreq(e) => write!(f, "bitreq: {}", e),
+            RestError::Http { status_code, body } =>
+                write!(f, "status: {}, body: {}", status_code, body),
+            RestError::Decode(e) => write!(f, "decoding response failed: {}", e),
+        }
+    }
+}
+
+impl error::Error for RestError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            RestError::Bitreq(e) => Some(e),
+            RestError::Decode(e) => Some(e),
+            RestError::Http { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitcoin::consensus::encode::serialize;
+
+    use super::*;
+    use crate::client_sync::test_server::serve_get;
+
+    fn genesis() -> block::Header {
+        bitcoin::constants::genesis_block(bitcoin::Network::Regtest).header
+    }
+
+    #[test]
+    fn block_headers_count_query() {
+        let header = genesis();
+        let (url, server) = serve_get(vec![(200, serialize(&header))]);
+
+        let headers = RestClient::new(&url).get_block_headers(header.block_hash(), 5).unwrap();
+        assert_eq!(headers, vec![header]);
+        let requests = server.join().unwrap();
+        assert_eq!(requests, [format!("GET /rest/headers/{}.bin?count=5", header.block_hash())]);
+    }
+
+    #[test]
+    fn block_headers_count_path_before_v24() {
+        let header = genesis();
+        let rejected = b"No header count specified. Use /rest/headers/<count>/<hash>.<ext>.\r\n";
+        let (url, server) = serve_get(vec![(400, rejected.to_vec()), (200, serialize(&header))]);
+
+        let headers = RestClient::new(&url).get_block_headers(header.block_hash(), 5).unwrap();
+        assert_eq!(headers, vec![header]);
+        let requests = server.join().unwrap();
+        assert_eq!(requests[1], format!("GET /rest/headers/5/{}.bin", header.block_hash()));
+    }
+
+    #[test]
+    fn block_headers_other_error() {
+        let rejected = b"Header count is invalid or out of acceptable range (1-2000): 0\r\n";
+        let (url, server) = serve_get(vec![(400, rejected.to_vec())]);
+
+        let res = RestClient::new(&url).get_block_headers(genesis().block_hash(), 0);
+        assert!(matches!(res, Err(Error::Rest(RestError::Http { status_code: 400, .. }))));
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+}
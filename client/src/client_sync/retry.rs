@@ -0,0 +1,305 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Retrying calls that fail with a transient error or because the cookie changed.
+
+#[cfg(feature = "client-sync")]
+use std::io;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::client_sync::{Auth, ClientOptions, Error, Result, Transport};
+
+/// Policy for retrying calls that fail with a transient error, using exponential backoff.
+///
+/// A call is retried if the connection to the server failed (e.g., connection refused while the
+/// node is starting), if its work queue is full (HTTP 503) or if the node responds with
+/// `RPC_IN_WARMUP` (-28). In all these cases the node did not execute the call. Calls that time out
+/// are not retried because the node may have executed them, sending a transaction twice for example.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one.
+    pub max_attempts: u32,
+    /// Time to wait before the first retry, doubled on each subsequent retry.
+    pub initial_backoff: Duration,
+    /// Upper bound on the time to wait between attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Returns the time to wait after failed attempt number `attempt` (starting at 1).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+/// Time to wait between attempts in [`poll_until_ready`].
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Returns true if `e` is likely to go away if the call is retried and the call was not executed.
+fn is_transient(e: &Error) -> bool {
+    match e {
+        // The server's work queue is full (HTTP 503).
+        Error::JsonRpc(e) if is_connect_error(e) || http_status(e) == Some(503) => true,
+        e => e.is_warmup(),
+    }
+}
+
+/// Returns true if `e` is a failure to connect to the server, before the request was sent.
+///
+/// E.g., connection refused or the host not resolving to an address. Timeouts are excluded since
+/// they can not be told apart from the server taking too long to respond.
+pub(crate) fn is_connect_error(e: &jsonrpc::Error) -> bool {
+    let e = match e {
+        jsonrpc::Error::Transport(e) => e,
+        _ => return false,
+    };
+    #[cfg(feature = "client-sync")]
+    {
+        use jsonrpc::http::{bitreq_http, simple_http};
+
+        if let Some(simple_http::Error::SocketError(e)) = e.downcast_ref() {
+            return is_connect_io_error(e);
+        }
+        match e.downcast_ref() {
+            Some(bitreq_http::Error::Bitreq(jsonrpc::bitreq::Error::AddressNotFound)) =>
+                return true,
+            Some(bitreq_http::Error::Bitreq(jsonrpc::bitreq::Error::IoError(e))) =>
+                return is_connect_io_error(e),
+            _ => {}
+        }
+    }
+    #[cfg(feature = "transport-reqwest")]
+    if let Some(e) = e.downcast_ref::<crate::client_sync::ReqwestError>() {
+        return e.is_connect_error();
+    }
+    false
+}
+
+/// Returns true if `e` can only be returned when opening a connection.
+#[cfg(feature = "client-sync")]
+fn is_connect_io_error(e: &io::Error) -> bool {
+    matches!(e.kind(), io::ErrorKind::ConnectionRefused | io::ErrorKind::AddrNotAvailable)
+}
+
+/// Returns true if `e` is the server rejecting the credentials (HTTP 401).
+fn is_unauthorized(e: &Error) -> bool {
+    matches!(e, Error::JsonRpc(e) if http_status(e) == Some(401))
+}
+
+/// Returns the HTTP status code if `e` is the server responding with an HTTP error.
+fn http_status(e: &jsonrpc::error::Error) -> Option<u16> {
+    let e = match e {
+        jsonrpc::error::Error::Transport(e) => e,
+        _ => return None,
+    };
+    #[cfg(feature = "client-sync")]
+    {
+        use jsonrpc::http::{bitreq_http, simple_http};
+
+        if let Some(simple_http::Error::HttpErrorCode(code)) = e.downcast_ref() {
+            return Some(*code);
+        }
+        if let Some(bitreq_http::Error::Http(e)) = e.downcast_ref() {
+            return u16::try_from(e.status_code).ok();
+        }
+    }
+    #[cfg(feature = "transport-reqwest")]
+    if let Some(e) = e.downcast_ref::<crate::client_sync::ReqwestError>() {
+        return e.status_code();
+    }
+    None
+}
+
+/// Calls `f`, retrying once with the credentials re-read from the cookie file if the server
+/// rejects the current ones.
+///
+/// Does nothing unless the client was built with [`Auth::CookieFile`].
+pub(crate) fn with_cookie_reload<T>(
+    options: &ClientOptions,
+    transport: &impl Transport,
+    mut f: impl FnMut() -> Result<T>,
+) -> Result<T> {
+    let path = match options.cookie_file {
+        Some(ref path) => path,
+        None => return f(),
+    };
+
+    match f() {
+        Err(e) if is_unauthorized(&e) => {
+            let (user, pass) = match Auth::CookieFile(path.clone()).get_user_pass() {
+                Ok((Some(user), pass)) => (user, pass),
+                _ => return Err(e),
+            };
+            if !transport.set_basic_auth(user, pass) {
+                return Err(e);
+            }
+            log::debug!(target: "corepc", "re-read cookie file {} after error: {}", path.display(), e);
+            f()
+        }
+        result => result,
+    }
+}
+
+/// Calls `f`, retrying according to `policy` while it fails with a transient error.
+///
+/// Fails with [`Error::DeadlineExceeded`] instead of retrying if the backoff would pass `deadline`.
+pub(crate) fn with_retry<T>(
+    policy: Option<&RetryPolicy>,
+    method: &str,
+    deadline: Option<Instant>,
+    mut f: impl FnMut() -> Result<T>,
+) -> Result<T> {
+    let policy = match policy {
+        Some(policy) => policy,
+        None => return f(),
+    };
+
+    let mut attempt = 1;
+    loop {
+        match f() {
+            Err(e) if attempt < policy.max_attempts && is_transient(&e) => {
+                let backoff = policy.backoff(attempt);
+                if deadline.is_some_and(|deadline| Instant::now() + backoff >= deadline) {
+                    return Err(Error::DeadlineExceeded);
+                }
+                log::debug!(target: "corepc", "retrying {} in {:?} after error: {}", method, backoff, e);
+                thread::sleep(backoff);
+                attempt += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Calls `f` until it does not fail with a transient error or `timeout` elapses.
+pub(crate) fn poll_until_ready(timeout: Duration, mut f: impl FnMut() -> Result<()>) -> Result<()> {
+    let start = Instant::now();
+    loop {
+        match f() {
+            Err(e) if is_transient(&e) && start.elapsed() < timeout => {
+                log::debug!(target: "corepc", "waiting for server to be ready: {}", e);
+                thread::sleep(READY_POLL_INTERVAL.min(timeout.saturating_sub(start.elapsed())));
+            }
+            result => return result,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "client-sync"))]
+mod tests {
+    use std::cell::Cell;
+
+    use jsonrpc::http::simple_http;
+
+    use super::*;
+
+    fn socket_error(kind: io::ErrorKind) -> Error {
+        Error::JsonRpc(simple_http::Error::SocketError(io::Error::from(kind)).into())
+    }
+
+    fn http_error(code: u16) -> Error {
+        Error::JsonRpc(simple_http::Error::HttpErrorCode(code).into())
+    }
+
+    fn rpc_error(code: i32) -> Error {
+        let e = jsonrpc::error::RpcError { code, message: "error".to_owned(), data: None };
+        Error::JsonRpc(jsonrpc::Error::Rpc(e))
+    }
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 4,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(3),
+        }
+    }
+
+    #[test]
+    fn backoff_schedule() {
+        let policy = RetryPolicy::default();
+        let backoffs = (1..=8).map(|attempt| policy.backoff(attempt)).collect::<Vec<_>>();
+        let millis = [100, 200, 400, 800, 1600, 3200, 5000, 5000];
+        assert_eq!(backoffs, millis.map(Duration::from_millis));
+
+        // Does not overflow for large attempt numbers.
+        assert_eq!(policy.backoff(u32::MAX), policy.max_backoff);
+    }
+
+    #[test]
+    fn transient_errors() {
+        assert!(is_transient(&socket_error(io::ErrorKind::ConnectionRefused)));
+        assert!(is_transient(&socket_error(io::ErrorKind::AddrNotAvailable)));
+        assert!(is_transient(&http_error(503)));
+        assert!(is_transient(&rpc_error(-28))); // RPC_IN_WARMUP
+
+        // The node may have executed the call.
+        assert!(!is_transient(&socket_error(io::ErrorKind::TimedOut)));
+        assert!(!is_transient(&socket_error(io::ErrorKind::ConnectionReset)));
+        assert!(!is_transient(&http_error(401)));
+        assert!(!is_transient(&http_error(500)));
+        assert!(!is_transient(&rpc_error(-8))); // RPC_INVALID_PARAMETER
+        assert!(!is_transient(&Error::UnexpectedStructure));
+    }
+
+    #[test]
+    fn retries_transient_errors_up_to_max_attempts() {
+        let attempts = Cell::new(0);
+        let result: Result<()> = with_retry(Some(&policy()), "getblockcount", None, || {
+            attempts.set(attempts.get() + 1);
+            Err(socket_error(io::ErrorKind::ConnectionRefused))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 4);
+
+        let attempts = Cell::new(0);
+        let result = with_retry(Some(&policy()), "getblockcount", None, || {
+            attempts.set(attempts.get() + 1);
+            match attempts.get() {
+                1 => Err(rpc_error(-28)),
+                _ => Ok(42),
+            }
+        });
+        assert_eq!(result.unwrap(), 42);
+        assert_eq!(attempts.get(), 2);
+    }
+
+    #[test]
+    fn does_not_retry_other_errors() {
+        let attempts = Cell::new(0);
+        let result: Result<()> = with_retry(Some(&policy()), "sendrawtransaction", None, || {
+            attempts.set(attempts.get() + 1);
+            Err(socket_error(io::ErrorKind::TimedOut))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+
+        let attempts = Cell::new(0);
+        let result: Result<()> = with_retry(None, "getblockcount", None, || {
+            attempts.set(attempts.get() + 1);
+            Err(http_error(503))
+        });
+        assert!(result.is_err());
+        assert_eq!(attempts.get(), 1);
+    }
+
+    #[test]
+    fn stops_at_deadline() {
+        let backoff = Duration::from_secs(60);
+        let policy = RetryPolicy { initial_backoff: backoff, max_backoff: backoff, ..policy() };
+        let deadline = Instant::now() + Duration::from_secs(1);
+        let result: Result<()> =
+            with_retry(Some(&policy), "getblockcount", Some(deadline), || Err(http_error(503)));
+        assert!(matches!(result, Err(Error::DeadlineExceeded)));
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Per-method statistics of the calls made by a `Client`.
+
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::client_sync::Result;
+
+/// Upper bounds of the [`LatencyHistogram`] buckets, a final bucket holds all slower calls.
+pub const LATENCY_BUCKETS: [Duration; 9] = [
+    Duration::from_millis(1),
+    Duration::from_millis(5),
+    Duration::from_millis(10),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+];
+
+/// Statistics of the calls made by a `Client`, returned by `Client::stats`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ClientStats {
+    methods: BTreeMap<String, MethodStats>,
+}
+
+impl ClientStats {
+    /// Returns the statistics for `method`, if it has been called.
+    pub fn get(&self, method: &str) -> Option<&MethodStats> { self.methods.get(method) }
+
+    /// Returns an iterator over the called methods and their statistics, ordered by method name.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &MethodStats)> {
+        self.methods.iter().map(|(method, stats)| (method.as_str(), stats))
+    }
+
+    /// Returns the total number of calls made, to any method.
+    pub fn total_calls(&self) -> u64 { self.methods.values().map(|stats| stats.calls).sum() }
+}
+
+/// Statistics of the calls made to a single method.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MethodStats {
+    /// Number of calls made, including failed ones.
+    pub calls: u64,
+    /// Number of calls that returned an error.
+    pub errors: u64,
+    /// Time taken by the calls, including any retries.
+    pub latency: LatencyHistogram,
+}
+
+/// Histogram of call durations, bucketed by [`LATENCY_BUCKETS`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    counts: [u64; LATENCY_BUCKETS.len() + 1],
+    total: Duration,
+}
+
+impl LatencyHistogram {
+    /// Adds a call that took `elapsed`.
+    fn record(&mut self, elapsed: Duration) {
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| elapsed <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+        self.counts[bucket] += 1;
+        self.total += elapsed;
+    }
+
+    /// Returns an iterator over the upper bound of each bucket and the number of calls in it.
+    ///
+    /// The upper bound of the last bucket is `None`, it holds all calls slower than the last bound
+    /// in [`LATENCY_BUCKETS`].
+    pub fn buckets(&self) -> impl Iterator<Item = (Option<Duration>, u64)> + '_ {
+        LATENCY_BUCKETS.iter().copied().map(Some).chain(Some(None)).zip(self.counts.iter().copied())
+    }
+
+    /// Returns the total time taken by all calls.
+    pub fn total(&self) -> Duration { self.total }
+
+    /// Returns the mean duration of a call, `None` if there have been no calls.
+    pub fn mean(&self) -> Option<Duration> {
+        let count = self.counts.iter().sum::<u64>();
+        if count == 0 {
+            return None;
+        }
+        Some(self.total / u32::try_from(count).unwrap_or(u32::MAX))
+    }
+}
+
+/// Collects the statistics of a `Client`, shared by all its calls.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct StatsRecorder(Arc<Mutex<ClientStats>>);
+
+impl StatsRecorder {
+    /// Calls `f`, recording its duration and whether the call to `method` failed.
+    pub(crate) fn record<T>(&self, method: &str, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        let start = Instant::now();
+        let result = f();
+        let elapsed = start.elapsed();
+
+        let mut stats = self.0.lock().unwrap_or_else(|e| e.into_inner());
+        let stats = stats.methods.entry(method.to_owned()).or_default();
+        stats.calls += 1;
+        if result.is_err() {
+            stats.errors += 1;
+        }
+        stats.latency.record(elapsed);
+        result
+    }
+
+    /// Returns a snapshot of the statistics collected so far.
+    pub(crate) fn snapshot(&self) -> ClientStats {
+        self.0.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Clears the statistics collected so far.
+    pub(crate) fn reset(&self) {
+        *self.0.lock().unwrap_or_else(|e| e.into_inner()) = ClientStats::default();
+    }
+}
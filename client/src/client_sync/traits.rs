@@ -0,0 +1,290 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Traits for the methods common to all supported versions, one for each section of the API docs.
+//!
+//! Implemented by the `Client` of every version module and by `AnyClient`, so code can be generic
+//! over any client supporting e.g., the wallet methods. Results are returned as the version
+//! nonspecific `model` types.
+
+use bitcoin::address::NetworkChecked;
+use bitcoin::{Address, Amount, Block, BlockHash, Transaction, Txid};
+use types::model;
+
+use crate::client_sync::v17::{HashOrHeight, StatField};
+use crate::client_sync::v21::ImportDescriptorsRequest;
+use crate::client_sync::{
+    v17, v18, v19, v20, v21, v22, v23, v24, v25, v26, v27, v28, v29, v30, v31, Error, Result,
+    Transport,
+};
+
+/// Methods found under the `== Blockchain ==` section of the API docs.
+pub trait BlockchainRpc {
+    /// Gets the hash of the best (tip) block in the most-work fully-validated chain.
+    fn get_best_block_hash(&self) -> Result<model::GetBestBlockHash>;
+
+    /// Gets the block with hash `hash`.
+    fn get_block(&self, hash: BlockHash) -> Result<Block>;
+
+    /// Gets the number of blocks in the longest blockchain.
+    fn get_block_count(&self) -> Result<model::GetBlockCount>;
+
+    /// Gets the hash of the block at `height` in the best chain.
+    fn get_block_hash(&self, height: u64) -> Result<model::GetBlockHash>;
+
+    /// Gets the header of the block with hash `hash`.
+    fn get_block_header(&self, hash: &BlockHash) -> Result<model::GetBlockHeader>;
+
+    /// Gets stats for the block at `block`, only computing the `stats` fields if given.
+    fn get_block_stats(
+        &self,
+        block: HashOrHeight,
+        stats: Option<&[StatField]>,
+    ) -> Result<model::GetBlockStats>;
+
+    /// Gets information about the state of the blockchain.
+    fn get_blockchain_info(&self) -> Result<model::GetBlockchainInfo>;
+
+    /// Gets information about all known tips in the block tree.
+    fn get_chain_tips(&self) -> Result<model::GetChainTips>;
+
+    /// Gets the proof-of-work difficulty as a multiple of the minimum difficulty.
+    fn get_difficulty(&self) -> Result<model::GetDifficulty>;
+
+    /// Gets information about the state of the mempool.
+    fn get_mempool_info(&self) -> Result<model::GetMempoolInfo>;
+
+    /// Gets the mempool entry of the transaction with ID `txid`.
+    fn get_mempool_entry(&self, txid: Txid) -> Result<model::GetMempoolEntry>;
+
+    /// Gets the IDs of all transactions in the mempool.
+    fn get_raw_mempool(&self) -> Result<model::GetRawMempool>;
+
+    /// Gets the unspent transaction output `vout` of transaction `txid`.
+    fn get_tx_out(&self, txid: Txid, vout: u64) -> Result<model::GetTxOut>;
+}
+
+/// Methods found under the `== Network ==` section of the API docs.
+pub trait NetworkRpc {
+    /// Gets information about the node's P2P networking state.
+    fn get_network_info(&self) -> Result<model::GetNetworkInfo>;
+
+    /// Gets information about each connected peer.
+    fn get_peer_info(&self) -> Result<model::GetPeerInfo>;
+
+    /// Requests that a ping is sent to all peers.
+    fn ping(&self) -> Result<()>;
+}
+
+/// Methods found under the `== Rawtransactions ==` section of the API docs.
+pub trait RawTransactionsRpc {
+    /// Gets the mempool or, if the node has a transaction index, any transaction with ID `txid`.
+    fn get_raw_transaction(&self, txid: Txid) -> Result<model::GetRawTransaction>;
+
+    /// Submits `tx` to the node and broadcasts it to the network.
+    fn send_raw_transaction(&self, tx: &Transaction) -> Result<model::SendRawTransaction>;
+}
+
+/// Methods found under the `== Wallet ==` section of the API docs.
+pub trait WalletRpc {
+    /// Gets the balance of the wallet.
+    fn get_balance(&self) -> Result<model::GetBalance>;
+
+    /// Gets a new address from the wallet for receiving payments.
+    fn get_new_address(&self) -> Result<model::GetNewAddress>;
+
+    /// Gets details of the wallet transaction with ID `txid`.
+    fn get_transaction(&self, txid: Txid) -> Result<model::GetTransaction>;
+
+    /// Gets information about the state of the wallet.
+    fn get_wallet_info(&self) -> Result<model::GetWalletInfo>;
+
+    /// Gets the unspent transaction outputs in the wallet.
+    fn list_unspent(&self) -> Result<model::ListUnspent>;
+
+    /// Gets the names of the loaded wallets.
+    fn list_wallets(&self) -> Result<model::ListWallets>;
+
+    /// Sends `amount` to `address`.
+    fn send_to_address(
+        &self,
+        address: &Address<NetworkChecked>,
+        amount: Amount,
+    ) -> Result<model::SendToAddress>;
+}
+
+/// Methods of descriptor wallets, found under the `== Wallet ==` section of the API docs.
+///
+/// Implemented by the `Client` of v0.21 and later.
+pub trait DescriptorWalletRpc: WalletRpc + Sized {
+    /// Returns a client for the loaded wallet `name`.
+    fn for_wallet(&self, name: &str) -> Result<Self>;
+
+    /// Creates a blank descriptor wallet `name` with private keys disabled (watch-only wallet).
+    fn create_watch_only_wallet(&self, name: &str) -> Result<model::CreateWallet>;
+
+    /// Imports the descriptors `requests`, rescanning the chain from their timestamps.
+    fn import_descriptors(
+        &self,
+        requests: &[ImportDescriptorsRequest],
+    ) -> Result<model::ImportDescriptors>;
+}
+
+/// Implements the section traits for the `Client` of each version module.
+macro_rules! impl_section_traits {
+    ($($module:ident),*) => {
+        $(
+            impl<Tr: Transport> BlockchainRpc for $module::Client<Tr> {
+                fn get_best_block_hash(&self) -> Result<model::GetBestBlockHash> {
+                    into_model($module::Client::get_best_block_hash(self)?.into_model())
+                }
+
+                fn get_block(&self, hash: BlockHash) -> Result<Block> {
+                    $module::Client::get_block(self, hash)
+                }
+
+                fn get_block_count(&self) -> Result<model::GetBlockCount> {
+                    Ok($module::Client::get_block_count(self)?.into_model())
+                }
+
+                fn get_block_hash(&self, height: u64) -> Result<model::GetBlockHash> {
+                    into_model($module::Client::get_block_hash(self, height)?.into_model())
+                }
+
+                fn get_block_header(&self, hash: &BlockHash) -> Result<model::GetBlockHeader> {
+                    into_model($module::Client::get_block_header(self, hash)?.into_model())
+                }
+
+                fn get_block_stats(
+                    &self,
+                    block: HashOrHeight,
+                    stats: Option<&[StatField]>,
+                ) -> Result<model::GetBlockStats> {
+                    into_model($module::Client::get_block_stats(self, block, stats)?.into_model())
+                }
+
+                fn get_blockchain_info(&self) -> Result<model::GetBlockchainInfo> {
+                    into_model($module::Client::get_blockchain_info(self)?.into_model())
+                }
+
+                fn get_chain_tips(&self) -> Result<model::GetChainTips> {
+                    into_model($module::Client::get_chain_tips(self)?.into_model())
+                }
+
+                fn get_difficulty(&self) -> Result<model::GetDifficulty> {
+                    Ok($module::Client::get_difficulty(self)?.into_model())
+                }
+
+                fn get_mempool_info(&self) -> Result<model::GetMempoolInfo> {
+                    into_model($module::Client::get_mempool_info(self)?.into_model())
+                }
+
+                fn get_mempool_entry(&self, txid: Txid) -> Result<model::GetMempoolEntry> {
+                    into_model($module::Client::get_mempool_entry(self, txid)?.into_model())
+                }
+
+                fn get_raw_mempool(&self) -> Result<model::GetRawMempool> {
+                    into_model($module::Client::get_raw_mempool(self)?.into_model())
+                }
+
+                fn get_tx_out(&self, txid: Txid, vout: u64) -> Result<model::GetTxOut> {
+                    into_model($module::Client::get_tx_out(self, txid, vout)?.into_model())
+                }
+            }
+
+            impl<Tr: Transport> NetworkRpc for $module::Client<Tr> {
+                fn get_network_info(&self) -> Result<model::GetNetworkInfo> {
+                    into_model($module::Client::get_network_info(self)?.into_model())
+                }
+
+                fn get_peer_info(&self) -> Result<model::GetPeerInfo> {
+                    Ok($module::Client::get_peer_info(self)?.into_model())
+                }
+
+                fn ping(&self) -> Result<()> { $module::Client::ping(self) }
+            }
+
+            impl<Tr: Transport> RawTransactionsRpc for $module::Client<Tr> {
+                fn get_raw_transaction(&self, txid: Txid) -> Result<model::GetRawTransaction> {
+                    into_model($module::Client::get_raw_transaction(self, txid)?.into_model())
+                }
+
+                fn send_raw_transaction(
+                    &self,
+                    tx: &Transaction,
+                ) -> Result<model::SendRawTransaction> {
+                    into_model($module::Client::send_raw_transaction(self, tx)?.into_model())
+                }
+            }
+
+            impl<Tr: Transport> WalletRpc for $module::Client<Tr> {
+                fn get_balance(&self) -> Result<model::GetBalance> {
+                    into_model($module::Client::get_balance(self)?.into_model())
+                }
+
+                fn get_new_address(&self) -> Result<model::GetNewAddress> {
+                    into_model($module::Client::get_new_address(self, None, None)?.into_model())
+                }
+
+                fn get_transaction(&self, txid: Txid) -> Result<model::GetTransaction> {
+                    into_model($module::Client::get_transaction(self, txid)?.into_model())
+                }
+
+                fn get_wallet_info(&self) -> Result<model::GetWalletInfo> {
+                    into_model($module::Client::get_wallet_info(self)?.into_model())
+                }
+
+                fn list_unspent(&self) -> Result<model::ListUnspent> {
+                    into_model($module::Client::list_unspent(self)?.into_model())
+                }
+
+                fn list_wallets(&self) -> Result<model::ListWallets> {
+                    Ok($module::Client::list_wallets(self)?.into_model())
+                }
+
+                fn send_to_address(
+                    &self,
+                    address: &Address<NetworkChecked>,
+                    amount: Amount,
+                ) -> Result<model::SendToAddress> {
+                    into_model($module::Client::send_to_address(self, address, amount)?.into_model())
+                }
+            }
+        )*
+    };
+}
+
+impl_section_traits!(v17, v18, v19, v20, v21, v22, v23, v24, v25, v26, v27, v28, v29, v30, v31);
+
+/// Implements [`DescriptorWalletRpc`] for the `Client` of each version module.
+macro_rules! impl_descriptor_wallet_trait {
+    ($($module:ident),*) => {
+        $(
+            impl<Tr: Transport> DescriptorWalletRpc for $module::Client<Tr> {
+                fn for_wallet(&self, name: &str) -> Result<Self> {
+                    $module::Client::for_wallet(self, name)
+                }
+
+                fn create_watch_only_wallet(&self, name: &str) -> Result<model::CreateWallet> {
+                    Ok($module::Client::create_watch_only_wallet(self, name)?.into_model())
+                }
+
+                fn import_descriptors(
+                    &self,
+                    requests: &[ImportDescriptorsRequest],
+                ) -> Result<model::ImportDescriptors> {
+                    Ok($module::Client::import_descriptors(self, requests)?.into_model())
+                }
+            }
+        )*
+    };
+}
+
+impl_descriptor_wallet_trait!(v21, v22, v23, v24, v25, v26, v27, v28, v29, v30, v31);
+
+/// Converts the error of a failed `into_model` call.
+pub(crate) fn into_model<T, E>(result: std::result::Result<T, E>) -> Result<T>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    result.map_err(|e| Error::IntoModel(Box::new(e)))
+}
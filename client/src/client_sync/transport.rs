@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! The transport a `Client` uses to send requests to the server.
+
+#[cfg(feature = "client-sync")]
+use std::fmt;
+use std::time::Duration;
+
+#[cfg(feature = "client-sync")]
+use jsonrpc::http::bitreq_http::BitreqHttpTransport;
+#[cfg(feature = "client-sync")]
+use jsonrpc::http::simple_http::SimpleHttpTransport;
+#[cfg(feature = "client-sync")]
+use jsonrpc::{Request, Response};
+
+#[cfg(feature = "client-sync")]
+use crate::client_sync::FailoverTransport;
+
+/// The transport a `Client` uses unless another one is given as type parameter.
+#[cfg(feature = "client-sync")]
+pub(crate) type DefaultTransport = HttpTransport;
+
+/// The transport a `Client` uses unless another one is given as type parameter.
+#[cfg(not(feature = "client-sync"))]
+pub(crate) type DefaultTransport = crate::client_sync::ReqwestTransport;
+
+/// A transport that sends JSON-RPC requests for a `Client`.
+///
+/// Requests are sent using [`jsonrpc::Transport::send_request`] and
+/// [`jsonrpc::Transport::send_batch`], implement both traits to plug in a different HTTP client
+/// or a test double. Use `Client::with_transport` to create a client using the transport.
+pub trait Transport: jsonrpc::Transport + Clone {
+    /// Returns the timeout after which this transport aborts requests, if known.
+    ///
+    /// Used to keep a configured timeout longer than the slow method timeout. The default
+    /// implementation returns `None`.
+    fn timeout(&self) -> Option<Duration> { None }
+
+    /// Returns a copy of this transport that aborts requests after `timeout` instead.
+    ///
+    /// Used for per-call and slow method timeouts. The default implementation ignores `timeout`
+    /// and returns a plain copy.
+    fn with_timeout(&self, timeout: Duration) -> Self {
+        let _ = timeout;
+        self.clone()
+    }
+
+    /// Returns a copy of this transport that does not share a connection with this one.
+    ///
+    /// Used by `Client::pipeline` to send calls concurrently. The default implementation returns a
+    /// plain copy, which is enough for transports that open a connection for each request.
+    fn with_new_connection(&self) -> Self { self.clone() }
+
+    /// Returns a copy of this transport that sends requests to `path` on the same server instead.
+    ///
+    /// Used by `Client::for_wallet`. The default implementation returns `None`, meaning the
+    /// transport cannot change its URL.
+    fn with_url_path(&self, path: &str) -> Option<Self> {
+        let _ = path;
+        None
+    }
+
+    /// Replaces the credentials used by this transport and all its copies.
+    ///
+    /// Used to reload the cookie file when the server rejects the credentials. Returns false if
+    /// the transport does not support changing its credentials, the default.
+    fn set_basic_auth(&self, user: String, pass: Option<String>) -> bool {
+        let _ = (user, pass);
+        false
+    }
+}
+
+#[cfg(feature = "client-sync")]
+impl Transport for BitreqHttpTransport {
+    fn timeout(&self) -> Option<Duration> { Some(BitreqHttpTransport::timeout(self)) }
+
+    fn with_timeout(&self, timeout: Duration) -> Self {
+        BitreqHttpTransport::with_timeout(self, timeout)
+    }
+
+    fn with_url_path(&self, path: &str) -> Option<Self> {
+        Some(BitreqHttpTransport::with_url_path(self, path))
+    }
+
+    fn set_basic_auth(&self, user: String, pass: Option<String>) -> bool {
+        BitreqHttpTransport::set_basic_auth(self, user, pass);
+        true
+    }
+}
+
+#[cfg(feature = "client-sync")]
+impl Transport for SimpleHttpTransport {
+    fn timeout(&self) -> Option<Duration> { Some(SimpleHttpTransport::timeout(self)) }
+
+    fn with_timeout(&self, timeout: Duration) -> Self {
+        SimpleHttpTransport::with_timeout(self, timeout)
+    }
+
+    fn with_new_connection(&self) -> Self { SimpleHttpTransport::with_new_connection(self) }
+
+    fn with_url_path(&self, path: &str) -> Option<Self> {
+        let mut tp = self.clone();
+        tp.set_url_path(path.to_owned());
+        Some(tp)
+    }
+
+    fn set_basic_auth(&self, user: String, pass: Option<String>) -> bool {
+        SimpleHttpTransport::set_basic_auth(self, user, pass);
+        true
+    }
+}
+
+/// The HTTP transport of a `Client` created with `ClientBuilder`.
+#[cfg(feature = "client-sync")]
+#[derive(Clone, Debug)]
+pub enum HttpTransport {
+    /// Opens a new connection for each request, supports all the builder options.
+    Bitreq(BitreqHttpTransport),
+    /// Reuses a single connection for sequential requests, see `ClientBuilder::keep_alive`.
+    KeepAlive(SimpleHttpTransport),
+    /// Fails over to other nodes, see `ClientBuilder::fallback_url`.
+    Failover(FailoverTransport),
+}
+
+#[cfg(feature = "client-sync")]
+impl jsonrpc::Transport for HttpTransport {
+    fn send_request(&self, req: Request) -> Result<Response, jsonrpc::Error> {
+        match self {
+            HttpTransport::Bitreq(tp) => tp.send_request(req),
+            HttpTransport::KeepAlive(tp) => tp.send_request(req),
+            HttpTransport::Failover(tp) => tp.send_request(req),
+        }
+    }
+
+    fn send_batch(&self, reqs: &[Request]) -> Result<Vec<Response>, jsonrpc::Error> {
+        match self {
+            HttpTransport::Bitreq(tp) => tp.send_batch(reqs),
+            HttpTransport::KeepAlive(tp) => tp.send_batch(reqs),
+            HttpTransport::Failover(tp) => tp.send_batch(reqs),
+        }
+    }
+
+    fn send_notification(&self, req: Request) -> Result<(), jsonrpc::Error> {
+        match self {
+            HttpTransport::Bitreq(tp) => tp.send_notification(req),
+            HttpTransport::KeepAlive(tp) => tp.send_notification(req),
+            HttpTransport::Failover(tp) => tp.send_notification(req),
+        }
+    }
+
+    fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HttpTransport::Bitreq(tp) => tp.fmt_target(f),
+            HttpTransport::KeepAlive(tp) => tp.fmt_target(f),
+            HttpTransport::Failover(tp) => tp.fmt_target(f),
+        }
+    }
+}
+
+#[cfg(feature = "client-sync")]
+impl Transport for HttpTransport {
+    fn timeout(&self) -> Option<Duration> {
+        match self {
+            HttpTransport::Bitreq(tp) => Transport::timeout(tp),
+            HttpTransport::KeepAlive(tp) => Transport::timeout(tp),
+            HttpTransport::Failover(tp) => tp.timeout(),
+        }
+    }
+
+    fn with_timeout(&self, timeout: Duration) -> Self {
+        match self {
+            HttpTransport::Bitreq(tp) => HttpTransport::Bitreq(tp.with_timeout(timeout)),
+            HttpTransport::KeepAlive(tp) => HttpTransport::KeepAlive(tp.with_timeout(timeout)),
+            HttpTransport::Failover(tp) => HttpTransport::Failover(tp.with_timeout(timeout)),
+        }
+    }
+
+    fn with_new_connection(&self) -> Self {
+        match self {
+            HttpTransport::Bitreq(tp) => HttpTransport::Bitreq(tp.clone()),
+            HttpTransport::KeepAlive(tp) => HttpTransport::KeepAlive(tp.with_new_connection()),
+            HttpTransport::Failover(tp) => HttpTransport::Failover(tp.with_new_connection()),
+        }
+    }
+
+    fn with_url_path(&self, path: &str) -> Option<Self> {
+        match self {
+            HttpTransport::Bitreq(tp) =>
+                Transport::with_url_path(tp, path).map(HttpTransport::Bitreq),
+            HttpTransport::KeepAlive(tp) =>
+                Transport::with_url_path(tp, path).map(HttpTransport::KeepAlive),
+            HttpTransport::Failover(tp) => tp.with_url_path(path).map(HttpTransport::Failover),
+        }
+    }
+
+    fn set_basic_auth(&self, user: String, pass: Option<String>) -> bool {
+        match self {
+            HttpTransport::Bitreq(tp) => Transport::set_basic_auth(tp, user, pass),
+            HttpTransport::KeepAlive(tp) => Transport::set_basic_auth(tp, user, pass),
+            HttpTransport::Failover(tp) => tp.set_basic_auth(user, pass),
+        }
+    }
+}
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getblockchaininfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_blockchain_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_blockchain_info(&self) -> Result<GetBlockchainInfo> {
                 self.call("getblockchaininfo", &[])
             }
@@ -24,8 +26,8 @@ macro_rules! impl_client_v17__get_blockchain_info {
 /// Implements Bitcoin Core JSON-RPC API method `getbestblockhash`.
 #[macro_export]
 macro_rules! impl_client_v17__get_best_block_hash {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Gets the blockhash of the current chain tip.
             pub fn best_block_hash(&self) -> Result<bitcoin::BlockHash> {
                 let json = self.get_best_block_hash()?;
@@ -42,8 +44,8 @@ macro_rules! impl_client_v17__get_best_block_hash {
 /// Implements Bitcoin Core JSON-RPC API method `getblock`.
 #[macro_export]
 macro_rules! impl_client_v17__get_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Gets a block by blockhash.
             pub fn get_block(&self, hash: BlockHash) -> Result<Block> {
                 let json = self.get_block_verbose_zero(hash)?;
@@ -59,6 +61,11 @@ macro_rules! impl_client_v17__get_block {
             pub fn get_block_verbose_one(&self, hash: BlockHash) -> Result<GetBlockVerboseOne> {
                 self.call("getblock", &[into_json(hash)?, 1.into()])
             }
+
+            /// Gets a block by blockhash with verbose set to 2.
+            pub fn get_block_verbose_two(&self, hash: BlockHash) -> Result<GetBlockVerboseTwo> {
+                self.call("getblock", &[into_json(hash)?, 2.into()])
+            }
         }
     };
 }
@@ -66,8 +73,8 @@ macro_rules! impl_client_v17__get_block {
 /// Implements Bitcoin Core JSON-RPC API method `getblockcount`.
 #[macro_export]
 macro_rules! impl_client_v17__get_block_count {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_block_count(&self) -> Result<GetBlockCount> {
                 self.call("getblockcount", &[])
             }
@@ -78,8 +85,8 @@ macro_rules! impl_client_v17__get_block_count {
 /// Implements Bitcoin Core JSON-RPC API method `getblockhash`.
 #[macro_export]
 macro_rules! impl_client_v17__get_block_hash {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_block_hash(&self, height: u64) -> Result<GetBlockHash> {
                 self.call("getblockhash", &[into_json(height)?])
             }
@@ -90,8 +97,8 @@ macro_rules! impl_client_v17__get_block_hash {
 /// Implements Bitcoin Core JSON-RPC API method `getblockheader`.
 #[macro_export]
 macro_rules! impl_client_v17__get_block_header {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_block_header(&self, hash: &BlockHash) -> Result<GetBlockHeader> {
                 self.call("getblockheader", &[into_json(hash)?, into_json(false)?])
             }
@@ -110,8 +117,8 @@ macro_rules! impl_client_v17__get_block_header {
 /// Implements Bitcoin Core JSON-RPC API method `getblockstats`.
 #[macro_export]
 macro_rules! impl_client_v17__get_block_stats {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_block_stats_by_height(
                 &self,
                 height: u32,
@@ -127,6 +134,18 @@ macro_rules! impl_client_v17__get_block_stats {
             ) -> Result<GetBlockStats> {
                 self.call("getblockstats", &[into_json(hash)?, into_json(stats)?])
             }
+
+            /// Gets stats for the block at `block`, a block hash or height.
+            ///
+            /// Pass `stats` to only compute the selected fields, all other fields in the result
+            /// will be `None`. This is considerably faster than computing every stat.
+            pub fn get_block_stats(
+                &self,
+                block: impl Into<HashOrHeight>,
+                stats: Option<&[StatField]>,
+            ) -> Result<GetBlockStats> {
+                self.call("getblockstats", &[into_json(block.into())?, into_json(stats)?])
+            }
         }
     };
 }
@@ -134,8 +153,8 @@ macro_rules! impl_client_v17__get_block_stats {
 /// Implements Bitcoin Core JSON-RPC API method `getchaintips`.
 #[macro_export]
 macro_rules! impl_client_v17__get_chain_tips {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_chain_tips(&self) -> Result<GetChainTips> { self.call("getchaintips", &[]) }
         }
     };
@@ -144,11 +163,24 @@ macro_rules! impl_client_v17__get_chain_tips {
 /// Implements Bitcoin Core JSON-RPC API method `getchaintxstats`.
 #[macro_export]
 macro_rules! impl_client_v17__get_chain_tx_stats {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_chain_tx_stats(&self) -> Result<GetChainTxStats> {
                 self.call("getchaintxstats", &[])
             }
+
+            /// Gets the transaction stats for the window of `n_blocks` blocks ending with block
+            /// `block_hash`.
+            ///
+            /// Defaults to a window of one month ending with the chain tip. The window fields of
+            /// the result are omitted for an empty window e.g., near the genesis block.
+            pub fn get_chain_tx_stats_with_window(
+                &self,
+                n_blocks: Option<u32>,
+                block_hash: Option<BlockHash>,
+            ) -> Result<GetChainTxStats> {
+                self.call("getchaintxstats", &[into_json(n_blocks)?, into_json(block_hash)?])
+            }
         }
     };
 }
@@ -156,8 +188,8 @@ macro_rules! impl_client_v17__get_chain_tx_stats {
 /// Implements Bitcoin Core JSON-RPC API method `getdifficulty`.
 #[macro_export]
 macro_rules! impl_client_v17__get_difficulty {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_difficulty(&self) -> Result<GetDifficulty> {
                 self.call("getdifficulty", &[])
             }
@@ -168,8 +200,8 @@ macro_rules! impl_client_v17__get_difficulty {
 /// Implements Bitcoin Core JSON-RPC API method `getmempoolancestors`.
 #[macro_export]
 macro_rules! impl_client_v17__get_mempool_ancestors {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_mempool_ancestors(&self, txid: Txid) -> Result<GetMempoolAncestors> {
                 // Equivalent to self.call("getmempoolancestors", &[into_json(txid)?, into_json(false)?])
                 self.call("getmempoolancestors", &[into_json(txid)?])
@@ -188,8 +220,8 @@ macro_rules! impl_client_v17__get_mempool_ancestors {
 /// Implements Bitcoin Core JSON-RPC API method `getmempooldescendants`.
 #[macro_export]
 macro_rules! impl_client_v17__get_mempool_descendants {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_mempool_descendants(&self, txid: Txid) -> Result<GetMempoolDescendants> {
                 // Equivalent to self.call("getmempooldescendants", &[into_json(txid)?, into_json(false)?])
                 self.call("getmempooldescendants", &[into_json(txid)?])
@@ -208,8 +240,8 @@ macro_rules! impl_client_v17__get_mempool_descendants {
 /// Implements Bitcoin Core JSON-RPC API method `getmempoolentry`.
 #[macro_export]
 macro_rules! impl_client_v17__get_mempool_entry {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_mempool_entry(&self, txid: Txid) -> Result<GetMempoolEntry> {
                 self.call("getmempoolentry", &[into_json(txid)?])
             }
@@ -220,8 +252,8 @@ macro_rules! impl_client_v17__get_mempool_entry {
 /// Implements Bitcoin Core JSON-RPC API method `getmempoolinfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_mempool_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_mempool_info(&self) -> Result<GetMempoolInfo> {
                 self.call("getmempoolinfo", &[])
             }
@@ -232,8 +264,8 @@ macro_rules! impl_client_v17__get_mempool_info {
 /// Implements Bitcoin Core JSON-RPC API method `getrawmempool`.
 #[macro_export]
 macro_rules! impl_client_v17__get_raw_mempool {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_raw_mempool(&self) -> Result<GetRawMempool> {
                 // Equivalent to self.call("getrawmempool", &[into_json(false)?])
                 self.call("getrawmempool", &[])
@@ -249,8 +281,8 @@ macro_rules! impl_client_v17__get_raw_mempool {
 /// Implements Bitcoin Core JSON-RPC API method `gettxout`.
 #[macro_export]
 macro_rules! impl_client_v17__get_tx_out {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_tx_out(&self, txid: Txid, vout: u64) -> Result<GetTxOut> {
                 self.call("gettxout", &[into_json(txid)?, into_json(vout)?])
             }
@@ -261,11 +293,20 @@ macro_rules! impl_client_v17__get_tx_out {
 /// Implements Bitcoin Core JSON-RPC API method `gettxoutproof`.
 #[macro_export]
 macro_rules! impl_client_v17__get_tx_out_proof {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_tx_out_proof(&self, txids: &[Txid]) -> Result<String> {
                 self.call("gettxoutproof", &[into_json(txids)?])
             }
+
+            /// Gets a proof that the transactions `txids` are included in a block.
+            pub fn get_tx_out_proof_merkle_block(
+                &self,
+                txids: &[Txid],
+            ) -> Result<bitcoin::MerkleBlock> {
+                let hex = self.get_tx_out_proof(txids)?;
+                Ok(bitcoin::consensus::encode::deserialize_hex(&hex)?)
+            }
         }
     };
 }
@@ -273,8 +314,8 @@ macro_rules! impl_client_v17__get_tx_out_proof {
 /// Implements Bitcoin Core JSON-RPC API method `gettxoutsetinfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_tx_out_set_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
                 self.call("gettxoutsetinfo", &[])
             }
@@ -285,8 +326,8 @@ macro_rules! impl_client_v17__get_tx_out_set_info {
 /// Implements Bitcoin Core JSON-RPC API method `preciousblock`.
 #[macro_export]
 macro_rules! impl_client_v17__precious_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn precious_block(&self, hash: BlockHash) -> Result<()> {
                 match self.call("preciousblock", &[into_json(hash)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -301,8 +342,8 @@ macro_rules! impl_client_v17__precious_block {
 /// Implements Bitcoin Core JSON-RPC API method `pruneblockchain`.
 #[macro_export]
 macro_rules! impl_client_v17__prune_blockchain {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Instructs the node to prune the blockchain up to a specified height or timestamp.
             pub fn prune_blockchain(&self, target: u64) -> Result<PruneBlockchain> {
                 self.call("pruneblockchain", &[target.into()])
@@ -314,8 +355,8 @@ macro_rules! impl_client_v17__prune_blockchain {
 /// Implements Bitcoin Core JSON-RPC API method `savemempool`.
 #[macro_export]
 macro_rules! impl_client_v17__save_mempool {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn save_mempool(&self) -> Result<()> {
                 match self.call("savemempool", &[]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -330,8 +371,8 @@ macro_rules! impl_client_v17__save_mempool {
 /// Implements Bitcoin Core JSON-RPC API method `scantxoutset`
 #[macro_export]
 macro_rules! impl_client_v17__scan_tx_out_set {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Aborts an ongoing `scantxoutset` scan.
             pub fn scan_tx_out_set_abort(&self) -> Result<ScanTxOutSetAbort> {
                 self.call("scantxoutset", &[into_json("abort")?])
@@ -345,6 +386,14 @@ macro_rules! impl_client_v17__scan_tx_out_set {
                 self.call("scantxoutset", &[into_json("start")?, into_json(scan_objects)?])
             }
 
+            /// Starts a scan of the UTXO set for descriptors, including ranged descriptors.
+            pub fn scan_tx_out_set_start_with_objects(
+                &self,
+                scan_objects: &[ScanObject],
+            ) -> Result<ScanTxOutSetStart> {
+                self.call("scantxoutset", &[into_json("start")?, into_json(scan_objects)?])
+            }
+
             /// Checks the status of an ongoing `scantxoutset` scan.
             pub fn scan_tx_out_set_status(&self) -> Result<Option<ScanTxOutSetStatus>> {
                 self.call("scantxoutset", &[into_json("status")?])
@@ -356,9 +405,19 @@ macro_rules! impl_client_v17__scan_tx_out_set {
 /// Implements Bitcoin Core JSON-RPC API method `verifychain`
 #[macro_export]
 macro_rules! impl_client_v17__verify_chain {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn verify_chain(&self) -> Result<VerifyChain> { self.call("verifychain", &[]) }
+
+            /// Verifies the last `n_blocks` blocks (default 6, 0 for all) at `check_level` (0-4,
+            /// default 3).
+            pub fn verify_chain_with_options(
+                &self,
+                check_level: Option<u32>,
+                n_blocks: Option<u32>,
+            ) -> Result<VerifyChain> {
+                self.call("verifychain", &[into_json(check_level)?, into_json(n_blocks)?])
+            }
         }
     };
 }
@@ -366,12 +425,21 @@ macro_rules! impl_client_v17__verify_chain {
 /// Implements Bitcoin Core JSON-RPC API method `verifytxoutproof`.
 #[macro_export]
 macro_rules! impl_client_v17__verify_tx_out_proof {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             // `proof` is the hex-encoded proof generated by `gettxoutproof`.
             pub fn verify_tx_out_proof(&self, proof: &str) -> Result<VerifyTxOutProof> {
                 self.call("verifytxoutproof", &[into_json(proof)?])
             }
+
+            /// Verifies `proof` against the best chain, returning the IDs of the proven transactions.
+            pub fn verify_tx_out_proof_merkle_block(
+                &self,
+                proof: &bitcoin::MerkleBlock,
+            ) -> Result<VerifyTxOutProof> {
+                let hex = bitcoin::consensus::encode::serialize_hex(proof);
+                self.verify_tx_out_proof(&hex)
+            }
         }
     };
 }
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getmemoryinfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_memory_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_memory_info(&self) -> Result<GetMemoryInfoStats> {
                 self.call("getmemoryinfo", &[])
             }
@@ -24,8 +26,8 @@ macro_rules! impl_client_v17__get_memory_info {
 /// Implements Bitcoin Core JSON-RPC API method `help`.
 #[macro_export]
 macro_rules! impl_client_v17__help {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn help(&self) -> Result<String> { self.call("help", &[]) }
         }
     };
@@ -34,8 +36,8 @@ macro_rules! impl_client_v17__help {
 /// Implements Bitcoin Core JSON-RPC API method `logging`.
 #[macro_export]
 macro_rules! impl_client_v17__logging {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn logging(&self) -> Result<Logging> { self.call("logging", &[]) }
         }
     };
@@ -44,8 +46,8 @@ macro_rules! impl_client_v17__logging {
 /// Implements Bitcoin Core JSON-RPC API method `stop`.
 #[macro_export]
 macro_rules! impl_client_v17__stop {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn stop(&self) -> Result<String> { self.call("stop", &[]) }
         }
     };
@@ -54,8 +56,8 @@ macro_rules! impl_client_v17__stop {
 /// Implements Bitcoin Core JSON-RPC API method `uptime`.
 #[macro_export]
 macro_rules! impl_client_v17__uptime {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn uptime(&self) -> Result<u32> { self.call("uptime", &[]) }
         }
     };
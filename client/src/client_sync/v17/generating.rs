@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `generatetoaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__generate_to_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn generate_to_address(
                 &self,
                 nblocks: usize,
@@ -28,8 +30,8 @@ macro_rules! impl_client_v17__generate_to_address {
 /// Implements Bitcoin Core JSON-RPC API method `generate`.
 #[macro_export]
 macro_rules! impl_client_v17__generate {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn generate(&self, nblocks: usize) -> Result<Generate> {
                 self.call("generate", &[nblocks.into()])
             }
@@ -41,8 +43,8 @@ macro_rules! impl_client_v17__generate {
 // This method does not appear in the output of `bitcoin-cli help`.
 #[macro_export]
 macro_rules! impl_client_v17__invalidate_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn invalidate_block(&self, hash: BlockHash) -> Result<()> {
                 match self.call("invalidateblock", &[into_json(hash)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
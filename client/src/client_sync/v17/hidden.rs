@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `estimaterawfee`.
 #[macro_export]
 macro_rules! impl_client_v17__estimate_raw_fee {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// # Panics
             ///
             /// * Panics if `conf_target` is outside the range [1, 1008].
@@ -32,11 +34,21 @@ macro_rules! impl_client_v17__estimate_raw_fee {
 /// Implements Bitcoin Core JSON-RPC API method `waitforblock`.
 #[macro_export]
 macro_rules! impl_client_v17__wait_for_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wait_for_block(&self, hash: &bitcoin::BlockHash) -> Result<WaitForBlock> {
                 self.call("waitforblock", &[into_json(hash)?])
             }
+
+            /// Waits up to `timeout` for the block with hash `hash`, returning the tip on timeout.
+            pub fn wait_for_block_with_timeout(
+                &self,
+                hash: &bitcoin::BlockHash,
+                timeout: std::time::Duration,
+            ) -> Result<WaitForBlock> {
+                let (millis, timeout) = $crate::client_sync::wait_for_timeout(timeout);
+                self.call_with_timeout("waitforblock", &[into_json(hash)?, millis], timeout)
+            }
         }
     };
 }
@@ -44,11 +56,21 @@ macro_rules! impl_client_v17__wait_for_block {
 /// Implements Bitcoin Core JSON-RPC API method `waitforblockheight`.
 #[macro_export]
 macro_rules! impl_client_v17__wait_for_block_height {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wait_for_block_height(&self, height: u64) -> Result<WaitForBlockHeight> {
                 self.call("waitforblockheight", &[into_json(height)?])
             }
+
+            /// Waits up to `timeout` for the tip to reach `height`, returning the tip on timeout.
+            pub fn wait_for_block_height_with_timeout(
+                &self,
+                height: u64,
+                timeout: std::time::Duration,
+            ) -> Result<WaitForBlockHeight> {
+                let (millis, timeout) = $crate::client_sync::wait_for_timeout(timeout);
+                self.call_with_timeout("waitforblockheight", &[into_json(height)?, millis], timeout)
+            }
         }
     };
 }
@@ -56,11 +78,20 @@ macro_rules! impl_client_v17__wait_for_block_height {
 /// Implements Bitcoin Core JSON-RPC API method `waitfornewblock`.
 #[macro_export]
 macro_rules! impl_client_v17__wait_for_new_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wait_for_new_block(&self) -> Result<WaitForNewBlock> {
                 self.call("waitfornewblock", &[])
             }
+
+            /// Waits up to `timeout` for a new block, returning the tip on timeout.
+            pub fn wait_for_new_block_with_timeout(
+                &self,
+                timeout: std::time::Duration,
+            ) -> Result<WaitForNewBlock> {
+                let (millis, timeout) = $crate::client_sync::wait_for_timeout(timeout);
+                self.call_with_timeout("waitfornewblock", &[millis], timeout)
+            }
         }
     };
 }
@@ -68,8 +99,8 @@ macro_rules! impl_client_v17__wait_for_new_block {
 /// Implements Bitcoin Core JSON-RPC API method `syncwithvalidationinterfacequeue`.
 #[macro_export]
 macro_rules! impl_client_v17__sync_with_validation_interface_queue {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn sync_with_validation_interface_queue(&self) -> Result<()> {
                 self.call("syncwithvalidationinterfacequeue", &[])
             }
@@ -80,11 +111,26 @@ macro_rules! impl_client_v17__sync_with_validation_interface_queue {
 /// Implements Bitcoin Core JSON-RPC API method `reconsiderblock`.
 #[macro_export]
 macro_rules! impl_client_v17__reconsider_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn reconsider_block(&self, block_hash: bitcoin::BlockHash) -> Result<()> {
                 self.call("reconsiderblock", &[into_json(block_hash)?])
             }
         }
     };
 }
+
+/// Implements Bitcoin Core JSON-RPC API method `setmocktime`.
+#[macro_export]
+macro_rules! impl_client_v17__set_mock_time {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            /// Sets the node's clock to the UNIX `timestamp`, `0` goes back to the system clock.
+            ///
+            /// Only allowed on regtest.
+            pub fn set_mock_time(&self, timestamp: u64) -> Result<()> {
+                self.call("setmocktime", &[into_json(timestamp)?])
+            }
+        }
+    };
+}
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getblocktemplate`.
 #[macro_export]
 macro_rules! impl_client_v17__get_block_template {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_block_template(
                 &self,
                 request: &TemplateRequest,
@@ -24,11 +26,40 @@ macro_rules! impl_client_v17__get_block_template {
     };
 }
 
+/// Implements Bitcoin Core JSON-RPC API method `getblocktemplate` with a `longpollid`.
+#[macro_export]
+macro_rules! impl_client_v17__get_block_template_longpoll {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            /// Waits for the template to change from the one `longpollid` was returned with.
+            ///
+            /// The server returns a new template when a block is found, or after a minute if the
+            /// mempool changed. Uses [`LONGPOLL_TIMEOUT`] instead of the client timeout, call again
+            /// if the call times out.
+            ///
+            /// [`LONGPOLL_TIMEOUT`]: crate::client_sync::LONGPOLL_TIMEOUT
+            pub fn get_block_template_longpoll(
+                &self,
+                request: &TemplateRequest,
+                longpollid: &str,
+            ) -> Result<GetBlockTemplate> {
+                let mut request = into_json(request)?;
+                request["longpollid"] = longpollid.into();
+                self.call_with_timeout(
+                    "getblocktemplate",
+                    &[request],
+                    $crate::client_sync::LONGPOLL_TIMEOUT,
+                )
+            }
+        }
+    };
+}
+
 /// Implements Bitcoin Core JSON-RPC API method `getmininginfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_mining_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_mining_info(&self) -> Result<GetMiningInfo> {
                 self.call("getmininginfo", &[])
             }
@@ -39,8 +70,8 @@ macro_rules! impl_client_v17__get_mining_info {
 /// Implements Bitcoin Core JSON-RPC API method `getnetworkhashps`.
 #[macro_export]
 macro_rules! impl_client_v17__get_network_hashes_per_second {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_network_hash_ps(&self) -> Result<f64> { self.call("getnetworkhashps", &[]) }
         }
     };
@@ -49,8 +80,8 @@ macro_rules! impl_client_v17__get_network_hashes_per_second {
 /// Implements Bitcoin Core JSON-RPC API method `prioritisetransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__prioritise_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn prioritise_transaction(
                 &self,
                 txid: &Txid,
@@ -66,8 +97,8 @@ macro_rules! impl_client_v17__prioritise_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `submitblock`.
 #[macro_export]
 macro_rules! impl_client_v17__submit_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn submit_block(&self, block: &Block) -> Result<()> {
                 let hex: String = bitcoin::consensus::encode::serialize_hex(block);
                 match self.call("submitblock", &[into_json(hex)?]) {
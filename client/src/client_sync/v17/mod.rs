@@ -19,7 +19,9 @@ use std::collections::{BTreeMap, HashMap};
 use std::path::Path;
 
 use bitcoin::address::{Address, NetworkChecked};
-use bitcoin::{sign_message, Amount, Block, BlockHash, PublicKey, Txid};
+use bitcoin::{
+    sign_message, Amount, Block, BlockHash, FeeRate, OutPoint, PublicKey, ScriptBuf, Txid, Weight,
+};
 use serde::{Deserialize, Serialize, Serializer};
 
 use crate::client_sync::into_json;
@@ -73,9 +75,11 @@ crate::impl_client_v17__wait_for_block_height!();
 crate::impl_client_v17__wait_for_new_block!();
 crate::impl_client_v17__sync_with_validation_interface_queue!();
 crate::impl_client_v17__reconsider_block!();
+crate::impl_client_v17__set_mock_time!();
 
 // == Mining ==
 crate::impl_client_v17__get_block_template!();
+crate::impl_client_v17__get_block_template_longpoll!();
 crate::impl_client_v17__get_mining_info!();
 crate::impl_client_v17__get_network_hashes_per_second!();
 crate::impl_client_v17__prioritise_transaction!();
@@ -220,6 +224,157 @@ pub enum TemplateRules {
     Taproot,
 }
 
+/// Arg for the `getblockstats` and `gettxoutsetinfo` methods, the block to get stats for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum HashOrHeight {
+    /// The block hash.
+    Hash(BlockHash),
+    /// The block height.
+    Height(u32),
+}
+
+impl From<BlockHash> for HashOrHeight {
+    fn from(hash: BlockHash) -> Self { HashOrHeight::Hash(hash) }
+}
+
+impl From<&BlockHash> for HashOrHeight {
+    fn from(hash: &BlockHash) -> Self { HashOrHeight::Hash(*hash) }
+}
+
+impl From<u32> for HashOrHeight {
+    fn from(height: u32) -> Self { HashOrHeight::Height(height) }
+}
+
+/// Arg for the `getblockstats` method, a stat to compute.
+///
+/// Variants are named after the corresponding field of `GetBlockStats`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum StatField {
+    /// `avgfee`: the average fee of the transactions in the block.
+    #[serde(rename = "avgfee")]
+    AverageFee,
+    /// `avgfeerate`: the average feerate, in satoshis per virtual byte.
+    #[serde(rename = "avgfeerate")]
+    AverageFeeRate,
+    /// `avgtxsize`: the average transaction size.
+    #[serde(rename = "avgtxsize")]
+    AverageTxSize,
+    /// `blockhash`: the block hash, to check for a reorg.
+    #[serde(rename = "blockhash")]
+    BlockHash,
+    /// `feerate_percentiles`: the feerates at the 10th, 25th, 50th, 75th and 90th percentile
+    /// weight unit.
+    #[serde(rename = "feerate_percentiles")]
+    FeeRatePercentiles,
+    /// `height`: the height of the block.
+    #[serde(rename = "height")]
+    Height,
+    /// `ins`: the number of inputs, excluding the coinbase.
+    #[serde(rename = "ins")]
+    Inputs,
+    /// `maxfee`: the maximum fee in the block.
+    #[serde(rename = "maxfee")]
+    MaxFee,
+    /// `maxfeerate`: the maximum feerate, in satoshis per virtual byte.
+    #[serde(rename = "maxfeerate")]
+    MaxFeeRate,
+    /// `maxtxsize`: the maximum transaction size.
+    #[serde(rename = "maxtxsize")]
+    MaxTxSize,
+    /// `medianfee`: the truncated median fee in the block.
+    #[serde(rename = "medianfee")]
+    MedianFee,
+    /// `mediantime`: the median time past of the block.
+    #[serde(rename = "mediantime")]
+    MedianTime,
+    /// `mediantxsize`: the truncated median transaction size.
+    #[serde(rename = "mediantxsize")]
+    MedianTxSize,
+    /// `minfee`: the minimum fee in the block.
+    #[serde(rename = "minfee")]
+    MinimumFee,
+    /// `minfeerate`: the minimum feerate, in satoshis per virtual byte.
+    #[serde(rename = "minfeerate")]
+    MinimumFeeRate,
+    /// `mintxsize`: the minimum transaction size.
+    #[serde(rename = "mintxsize")]
+    MinimumTxSize,
+    /// `outs`: the number of outputs.
+    #[serde(rename = "outs")]
+    Outputs,
+    /// `subsidy`: the block subsidy.
+    #[serde(rename = "subsidy")]
+    Subsidy,
+    /// `swtotal_size`: the total size of the segwit transactions.
+    #[serde(rename = "swtotal_size")]
+    SegwitTotalSize,
+    /// `swtotal_weight`: the total weight of the segwit transactions.
+    #[serde(rename = "swtotal_weight")]
+    SegwitTotalWeight,
+    /// `swtxs`: the number of segwit transactions.
+    #[serde(rename = "swtxs")]
+    SegwitTxs,
+    /// `time`: the block time.
+    #[serde(rename = "time")]
+    Time,
+    /// `total_out`: the total amount of the outputs, excluding the coinbase.
+    #[serde(rename = "total_out")]
+    TotalOut,
+    /// `total_size`: the total size of the transactions, excluding the coinbase.
+    #[serde(rename = "total_size")]
+    TotalSize,
+    /// `total_weight`: the total weight of the transactions, excluding the coinbase.
+    #[serde(rename = "total_weight")]
+    TotalWeight,
+    /// `totalfee`: the total fee of the transactions in the block.
+    #[serde(rename = "totalfee")]
+    TotalFee,
+    /// `txs`: the number of transactions, including the coinbase.
+    #[serde(rename = "txs")]
+    Txs,
+    /// `utxo_increase`: the change in the number of unspent outputs.
+    #[serde(rename = "utxo_increase")]
+    UtxoIncrease,
+    /// `utxo_size_inc`: the change in the size of the UTXO set.
+    #[serde(rename = "utxo_size_inc")]
+    UtxoSizeIncrease,
+    /// `utxo_increase_actual`: the change in the number of unspent outputs, not counting
+    /// unspendable ones. Core v25 onwards.
+    #[serde(rename = "utxo_increase_actual")]
+    UtxoIncreaseActual,
+    /// `utxo_size_inc_actual`: the change in the size of the UTXO set, not counting unspendable
+    /// outputs. Core v25 onwards.
+    #[serde(rename = "utxo_size_inc_actual")]
+    UtxoSizeIncreaseActual,
+}
+
+/// Arg for the `hash_type` parameter of the `gettxoutsetinfo` method, the UTXO set hash to compute.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum TxOutSetHashType {
+    /// The legacy hash of the serialized UTXO set, Core v0.21 to v25.
+    #[serde(rename = "hash_serialized_2")]
+    HashSerialized2,
+    /// The hash of the serialized UTXO set, Core v26 onwards.
+    #[serde(rename = "hash_serialized_3")]
+    HashSerialized3,
+    /// The MuHash of the UTXO set, Core v0.21 onwards.
+    #[serde(rename = "muhash")]
+    Muhash,
+    /// Do not compute a hash.
+    #[serde(rename = "none")]
+    None,
+}
+
+/// Arg for the `filter_type` parameter of the `getblockfilter` and `scanblocks` methods.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub enum BlockFilterType {
+    /// The BIP-158 basic filter, the only type supported by Core.
+    #[default]
+    #[serde(rename = "basic")]
+    Basic,
+}
+
 /// Input used as parameter to `create_raw_transaction`.
 #[derive(Debug, Serialize)]
 pub struct Input {
@@ -235,17 +390,117 @@ pub struct Input {
 // Abuse `HashMap` so we can derive serialize to get the correct JSON object.
 #[derive(Debug, Serialize)]
 pub struct Output(
-    /// Map of address to value. Always only has a single item in it.
-    HashMap<String, f64>,
+    /// Map of address to value, or `"data"` to hex. Always only has a single item in it.
+    HashMap<String, serde_json::Value>,
 );
 
 impl Output {
     /// Creates a single output that serializes as Core expects.
     pub fn new(addr: Address, value: Amount) -> Self {
         let mut map = HashMap::new();
-        map.insert(addr.to_string(), value.to_btc());
+        map.insert(addr.to_string(), value.to_btc().into());
         Output(map)
     }
+
+    /// Creates a zero value `OP_RETURN` output carrying `data`.
+    pub fn data(data: &[u8]) -> Self {
+        use bitcoin::hex::DisplayHex as _;
+
+        let mut map = HashMap::new();
+        map.insert("data".to_owned(), data.to_lower_hex_string().into());
+        Output(map)
+    }
+}
+
+/// The `options` argument of method `fundrawtransaction`, options not set use the node's defaults.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FundRawTransactionOptions {
+    /// The address to receive the change.
+    pub change_address: Option<Address>,
+    /// The index of the change output.
+    pub change_position: Option<u32>,
+    /// Also select inputs which are watch only.
+    pub include_watching: Option<bool>,
+    /// Lock the selected unspent outputs.
+    pub lock_unspents: Option<bool>,
+    /// The fee rate to pay.
+    ///
+    /// Always sent as the `feeRate` option in BTC/kvB, which all versions accept and which holds
+    /// any `FeeRate` exactly. The `fee_rate` option in sat/vB (v0.21 onwards) is never sent.
+    pub fee_rate: Option<FeeRate>,
+    /// The outputs to deduct the fee from, by index.
+    pub subtract_fee_from_outputs: Vec<u32>,
+    /// The maximum weight of external inputs that are not in the wallet (v23 onwards).
+    pub input_weights: Vec<(OutPoint, Weight)>,
+}
+
+impl Serialize for FundRawTransactionOptions {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        use serde::ser::SerializeMap as _;
+
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(ref address) = self.change_address {
+            map.serialize_entry("changeAddress", address)?;
+        }
+        if let Some(position) = self.change_position {
+            map.serialize_entry("changePosition", &position)?;
+        }
+        if let Some(include_watching) = self.include_watching {
+            map.serialize_entry("includeWatching", &include_watching)?;
+        }
+        if let Some(lock_unspents) = self.lock_unspents {
+            map.serialize_entry("lockUnspents", &lock_unspents)?;
+        }
+        if let Some(fee_rate) = self.fee_rate {
+            map.serialize_entry("feeRate", &crate::client_sync::fee_rate_to_btc_per_kvb(fee_rate))?;
+        }
+        if !self.subtract_fee_from_outputs.is_empty() {
+            map.serialize_entry("subtractFeeFromOutputs", &self.subtract_fee_from_outputs)?;
+        }
+        if !self.input_weights.is_empty() {
+            let input_weights = self
+                .input_weights
+                .iter()
+                .map(|(outpoint, weight)| {
+                    serde_json::json!({
+                        "txid": outpoint.txid,
+                        "vout": outpoint.vout,
+                        "weight": weight.to_wu(),
+                    })
+                })
+                .collect::<Vec<_>>();
+            map.serialize_entry("input_weights", &input_weights)?;
+        }
+        map.end()
+    }
+}
+
+/// A previous output, an element in the `prevtxs` argument of method `signrawtransactionwithkey`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PrevTx {
+    /// The txid of the transaction that contains the output.
+    pub txid: Txid,
+    /// The index of the output.
+    pub vout: u32,
+    /// The script of the output.
+    #[serde(rename = "scriptPubKey")]
+    pub script_pubkey: ScriptBuf,
+    /// The redeem script, required for P2SH outputs.
+    #[serde(rename = "redeemScript", skip_serializing_if = "Option::is_none")]
+    pub redeem_script: Option<ScriptBuf>,
+    /// The witness script, required for P2WSH and P2SH-P2WSH outputs.
+    #[serde(rename = "witnessScript", skip_serializing_if = "Option::is_none")]
+    pub witness_script: Option<ScriptBuf>,
+    /// The value of the output, required for segwit outputs.
+    #[serde(
+        default,
+        with = "bitcoin::amount::serde::as_btc::opt",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub amount: Option<Amount>,
 }
 
 /// An element in the `inputs` argument of method `walletcreatefundedpsbt`.
@@ -322,6 +577,27 @@ impl Serialize for ImportMultiTimestamp {
     }
 }
 
+/// An element of the `scanobjects` arg of the `scantxoutset` method.
+///
+/// Also used for the output descriptors of e.g., `getdescriptoractivity` and `utxoupdatepsbt`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum ScanObject {
+    /// An output descriptor, ranged descriptors are scanned up to child index 1000.
+    Descriptor(String),
+    /// A ranged output descriptor and the first and last child index to scan (v18 onwards).
+    Ranged {
+        /// The output descriptor.
+        desc: String,
+        /// The first and last child index to scan.
+        range: (u32, u32),
+    },
+}
+
+impl From<&str> for ScanObject {
+    fn from(desc: &str) -> Self { ScanObject::Descriptor(desc.to_owned()) }
+}
+
 /// Arg for the `estimatesmartfee` method.
 #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
 #[serde(rename_all = "UPPERCASE")]
@@ -333,3 +609,49 @@ pub enum FeeEstimateMode {
     /// Force estimatesmartfee to use conservative estimates
     Conservative,
 }
+
+/// Arg for the `sighashtype` parameter of the signing methods.
+///
+/// Used by `signrawtransactionwithkey`, `signrawtransactionwithwallet`, `walletprocesspsbt` and
+/// `descriptorprocesspsbt`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum SighashType {
+    /// Use the default signature hash type (taproot only, Core v22 onwards).
+    #[serde(rename = "DEFAULT")]
+    Default,
+    /// Sign all inputs and outputs.
+    #[serde(rename = "ALL")]
+    All,
+    /// Sign all inputs and no outputs.
+    #[serde(rename = "NONE")]
+    None,
+    /// Sign all inputs and the output with the same index.
+    #[serde(rename = "SINGLE")]
+    Single,
+    /// Sign this input and all outputs.
+    #[serde(rename = "ALL|ANYONECANPAY")]
+    AllPlusAnyoneCanPay,
+    /// Sign this input and no outputs.
+    #[serde(rename = "NONE|ANYONECANPAY")]
+    NonePlusAnyoneCanPay,
+    /// Sign this input and the output with the same index.
+    #[serde(rename = "SINGLE|ANYONECANPAY")]
+    SinglePlusAnyoneCanPay,
+}
+
+impl fmt::Display for SighashType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use SighashType::*;
+
+        let s = match *self {
+            Default => "DEFAULT",
+            All => "ALL",
+            None => "NONE",
+            Single => "SINGLE",
+            AllPlusAnyoneCanPay => "ALL|ANYONECANPAY",
+            NonePlusAnyoneCanPay => "NONE|ANYONECANPAY",
+            SinglePlusAnyoneCanPay => "SINGLE|ANYONECANPAY",
+        };
+        fmt::Display::fmt(s, f)
+    }
+}
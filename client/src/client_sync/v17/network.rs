@@ -12,8 +12,8 @@
 /// Implements Bitcoin Core JSON-RPC API method `addnode`.
 #[macro_export]
 macro_rules! impl_client_v17__add_node {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn add_node(&self, node: &str, command: AddNodeCommand) -> Result<()> {
                 match self.call("addnode", &[into_json(node)?, into_json(command)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -28,8 +28,8 @@ macro_rules! impl_client_v17__add_node {
 /// Implements Bitcoin Core JSON-RPC API method `clearbanned`.
 #[macro_export]
 macro_rules! impl_client_v17__clear_banned {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn clear_banned(&self) -> Result<()> {
                 match self.call("clearbanned", &[]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -44,8 +44,8 @@ macro_rules! impl_client_v17__clear_banned {
 /// Implements Bitcoin Core JSON-RPC API method `disconnectnode`.
 #[macro_export]
 macro_rules! impl_client_v17__disconnect_node {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn disconnect_node(&self, address: &str) -> Result<()> {
                 match self.call("disconnectnode", &[into_json(address)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -60,8 +60,8 @@ macro_rules! impl_client_v17__disconnect_node {
 /// Implements Bitcoin Core JSON-RPC API method `getaddednodeinfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_added_node_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_added_node_info(&self) -> Result<GetAddedNodeInfo> {
                 self.call("getaddednodeinfo", &[])
             }
@@ -72,8 +72,8 @@ macro_rules! impl_client_v17__get_added_node_info {
 /// Implements Bitcoin Core JSON-RPC API method `getconnectioncount`.
 #[macro_export]
 macro_rules! impl_client_v17__get_connection_count {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_connection_count(&self) -> Result<GetConnectionCount> {
                 self.call("getconnectioncount", &[])
             }
@@ -84,8 +84,8 @@ macro_rules! impl_client_v17__get_connection_count {
 /// Implements Bitcoin Core JSON-RPC API method `getnettotals`.
 #[macro_export]
 macro_rules! impl_client_v17__get_net_totals {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_net_totals(&self) -> Result<GetNetTotals> { self.call("getnettotals", &[]) }
         }
     };
@@ -94,8 +94,8 @@ macro_rules! impl_client_v17__get_net_totals {
 /// Implements Bitcoin Core JSON-RPC API method `getnetworkinfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_network_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Returns the server version field of `GetNetworkInfo`.
             pub fn server_version(&self) -> Result<usize> {
                 let info = self.get_network_info()?;
@@ -112,8 +112,8 @@ macro_rules! impl_client_v17__get_network_info {
 /// Implements Bitcoin Core JSON-RPC API method `getpeerinfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_peer_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_peer_info(&self) -> Result<GetPeerInfo> { self.call("getpeerinfo", &[]) }
         }
     };
@@ -122,8 +122,8 @@ macro_rules! impl_client_v17__get_peer_info {
 /// Implements Bitcoin Core JSON-RPC API method `listbanned`.
 #[macro_export]
 macro_rules! impl_client_v17__list_banned {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_banned(&self) -> Result<ListBanned> { self.call("listbanned", &[]) }
         }
     };
@@ -132,8 +132,8 @@ macro_rules! impl_client_v17__list_banned {
 /// Implements Bitcoin Core JSON-RPC API method `ping`.
 #[macro_export]
 macro_rules! impl_client_v17__ping {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn ping(&self) -> Result<()> {
                 match self.call("ping", &[]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -148,8 +148,8 @@ macro_rules! impl_client_v17__ping {
 /// Implements Bitcoin Core JSON-RPC API method `setban`.
 #[macro_export]
 macro_rules! impl_client_v17__set_ban {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn set_ban(&self, subnet: &str, command: SetBanCommand) -> Result<()> {
                 match self.call("setban", &[into_json(subnet)?, into_json(command)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -164,8 +164,8 @@ macro_rules! impl_client_v17__set_ban {
 /// Implements Bitcoin Core JSON-RPC API method `setnetworkactive`.
 #[macro_export]
 macro_rules! impl_client_v17__set_network_active {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn set_network_active(&self, state: bool) -> Result<SetNetworkActive> {
                 self.call("setnetworkactive", &[into_json(state)?])
             }
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `combinepsbt`.
 #[macro_export]
 macro_rules! impl_client_v17__combine_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn combine_psbt(&self, txs: &[bitcoin::Psbt]) -> Result<CombinePsbt> {
                 let txs = txs.iter().map(|psbt| format!("{}", psbt)).collect::<Vec<String>>();
                 self.call("combinepsbt", &[txs.into()])
@@ -25,8 +27,8 @@ macro_rules! impl_client_v17__combine_psbt {
 /// Implements Bitcoin Core JSON-RPC API method `combinerawtransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__combine_raw_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn combine_raw_transaction(
                 &self,
                 txs: &[bitcoin::Transaction],
@@ -37,6 +39,16 @@ macro_rules! impl_client_v17__combine_raw_transaction {
                     .collect::<Vec<String>>();
                 self.call("combinerawtransaction", &[into_json(encoded)?])
             }
+
+            /// Combines the signatures of the serialized transactions `hexes`.
+            ///
+            /// All must be versions of the same transaction, each possibly partially signed.
+            pub fn combine_raw_transaction_hex(
+                &self,
+                hexes: &[&str],
+            ) -> Result<CombineRawTransaction> {
+                self.call("combinerawtransaction", &[into_json(hexes)?])
+            }
         }
     };
 }
@@ -44,12 +56,30 @@ macro_rules! impl_client_v17__combine_raw_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `converttopsbt`.
 #[macro_export]
 macro_rules! impl_client_v17__convert_to_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn convert_to_psbt(&self, tx: &bitcoin::Transaction) -> Result<ConvertToPsbt> {
                 let hex = bitcoin::consensus::encode::serialize_hex(tx);
                 self.call("converttopsbt", &[hex.into()])
             }
+
+            /// Converts `tx` to a PSBT.
+            ///
+            /// Signatures in `tx` are dropped if `permit_sig_data` is true, otherwise the call
+            /// fails for a transaction that has any. `is_witness` is passed to the node's
+            /// decoding of `tx`, see `decode_raw_transaction_hex`.
+            pub fn convert_to_psbt_with_options(
+                &self,
+                tx: &bitcoin::Transaction,
+                permit_sig_data: Option<bool>,
+                is_witness: Option<bool>,
+            ) -> Result<ConvertToPsbt> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call(
+                    "converttopsbt",
+                    &[hex.into(), into_json(permit_sig_data)?, into_json(is_witness)?],
+                )
+            }
         }
     };
 }
@@ -57,11 +87,33 @@ macro_rules! impl_client_v17__convert_to_psbt {
 /// Implements Bitcoin Core JSON-RPC API method `createpsbt`.
 #[macro_export]
 macro_rules! impl_client_v17__create_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn create_psbt(&self, inputs: &[Input], outputs: &[Output]) -> Result<CreatePsbt> {
                 self.call("createpsbt", &[into_json(inputs)?, into_json(outputs)?])
             }
+
+            /// Creates a PSBT with lock time `lock_time` (default 0).
+            ///
+            /// If `replaceable` is true inputs without a sequence number signal BIP-125
+            /// replaceability, the default is false before v24 and true after.
+            pub fn create_psbt_with_options(
+                &self,
+                inputs: &[Input],
+                outputs: &[Output],
+                lock_time: Option<bitcoin::absolute::LockTime>,
+                replaceable: Option<bool>,
+            ) -> Result<CreatePsbt> {
+                self.call(
+                    "createpsbt",
+                    &[
+                        into_json(inputs)?,
+                        into_json(outputs)?,
+                        into_json(lock_time.map(|l| l.to_consensus_u32()))?,
+                        into_json(replaceable)?,
+                    ],
+                )
+            }
         }
     };
 }
@@ -69,8 +121,8 @@ macro_rules! impl_client_v17__create_psbt {
 /// Implements Bitcoin Core JSON-RPC API method `createrawtransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__create_raw_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn create_raw_transaction(
                 &self,
                 inputs: &[Input],
@@ -78,6 +130,28 @@ macro_rules! impl_client_v17__create_raw_transaction {
             ) -> Result<CreateRawTransaction> {
                 self.call("createrawtransaction", &[into_json(inputs)?, into_json(outputs)?])
             }
+
+            /// Creates a transaction with lock time `lock_time` (default 0).
+            ///
+            /// If `replaceable` is true inputs without a sequence number signal BIP-125
+            /// replaceability, the default is false before v24 and true after.
+            pub fn create_raw_transaction_with_options(
+                &self,
+                inputs: &[Input],
+                outputs: &[Output],
+                lock_time: Option<bitcoin::absolute::LockTime>,
+                replaceable: Option<bool>,
+            ) -> Result<CreateRawTransaction> {
+                self.call(
+                    "createrawtransaction",
+                    &[
+                        into_json(inputs)?,
+                        into_json(outputs)?,
+                        into_json(lock_time.map(|l| l.to_consensus_u32()))?,
+                        into_json(replaceable)?,
+                    ],
+                )
+            }
         }
     };
 }
@@ -85,8 +159,8 @@ macro_rules! impl_client_v17__create_raw_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `decodepsbt`.
 #[macro_export]
 macro_rules! impl_client_v17__decode_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn decode_psbt(&self, psbt: &str) -> Result<DecodePsbt> {
                 self.call("decodepsbt", &[psbt.into()])
             }
@@ -97,13 +171,26 @@ macro_rules! impl_client_v17__decode_psbt {
 /// Implements Bitcoin Core JSON-RPC API method `finalizepsbt`.
 #[macro_export]
 macro_rules! impl_client_v17__finalize_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn finalize_psbt(&self, psbt: &bitcoin::Psbt) -> Result<FinalizePsbt> {
                 let psbt = format!("{}", psbt);
                 // Pass extract=false so Core returns the PSBT field in the response.
                 self.call("finalizepsbt", &[psbt.into(), false.into()])
             }
+
+            /// Finalizes the inputs of `psbt`.
+            ///
+            /// If all inputs are finalized and `extract` is true (the default) the network
+            /// transaction is returned instead of the PSBT, see `FinalizePsbt::into_finalized`.
+            pub fn finalize_psbt_with_options(
+                &self,
+                psbt: &bitcoin::Psbt,
+                extract: Option<bool>,
+            ) -> Result<FinalizePsbt> {
+                let psbt = format!("{}", psbt);
+                self.call("finalizepsbt", &[psbt.into(), into_json(extract)?])
+            }
         }
     };
 }
@@ -111,8 +198,8 @@ macro_rules! impl_client_v17__finalize_psbt {
 /// Implements Bitcoin Core JSON-RPC API method `decoderawtransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__decode_raw_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn decode_raw_transaction(
                 &self,
                 tx: &bitcoin::Transaction,
@@ -120,6 +207,18 @@ macro_rules! impl_client_v17__decode_raw_transaction {
                 let hex = bitcoin::consensus::encode::serialize_hex(tx);
                 self.call("decoderawtransaction", &[hex.into()])
             }
+
+            /// Decodes the serialized transaction `hex`.
+            ///
+            /// Some serializations are valid both with and without witness data. If `is_witness` is
+            /// not given the node tries both, picking the one that consumes all of `hex`.
+            pub fn decode_raw_transaction_hex(
+                &self,
+                hex: &str,
+                is_witness: Option<bool>,
+            ) -> Result<DecodeRawTransaction> {
+                self.call("decoderawtransaction", &[hex.into(), into_json(is_witness)?])
+            }
         }
     };
 }
@@ -127,8 +226,8 @@ macro_rules! impl_client_v17__decode_raw_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `decodescript`.
 #[macro_export]
 macro_rules! impl_client_v17__decode_script {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             // Arg is the hex encoded script we want to decode.
             pub fn decode_script(&self, script: &str) -> Result<DecodeScript> {
                 self.call("decodescript", &[script.into()])
@@ -140,14 +239,27 @@ macro_rules! impl_client_v17__decode_script {
 /// Implements Bitcoin Core JSON-RPC API method `fundrawtransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__fund_raw_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn fund_raw_transaction(
                 &self,
                 tx: &bitcoin::Transaction,
             ) -> Result<FundRawTransaction> {
                 let hex = bitcoin::consensus::encode::serialize_hex(tx);
-                self.call("fundrawtransaction", &[hex.into()])
+                self.call_named("fundrawtransaction", &[("hexstring", hex.into())])
+            }
+
+            /// Adds inputs from the wallet, and a change output if needed, to `tx`.
+            pub fn fund_raw_transaction_with_options(
+                &self,
+                tx: &bitcoin::Transaction,
+                options: &FundRawTransactionOptions,
+            ) -> Result<FundRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call_named(
+                    "fundrawtransaction",
+                    &[("hexstring", hex.into()), ("options", into_json(options)?)],
+                )
             }
         }
     };
@@ -156,8 +268,8 @@ macro_rules! impl_client_v17__fund_raw_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `getrawtransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__get_raw_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_raw_transaction(&self, txid: bitcoin::Txid) -> Result<GetRawTransaction> {
                 self.call("getrawtransaction", &[into_json(&txid)?, false.into()])
             }
@@ -168,6 +280,30 @@ macro_rules! impl_client_v17__get_raw_transaction {
             ) -> Result<GetRawTransactionVerbose> {
                 self.call("getrawtransaction", &[into_json(&txid)?, true.into()])
             }
+
+            /// Gets the transaction `txid` from the block `block_hash`, works without `-txindex`.
+            pub fn get_raw_transaction_in_block(
+                &self,
+                txid: Txid,
+                block_hash: bitcoin::BlockHash,
+            ) -> Result<GetRawTransaction> {
+                self.call(
+                    "getrawtransaction",
+                    &[into_json(&txid)?, false.into(), into_json(&block_hash)?],
+                )
+            }
+
+            /// Gets the transaction `txid` from the block `block_hash`, works without `-txindex`.
+            pub fn get_raw_transaction_verbose_in_block(
+                &self,
+                txid: Txid,
+                block_hash: bitcoin::BlockHash,
+            ) -> Result<GetRawTransactionVerbose> {
+                self.call(
+                    "getrawtransaction",
+                    &[into_json(&txid)?, true.into(), into_json(&block_hash)?],
+                )
+            }
         }
     };
 }
@@ -175,8 +311,8 @@ macro_rules! impl_client_v17__get_raw_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `sendrawtransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__send_raw_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn send_raw_transaction(
                 &self,
                 tx: &bitcoin::Transaction,
@@ -191,8 +327,8 @@ macro_rules! impl_client_v17__send_raw_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `signrawtransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__sign_raw_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn sign_raw_transaction(
                 &self,
                 tx: &bitcoin::Transaction,
@@ -207,8 +343,8 @@ macro_rules! impl_client_v17__sign_raw_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `signrawtransactionwithkey`.
 #[macro_export]
 macro_rules! impl_client_v17__sign_raw_transaction_with_key {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn sign_raw_transaction_with_key(
                 &self,
                 tx: &bitcoin::Transaction,
@@ -218,6 +354,39 @@ macro_rules! impl_client_v17__sign_raw_transaction_with_key {
                 let keys = keys.iter().map(|k| format!("{}", k)).collect::<Vec<String>>();
                 self.call("signrawtransactionwithkey", &[hex.into(), into_json(keys)?])
             }
+
+            /// Calls `signrawtransactionwithkey` signing with `sighash_type`.
+            ///
+            /// Same as [`Self::sign_raw_transaction_with_key_with_options`] without `prevtxs`, the
+            /// node looks up the outputs spent by `tx` itself.
+            pub fn sign_raw_transaction_with_key_and_sighash(
+                &self,
+                tx: &bitcoin::Transaction,
+                keys: &[bitcoin::PrivateKey],
+                sighash_type: SighashType,
+            ) -> Result<SignRawTransactionWithKey> {
+                self.sign_raw_transaction_with_key_with_options(tx, keys, &[], Some(sighash_type))
+            }
+
+            /// Calls `signrawtransactionwithkey` with all arguments.
+            ///
+            /// `prevtxs` describes outputs spent by `tx` that the node does not know about e.g.,
+            /// outputs of transactions that are not yet broadcast. `None` for `sighash_type` uses
+            /// the node's default, `ALL`.
+            pub fn sign_raw_transaction_with_key_with_options(
+                &self,
+                tx: &bitcoin::Transaction,
+                keys: &[bitcoin::PrivateKey],
+                prevtxs: &[PrevTx],
+                sighash_type: Option<SighashType>,
+            ) -> Result<SignRawTransactionWithKey> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                let keys = keys.iter().map(|k| format!("{}", k)).collect::<Vec<String>>();
+                self.call(
+                    "signrawtransactionwithkey",
+                    &[hex.into(), into_json(keys)?, into_json(prevtxs)?, into_json(sighash_type)?],
+                )
+            }
         }
     };
 }
@@ -225,8 +394,8 @@ macro_rules! impl_client_v17__sign_raw_transaction_with_key {
 /// Implements Bitcoin Core JSON-RPC API method `testmempoolaccept`.
 #[macro_export]
 macro_rules! impl_client_v17__test_mempool_accept {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn test_mempool_accept(
                 &self,
                 txs: &[bitcoin::Transaction],
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `createmultisig`.
 #[macro_export]
 macro_rules! impl_client_v17__create_multisig {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn create_multisig(
                 &self,
                 nrequired: u32,
@@ -28,8 +30,8 @@ macro_rules! impl_client_v17__create_multisig {
 /// Implements Bitcoin Core JSON-RPC API method `estimatesmartfee`.
 #[macro_export]
 macro_rules! impl_client_v17__estimate_smart_fee {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn estimate_smart_fee(&self, blocks: u32) -> Result<EstimateSmartFee> {
                 self.call("estimatesmartfee", &[blocks.into()])
             }
@@ -48,8 +50,8 @@ macro_rules! impl_client_v17__estimate_smart_fee {
 /// Implements Bitcoin Core JSON-RPC API method `signmessagewithprivkey`.
 #[macro_export]
 macro_rules! impl_client_v17__sign_message_with_priv_key {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn sign_message_with_privkey(
                 &self,
                 privkey: &bitcoin::PrivateKey,
@@ -64,8 +66,8 @@ macro_rules! impl_client_v17__sign_message_with_priv_key {
 /// Implements Bitcoin Core JSON-RPC API method `validateaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__validate_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn validate_address(
                 &self,
                 address: &Address<NetworkChecked>,
@@ -79,8 +81,8 @@ macro_rules! impl_client_v17__validate_address {
 /// Implements Bitcoin Core JSON-RPC API method `verifymessage`.
 #[macro_export]
 macro_rules! impl_client_v17__verify_message {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn verify_message(
                 &self,
                 address: &Address<NetworkChecked>,
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `abandontransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__abandon_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn abandon_transaction(&self, txid: Txid) -> Result<()> {
                 match self.call("abandontransaction", &[into_json(txid)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -28,8 +30,8 @@ macro_rules! impl_client_v17__abandon_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `abortrescan`.
 #[macro_export]
 macro_rules! impl_client_v17__abort_rescan {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn abort_rescan(&self) -> Result<AbortRescan> { self.call("abortrescan", &[]) }
         }
     };
@@ -38,8 +40,8 @@ macro_rules! impl_client_v17__abort_rescan {
 /// Implements Bitcoin Core JSON-RPC API method `addmultisigaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__add_multisig_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn add_multisig_address_with_keys(
                 &self,
                 nrequired: u32,
@@ -55,6 +57,29 @@ macro_rules! impl_client_v17__add_multisig_address {
             ) -> Result<AddMultisigAddress> {
                 self.call("addmultisigaddress", &[nrequired.into(), into_json(keys)?])
             }
+
+            /// Adds a multisig address to the (legacy) wallet.
+            ///
+            /// `keys_or_addresses` may contain hex encoded public keys and addresses. The label
+            /// defaults to "" if only an address type is given.
+            pub fn add_multisig_address(
+                &self,
+                nrequired: u32,
+                keys_or_addresses: &[&str],
+                label: Option<&str>,
+                address_type: Option<AddressType>,
+            ) -> Result<AddMultisigAddress> {
+                let mut args = vec![nrequired.into(), into_json(keys_or_addresses)?];
+                match (label, address_type) {
+                    (label, Some(ty)) => {
+                        args.push(label.unwrap_or_default().into());
+                        args.push(into_json(ty)?);
+                    }
+                    (Some(label), None) => args.push(label.into()),
+                    (None, None) => {}
+                }
+                self.call("addmultisigaddress", &args)
+            }
         }
     };
 }
@@ -62,8 +87,8 @@ macro_rules! impl_client_v17__add_multisig_address {
 /// Implements Bitcoin Core JSON-RPC API method `bumpfee`.
 #[macro_export]
 macro_rules! impl_client_v17__backup_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn backup_wallet(&self, destination: &Path) -> Result<()> {
                 match self.call("backupwallet", &[into_json(destination)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -78,8 +103,8 @@ macro_rules! impl_client_v17__backup_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `bumpfee`.
 #[macro_export]
 macro_rules! impl_client_v17__bump_fee {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn bump_fee(&self, txid: Txid) -> Result<BumpFee> {
                 self.call("bumpfee", &[into_json(txid)?])
             }
@@ -90,10 +115,10 @@ macro_rules! impl_client_v17__bump_fee {
 /// Implements Bitcoin Core JSON-RPC API method `createwallet`.
 #[macro_export]
 macro_rules! impl_client_v17__create_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
-                self.call("createwallet", &[wallet.into()])
+                self.call_named("createwallet", &[("wallet_name", wallet.into())])
             }
         }
     };
@@ -102,8 +127,8 @@ macro_rules! impl_client_v17__create_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `dumpprivkey`.
 #[macro_export]
 macro_rules! impl_client_v17__dump_priv_key {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn dump_priv_key(&self, address: &Address) -> Result<DumpPrivKey> {
                 self.call("dumpprivkey", &[into_json(address)?])
             }
@@ -114,8 +139,8 @@ macro_rules! impl_client_v17__dump_priv_key {
 /// Implements Bitcoin Core JSON-RPC API method `dumpwallet`.
 #[macro_export]
 macro_rules! impl_client_v17__dump_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             // filename is either absolute or relative to bitcoind.
             pub fn dump_wallet(&self, filename: &Path) -> Result<DumpWallet> {
                 self.call("dumpwallet", &[into_json(filename)?])
@@ -127,8 +152,8 @@ macro_rules! impl_client_v17__dump_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `encryptwallet`.
 #[macro_export]
 macro_rules! impl_client_v17__encrypt_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             // filename is either absolute or relative to bitcoind.
             pub fn encrypt_wallet(&self, passphrase: &str) -> Result<EncryptWallet> {
                 self.call("encryptwallet", &[into_json(passphrase)?])
@@ -140,8 +165,8 @@ macro_rules! impl_client_v17__encrypt_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `getaddressesbylabel`.
 #[macro_export]
 macro_rules! impl_client_v17__get_addresses_by_label {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_addresses_by_label(&self, label: &str) -> Result<GetAddressesByLabel> {
                 self.call("getaddressesbylabel", &[label.into()])
             }
@@ -152,8 +177,8 @@ macro_rules! impl_client_v17__get_addresses_by_label {
 /// Implements Bitcoin Core JSON-RPC API method `getaddressinfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_address_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_address_info(&self, address: &Address) -> Result<GetAddressInfo> {
                 self.call("getaddressinfo", &[into_json(address)?])
             }
@@ -164,8 +189,8 @@ macro_rules! impl_client_v17__get_address_info {
 /// Implements Bitcoin Core JSON-RPC API method `getbalance`.
 #[macro_export]
 macro_rules! impl_client_v17__get_balance {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_balance(&self) -> Result<GetBalance> { self.call("getbalance", &[]) }
         }
     };
@@ -174,8 +199,8 @@ macro_rules! impl_client_v17__get_balance {
 /// Implements Bitcoin Core JSON-RPC API method `getnewaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__get_new_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Gets a new address from `bitcoind` and parses it assuming its correct.
             pub fn new_address(&self) -> Result<bitcoin::Address> {
                 let json = self.get_new_address(None, None)?;
@@ -207,13 +232,29 @@ macro_rules! impl_client_v17__get_new_address {
                 label: Option<&str>,
                 ty: Option<AddressType>,
             ) -> Result<GetNewAddress> {
-                match (label, ty) {
-                    (Some(label), Some(ty)) =>
-                        self.call("getnewaddress", &[into_json(label)?, into_json(ty)?]),
-                    (Some(label), None) => self.call("getnewaddress", &[into_json(label)?]),
-                    (None, Some(ty)) => self.call("getnewaddress", &["".into(), into_json(ty)?]),
-                    (None, None) => self.call("getnewaddress", &[]),
-                }
+                let args = match (label, ty) {
+                    (Some(label), Some(ty)) => vec![into_json(label)?, into_json(ty)?],
+                    (Some(label), None) => vec![into_json(label)?],
+                    (None, Some(ty)) => vec!["".into(), into_json(ty)?],
+                    (None, None) => vec![],
+                };
+                self.call("getnewaddress", &args)
+            }
+
+            /// Gets `n` new addresses using a single JSON-RPC batch request.
+            pub fn get_new_addresses(
+                &self,
+                n: usize,
+                label: Option<&str>,
+                ty: Option<AddressType>,
+            ) -> Result<Vec<GetNewAddress>> {
+                let args = match (label, ty) {
+                    (Some(label), Some(ty)) => vec![into_json(label)?, into_json(ty)?],
+                    (Some(label), None) => vec![into_json(label)?],
+                    (None, Some(ty)) => vec!["".into(), into_json(ty)?],
+                    (None, None) => vec![],
+                };
+                self.call_batch("getnewaddress", &vec![args; n])
             }
         }
     };
@@ -222,8 +263,8 @@ macro_rules! impl_client_v17__get_new_address {
 /// Implements Bitcoin Core JSON-RPC API method `getrawchangeaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__get_raw_change_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_raw_change_address(&self) -> Result<GetRawChangeAddress> {
                 self.call("getrawchangeaddress", &[])
             }
@@ -234,8 +275,8 @@ macro_rules! impl_client_v17__get_raw_change_address {
 /// Implements Bitcoin Core JSON-RPC API method `getreceivedbyaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__get_received_by_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_received_by_address(
                 &self,
                 address: &Address<NetworkChecked>,
@@ -249,8 +290,8 @@ macro_rules! impl_client_v17__get_received_by_address {
 /// Implements Bitcoin Core JSON-RPC API method `gettransaction`.
 #[macro_export]
 macro_rules! impl_client_v17__get_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_transaction(&self, txid: Txid) -> Result<GetTransaction> {
                 self.call("gettransaction", &[into_json(txid)?])
             }
@@ -261,8 +302,8 @@ macro_rules! impl_client_v17__get_transaction {
 /// Implements Bitcoin Core JSON-RPC API method `getunconfirmedbalance`.
 #[macro_export]
 macro_rules! impl_client_v17__get_unconfirmed_balance {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_unconfirmed_balance(&self) -> Result<GetUnconfirmedBalance> {
                 self.call("getunconfirmedbalance", &[])
             }
@@ -273,8 +314,8 @@ macro_rules! impl_client_v17__get_unconfirmed_balance {
 /// Implements Bitcoin Core JSON-RPC API method `getwalletinfo`.
 #[macro_export]
 macro_rules! impl_client_v17__get_wallet_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_wallet_info(&self) -> Result<GetWalletInfo> {
                 self.call("getwalletinfo", &[])
             }
@@ -285,8 +326,8 @@ macro_rules! impl_client_v17__get_wallet_info {
 /// Implements Bitcoin Core JSON-RPC API method `importaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__import_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn import_address(&self, address: &Address) -> Result<()> {
                 match self.call("importaddress", &[into_json(address)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -301,8 +342,8 @@ macro_rules! impl_client_v17__import_address {
 /// Implements Bitcoin Core JSON-RPC API method `importmulti`.
 #[macro_export]
 macro_rules! impl_client_v17__import_multi {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn import_multi(&self, requests: &[ImportMultiRequest]) -> Result<ImportMulti> {
                 self.call("importmulti", &[into_json(requests)?])
             }
@@ -313,8 +354,8 @@ macro_rules! impl_client_v17__import_multi {
 /// Implements Bitcoin Core JSON-RPC API method `importprivkey`.
 #[macro_export]
 macro_rules! impl_client_v17__import_privkey {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn import_privkey(&self, privkey: &bitcoin::PrivateKey) -> Result<()> {
                 match self.call("importprivkey", &[into_json(privkey)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -329,8 +370,8 @@ macro_rules! impl_client_v17__import_privkey {
 /// Implements Bitcoin Core JSON-RPC API method `importprunedfunds`.
 #[macro_export]
 macro_rules! impl_client_v17__import_pruned_funds {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn import_pruned_funds(
                 &self,
                 raw_transaction: &str,
@@ -352,8 +393,8 @@ macro_rules! impl_client_v17__import_pruned_funds {
 /// Implements Bitcoin Core JSON-RPC API method `importpubkey`.
 #[macro_export]
 macro_rules! impl_client_v17__import_pubkey {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn import_pubkey(&self, pubkey: &bitcoin::PublicKey) -> Result<()> {
                 match self.call("importpubkey", &[into_json(pubkey)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -368,8 +409,8 @@ macro_rules! impl_client_v17__import_pubkey {
 /// Implements Bitcoin Core JSON-RPC API method `importwallet`.
 #[macro_export]
 macro_rules! impl_client_v17__import_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn import_wallet(&self, filename: &Path) -> Result<()> {
                 match self.call("importwallet", &[into_json(filename)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -384,8 +425,8 @@ macro_rules! impl_client_v17__import_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `keypoolrefill`.
 #[macro_export]
 macro_rules! impl_client_v17__keypool_refill {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn keypool_refill(&self) -> Result<()> {
                 match self.call("keypoolrefill", &[]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -400,8 +441,8 @@ macro_rules! impl_client_v17__keypool_refill {
 /// Implements Bitcoin Core JSON-RPC API method `listaddressgroupings`.
 #[macro_export]
 macro_rules! impl_client_v17__list_address_groupings {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_address_groupings(&self) -> Result<ListAddressGroupings> {
                 self.call("listaddressgroupings", &[])
             }
@@ -412,8 +453,8 @@ macro_rules! impl_client_v17__list_address_groupings {
 /// Implements Bitcoin Core JSON-RPC API method `listlabels`.
 #[macro_export]
 macro_rules! impl_client_v17__list_labels {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_labels(&self) -> Result<ListLabels> { self.call("listlabels", &[]) }
         }
     };
@@ -422,8 +463,8 @@ macro_rules! impl_client_v17__list_labels {
 /// Implements Bitcoin Core JSON-RPC API method `listlockunspent`.
 #[macro_export]
 macro_rules! impl_client_v17__list_lock_unspent {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_lock_unspent(&self) -> Result<ListLockUnspent> {
                 self.call("listlockunspent", &[])
             }
@@ -434,8 +475,8 @@ macro_rules! impl_client_v17__list_lock_unspent {
 /// Implements Bitcoin Core JSON-RPC API method `listreceivedbyaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__list_received_by_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_received_by_address(&self) -> Result<ListReceivedByAddress> {
                 self.call("listreceivedbyaddress", &[])
             }
@@ -446,8 +487,8 @@ macro_rules! impl_client_v17__list_received_by_address {
 /// Implements Bitcoin Core JSON-RPC API method `listsinceblock`.
 #[macro_export]
 macro_rules! impl_client_v17__list_since_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_since_block(&self) -> Result<ListSinceBlock> {
                 self.call("listsinceblock", &[])
             }
@@ -458,8 +499,8 @@ macro_rules! impl_client_v17__list_since_block {
 /// Implements Bitcoin Core JSON-RPC API method `listtransactions`.
 #[macro_export]
 macro_rules! impl_client_v17__list_transactions {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_transactions(&self) -> Result<ListTransactions> {
                 self.call("listtransactions", &[])
             }
@@ -470,8 +511,8 @@ macro_rules! impl_client_v17__list_transactions {
 /// Implements Bitcoin Core JSON-RPC API method `listunspent`.
 #[macro_export]
 macro_rules! impl_client_v17__list_unspent {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_unspent(&self) -> Result<ListUnspent> { self.call("listunspent", &[]) }
         }
     };
@@ -480,8 +521,8 @@ macro_rules! impl_client_v17__list_unspent {
 /// Implements Bitcoin Core JSON-RPC API method `listwallets`.
 #[macro_export]
 macro_rules! impl_client_v17__list_wallets {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_wallets(&self) -> Result<ListWallets> { self.call("listwallets", &[]) }
         }
     };
@@ -490,8 +531,8 @@ macro_rules! impl_client_v17__list_wallets {
 /// Implements Bitcoin Core JSON-RPC API method `loadwallet`.
 #[macro_export]
 macro_rules! impl_client_v17__load_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn load_wallet(&self, filename: &str) -> Result<LoadWallet> {
                 self.call("loadwallet", &[into_json(filename)?])
             }
@@ -502,8 +543,8 @@ macro_rules! impl_client_v17__load_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `lockunspent`.
 #[macro_export]
 macro_rules! impl_client_v17__lock_unspent {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Lock the given list of transaction outputs. Returns true on success.
             ///
             /// This wraps Core RPC: `lockunspent false [{"txid":"..","vout":n},...]`.
@@ -532,8 +573,8 @@ macro_rules! impl_client_v17__lock_unspent {
 /// Implements Bitcoin Core JSON-RPC API method `removeprunedfunds`.
 #[macro_export]
 macro_rules! impl_client_v17__remove_pruned_funds {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn remove_pruned_funds(&self, txid: Txid) -> Result<()> {
                 self.call("removeprunedfunds", &[into_json(txid)?])
             }
@@ -544,8 +585,8 @@ macro_rules! impl_client_v17__remove_pruned_funds {
 /// Implements Bitcoin Core JSON-RPC API method `rescanblockchain`.
 #[macro_export]
 macro_rules! impl_client_v17__rescan_blockchain {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn rescan_blockchain(&self) -> Result<RescanBlockchain> {
                 self.call("rescanblockchain", &[])
             }
@@ -556,8 +597,8 @@ macro_rules! impl_client_v17__rescan_blockchain {
 /// Implements Bitcoin Core JSON-RPC API method `sendmany`.
 #[macro_export]
 macro_rules! impl_client_v17__send_many {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn send_many(&self, amounts: BTreeMap<Address, Amount>) -> Result<SendMany> {
                 let dummy = ""; // Must be set to "" for backwards compatibility.
                 let amount_btc: BTreeMap<String, f64> = amounts
@@ -573,8 +614,8 @@ macro_rules! impl_client_v17__send_many {
 /// Implements Bitcoin Core JSON-RPC API method `sendtoaddress`.
 #[macro_export]
 macro_rules! impl_client_v17__send_to_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             // Send to address - no RBF.
             pub fn send_to_address(
                 &self,
@@ -613,8 +654,8 @@ macro_rules! impl_client_v17__send_to_address {
 /// Implements Bitcoin Core JSON-RPC API method `sethdseed`.
 #[macro_export]
 macro_rules! impl_client_v17__set_hd_seed {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn set_hd_seed(&self) -> Result<()> {
                 match self.call("sethdseed", &[]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -629,8 +670,8 @@ macro_rules! impl_client_v17__set_hd_seed {
 /// Implements Bitcoin Core JSON-RPC API method `settxfee`.
 #[macro_export]
 macro_rules! impl_client_v17__set_tx_fee {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn set_tx_fee(&self, fee_rate: bitcoin::FeeRate) -> Result<SetTxFee> {
                 let fee_rate_btc_kvb = fee_rate.to_sat_per_vb_floor() as f64 / 100_000.0;
                 self.call("settxfee", &[fee_rate_btc_kvb.into()])
@@ -642,8 +683,8 @@ macro_rules! impl_client_v17__set_tx_fee {
 /// Implements Bitcoin Core JSON-RPC API method `signmessage`.
 #[macro_export]
 macro_rules! impl_client_v17__sign_message {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn sign_message(&self, address: &Address, message: &str) -> Result<SignMessage> {
                 self.call("signmessage", &[into_json(address)?, into_json(message)?])
             }
@@ -654,8 +695,8 @@ macro_rules! impl_client_v17__sign_message {
 /// Implements Bitcoin Core JSON-RPC API method `signrawtransactionwithwallet`.
 #[macro_export]
 macro_rules! impl_client_v17__sign_raw_transaction_with_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             // `hexstring`: The transaction hex string.
             pub fn sign_raw_transaction_with_wallet(
                 &self,
@@ -664,6 +705,18 @@ macro_rules! impl_client_v17__sign_raw_transaction_with_wallet {
                 let hex = bitcoin::consensus::encode::serialize_hex(tx);
                 self.call("signrawtransactionwithwallet", &[into_json(hex)?])
             }
+
+            pub fn sign_raw_transaction_with_wallet_and_sighash(
+                &self,
+                tx: &bitcoin::Transaction,
+                sighash_type: SighashType,
+            ) -> Result<SignRawTransactionWithWallet> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call(
+                    "signrawtransactionwithwallet",
+                    &[into_json(hex)?, serde_json::Value::Null, into_json(sighash_type)?],
+                )
+            }
         }
     };
 }
@@ -671,8 +724,8 @@ macro_rules! impl_client_v17__sign_raw_transaction_with_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `unloadwallet`.
 #[macro_export]
 macro_rules! impl_client_v17__unload_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn unload_wallet(&self, wallet_name: &str) -> Result<()> {
                 match self.call("unloadwallet", &[into_json(wallet_name)?]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -687,8 +740,8 @@ macro_rules! impl_client_v17__unload_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `walletpassphrase`.
 #[macro_export]
 macro_rules! impl_client_v17__wallet_passphrase {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wallet_passphrase(&self, passphrase: &str, timeout: u64) -> Result<()> {
                 match self.call("walletpassphrase", &[passphrase.into(), timeout.into()]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -703,8 +756,8 @@ macro_rules! impl_client_v17__wallet_passphrase {
 /// Implements Bitcoin Core JSON-RPC API method `walletcreatefundedpsbt`.
 #[macro_export]
 macro_rules! impl_client_v17__wallet_create_funded_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wallet_create_funded_psbt(
                 &self,
                 inputs: Vec<WalletCreateFundedPsbtInput>,
@@ -728,8 +781,8 @@ macro_rules! impl_client_v17__wallet_create_funded_psbt {
 /// Implements Bitcoin Core JSON-RPC API method `walletlock`.
 #[macro_export]
 macro_rules! impl_client_v17__wallet_lock {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wallet_lock(&self) -> Result<()> {
                 match self.call("walletlock", &[]) {
                     Ok(serde_json::Value::Null) => Ok(()),
@@ -744,8 +797,8 @@ macro_rules! impl_client_v17__wallet_lock {
 /// Implements Bitcoin Core JSON-RPC API method `walletpassphrasechange`.
 #[macro_export]
 macro_rules! impl_client_v17__wallet_passphrase_change {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wallet_passphrase_change(
                 &self,
                 old_passphrase: &str,
@@ -766,8 +819,8 @@ macro_rules! impl_client_v17__wallet_passphrase_change {
 /// Implements Bitcoin Core JSON-RPC API method `walletprocesspsbt`.
 #[macro_export]
 macro_rules! impl_client_v17__wallet_process_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wallet_process_psbt(&self, psbt: &bitcoin::Psbt) -> Result<WalletProcessPsbt> {
                 // Core expects the PSBT as a base64 string argument (same representation
                 // used by `finalizepsbt`). Serializing the struct with `into_json` produced
@@ -775,6 +828,18 @@ macro_rules! impl_client_v17__wallet_process_psbt {
                 let psbt = format!("{}", psbt);
                 self.call("walletprocesspsbt", &[psbt.into()])
             }
+
+            pub fn wallet_process_psbt_with_sighash(
+                &self,
+                psbt: &bitcoin::Psbt,
+                sighash_type: SighashType,
+            ) -> Result<WalletProcessPsbt> {
+                let psbt = format!("{}", psbt);
+                self.call(
+                    "walletprocesspsbt",
+                    &[psbt.into(), true.into(), into_json(sighash_type)?],
+                )
+            }
         }
     };
 }
@@ -5,8 +5,8 @@
 /// Implements Bitcoin Core JSON-RPC API method `getzmqnotifications`.
 #[macro_export]
 macro_rules! impl_client_v17__get_zmq_notifications {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_zmq_notifications(&self) -> Result<Vec<GetZmqNotifications>> {
                 self.call("getzmqnotifications", &[])
             }
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `submitheader`.
 #[macro_export]
 macro_rules! impl_client_v18__submit_header {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn submit_header(&self, header: &bitcoin::block::Header) -> Result<()> {
                 let hexdata = bitcoin::consensus::encode::serialize_hex(header);
                 match self.call("submitheader", &[hexdata.into()]) {
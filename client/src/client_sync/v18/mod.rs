@@ -23,8 +23,8 @@ use crate::types::v18::*;
 #[rustfmt::skip]                // Keep public re-exports separate.
 pub use crate::client_sync::{
     v17::{
-        AddNodeCommand, AddressType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest,
-        TemplateRules, WalletCreateFundedPsbtInput, FeeEstimateMode,
+        AddNodeCommand, AddressType, BlockFilterType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest,
+        TemplateRules, WalletCreateFundedPsbtInput, FeeEstimateMode, FundRawTransactionOptions, HashOrHeight, PrevTx, ScanObject, SighashType, StatField, TxOutSetHashType,
     },
 };
 
@@ -78,9 +78,11 @@ crate::impl_client_v17__wait_for_block_height!();
 crate::impl_client_v17__wait_for_new_block!();
 crate::impl_client_v17__sync_with_validation_interface_queue!();
 crate::impl_client_v17__reconsider_block!();
+crate::impl_client_v17__set_mock_time!();
 
 // == Mining ==
 crate::impl_client_v17__get_block_template!();
+crate::impl_client_v17__get_block_template_longpoll!();
 crate::impl_client_v17__get_mining_info!();
 crate::impl_client_v17__get_network_hashes_per_second!();
 crate::impl_client_v17__prioritise_transaction!();
@@ -12,8 +12,8 @@
 /// Implements Bitcoin Core JSON-RPC API method `getnodeaddresses`.
 #[macro_export]
 macro_rules! impl_client_v18__get_node_addresses {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_node_addresses(&self) -> Result<GetNodeAddresses> {
                 self.call("getnodeaddresses", &[])
             }
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `analyzepsbt`.
 #[macro_export]
 macro_rules! impl_client_v18__analyze_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn analyze_psbt(&self, psbt: &bitcoin::Psbt) -> Result<AnalyzePsbt> {
                 let psbt = format!("{}", psbt);
                 self.call("analyzepsbt", &[psbt.into()])
@@ -25,8 +27,8 @@ macro_rules! impl_client_v18__analyze_psbt {
 /// Implements Bitcoin Core JSON-RPC API method `joinpsbts`.
 #[macro_export]
 macro_rules! impl_client_v18__join_psbts {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn join_psbts(&self, psbts: &[bitcoin::Psbt]) -> Result<JoinPsbts> {
                 let psbts = psbts.iter().map(|psbt| format!("{}", psbt)).collect::<Vec<String>>();
                 self.call("joinpsbts", &[psbts.into()])
@@ -38,8 +40,8 @@ macro_rules! impl_client_v18__join_psbts {
 /// Implements Bitcoin Core JSON-RPC API method `utxoupdatepsbt`.
 #[macro_export]
 macro_rules! impl_client_v18__utxo_update_psbt {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn utxo_update_psbt(&self, psbt: &bitcoin::Psbt) -> Result<UtxoUpdatePsbt> {
                 let psbt = format!("{}", psbt);
                 self.call("utxoupdatepsbt", &[psbt.into()])
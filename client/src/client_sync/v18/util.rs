@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `deriveaddresses`.
 #[macro_export]
 macro_rules! impl_client_v18__derive_addresses {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn derive_addresses(&self, descriptor: &str) -> Result<DeriveAddresses> {
                 self.call("deriveaddresses", &[descriptor.into()])
             }
@@ -24,8 +26,8 @@ macro_rules! impl_client_v18__derive_addresses {
 /// Implements Bitcoin Core JSON-RPC API method `getdescriptorinfo`.
 #[macro_export]
 macro_rules! impl_client_v18__get_descriptor_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_descriptor_info(&self, descriptor: &str) -> Result<GetDescriptorInfo> {
                 self.call("getdescriptorinfo", &[descriptor.into()])
             }
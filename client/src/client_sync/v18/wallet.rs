@@ -7,12 +7,14 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 /// Implements Bitcoin Core JSON-RPC API method `getreceivedbylabel`.
 #[macro_export]
 macro_rules! impl_client_v18__get_received_by_label {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_received_by_label(&self, label: &str) -> Result<GetReceivedByLabel> {
                 self.call("getreceivedbylabel", &[label.into()])
             }
@@ -23,8 +25,8 @@ macro_rules! impl_client_v18__get_received_by_label {
 /// Implements Bitcoin Core JSON-RPC API method `listreceivedbylabel`.
 #[macro_export]
 macro_rules! impl_client_v18__list_received_by_label {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_received_by_label(&self) -> Result<ListReceivedByLabel> {
                 self.call("listreceivedbylabel", &[])
             }
@@ -35,8 +37,8 @@ macro_rules! impl_client_v18__list_received_by_label {
 /// Implements Bitcoin Core JSON-RPC API method `listwalletdir`.
 #[macro_export]
 macro_rules! impl_client_v18__list_wallet_dir {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_wallet_dir(&self) -> Result<ListWalletDir> {
                 self.call("listwalletdir", &[])
             }
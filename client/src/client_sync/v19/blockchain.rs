@@ -7,16 +7,29 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getblockfilter`.
 #[macro_export]
 macro_rules! impl_client_v19__get_block_filter {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_block_filter(&self, block: BlockHash) -> Result<GetBlockFilter> {
                 self.call("getblockfilter", &[into_json(block)?])
             }
+
+            /// Gets the filter of type `filter_type` for block `block`.
+            ///
+            /// Requires the node to be started with `-blockfilterindex`.
+            pub fn get_block_filter_with_type(
+                &self,
+                block: BlockHash,
+                filter_type: BlockFilterType,
+            ) -> Result<GetBlockFilter> {
+                self.call("getblockfilter", &[into_json(block)?, into_json(filter_type)?])
+            }
         }
     };
 }
@@ -5,6 +5,7 @@
 //! We ignore option arguments unless they effect the shape of the returned JSON data.
 
 pub mod blockchain;
+pub mod raw_transactions;
 pub mod wallet;
 
 use std::collections::BTreeMap;
@@ -19,8 +20,8 @@ use crate::types::v19::*;
 #[rustfmt::skip]                // Keep public re-exports separate.
 pub use crate::client_sync::{
     v17::{
-        AddNodeCommand, AddressType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest,
-        TemplateRules, WalletCreateFundedPsbtInput, FeeEstimateMode,
+        AddNodeCommand, AddressType, BlockFilterType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest,
+        TemplateRules, WalletCreateFundedPsbtInput, FeeEstimateMode, FundRawTransactionOptions, HashOrHeight, PrevTx, ScanObject, SighashType, StatField, TxOutSetHashType,
     },
 };
 
@@ -73,9 +74,11 @@ crate::impl_client_v17__wait_for_block_height!();
 crate::impl_client_v17__wait_for_new_block!();
 crate::impl_client_v17__sync_with_validation_interface_queue!();
 crate::impl_client_v17__reconsider_block!();
+crate::impl_client_v17__set_mock_time!();
 
 // == Mining ==
 crate::impl_client_v17__get_block_template!();
+crate::impl_client_v17__get_block_template_longpoll!();
 crate::impl_client_v17__get_mining_info!();
 crate::impl_client_v17__get_network_hashes_per_second!();
 crate::impl_client_v17__prioritise_transaction!();
@@ -111,11 +114,11 @@ crate::impl_client_v17__finalize_psbt!();
 crate::impl_client_v17__fund_raw_transaction!();
 crate::impl_client_v17__get_raw_transaction!();
 crate::impl_client_v18__join_psbts!();
-crate::impl_client_v17__send_raw_transaction!();
+crate::impl_client_v19__send_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction_with_key!();
-crate::impl_client_v17__test_mempool_accept!();
-crate::impl_client_v18__utxo_update_psbt!();
+crate::impl_client_v19__test_mempool_accept!();
+crate::impl_client_v19__utxo_update_psbt!();
 
 // == Util ==
 crate::impl_client_v17__create_multisig!();
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of Bitcoin Core `v0.19`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
+
+/// Implements Bitcoin Core JSON-RPC API method `sendrawtransaction`.
+#[macro_export]
+macro_rules! impl_client_v19__send_raw_transaction {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            pub fn send_raw_transaction(
+                &self,
+                tx: &bitcoin::Transaction,
+            ) -> Result<SendRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("sendrawtransaction", &[hex.into()])
+            }
+
+            /// Calls `sendrawtransaction` with all arguments.
+            ///
+            /// The transaction is rejected if its fee rate is above `max_fee_rate`, `None` uses
+            /// the node's default of 0.10 BTC/kvB.
+            pub fn send_raw_transaction_with_options(
+                &self,
+                tx: &bitcoin::Transaction,
+                max_fee_rate: Option<bitcoin::FeeRate>,
+            ) -> Result<SendRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                let max_fee_rate_btc_kvb =
+                    max_fee_rate.map($crate::client_sync::fee_rate_to_btc_per_kvb);
+                self.call("sendrawtransaction", &[hex.into(), max_fee_rate_btc_kvb.into()])
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `testmempoolaccept`.
+#[macro_export]
+macro_rules! impl_client_v19__test_mempool_accept {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            pub fn test_mempool_accept(
+                &self,
+                txs: &[bitcoin::Transaction],
+            ) -> Result<TestMempoolAccept> {
+                self.test_mempool_accept_with_options(txs, None)
+            }
+
+            /// Calls `testmempoolaccept` with all arguments.
+            ///
+            /// A transaction is not allowed if its fee rate is above `max_fee_rate`, `None` uses
+            /// the node's default of 0.10 BTC/kvB.
+            pub fn test_mempool_accept_with_options(
+                &self,
+                txs: &[bitcoin::Transaction],
+                max_fee_rate: Option<bitcoin::FeeRate>,
+            ) -> Result<TestMempoolAccept> {
+                let encoded = txs
+                    .iter()
+                    .map(|tx| bitcoin::consensus::encode::serialize_hex(tx))
+                    .collect::<Vec<String>>();
+                let max_fee_rate_btc_kvb =
+                    max_fee_rate.map($crate::client_sync::fee_rate_to_btc_per_kvb);
+                self.call("testmempoolaccept", &[into_json(encoded)?, max_fee_rate_btc_kvb.into()])
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `utxoupdatepsbt`.
+#[macro_export]
+macro_rules! impl_client_v19__utxo_update_psbt {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            pub fn utxo_update_psbt(&self, psbt: &bitcoin::Psbt) -> Result<UtxoUpdatePsbt> {
+                let psbt = format!("{}", psbt);
+                self.call("utxoupdatepsbt", &[psbt.into()])
+            }
+
+            /// Updates `psbt` with UTXOs from the UTXO set or mempool and with the scripts and
+            /// key derivations of the outputs described by `descriptors`.
+            pub fn utxo_update_psbt_with_descriptors(
+                &self,
+                psbt: &bitcoin::Psbt,
+                descriptors: &[ScanObject],
+            ) -> Result<UtxoUpdatePsbt> {
+                let psbt = format!("{}", psbt);
+                self.call("utxoupdatepsbt", &[psbt.into(), into_json(descriptors)?])
+            }
+        }
+    };
+}
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getbalances`.
 #[macro_export]
 macro_rules! impl_client_v19__get_balances {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_balances(&self) -> Result<GetBalances> { self.call("getbalances", &[]) }
         }
     };
@@ -22,8 +24,8 @@ macro_rules! impl_client_v19__get_balances {
 /// Implements Bitcoin Core JSON-RPC API method `setwalletflag`.
 #[macro_export]
 macro_rules! impl_client_v19__set_wallet_flag {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn set_wallet_flag(&self, flag: &str) -> Result<SetWalletFlag> {
                 self.call("setwalletflag", &[into_json(flag)?])
             }
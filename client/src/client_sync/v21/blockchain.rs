@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getrawmempool`.
 #[macro_export]
 macro_rules! impl_client_v21__get_raw_mempool {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_raw_mempool(&self) -> Result<GetRawMempool> {
                 // Equivalent to self.call("getrawmempool", &[into_json(false)?])
                 self.call("getrawmempool", &[])
@@ -29,3 +31,23 @@ macro_rules! impl_client_v21__get_raw_mempool {
         }
     };
 }
+
+/// Implements Bitcoin Core JSON-RPC API method `gettxoutsetinfo`.
+#[macro_export]
+macro_rules! impl_client_v21__get_tx_out_set_info {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            pub fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[])
+            }
+
+            /// Calls `gettxoutsetinfo` computing the `hash_type` UTXO set hash.
+            pub fn get_tx_out_set_info_with_options(
+                &self,
+                hash_type: TxOutSetHashType,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[into_json(hash_type)?])
+            }
+        }
+    };
+}
@@ -7,14 +7,23 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getindexinfo`.
 #[macro_export]
 macro_rules! impl_client_v21__get_index_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_index_info(&self) -> Result<GetIndexInfo> { self.call("getindexinfo", &[]) }
+
+            /// Gets the status of the index `index_name` e.g., `"txindex"`.
+            ///
+            /// The result is empty if the index is not enabled.
+            pub fn get_index_info_by_name(&self, index_name: &str) -> Result<GetIndexInfo> {
+                self.call("getindexinfo", &[index_name.into()])
+            }
         }
     };
 }
@@ -7,19 +7,21 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `createwallet`.
 #[macro_export]
 macro_rules! impl_client_v21__create_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Calls `createwallet` with `wallet` as the only argument.
             ///
             /// In v21 and v22 this creates a legacy wallet. Use `create_descriptor_wallet` to create
             /// a descriptor wallet.
             pub fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
-                self.call("createwallet", &[wallet.into()])
+                self.call_named("createwallet", &[("wallet_name", wallet.into())])
             }
 
             /// Creates a wallet with descriptors=true (descriptor wallet).
@@ -37,21 +39,31 @@ macro_rules! impl_client_v21__create_wallet {
             /// > 6. descriptors             (boolean, optional, default=true) Create a native descriptor wallet. The wallet will use descriptors internally to handle address creation
             /// > 7. load_on_startup         (boolean, optional) Save wallet name to persistent settings and load on startup. True to add wallet to startup list, false to remove, null to leave unchanged.
             pub fn create_descriptor_wallet(&self, wallet: &str) -> Result<CreateWallet> {
-                let disable_private_keys = false;
-                let blank = false;
-                let passphrase = String::new();
-                let avoid_reuse = false;
-                let descriptors = true;
+                self.call_named(
+                    "createwallet",
+                    &[("wallet_name", wallet.into()), ("descriptors", true.into())],
+                )
+            }
+        }
+    };
+}
 
-                self.call(
+/// Implements Bitcoin Core JSON-RPC API method `createwallet` for watch-only wallets.
+#[macro_export]
+macro_rules! impl_client_v21__create_watch_only_wallet {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            /// Creates a blank descriptor wallet with private keys disabled (watch-only wallet).
+            ///
+            /// Descriptors can then be added using `import_descriptors`.
+            pub fn create_watch_only_wallet(&self, wallet: &str) -> Result<CreateWallet> {
+                self.call_named(
                     "createwallet",
                     &[
-                        wallet.into(),
-                        disable_private_keys.into(),
-                        blank.into(),
-                        passphrase.into(),
-                        avoid_reuse.into(),
-                        descriptors.into(),
+                        ("wallet_name", wallet.into()),
+                        ("disable_private_keys", true.into()),
+                        ("blank", true.into()),
+                        ("descriptors", true.into()),
                     ],
                 )
             }
@@ -62,8 +74,8 @@ macro_rules! impl_client_v21__create_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `importdescriptors`.
 #[macro_export]
 macro_rules! impl_client_v21__import_descriptors {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn import_descriptors(
                 &self,
                 requests: &[ImportDescriptorsRequest],
@@ -77,8 +89,8 @@ macro_rules! impl_client_v21__import_descriptors {
 /// Implements Bitcoin Core JSON-RPC API method `psbtbumpfee`.
 #[macro_export]
 macro_rules! impl_client_v21__psbt_bump_fee {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn psbt_bump_fee(&self, txid: &bitcoin::Txid) -> Result<PsbtBumpFee> {
                 self.call("psbtbumpfee", &[into_json(txid)?])
             }
@@ -89,8 +101,8 @@ macro_rules! impl_client_v21__psbt_bump_fee {
 /// Implements Bitcoin Core JSON-RPC API method `send`.
 #[macro_export]
 macro_rules! impl_client_v21__send {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn send(&self, outputs: &BTreeMap<String, f64>) -> Result<Send> {
                 self.call("send", &[into_json(outputs)?])
             }
@@ -101,8 +113,8 @@ macro_rules! impl_client_v21__send {
 /// Implements Bitcoin Core JSON-RPC API method `sendmany` with `verbose=true` (v21+).
 #[macro_export]
 macro_rules! impl_client_v21__send_many_verbose {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn send_many_verbose(
                 &self,
                 amounts: BTreeMap<Address, Amount>,
@@ -143,8 +155,8 @@ macro_rules! impl_client_v21__send_many_verbose {
 /// Implements Bitcoin Core JSON-RPC API method `unloadwallet`.
 #[macro_export]
 macro_rules! impl_client_v21__unload_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn unload_wallet(&self, wallet: &str) -> Result<UnloadWallet> {
                 self.call("unloadwallet", &[wallet.into()])
             }
@@ -155,8 +167,8 @@ macro_rules! impl_client_v21__unload_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `upgradewallet`.
 #[macro_export]
 macro_rules! impl_client_v21__upgrade_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn upgrade_wallet(&self) -> Result<UpgradeWallet> {
                 self.call("upgradewallet", &[])
             }
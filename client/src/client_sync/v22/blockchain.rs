@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Blockchain ==` section of the
+//! API docs of Bitcoin Core `v22`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
+
+/// Implements Bitcoin Core JSON-RPC API method `gettxoutsetinfo`.
+#[macro_export]
+macro_rules! impl_client_v22__get_tx_out_set_info {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            pub fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
+                self.call("gettxoutsetinfo", &[])
+            }
+
+            /// Calls `gettxoutsetinfo` with all arguments.
+            ///
+            /// Statistics for a block other than the tip (`hash_or_height`) require
+            /// `-coinstatsindex`.
+            pub fn get_tx_out_set_info_with_options(
+                &self,
+                hash_type: TxOutSetHashType,
+                hash_or_height: Option<HashOrHeight>,
+                use_index: Option<bool>,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call(
+                    "gettxoutsetinfo",
+                    &[into_json(hash_type)?, into_json(hash_or_height)?, into_json(use_index)?],
+                )
+            }
+        }
+    };
+}
@@ -12,8 +12,8 @@
 /// Implements Bitcoin Core JSON-RPC API method `addconnection`.
 #[macro_export]
 macro_rules! impl_client_v22__add_connection {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn add_connection(
                 &self,
                 address: &str,
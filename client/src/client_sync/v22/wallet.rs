@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `listdescriptors`.
 #[macro_export]
 macro_rules! impl_client_v22__list_descriptors {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn list_descriptors(&self) -> Result<ListDescriptors> {
                 self.call("listdescriptors", &[])
             }
@@ -24,8 +26,8 @@ macro_rules! impl_client_v22__list_descriptors {
 /// Implements Bitcoin Core JSON-RPC API method `loadwallet`.
 #[macro_export]
 macro_rules! impl_client_v22__load_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn load_wallet(&self, wallet: &str) -> Result<LoadWallet> {
                 self.call("loadwallet", &[wallet.into()])
             }
@@ -36,8 +38,8 @@ macro_rules! impl_client_v22__load_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `walletdisplayaddress`.
 #[macro_export]
 macro_rules! impl_client_v22__wallet_display_address {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn wallet_display_address(&self, address: &str) -> Result<WalletDisplayAddress> {
                 self.call("walletdisplayaddress", &[address.into()])
             }
@@ -48,8 +50,8 @@ macro_rules! impl_client_v22__wallet_display_address {
 /// Implements Bitcoin Core JSON-RPC API method `createwallet`.
 #[macro_export]
 macro_rules! impl_client_v22__create_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Creates a wallet with external_signer=true.
             ///
             /// > createwallet "wallet_name" ( disable_private_keys blank "passphrase" avoid_reuse descriptors load_on_startup external_signer )
@@ -66,25 +68,14 @@ macro_rules! impl_client_v22__create_wallet {
             /// > 7. load_on_startup         (boolean, optional) Save wallet name to persistent settings and load on startup. True to add wallet to startup list, false to remove, null to leave unchanged.
             /// > 8. external_signer         (boolean, optional, default=false) Use an external signer such as a hardware wallet. Requires -signer to be configured. Wallet creation will fail if keys cannot be fetched. Requires disable_private_keys and descriptors set to true.
             pub fn create_wallet_external_signer(&self, wallet: &str) -> Result<CreateWallet> {
-                let disable_private_keys = true;
-                let blank = false;
-                let passphrase = String::new();
-                let avoid_reuse = false;
-                let descriptors = true;
-                let load_on_startup = false;
-                let external_signer = true;
-
-                self.call(
+                self.call_named(
                     "createwallet",
                     &[
-                        wallet.into(),
-                        disable_private_keys.into(),
-                        blank.into(),
-                        passphrase.into(),
-                        avoid_reuse.into(),
-                        descriptors.into(),
-                        load_on_startup.into(),
-                        external_signer.into(),
+                        ("wallet_name", wallet.into()),
+                        ("disable_private_keys", true.into()),
+                        ("descriptors", true.into()),
+                        ("load_on_startup", false.into()),
+                        ("external_signer", true.into()),
                     ],
                 )
             }
@@ -7,13 +7,19 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getblockfrompeer`.
 #[macro_export]
 macro_rules! impl_client_v23__get_block_from_peer {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            /// Requests the block `block_hash` from the peer with ID `peer_id` e.g., a stale block.
+            ///
+            /// The header of the block must already be known to the node. Returns once the request
+            /// is scheduled, the block is fetched asynchronously.
             pub fn get_block_from_peer(&self, block_hash: BlockHash, peer_id: u32) -> Result<()> {
                 match self.call("getblockfrompeer", &[into_json(block_hash)?, into_json(peer_id)?])
                 {
@@ -29,8 +35,8 @@ macro_rules! impl_client_v23__get_block_from_peer {
 /// Implements Bitcoin Core JSON-RPC API method `getdeploymentinfo`.
 #[macro_export]
 macro_rules! impl_client_v23__get_deployment_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Query deployment info at the current chain tip.
             pub fn get_deployment_info_tip(&self) -> Result<GetDeploymentInfo> {
                 self.call("getdeploymentinfo", &[])
@@ -47,8 +53,8 @@ macro_rules! impl_client_v23__get_deployment_info {
 /// Implements Bitcoin Core JSON-RPC API method `savemempool`.
 #[macro_export]
 macro_rules! impl_client_v23__save_mempool {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn save_mempool(&self) -> Result<SaveMempool> { self.call("savemempool", &[]) }
         }
     };
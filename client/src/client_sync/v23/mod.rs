@@ -21,7 +21,7 @@ use crate::types::v23::*;
 pub use crate::client_sync::{
     v17::{
         AddNodeCommand, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest, TemplateRules,
-        WalletCreateFundedPsbtInput, FeeEstimateMode,
+        WalletCreateFundedPsbtInput, FeeEstimateMode, FundRawTransactionOptions, HashOrHeight, PrevTx, ScanObject, SighashType, StatField, TxOutSetHashType, BlockFilterType,
     },
     v21::ImportDescriptorsRequest,
 };
@@ -50,7 +50,7 @@ crate::impl_client_v17__get_mempool_info!();
 crate::impl_client_v21__get_raw_mempool!();
 crate::impl_client_v17__get_tx_out!();
 crate::impl_client_v17__get_tx_out_proof!();
-crate::impl_client_v17__get_tx_out_set_info!();
+crate::impl_client_v22__get_tx_out_set_info!();
 crate::impl_client_v17__precious_block!();
 crate::impl_client_v17__prune_blockchain!();
 crate::impl_client_v23__save_mempool!();
@@ -81,10 +81,12 @@ crate::impl_client_v17__wait_for_block_height!();
 crate::impl_client_v17__wait_for_new_block!();
 crate::impl_client_v17__sync_with_validation_interface_queue!();
 crate::impl_client_v17__reconsider_block!();
+crate::impl_client_v17__set_mock_time!();
 crate::impl_client_v20__mock_scheduler!();
 
 // == Mining ==
 crate::impl_client_v17__get_block_template!();
+crate::impl_client_v17__get_block_template_longpoll!();
 crate::impl_client_v17__get_mining_info!();
 crate::impl_client_v17__get_network_hashes_per_second!();
 crate::impl_client_v17__prioritise_transaction!();
@@ -120,11 +122,11 @@ crate::impl_client_v17__finalize_psbt!();
 crate::impl_client_v17__fund_raw_transaction!();
 crate::impl_client_v17__get_raw_transaction!();
 crate::impl_client_v18__join_psbts!();
-crate::impl_client_v17__send_raw_transaction!();
+crate::impl_client_v19__send_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction_with_key!();
-crate::impl_client_v17__test_mempool_accept!();
-crate::impl_client_v18__utxo_update_psbt!();
+crate::impl_client_v19__test_mempool_accept!();
+crate::impl_client_v19__utxo_update_psbt!();
 
 // == Signer ==
 crate::impl_client_v22__enumerate_signers!();
@@ -147,6 +149,7 @@ crate::impl_client_v17__backup_wallet!();
 crate::impl_client_v17__bump_fee!();
 crate::impl_client_v22__create_wallet!();
 crate::impl_client_v23__create_wallet!();
+crate::impl_client_v21__create_watch_only_wallet!();
 crate::impl_client_v17__dump_priv_key!();
 crate::impl_client_v17__dump_wallet!();
 crate::impl_client_v17__encrypt_wallet!();
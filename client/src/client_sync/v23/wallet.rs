@@ -7,19 +7,21 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `createwallet`.
 #[macro_export]
 macro_rules! impl_client_v23__create_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Calls `createwallet` with `wallet` as the only argument.
             ///
             /// In v23 and later this creates a descriptor wallet. Use `create_legacy_wallet` to create
             /// a legacy wallet.
             pub fn create_wallet(&self, wallet: &str) -> Result<CreateWallet> {
-                self.call("createwallet", &[wallet.into()])
+                self.call_named("createwallet", &[("wallet_name", wallet.into())])
             }
 
             /// Creates a legacy wallet (i.e not a native descriptor wallet).
@@ -38,22 +40,9 @@ macro_rules! impl_client_v23__create_wallet {
             /// > 7. load_on_startup         (boolean, optional) Save wallet name to persistent settings and load on startup. True to add wallet to startup list, false to remove, null to leave unchanged.
             /// > 8. external_signer         (boolean, optional, default=false) Use an external signer such as a hardware wallet. Requires -signer to be configured. Wallet creation will fail if keys cannot be fetched. Requires disable_private_keys and descriptors set to true.
             pub fn create_legacy_wallet(&self, wallet: &str) -> Result<CreateWallet> {
-                let disable_private_keys = false;
-                let blank = false;
-                let passphrase = String::new();
-                let avoid_reuse = false;
-                let descriptors = false;
-
-                self.call(
+                self.call_named(
                     "createwallet",
-                    &[
-                        wallet.into(),
-                        disable_private_keys.into(),
-                        blank.into(),
-                        passphrase.into(),
-                        avoid_reuse.into(),
-                        descriptors.into(),
-                    ],
+                    &[("wallet_name", wallet.into()), ("descriptors", false.into())],
                 )
             }
         }
@@ -63,8 +52,8 @@ macro_rules! impl_client_v23__create_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `newkeypool`.
 #[macro_export]
 macro_rules! impl_client_v23__new_keypool {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Calls `newkeypool` for the loaded wallet.
             ///
             /// > newkeypool
@@ -85,8 +74,8 @@ macro_rules! impl_client_v23__new_keypool {
 /// Implements Bitcoin Core JSON-RPC API method `restorewallet`.
 #[macro_export]
 macro_rules! impl_client_v23__restore_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Calls `restorewallet` with required and optional arguments.
             ///
             /// > restorewallet "wallet_name" "backup_file" ( load_on_startup )
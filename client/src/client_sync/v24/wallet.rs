@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `migratewallet`.
 #[macro_export]
 macro_rules! impl_client_v24__migrate_wallet {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn migrate_wallet(&self, wallet_name: &str) -> Result<MigrateWallet> {
                 self.call("migratewallet", &[wallet_name.into()])
             }
@@ -24,8 +26,8 @@ macro_rules! impl_client_v24__migrate_wallet {
 /// Implements Bitcoin Core JSON-RPC API method `sendall`.
 #[macro_export]
 macro_rules! impl_client_v24__send_all {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn send_all(&self, recipients: &[Address]) -> Result<SendAll> {
                 self.call("sendall", &[into_json(recipients)?])
             }
@@ -36,8 +38,8 @@ macro_rules! impl_client_v24__send_all {
 /// Implements Bitcoin Core JSON-RPC API method `simulaterawtransaction`.
 #[macro_export]
 macro_rules! impl_client_v24__simulate_raw_transaction {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn simulate_raw_transaction(
                 &self,
                 rawtxs: &[String],
@@ -7,13 +7,49 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
+
+/// Implements Bitcoin Core JSON-RPC API method `getblock`.
+#[macro_export]
+macro_rules! impl_client_v25__get_block {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            /// Gets a block by blockhash.
+            pub fn get_block(&self, hash: BlockHash) -> Result<Block> {
+                let json = self.get_block_verbose_zero(hash)?;
+                Ok(json.block()?)
+            }
+
+            /// Gets a block by blockhash with verbose set to 0.
+            pub fn get_block_verbose_zero(&self, hash: BlockHash) -> Result<GetBlockVerboseZero> {
+                self.call("getblock", &[into_json(hash)?, 0.into()])
+            }
+
+            /// Gets a block by blockhash with verbose set to 1.
+            pub fn get_block_verbose_one(&self, hash: BlockHash) -> Result<GetBlockVerboseOne> {
+                self.call("getblock", &[into_json(hash)?, 1.into()])
+            }
+
+            /// Gets a block by blockhash with verbose set to 2.
+            pub fn get_block_verbose_two(&self, hash: BlockHash) -> Result<GetBlockVerboseTwo> {
+                self.call("getblock", &[into_json(hash)?, 2.into()])
+            }
+
+            /// Gets a block by blockhash with verbose set to 3, including the prevout of each input.
+            pub fn get_block_verbose_three(&self, hash: BlockHash) -> Result<GetBlockVerboseThree> {
+                self.call("getblock", &[into_json(hash)?, 3.into()])
+            }
+        }
+    };
+}
 
 /// Implements Bitcoin Core JSON-RPC API method `scanblocks`
 #[macro_export]
 macro_rules! impl_client_v25__scan_blocks {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Aborts an ongoing `scanblocks` scan.
             pub fn scan_blocks_abort(&self) -> Result<ScanBlocksAbort> {
                 self.call("scanblocks", &[into_json("abort")?])
@@ -24,6 +60,35 @@ macro_rules! impl_client_v25__scan_blocks {
                 self.call("scanblocks", &[into_json("start")?, into_json(scan_objects)?])
             }
 
+            /// Starts a scan of blocks for descriptors with all the optional arguments.
+            ///
+            /// Scans blocks `start_height` (default 0) to `stop_height` (default the tip) using
+            /// the block filters of `filter_type` (default [`BlockFilterType::Basic`]), requires
+            /// `-blockfilterindex`.
+            /// If `filter_false_positives` is true blocks are checked and false positives removed.
+            pub fn scan_blocks_start_with_options(
+                &self,
+                scan_objects: &[ScanObject],
+                start_height: Option<u32>,
+                stop_height: Option<u32>,
+                filter_type: Option<BlockFilterType>,
+                filter_false_positives: Option<bool>,
+            ) -> Result<ScanBlocksStart> {
+                let options = filter_false_positives
+                    .map(|f| serde_json::json!({ "filter_false_positives": f }));
+                self.call(
+                    "scanblocks",
+                    &[
+                        into_json("start")?,
+                        into_json(scan_objects)?,
+                        into_json(start_height)?,
+                        into_json(stop_height)?,
+                        into_json(filter_type)?,
+                        into_json(options)?,
+                    ],
+                )
+            }
+
             /// Checks the status of an ongoing `scanblocks` scan.
             pub fn scan_blocks_status(&self) -> Result<Option<ScanBlocksStatus>> {
                 self.call("scanblocks", &[into_json("status")?])
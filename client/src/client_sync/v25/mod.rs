@@ -6,6 +6,7 @@
 
 pub mod blockchain;
 pub mod generating;
+pub mod raw_transactions;
 
 use std::collections::BTreeMap;
 use std::path::Path;
@@ -20,7 +21,7 @@ use crate::types::v25::*;
 pub use crate::client_sync::{
     v17::{
         AddNodeCommand, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest, TemplateRules,
-        WalletCreateFundedPsbtInput, FeeEstimateMode,
+        WalletCreateFundedPsbtInput, FeeEstimateMode, FundRawTransactionOptions, HashOrHeight, PrevTx, ScanObject, SighashType, StatField, TxOutSetHashType, BlockFilterType,
     },
     v21::ImportDescriptorsRequest,
     v23::AddressType,
@@ -31,7 +32,7 @@ crate::impl_client_check_expected_server_version!({ [250200] });
 
 // == Blockchain ==
 crate::impl_client_v17__get_best_block_hash!();
-crate::impl_client_v17__get_block!();
+crate::impl_client_v25__get_block!();
 crate::impl_client_v17__get_blockchain_info!();
 crate::impl_client_v17__get_block_count!();
 crate::impl_client_v19__get_block_filter!();
@@ -50,7 +51,7 @@ crate::impl_client_v17__get_mempool_info!();
 crate::impl_client_v21__get_raw_mempool!();
 crate::impl_client_v17__get_tx_out!();
 crate::impl_client_v17__get_tx_out_proof!();
-crate::impl_client_v17__get_tx_out_set_info!();
+crate::impl_client_v22__get_tx_out_set_info!();
 crate::impl_client_v24__get_tx_spending_prevout!();
 crate::impl_client_v17__precious_block!();
 crate::impl_client_v17__prune_blockchain!();
@@ -83,10 +84,12 @@ crate::impl_client_v17__wait_for_block_height!();
 crate::impl_client_v17__wait_for_new_block!();
 crate::impl_client_v17__sync_with_validation_interface_queue!();
 crate::impl_client_v17__reconsider_block!();
+crate::impl_client_v17__set_mock_time!();
 crate::impl_client_v20__mock_scheduler!();
 
 // == Mining ==
 crate::impl_client_v17__get_block_template!();
+crate::impl_client_v17__get_block_template_longpoll!();
 crate::impl_client_v17__get_mining_info!();
 crate::impl_client_v17__get_network_hashes_per_second!();
 crate::impl_client_v17__prioritise_transaction!();
@@ -120,13 +123,13 @@ crate::impl_client_v17__decode_raw_transaction!();
 crate::impl_client_v17__decode_script!();
 crate::impl_client_v17__finalize_psbt!();
 crate::impl_client_v17__fund_raw_transaction!();
-crate::impl_client_v17__get_raw_transaction!();
+crate::impl_client_v25__get_raw_transaction!();
 crate::impl_client_v18__join_psbts!();
-crate::impl_client_v17__send_raw_transaction!();
+crate::impl_client_v25__send_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction_with_key!();
-crate::impl_client_v17__test_mempool_accept!();
-crate::impl_client_v18__utxo_update_psbt!();
+crate::impl_client_v19__test_mempool_accept!();
+crate::impl_client_v19__utxo_update_psbt!();
 
 // == Signer ==
 crate::impl_client_v22__enumerate_signers!();
@@ -149,6 +152,7 @@ crate::impl_client_v17__backup_wallet!();
 crate::impl_client_v17__bump_fee!();
 crate::impl_client_v22__create_wallet!();
 crate::impl_client_v23__create_wallet!();
+crate::impl_client_v21__create_watch_only_wallet!();
 crate::impl_client_v17__dump_priv_key!();
 crate::impl_client_v17__dump_wallet!();
 crate::impl_client_v17__encrypt_wallet!();
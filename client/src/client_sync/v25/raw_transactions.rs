@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Macros for implementing JSON-RPC methods on a client.
+//!
+//! Specifically this is methods found under the `== Rawtransactions ==` section of the
+//! API docs of Bitcoin Core `v25`.
+//!
+//! All macros require `Client` to be in scope.
+//!
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
+
+/// Implements Bitcoin Core JSON-RPC API method `getrawtransaction`.
+#[macro_export]
+macro_rules! impl_client_v25__get_raw_transaction {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            pub fn get_raw_transaction(&self, txid: bitcoin::Txid) -> Result<GetRawTransaction> {
+                self.call("getrawtransaction", &[into_json(&txid)?, 0.into()])
+            }
+
+            pub fn get_raw_transaction_verbose(
+                &self,
+                txid: Txid,
+            ) -> Result<GetRawTransactionVerbose> {
+                self.call("getrawtransaction", &[into_json(&txid)?, 1.into()])
+            }
+
+            /// Gets the transaction `txid` from the block `block_hash`, works without `-txindex`.
+            pub fn get_raw_transaction_in_block(
+                &self,
+                txid: Txid,
+                block_hash: bitcoin::BlockHash,
+            ) -> Result<GetRawTransaction> {
+                self.call(
+                    "getrawtransaction",
+                    &[into_json(&txid)?, 0.into(), into_json(&block_hash)?],
+                )
+            }
+
+            /// Gets the transaction `txid` from the block `block_hash`, works without `-txindex`.
+            pub fn get_raw_transaction_verbose_in_block(
+                &self,
+                txid: Txid,
+                block_hash: bitcoin::BlockHash,
+            ) -> Result<GetRawTransactionVerbose> {
+                self.call(
+                    "getrawtransaction",
+                    &[into_json(&txid)?, 1.into(), into_json(&block_hash)?],
+                )
+            }
+
+            /// Calls `getrawtransaction` with verbosity 2, adding the prevout of each input and
+            /// the fee.
+            ///
+            /// The prevouts and fee are only included if the node has the undo data of the block
+            /// containing the transaction, or the transaction is in the mempool.
+            pub fn get_raw_transaction_verbose_two(
+                &self,
+                txid: Txid,
+            ) -> Result<GetRawTransactionVerboseTwo> {
+                self.call("getrawtransaction", &[into_json(&txid)?, 2.into()])
+            }
+
+            /// Calls `getrawtransaction` with verbosity 2 for the transaction `txid` from the
+            /// block `block_hash`, works without `-txindex`.
+            pub fn get_raw_transaction_verbose_two_in_block(
+                &self,
+                txid: Txid,
+                block_hash: bitcoin::BlockHash,
+            ) -> Result<GetRawTransactionVerboseTwo> {
+                self.call(
+                    "getrawtransaction",
+                    &[into_json(&txid)?, 2.into(), into_json(&block_hash)?],
+                )
+            }
+        }
+    };
+}
+
+/// Implements Bitcoin Core JSON-RPC API method `sendrawtransaction`.
+#[macro_export]
+macro_rules! impl_client_v25__send_raw_transaction {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            pub fn send_raw_transaction(
+                &self,
+                tx: &bitcoin::Transaction,
+            ) -> Result<SendRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                self.call("sendrawtransaction", &[hex.into()])
+            }
+
+            /// Calls `sendrawtransaction` with all arguments.
+            ///
+            /// The transaction is rejected if its fee rate is above `max_fee_rate`, `None` uses
+            /// the node's default of 0.10 BTC/kvB. It is also rejected if it sends more than
+            /// `max_burn_amount` to provably unspendable outputs, `None` allows no burning.
+            pub fn send_raw_transaction_with_options(
+                &self,
+                tx: &bitcoin::Transaction,
+                max_fee_rate: Option<bitcoin::FeeRate>,
+                max_burn_amount: Option<bitcoin::Amount>,
+            ) -> Result<SendRawTransaction> {
+                let hex = bitcoin::consensus::encode::serialize_hex(tx);
+                let max_fee_rate_btc_kvb =
+                    max_fee_rate.map($crate::client_sync::fee_rate_to_btc_per_kvb);
+                let max_burn_amount_btc = max_burn_amount.map(|a| a.to_btc());
+                self.call(
+                    "sendrawtransaction",
+                    &[hex.into(), max_fee_rate_btc_kvb.into(), max_burn_amount_btc.into()],
+                )
+            }
+        }
+    };
+}
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `dumptxoutset`.
 #[macro_export]
 macro_rules! impl_client_v26__dump_tx_out_set {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn dump_tx_out_set(&self, path: &str) -> Result<DumpTxOutSet> {
                 self.call("dumptxoutset", &[path.into()])
             }
@@ -24,8 +26,8 @@ macro_rules! impl_client_v26__dump_tx_out_set {
 /// Implements Bitcoin Core JSON-RPC API method `getchainstates`.
 #[macro_export]
 macro_rules! impl_client_v26__get_chain_states {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_chain_states(&self) -> Result<GetChainStates> {
                 self.call("getchainstates", &[])
             }
@@ -36,11 +38,27 @@ macro_rules! impl_client_v26__get_chain_states {
 /// Implements Bitcoin Core JSON-RPC API method `gettxoutsetinfo`.
 #[macro_export]
 macro_rules! impl_client_v26__get_tx_out_set_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_tx_out_set_info(&self) -> Result<GetTxOutSetInfo> {
                 self.call("gettxoutsetinfo", &[])
             }
+
+            /// Calls `gettxoutsetinfo` with all arguments.
+            ///
+            /// Statistics for a block other than the tip (`hash_or_height`) require
+            /// `-coinstatsindex`.
+            pub fn get_tx_out_set_info_with_options(
+                &self,
+                hash_type: TxOutSetHashType,
+                hash_or_height: Option<HashOrHeight>,
+                use_index: Option<bool>,
+            ) -> Result<GetTxOutSetInfo> {
+                self.call(
+                    "gettxoutsetinfo",
+                    &[into_json(hash_type)?, into_json(hash_or_height)?, into_json(use_index)?],
+                )
+            }
         }
     };
 }
@@ -48,10 +66,37 @@ macro_rules! impl_client_v26__get_tx_out_set_info {
 /// Implements Bitcoin Core JSON-RPC API method `importmempool`.
 #[macro_export]
 macro_rules! impl_client_v26__import_mempool {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn import_mempool(&self, filepath: &str) -> Result<()> {
-                match self.call("importmempool", &[filepath.into()]) {
+                self.import_mempool_with_options(filepath, None, None, None)
+            }
+
+            /// Imports the mempool file `filepath`, options not given use the node's defaults.
+            ///
+            /// If `use_current_time` is true (default) the current time is used as the time the
+            /// transactions entered the mempool. `apply_fee_delta_priority` applies the fee deltas
+            /// set by `prioritisetransaction` in the file and `apply_unbroadcast_set` marks the
+            /// transactions in the file's unbroadcast set for rebroadcast, both default to false.
+            pub fn import_mempool_with_options(
+                &self,
+                filepath: &str,
+                use_current_time: Option<bool>,
+                apply_fee_delta_priority: Option<bool>,
+                apply_unbroadcast_set: Option<bool>,
+            ) -> Result<()> {
+                let mut options = serde_json::Map::new();
+                for (key, value) in [
+                    ("use_current_time", use_current_time),
+                    ("apply_fee_delta_priority", apply_fee_delta_priority),
+                    ("apply_unbroadcast_set", apply_unbroadcast_set),
+                ] {
+                    if let Some(value) = value {
+                        options.insert(key.to_owned(), value.into());
+                    }
+                }
+
+                match self.call("importmempool", &[filepath.into(), options.into()]) {
                     Ok(serde_json::Value::Object(ref map)) if map.is_empty() => Ok(()),
                     Ok(res) => Err(Error::Returned(res.to_string())),
                     Err(err) => Err(err.into()),
@@ -64,8 +109,8 @@ macro_rules! impl_client_v26__import_mempool {
 /// Implements Bitcoin Core JSON-RPC API method `loadtxoutset`.
 #[macro_export]
 macro_rules! impl_client_v26__load_tx_out_set {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn load_tx_out_set(&self, path: &str) -> Result<LoadTxOutSet> {
                 self.call("loadtxoutset", &[path.into()])
             }
@@ -23,7 +23,7 @@ use crate::types::v26::*;
 pub use crate::client_sync::{
     v17::{
         AddNodeCommand, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp, Input, Output, SetBanCommand, TemplateRequest, TemplateRules,
-        WalletCreateFundedPsbtInput, FeeEstimateMode,
+        WalletCreateFundedPsbtInput, FeeEstimateMode, FundRawTransactionOptions, HashOrHeight, PrevTx, ScanObject, SighashType, StatField, TxOutSetHashType, BlockFilterType,
     },
     v21::ImportDescriptorsRequest,
     v23::AddressType,
@@ -35,7 +35,7 @@ crate::impl_client_check_expected_server_version!({ [260000, 260100, 260200] });
 // == Blockchain ==
 crate::impl_client_v26__dump_tx_out_set!();
 crate::impl_client_v17__get_best_block_hash!();
-crate::impl_client_v17__get_block!();
+crate::impl_client_v25__get_block!();
 crate::impl_client_v17__get_blockchain_info!();
 crate::impl_client_v17__get_block_count!();
 crate::impl_client_v19__get_block_filter!();
@@ -88,6 +88,7 @@ crate::impl_client_v17__estimate_raw_fee!();
 crate::impl_client_v26__get_raw_addrman!();
 crate::impl_client_v20__mock_scheduler!();
 crate::impl_client_v17__reconsider_block!();
+crate::impl_client_v17__set_mock_time!();
 crate::impl_client_v17__sync_with_validation_interface_queue!();
 crate::impl_client_v17__wait_for_block!();
 crate::impl_client_v17__wait_for_block_height!();
@@ -95,6 +96,7 @@ crate::impl_client_v17__wait_for_new_block!();
 
 // == Mining ==
 crate::impl_client_v17__get_block_template!();
+crate::impl_client_v17__get_block_template_longpoll!();
 crate::impl_client_v17__get_mining_info!();
 crate::impl_client_v17__get_network_hashes_per_second!();
 crate::impl_client_v26__get_prioritised_transactions!();
@@ -128,16 +130,17 @@ crate::impl_client_v17__create_raw_transaction!();
 crate::impl_client_v17__decode_psbt!();
 crate::impl_client_v17__decode_raw_transaction!();
 crate::impl_client_v17__decode_script!();
+crate::impl_client_v26__descriptor_process_psbt!();
 crate::impl_client_v17__finalize_psbt!();
 crate::impl_client_v17__fund_raw_transaction!();
-crate::impl_client_v17__get_raw_transaction!();
+crate::impl_client_v25__get_raw_transaction!();
 crate::impl_client_v18__join_psbts!();
-crate::impl_client_v17__send_raw_transaction!();
+crate::impl_client_v25__send_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction!();
 crate::impl_client_v17__sign_raw_transaction_with_key!();
 crate::impl_client_v26__submit_package!();
-crate::impl_client_v17__test_mempool_accept!();
-crate::impl_client_v18__utxo_update_psbt!();
+crate::impl_client_v19__test_mempool_accept!();
+crate::impl_client_v19__utxo_update_psbt!();
 
 // == Signer ==
 crate::impl_client_v22__enumerate_signers!();
@@ -160,6 +163,7 @@ crate::impl_client_v17__backup_wallet!();
 crate::impl_client_v17__bump_fee!();
 crate::impl_client_v22__create_wallet!();
 crate::impl_client_v23__create_wallet!();
+crate::impl_client_v21__create_watch_only_wallet!();
 crate::impl_client_v17__dump_priv_key!();
 crate::impl_client_v17__dump_wallet!();
 crate::impl_client_v17__encrypt_wallet!();
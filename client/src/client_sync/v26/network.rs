@@ -12,8 +12,8 @@
 /// Implements Bitcoin Core JSON-RPC API method `getaddrmaninfo`.
 #[macro_export]
 macro_rules! impl_client_v26__get_addr_man_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_addr_man_info(&self) -> Result<GetAddrManInfo> {
                 self.call("getaddrmaninfo", &[])
             }
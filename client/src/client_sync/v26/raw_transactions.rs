@@ -7,13 +7,58 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
+
+/// Implements Bitcoin Core JSON-RPC API method `descriptorprocesspsbt`.
+#[macro_export]
+macro_rules! impl_client_v26__descriptor_process_psbt {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            /// Updates `psbt` with the UTXOs known to the node and the information of
+            /// `descriptors`, then signs the inputs it can with the private keys in `descriptors`.
+            pub fn descriptor_process_psbt(
+                &self,
+                psbt: &bitcoin::Psbt,
+                descriptors: &[ScanObject],
+            ) -> Result<DescriptorProcessPsbt> {
+                self.descriptor_process_psbt_with_options(psbt, descriptors, None, None, None)
+            }
+
+            /// Calls `descriptorprocesspsbt` with all arguments.
+            ///
+            /// `None` uses the node's defaults: sign with `DEFAULT` (or `ALL` for non-taproot
+            /// inputs), include BIP-32 derivations and finalize the inputs that are complete.
+            pub fn descriptor_process_psbt_with_options(
+                &self,
+                psbt: &bitcoin::Psbt,
+                descriptors: &[ScanObject],
+                sighash_type: Option<SighashType>,
+                bip32_derivs: Option<bool>,
+                finalize: Option<bool>,
+            ) -> Result<DescriptorProcessPsbt> {
+                let psbt = format!("{}", psbt);
+                self.call(
+                    "descriptorprocesspsbt",
+                    &[
+                        psbt.into(),
+                        into_json(descriptors)?,
+                        into_json(sighash_type)?,
+                        into_json(bip32_derivs)?,
+                        into_json(finalize)?,
+                    ],
+                )
+            }
+        }
+    };
+}
 
 /// Implements Bitcoin Core JSON-RPC API method `submitpackage`.
 #[macro_export]
 macro_rules! impl_client_v26__submit_package {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn submit_package(
                 &self,
                 package: &[bitcoin::Transaction],
@@ -7,13 +7,20 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `submitpackage`.
 #[macro_export]
 macro_rules! impl_client_v28__submit_package {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
+            /// Submits a package of transactions, parents before children, to the mempool.
+            ///
+            /// Transactions with a fee rate above `max_fee_rate` are rejected, `None` uses the
+            /// node's default of 0.10 BTC/kvB. Sending more than `max_burn_amount` to provably
+            /// unspendable outputs is rejected, `None` allows no burning.
             pub fn submit_package(
                 &self,
                 package: &[bitcoin::Transaction],
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `gethdkeys`.
 #[macro_export]
 macro_rules! impl_client_v28__get_hd_keys {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_hd_keys(&self) -> Result<GetHdKeys> { self.call("gethdkeys", &[]) }
         }
     };
@@ -22,8 +24,8 @@ macro_rules! impl_client_v28__get_hd_keys {
 /// Implements Bitcoin Core JSON-RPC API method `createwalletdescriptor`.
 #[macro_export]
 macro_rules! impl_client_v28__create_wallet_descriptor {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn create_wallet_descriptor(&self, address_type: &str, hdkey: &str) -> Result<CreateWalletDescriptor> {
                 let hdkey = serde_json::json!({ "hdkey": hdkey });
                 self.call("createwalletdescriptor", &[address_type.into(), hdkey.into()])
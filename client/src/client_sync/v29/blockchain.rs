@@ -7,16 +7,30 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `dumptxoutset`.
 #[macro_export]
 macro_rules! impl_client_v29__dump_tx_out_set {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn dump_tx_out_set(&self, path: &str, snapshot_type: &str) -> Result<DumpTxOutSet> {
                 self.call("dumptxoutset", &[path.into(), snapshot_type.into()])
             }
+
+            /// Dumps the UTXO set as it was at block `rollback`, which must be in the best chain.
+            ///
+            /// The node temporarily rolls back its chain to the block and is unusable meanwhile.
+            pub fn dump_tx_out_set_with_rollback(
+                &self,
+                path: &str,
+                rollback: HashOrHeight,
+            ) -> Result<DumpTxOutSet> {
+                let options = json!({ "rollback": rollback });
+                self.call("dumptxoutset", &[path.into(), "rollback".into(), options])
+            }
         }
     };
 }
@@ -24,8 +38,8 @@ macro_rules! impl_client_v29__dump_tx_out_set {
 /// Implements Bitcoin Core JSON-RPC API method `getdescriptoractivity`.
 #[macro_export]
 macro_rules! impl_client_v29__get_descriptor_activity {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_descriptor_activity(&self) -> Result<GetDescriptorActivity> {
                 let block_hashes: &[BlockHash] = &[];
                 let scan_objects: &[&str] = &[];
@@ -33,6 +47,25 @@ macro_rules! impl_client_v29__get_descriptor_activity {
                 let params = vec![json!(block_hashes), json!(scan_objects)];
                 self.call("getdescriptoractivity", &params)
             }
+
+            /// Gets the activity of `scan_objects` in the blocks `block_hashes`.
+            ///
+            /// If `include_mempool` is true (default) unconfirmed activity is included as well.
+            pub fn get_descriptor_activity_with_options(
+                &self,
+                block_hashes: &[BlockHash],
+                scan_objects: &[ScanObject],
+                include_mempool: Option<bool>,
+            ) -> Result<GetDescriptorActivity> {
+                self.call(
+                    "getdescriptoractivity",
+                    &[
+                        into_json(block_hashes)?,
+                        into_json(scan_objects)?,
+                        into_json(include_mempool)?,
+                    ],
+                )
+            }
         }
     };
 }
@@ -40,8 +73,8 @@ macro_rules! impl_client_v29__get_descriptor_activity {
 /// Implements Bitcoin Core JSON-RPC API method `getblock`.
 #[macro_export]
 macro_rules! impl_client_v29__get_block {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             /// Gets a block by blockhash. Kept for compatibility; uses verbose set to 0.
             pub fn get_block(&self, hash: BlockHash) -> Result<Block> {
                 let json = self.get_block_verbose_zero(hash)?;
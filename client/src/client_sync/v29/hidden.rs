@@ -12,8 +12,8 @@
 /// Implements Bitcoin Core JSON-RPC API method `getorphantxs` verbosity level 0.
 #[macro_export]
 macro_rules! impl_client_v29__get_orphan_txs {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_orphan_txs(&self) -> Result<GetOrphanTxs> { self.call("getorphantxs", &[]) }
         }
     };
@@ -22,8 +22,8 @@ macro_rules! impl_client_v29__get_orphan_txs {
 /// Implements Bitcoin Core JSON-RPC API method `getorphantxs` verbosity level 1.
 #[macro_export]
 macro_rules! impl_client_v29__get_orphan_txs_verbosity_1 {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_orphan_txs_verbosity_1(&self) -> Result<GetOrphanTxsVerboseOne> {
                 self.call("getorphantxs", &[into_json(1)?])
             }
@@ -34,8 +34,8 @@ macro_rules! impl_client_v29__get_orphan_txs_verbosity_1 {
 /// Implements Bitcoin Core JSON-RPC API method `getorphantxs` verbosity level 2.
 #[macro_export]
 macro_rules! impl_client_v29__get_orphan_txs_verbosity_2 {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_orphan_txs_verbosity_2(&self) -> Result<GetOrphanTxsVerboseTwo> {
                 self.call("getorphantxs", &[into_json(2)?])
             }
@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getdescriptoractivity`.
 #[macro_export]
 macro_rules! impl_client_v30__get_descriptor_activity {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_descriptor_activity(
                 &self,
                 block_hashes: &[BlockHash],
@@ -22,6 +24,25 @@ macro_rules! impl_client_v30__get_descriptor_activity {
                 let params = vec![json!(block_hashes), json!(scan_objects)];
                 self.call("getdescriptoractivity", &params)
             }
+
+            /// Gets the activity of `scan_objects` in the blocks `block_hashes`.
+            ///
+            /// If `include_mempool` is true (default) unconfirmed activity is included as well.
+            pub fn get_descriptor_activity_with_options(
+                &self,
+                block_hashes: &[BlockHash],
+                scan_objects: &[ScanObject],
+                include_mempool: Option<bool>,
+            ) -> Result<GetDescriptorActivity> {
+                self.call(
+                    "getdescriptoractivity",
+                    &[
+                        into_json(block_hashes)?,
+                        into_json(scan_objects)?,
+                        into_json(include_mempool)?,
+                    ],
+                )
+            }
         }
     };
 }
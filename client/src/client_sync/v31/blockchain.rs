@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `getmempoolcluster`.
 #[macro_export]
 macro_rules! impl_client_v31__get_mempool_cluster {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_mempool_cluster(&self, txid: Txid) -> Result<GetMempoolCluster> {
                 self.call("getmempoolcluster", &[into_json(txid)?])
             }
@@ -24,8 +26,8 @@ macro_rules! impl_client_v31__get_mempool_cluster {
 /// Implements Bitcoin Core JSON-RPC API method `getmempoolfeeratediagram`.
 #[macro_export]
 macro_rules! impl_client_v31__get_mempool_feerate_diagram {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_mempool_feerate_diagram(&self) -> Result<GetMempoolFeerateDiagram> {
                 self.call("getmempoolfeeratediagram", &[])
             }
@@ -36,8 +38,8 @@ macro_rules! impl_client_v31__get_mempool_feerate_diagram {
 /// Implements Bitcoin Core JSON-RPC API method `gettxspendingprevout`.
 #[macro_export]
 macro_rules! impl_client_v31__get_tx_spending_prevout {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_tx_spending_prevout(
                 &self,
                 outputs: &[bitcoin::OutPoint],
@@ -50,6 +52,32 @@ macro_rules! impl_client_v31__get_tx_spending_prevout {
                 }).collect();
                 self.call("gettxspendingprevout", &[json_outputs.into()])
             }
+
+            /// Gets the transactions spending `outputs`, options not given use the node's defaults.
+            ///
+            /// Confirmed spends are only found if `mempool_only` is false, which requires
+            /// `-txospenderindex`. If `return_spending_tx` is true the spending transactions are
+            /// returned as well.
+            pub fn get_tx_spending_prevout_with_options(
+                &self,
+                outputs: &[bitcoin::OutPoint],
+                mempool_only: Option<bool>,
+                return_spending_tx: Option<bool>,
+            ) -> Result<GetTxSpendingPrevout> {
+                let json_outputs: Vec<_> = outputs
+                    .iter()
+                    .map(|out| serde_json::json!({ "txid": out.txid.to_string(), "vout": out.vout }))
+                    .collect();
+                let mut options = serde_json::Map::new();
+                for (key, value) in
+                    [("mempool_only", mempool_only), ("return_spending_tx", return_spending_tx)]
+                {
+                    if let Some(value) = value {
+                        options.insert(key.to_owned(), value.into());
+                    }
+                }
+                self.call("gettxspendingprevout", &[json_outputs.into(), options.into()])
+            }
         }
     };
 }
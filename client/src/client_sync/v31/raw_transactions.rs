@@ -7,13 +7,15 @@
 //!
 //! All macros require `Client` to be in scope.
 //!
-//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`.
+//! See or use the `define_jsonrpc_bitreq_client!` macro to define a `Client`. Pass `async` to a
+//! macro to implement its methods as `async fn`s on the client defined by
+//! `define_jsonrpc_bitreq_async_client!`.
 
 /// Implements Bitcoin Core JSON-RPC API method `abortprivatebroadcast`.
 #[macro_export]
 macro_rules! impl_client_v31__abort_private_broadcast {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn abort_private_broadcast(&self, id: &str) -> Result<AbortPrivateBroadcast> {
                 self.call("abortprivatebroadcast", &[id.into()])
             }
@@ -24,8 +26,8 @@ macro_rules! impl_client_v31__abort_private_broadcast {
 /// Implements Bitcoin Core JSON-RPC API method `getprivatebroadcastinfo`.
 #[macro_export]
 macro_rules! impl_client_v31__get_private_broadcast_info {
-    () => {
-        impl Client {
+    ($($mode:tt)?) => {
+        $crate::impl_client_methods! { [$($mode)?]
             pub fn get_private_broadcast_info(&self) -> Result<GetPrivateBroadcastInfo> {
                 self.call("getprivatebroadcastinfo", &[])
             }
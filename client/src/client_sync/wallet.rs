@@ -0,0 +1,110 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Helpers for common multi-step wallet workflows.
+
+use std::time::Duration;
+use std::{error, fmt, thread};
+
+use types::model::GetWalletInfoScanning;
+
+use crate::client_sync::v21::ImportDescriptorsRequest;
+use crate::client_sync::{DescriptorWalletRpc, Error};
+
+/// The time between polls of `getwalletinfo` while the chain is rescanned.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Creates the watch-only wallet `name` tracking `descriptors` and returns a client for it.
+///
+/// The wallet is created blank with private keys disabled, the descriptors are imported and the
+/// chain is rescanned from `birth_time` (UNIX epoch time). While the rescan runs `progress` is
+/// called with the fraction of blocks scanned, polled from `getwalletinfo`, and once with 1.0
+/// when it is done. Requires Bitcoin Core v0.21 or later.
+///
+/// The import, including the rescan, must complete within [`SLOW_METHOD_TIMEOUT`].
+///
+/// [`SLOW_METHOD_TIMEOUT`]: crate::client_sync::SLOW_METHOD_TIMEOUT
+pub fn setup_watch_only<C, F>(
+    client: &C,
+    name: &str,
+    descriptors: &[&str],
+    birth_time: u64,
+    mut progress: F,
+) -> Result<C, SetupWatchOnlyError>
+where
+    C: DescriptorWalletRpc + Sync,
+    F: FnMut(f64),
+{
+    client.create_watch_only_wallet(name)?;
+    let wallet = client.for_wallet(name)?;
+
+    let requests = descriptors
+        .iter()
+        .map(|desc| ImportDescriptorsRequest::new(*desc, birth_time))
+        .collect::<Vec<_>>();
+    // The import only returns once the rescan is done, poll the progress in the meantime.
+    let imported = thread::scope(|s| {
+        let import = s.spawn(|| wallet.import_descriptors(&requests));
+        while !import.is_finished() {
+            if let Ok(info) = wallet.get_wallet_info() {
+                if let Some(GetWalletInfoScanning::Details { progress: p, .. }) = info.scanning {
+                    progress(p);
+                }
+            }
+            thread::sleep(PROGRESS_INTERVAL);
+        }
+        import.join().expect("import thread panicked")
+    })?;
+
+    for (desc, result) in descriptors.iter().zip(imported.0) {
+        if !result.success {
+            return Err(SetupWatchOnlyError::Import {
+                descriptor: desc.to_string(),
+                error: result.error.unwrap_or_default(),
+            });
+        }
+        for warning in result.warnings {
+            log::warn!(target: "corepc", "importing descriptor {}: {}", desc, warning);
+        }
+    }
+    progress(1.0);
+
+    Ok(wallet)
+}
+
+/// An error returned by [`setup_watch_only`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SetupWatchOnlyError {
+    /// A call to the node failed.
+    Rpc(Error),
+    /// The node failed to import a descriptor.
+    Import {
+        /// The descriptor that was not imported.
+        descriptor: String,
+        /// The error returned by the node.
+        error: String,
+    },
+}
+
+impl From<Error> for SetupWatchOnlyError {
+    fn from(e: Error) -> Self { SetupWatchOnlyError::Rpc(e) }
+}
+
+impl fmt::Display for SetupWatchOnlyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SetupWatchOnlyError::Rpc(e) => write!(f, "RPC: {}", e),
+            SetupWatchOnlyError::Import { descriptor, error } =>
+                write!(f, "failed to import descriptor {}: {}", descriptor, error),
+        }
+    }
+}
+
+impl error::Error for SetupWatchOnlyError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            SetupWatchOnlyError::Rpc(e) => Some(e),
+            SetupWatchOnlyError::Import { .. } => None,
+        }
+    }
+}
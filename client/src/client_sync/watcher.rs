@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Watching for new blocks by polling the node.
+
+use std::collections::VecDeque;
+use std::thread;
+use std::time::Duration;
+
+use bitcoin::{Block, BlockHash};
+
+use crate::client_sync::{BlockchainRpc, Result, RpcErrorCode};
+
+/// The default time between polls of the best block hash.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The number of blocks remembered to find the fork point of a reorg.
+const HISTORY_LEN: usize = 144;
+
+/// An iterator over the blocks connected to the best chain, found by polling `getbestblockhash`.
+///
+/// Starts at the tip of the best chain when created and yields each new block in order as
+/// `(height, hash, block)`. If the chain is reorganized the watcher walks back to the fork point
+/// and continues with the blocks of the new best chain, so a yielded height not above the previous
+/// one means the blocks above it were disconnected.
+///
+/// For nodes with ZMQ enabled the `zmq` module avoids the polling. The iterator never ends, an
+/// error is returned if a call to the node fails and the next call to `next` retries.
+#[derive(Debug)]
+pub struct BlockWatcher<'a, C> {
+    client: &'a C,
+    interval: Duration,
+    /// The height and hash of the recently yielded blocks, the tip last.
+    chain: VecDeque<(u64, BlockHash)>,
+}
+
+impl<'a, C: BlockchainRpc> BlockWatcher<'a, C> {
+    /// Creates a watcher yielding the blocks connected after the current tip of `client`.
+    pub fn new(client: &'a C) -> Result<Self> {
+        let height = client.get_block_count()?.0;
+        let hash = client.get_block_hash(height)?.0;
+        Ok(BlockWatcher {
+            client,
+            interval: DEFAULT_INTERVAL,
+            chain: VecDeque::from([(height, hash)]),
+        })
+    }
+
+    /// Sets the time between polls while waiting for a new block, 1 second by default.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the height and hash of the last block yielded, or the starting tip.
+    pub fn tip(&self) -> (u64, BlockHash) { *self.chain.back().expect("chain is never empty") }
+
+    /// Waits for and returns the next block of the best chain.
+    fn next_block(&mut self) -> Result<(u64, BlockHash, Block)> {
+        loop {
+            let (height, hash) = self.tip();
+            if self.client.get_best_block_hash()?.0 == hash {
+                thread::sleep(self.interval);
+                continue;
+            }
+
+            let next = match self.client.get_block_hash(height + 1) {
+                Ok(next) => next.0,
+                // The new best chain is shorter.
+                Err(e) if e.rpc_code() == Some(RpcErrorCode::InvalidParameter) => {
+                    self.rewind()?;
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            let block = self.client.get_block(next)?;
+            if block.header.prev_blockhash != hash {
+                self.rewind()?;
+                continue;
+            }
+
+            if self.chain.len() == HISTORY_LEN {
+                self.chain.pop_front();
+            }
+            self.chain.push_back((height + 1, next));
+            return Ok((height + 1, next, block));
+        }
+    }
+
+    /// Removes the blocks no longer in the best chain, keeping at least one block.
+    fn rewind(&mut self) -> Result<()> {
+        loop {
+            let (height, hash) = self.tip();
+            match self.client.get_block_hash(height) {
+                Ok(best) if best.0 == hash => return Ok(()),
+                Ok(_) => {}
+                Err(e) if e.rpc_code() == Some(RpcErrorCode::InvalidParameter) => {}
+                Err(e) => return Err(e),
+            }
+            if self.chain.len() > 1 {
+                self.chain.pop_back();
+            } else {
+                // All remembered blocks were disconnected, continue from the block before.
+                let height = height.saturating_sub(1);
+                self.chain[0] = (height, self.client.get_block_hash(height)?.0);
+                return Ok(());
+            }
+        }
+    }
+}
+
+impl<C: BlockchainRpc> Iterator for BlockWatcher<'_, C> {
+    type Item = Result<(u64, BlockHash, Block)>;
+
+    fn next(&mut self) -> Option<Self::Item> { Some(self.next_block()) }
+}
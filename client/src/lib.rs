@@ -2,12 +2,32 @@
 
 //! Support for connecting to Bitcoin Core via JSON-RPC.
 
+// `await_self_calls!` recurses once per token of a method body of the async client.
+#![recursion_limit = "256"]
+
 /// Re-export the `rust-bitcoin` crate.
 pub extern crate bitcoin;
 
 /// Re-export the `corepc-types` crate.
 pub extern crate types;
 
-#[cfg(feature = "client-sync")]
+/// Re-export the `jsonrpc` crate, needed to implement a custom transport.
+#[cfg(any(feature = "client-sync", feature = "client-async", feature = "transport-reqwest"))]
+pub extern crate jsonrpc;
+
+#[cfg(any(feature = "client-sync", feature = "transport-reqwest"))]
 #[macro_use]
 pub mod client_sync;
+
+#[cfg(feature = "client-async")]
+#[macro_use]
+pub mod client_async;
+
+#[cfg(any(feature = "client-sync", feature = "client-async", feature = "transport-reqwest"))]
+mod redact;
+
+#[cfg(feature = "tracing")]
+mod span;
+
+#[cfg(feature = "zmq")]
+pub mod zmq;
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Masking passphrases and private keys before calls are logged or recorded.
+
+use std::borrow::Cow;
+
+use serde_json::Value;
+
+/// Logged in place of a sensitive value.
+pub(crate) const REDACTED: &str = "[redacted]";
+
+/// Returns the position and name of the arguments of `method` that may hold a secret.
+fn sensitive_args(method: &str) -> &'static [(usize, &'static str)] {
+    match method {
+        "createwallet" => &[(3, "passphrase")],
+        "descriptorprocesspsbt" | "utxoupdatepsbt" => &[(1, "descriptors")],
+        "deriveaddresses" | "getdescriptorinfo" => &[(0, "descriptor")],
+        "encryptwallet" | "walletpassphrase" => &[(0, "passphrase")],
+        "importdescriptors" | "importmulti" => &[(0, "requests")],
+        "importprivkey" | "signmessagewithprivkey" => &[(0, "privkey")],
+        "migratewallet" => &[(1, "passphrase")],
+        "scanblocks" | "scantxoutset" => &[(1, "scanobjects")],
+        "sethdseed" => &[(1, "seed")],
+        "signrawtransactionwithkey" => &[(1, "privkeys")],
+        "walletpassphrasechange" => &[(0, "oldpassphrase"), (1, "newpassphrase")],
+        _ => &[],
+    }
+}
+
+/// Returns true if the result of a call to `method` with `params` is a secret.
+///
+/// Handles both positional (array) and named (object) params.
+pub(crate) fn is_sensitive_result(method: &str, params: &Value) -> bool {
+    let arg = |position: usize, name: &str| match params {
+        Value::Array(args) => args.get(position),
+        Value::Object(args) => args.get(name),
+        _ => None,
+    };
+    let yes = Some(&Value::Bool(true));
+    match method {
+        "dumpprivkey" => true,
+        // Private keys are only returned if asked for.
+        "listdescriptors" => arg(0, "private") == yes,
+        "gethdkeys" => arg(0, "options").and_then(|options| options.get("private")) == yes,
+        _ => false,
+    }
+}
+
+/// Replaces the sensitive arguments in `params` of a call to `method`.
+///
+/// Handles both positional (array) and named (object) params.
+pub(crate) fn redact_params(method: &str, params: &mut Value) {
+    for &(position, name) in sensitive_args(method) {
+        let arg = match params {
+            Value::Array(args) => args.get_mut(position),
+            Value::Object(args) => args.get_mut(name),
+            _ => None,
+        };
+        if let Some(arg) = arg {
+            *arg = REDACTED.into();
+        }
+    }
+}
+
+/// Returns the raw JSON `params` of a call to `method`, with the sensitive arguments replaced.
+pub(crate) fn params<'a>(method: &str, params: &'a str) -> Cow<'a, str> {
+    if sensitive_args(method).is_empty() {
+        return Cow::Borrowed(params);
+    }
+    match serde_json::from_str(params) {
+        Ok(mut value) => {
+            redact_params(method, &mut value);
+            Cow::Owned(value.to_string())
+        }
+        Err(_) => Cow::Borrowed(REDACTED),
+    }
+}
+
+/// Returns the raw JSON `result` of a call to `method` with the raw JSON `params`, or a
+/// placeholder if it is a secret.
+pub(crate) fn result<'a>(method: &str, params: &str, result: &'a str) -> &'a str {
+    match serde_json::from_str(params) {
+        Ok(params) if !is_sensitive_result(method, &params) => result,
+        _ => REDACTED,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn params_positional() {
+        let args = json!(["hunter2", 60]).to_string();
+        assert_eq!(params("walletpassphrase", &args), json!([REDACTED, 60]).to_string());
+
+        let args = json!(["old", "new"]).to_string();
+        let redacted = json!([REDACTED, REDACTED]).to_string();
+        assert_eq!(params("walletpassphrasechange", &args), redacted);
+    }
+
+    #[test]
+    fn params_named() {
+        let args = json!({ "passphrase": "hunter2", "timeout": 60 }).to_string();
+        let redacted = json!({ "passphrase": REDACTED, "timeout": 60 }).to_string();
+        assert_eq!(params("walletpassphrase", &args), redacted);
+    }
+
+    #[test]
+    fn params_descriptors() {
+        let desc = "wpkh(tprv8ZgxMBicQKsPd/0/*)";
+        let args = json!(["psbt", [desc]]).to_string();
+        assert_eq!(params("utxoupdatepsbt", &args), json!(["psbt", REDACTED]).to_string());
+
+        let args = json!(["start", [{ "desc": desc, "range": 100 }]]).to_string();
+        let redacted = json!(["start", REDACTED]).to_string();
+        assert_eq!(params("scantxoutset", &args), redacted);
+        assert_eq!(params("scanblocks", &args), redacted);
+
+        let args = json!({ "action": "start", "scanobjects": [desc] }).to_string();
+        let redacted = json!({ "action": "start", "scanobjects": REDACTED }).to_string();
+        assert_eq!(params("scanblocks", &args), redacted);
+        // Status and abort calls have no scan objects.
+        let args = json!(["status"]).to_string();
+        assert_eq!(params("scantxoutset", &args), args);
+    }
+
+    #[test]
+    fn params_not_sensitive() {
+        let args = json!([0]).to_string();
+        assert_eq!(params("getblockhash", &args), args);
+        // Missing optional arguments are not added.
+        let args = json!(["wallet"]).to_string();
+        assert_eq!(params("createwallet", &args), args);
+    }
+
+    #[test]
+    fn params_invalid_json() {
+        assert_eq!(params("walletpassphrase", "not json"), REDACTED);
+    }
+
+    #[test]
+    fn result_always_sensitive() {
+        let args = json!(["address"]).to_string();
+        assert_eq!(result("dumpprivkey", &args, "\"key\""), REDACTED);
+        assert_eq!(result("getblockcount", "[]", "101"), "101");
+    }
+
+    #[test]
+    fn result_positional() {
+        let private = json!([true]).to_string();
+        assert_eq!(result("listdescriptors", &private, "{}"), REDACTED);
+        assert_eq!(result("listdescriptors", "[false]", "{}"), "{}");
+        assert_eq!(result("listdescriptors", "[]", "{}"), "{}");
+
+        let private = json!([{ "private": true }]).to_string();
+        assert_eq!(result("gethdkeys", &private, "[]"), REDACTED);
+        let public = json!([{ "active_only": true }]).to_string();
+        assert_eq!(result("gethdkeys", &public, "[]"), "[]");
+    }
+
+    #[test]
+    fn result_named() {
+        let private = json!({ "private": true }).to_string();
+        assert_eq!(result("listdescriptors", &private, "{}"), REDACTED);
+        assert_eq!(result("listdescriptors", "{}", "{}"), "{}");
+
+        let private = json!({ "options": { "private": true } }).to_string();
+        assert_eq!(result("gethdkeys", &private, "[]"), REDACTED);
+        let public = json!({ "options": { "private": false } }).to_string();
+        assert_eq!(result("gethdkeys", &public, "[]"), "[]");
+    }
+}
@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Spans around RPC calls, emitted when the `tracing` feature is enabled.
+
+use std::fmt;
+use std::time::Instant;
+
+use tracing::field::Empty;
+use tracing::Span;
+
+/// Returns a new span for a call to `method`.
+pub(crate) fn call(method: &str) -> Span {
+    tracing::debug_span!(target: "corepc", "rpc", method, duration_ms = Empty, error_code = Empty)
+}
+
+/// Records the duration of a call started at `start` and its error, if it failed.
+///
+/// `error_code` is the code of the JSON-RPC error returned by the server, if any.
+pub(crate) fn record<T, E: fmt::Display>(
+    span: &Span,
+    start: Instant,
+    result: &Result<T, E>,
+    error_code: Option<i32>,
+) {
+    span.record("duration_ms", start.elapsed().as_millis() as u64);
+    if let Err(e) = result {
+        if let Some(code) = error_code {
+            span.record("error_code", code);
+        }
+        tracing::debug!(target: "corepc", parent: span, error = %e, "call failed");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::fmt;
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use crate::client_sync::{v17, MockTransport};
+
+    /// A subscriber capturing the fields recorded on each span.
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<BTreeMap<String, String>>>>);
+
+    impl Capture {
+        fn spans(&self) -> Vec<BTreeMap<String, String>> { self.0.lock().unwrap().clone() }
+    }
+
+    struct Fields<'a>(&'a mut BTreeMap<String, String>);
+
+    impl Visit for Fields<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_owned(), value.to_owned());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0.insert(field.name().to_owned(), format!("{:?}", value));
+        }
+    }
+
+    impl Subscriber for Capture {
+        fn enabled(&self, _: &Metadata<'_>) -> bool { true }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut spans = self.0.lock().unwrap();
+            let mut fields = BTreeMap::new();
+            attrs.record(&mut Fields(&mut fields));
+            spans.push(fields);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, id: &Id, values: &Record<'_>) {
+            let mut spans = self.0.lock().unwrap();
+            values.record(&mut Fields(&mut spans[id.into_u64() as usize - 1]));
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn call_records_span_fields() {
+        let mock = MockTransport::new();
+        mock.respond("getblockcount", serde_json::json!(42));
+        mock.respond_error("getblockhash", -8, "Block height out of range");
+        let client = v17::Client::with_transport(mock);
+
+        let capture = Capture::default();
+        tracing::subscriber::with_default(capture.clone(), || {
+            client.get_block_count().unwrap();
+            client.get_block_hash(1).unwrap_err();
+        });
+
+        let spans = capture.spans();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0]["method"], "getblockcount");
+        assert!(spans[0]["duration_ms"].parse::<u64>().is_ok());
+        assert!(!spans[0].contains_key("error_code"));
+        assert_eq!(spans[1]["method"], "getblockhash");
+        assert!(spans[1]["duration_ms"].parse::<u64>().is_ok());
+        assert_eq!(spans[1]["error_code"], "-8");
+    }
+}
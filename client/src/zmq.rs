@@ -0,0 +1,511 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Subscribing to the notifications Bitcoin Core publishes over ZMQ.
+//!
+//! Bitcoin Core publishes a notification for each new block and transaction if started with e.g.,
+//! `-zmqpubhashblock=tcp://127.0.0.1:28332`. A [`Subscriber`] connects to such an address and
+//! yields the notifications as typed [`Event`]s, no ZMQ library is needed.
+//!
+//! ```no_run
+//! use corepc_client::zmq::{Event, Subscriber, Topic};
+//!
+//! let subscriber = Subscriber::connect("127.0.0.1:28332", &[Topic::HashBlock, Topic::RawTx])?;
+//! for notification in subscriber {
+//!     match notification?.event {
+//!         Event::HashBlock(hash) => println!("new block: {}", hash),
+//!         Event::RawTx(tx) => println!("new transaction: {}", tx.compute_txid()),
+//!         _ => {}
+//!     }
+//! }
+//! # Ok::<(), corepc_client::zmq::Error>(())
+//! ```
+
+use std::io::{self, Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::Duration;
+use std::{error, fmt, mem};
+
+use bitcoin::consensus::encode;
+use bitcoin::hashes::Hash;
+use bitcoin::{Block, BlockHash, Transaction, Txid};
+
+/// The largest frame accepted, well above the size of the largest valid block.
+const MAX_FRAME_SIZE: u64 = 32 * 1024 * 1024;
+
+/// The number of bytes read from the socket at a time.
+const READ_SIZE: usize = 64 * 1024;
+
+/// Frame flag set if more frames of the same message follow.
+const FLAG_MORE: u8 = 0x01;
+/// Frame flag set if the size is encoded using 8 bytes.
+const FLAG_LONG: u8 = 0x02;
+/// Frame flag set if the frame is a command rather than part of a message.
+const FLAG_COMMAND: u8 = 0x04;
+
+/// A notification topic published by Bitcoin Core.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Topic {
+    /// The hash of each block connected to the chain, `-zmqpubhashblock`.
+    HashBlock,
+    /// The ID of each transaction added to the mempool or in a connected block, `-zmqpubhashtx`.
+    HashTx,
+    /// Each block connected to the chain, `-zmqpubrawblock`.
+    RawBlock,
+    /// Each transaction added to the mempool or in a connected block, `-zmqpubrawtx`.
+    RawTx,
+    /// Blocks connected and disconnected and transactions added to and removed from the mempool,
+    /// `-zmqpubsequence` (v0.21 and later).
+    Sequence,
+}
+
+impl Topic {
+    /// Returns the name of the topic, as sent in each notification.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Topic::HashBlock => "hashblock",
+            Topic::HashTx => "hashtx",
+            Topic::RawBlock => "rawblock",
+            Topic::RawTx => "rawtx",
+            Topic::Sequence => "sequence",
+        }
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            b"hashblock" => Some(Topic::HashBlock),
+            b"hashtx" => Some(Topic::HashTx),
+            b"rawblock" => Some(Topic::RawBlock),
+            b"rawtx" => Some(Topic::RawTx),
+            b"sequence" => Some(Topic::Sequence),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Topic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str(self.as_str()) }
+}
+
+/// A notification received from Bitcoin Core.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Notification {
+    /// The content of the notification.
+    pub event: Event,
+    /// The number of notifications previously published on the same topic, a gap means some were
+    /// missed e.g., because they were published faster than they were received.
+    pub sequence: u32,
+}
+
+/// The content of a notification, one variant for each [`Topic`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Event {
+    /// A block was connected to the chain.
+    HashBlock(BlockHash),
+    /// A transaction was added to the mempool or is in a connected block.
+    HashTx(Txid),
+    /// A block was connected to the chain.
+    RawBlock(Block),
+    /// A transaction was added to the mempool or is in a connected block.
+    RawTx(Transaction),
+    /// The chain or the mempool changed.
+    Sequence(SequenceEvent),
+}
+
+/// A change to the chain or the mempool, published on the `sequence` topic.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SequenceEvent {
+    /// A block was connected to the chain.
+    BlockConnected(BlockHash),
+    /// A block was disconnected from the chain e.g., during a reorg.
+    BlockDisconnected(BlockHash),
+    /// A transaction was added to the mempool.
+    TxAdded {
+        /// The ID of the transaction.
+        txid: Txid,
+        /// The mempool sequence number, as returned by `getrawmempool` with `mempool_sequence`.
+        mempool_sequence: u64,
+    },
+    /// A transaction was removed from the mempool for a reason other than being mined.
+    TxRemoved {
+        /// The ID of the transaction.
+        txid: Txid,
+        /// The mempool sequence number, as returned by `getrawmempool` with `mempool_sequence`.
+        mempool_sequence: u64,
+    },
+}
+
+/// A subscription to some of the topics published on a ZMQ address of a Bitcoin Core node.
+///
+/// Speaks just enough of the ZMTP 3.0 protocol to act as a ZMQ `SUB` socket. Unlike a ZMQ socket
+/// it does not reconnect, once the node stops [`Subscriber::receive`] returns an error and a new
+/// subscriber must be connected. Topics published on different addresses need a subscriber each.
+///
+/// Iterating over a subscriber receives notifications until reading from the socket fails or the
+/// node breaks the protocol, the error is returned and then the iteration ends. Malformed
+/// notifications and timed out receives are returned without ending the iteration.
+#[derive(Debug)]
+pub struct Subscriber {
+    stream: TcpStream,
+    /// Bytes read from the socket that are not yet part of a complete frame.
+    buf: Vec<u8>,
+    /// The frames of the message being received.
+    parts: Vec<Vec<u8>>,
+    /// Whether a previous receive failed, leaving the socket unusable.
+    failed: bool,
+}
+
+impl Subscriber {
+    /// Connects to the ZMQ address `addr` e.g., `"127.0.0.1:28332"`, and subscribes to `topics`.
+    ///
+    /// Notifications published before the node processed the subscription are not received.
+    pub fn connect<A: ToSocketAddrs>(addr: A, topics: &[Topic]) -> Result<Self, Error> {
+        let stream = TcpStream::connect(addr)?;
+        stream.set_nodelay(true)?;
+        let mut subscriber =
+            Subscriber { stream, buf: Vec::new(), parts: Vec::new(), failed: false };
+        subscriber.handshake()?;
+        for topic in topics {
+            // A ZMTP 3.0 subscription is a message with a leading 1 byte.
+            let mut body = vec![1];
+            body.extend_from_slice(topic.as_str().as_bytes());
+            subscriber.write_frame(0, &body)?;
+        }
+        Ok(subscriber)
+    }
+
+    /// Sets the timeout of [`Subscriber::receive`], `None` blocks until a notification arrives.
+    ///
+    /// A receive that timed out returns an [`Error::Io`] of kind `WouldBlock` or `TimedOut`,
+    /// depending on the platform. The part of the notification received so far is kept, so
+    /// receiving again continues where the timed out receive stopped.
+    pub fn set_read_timeout(&self, timeout: Option<Duration>) -> Result<(), Error> {
+        Ok(self.stream.set_read_timeout(timeout)?)
+    }
+
+    /// Receives the next notification, blocking until one arrives.
+    ///
+    /// Once reading from the socket failed with an error other than a timeout, or the node broke
+    /// the protocol, all further receives fail.
+    pub fn receive(&mut self) -> Result<Notification, Error> {
+        if self.failed {
+            return Err(Error::Io(io::Error::new(
+                io::ErrorKind::NotConnected,
+                "a previous receive failed",
+            )));
+        }
+        let parts = match self.read_message() {
+            Ok(parts) => parts,
+            Err(e) => {
+                self.failed = !is_timeout(&e);
+                return Err(e);
+            }
+        };
+        let [topic, body, sequence] = &parts[..] else {
+            return Err(Error::Protocol(format!("expected 3 message parts, got {}", parts.len())));
+        };
+        let topic = Topic::from_bytes(topic)
+            .ok_or_else(|| Error::UnknownTopic(String::from_utf8_lossy(topic).into_owned()))?;
+        let sequence = <[u8; 4]>::try_from(&sequence[..])
+            .map_err(|_| Error::InvalidBody(topic))
+            .map(u32::from_le_bytes)?;
+        Ok(Notification { event: Event::decode(topic, body)?, sequence })
+    }
+
+    /// Exchanges the greeting and the `READY` command with the node.
+    fn handshake(&mut self) -> Result<(), Error> {
+        let mut greeting = [0; 64];
+        greeting[0] = 0xff; // Signature.
+        greeting[9] = 0x7f;
+        greeting[10] = 3; // Version 3.0.
+        greeting[12..16].copy_from_slice(b"NULL"); // Security mechanism, as-server and filler are 0.
+        self.stream.write_all(&greeting)?;
+
+        let greeting = self.read_exact(64)?;
+        if greeting[0] != 0xff || greeting[9] != 0x7f || greeting[10] < 3 {
+            return Err(Error::Protocol("peer is not a ZMTP 3 socket".to_owned()));
+        }
+        if !greeting[12..32].starts_with(b"NULL\0") {
+            return Err(Error::Protocol("peer requires a security mechanism".to_owned()));
+        }
+
+        let mut ready = command_name("READY");
+        ready.push(11);
+        ready.extend_from_slice(b"Socket-Type");
+        ready.extend_from_slice(&3u32.to_be_bytes());
+        ready.extend_from_slice(b"SUB");
+        self.write_frame(FLAG_COMMAND, &ready)?;
+
+        let (flags, body) = self.read_frame()?;
+        if flags & FLAG_COMMAND == 0 || !body.starts_with(&command_name("READY")) {
+            return Err(Error::Protocol("expected READY command".to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Reads the frames of the next message, skipping any commands.
+    ///
+    /// The frames already read are kept if reading the next one fails.
+    fn read_message(&mut self) -> Result<Vec<Vec<u8>>, Error> {
+        loop {
+            let (flags, body) = self.read_frame()?;
+            if flags & FLAG_COMMAND != 0 {
+                continue;
+            }
+            self.parts.push(body);
+            if flags & FLAG_MORE == 0 {
+                return Ok(mem::take(&mut self.parts));
+            }
+        }
+    }
+
+    /// Reads a frame, returning its flags and body.
+    ///
+    /// The frame is only consumed once it was read completely.
+    fn read_frame(&mut self) -> Result<(u8, Vec<u8>), Error> {
+        self.fill(2)?;
+        let flags = self.buf[0];
+        let (header, size) = if flags & FLAG_LONG != 0 {
+            self.fill(9)?;
+            let size = <[u8; 8]>::try_from(&self.buf[1..9]).expect("8 bytes");
+            (9, u64::from_be_bytes(size))
+        } else {
+            (2, u64::from(self.buf[1]))
+        };
+        if size > MAX_FRAME_SIZE {
+            return Err(Error::Protocol(format!("frame of {} bytes is too large", size)));
+        }
+        let body = self.read_exact(header + size as usize)?.split_off(header);
+        Ok((flags, body))
+    }
+
+    /// Reads exactly `n` bytes.
+    fn read_exact(&mut self, n: usize) -> Result<Vec<u8>, Error> {
+        self.fill(n)?;
+        let rest = self.buf.split_off(n);
+        Ok(mem::replace(&mut self.buf, rest))
+    }
+
+    /// Reads from the socket until at least `n` bytes are buffered.
+    fn fill(&mut self, n: usize) -> Result<(), Error> {
+        while self.buf.len() < n {
+            let start = self.buf.len();
+            self.buf.resize(n.max(start + READ_SIZE), 0);
+            let read = self.stream.read(&mut self.buf[start..]);
+            self.buf.truncate(start + read.as_ref().map_or(0, |read| *read));
+            match read {
+                Ok(0) => return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into()),
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a single frame with `flags` and `body`.
+    fn write_frame(&mut self, flags: u8, body: &[u8]) -> Result<(), Error> {
+        let mut frame = Vec::with_capacity(body.len() + 9);
+        match u8::try_from(body.len()) {
+            Ok(size) => {
+                frame.push(flags);
+                frame.push(size);
+            }
+            Err(_) => {
+                frame.push(flags | FLAG_LONG);
+                frame.extend_from_slice(&(body.len() as u64).to_be_bytes());
+            }
+        }
+        frame.extend_from_slice(body);
+        Ok(self.stream.write_all(&frame)?)
+    }
+}
+
+impl Iterator for Subscriber {
+    type Item = Result<Notification, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        Some(self.receive())
+    }
+}
+
+impl Event {
+    /// Decodes the body of a notification published on `topic`.
+    fn decode(topic: Topic, body: &[u8]) -> Result<Self, Error> {
+        let invalid = || Error::InvalidBody(topic);
+        let event = match topic {
+            Topic::HashBlock =>
+                Event::HashBlock(BlockHash::from_byte_array(hash(body).ok_or_else(invalid)?)),
+            Topic::HashTx => Event::HashTx(Txid::from_byte_array(hash(body).ok_or_else(invalid)?)),
+            Topic::RawBlock => Event::RawBlock(encode::deserialize(body)?),
+            Topic::RawTx => Event::RawTx(encode::deserialize(body)?),
+            Topic::Sequence => {
+                if body.len() < 32 {
+                    return Err(invalid());
+                }
+                let (hash_bytes, rest) = body.split_at(32);
+                let hash = hash(hash_bytes).ok_or_else(invalid)?;
+                let event = match rest {
+                    [b'C'] => SequenceEvent::BlockConnected(BlockHash::from_byte_array(hash)),
+                    [b'D'] => SequenceEvent::BlockDisconnected(BlockHash::from_byte_array(hash)),
+                    [label @ (b'A' | b'R'), sequence @ ..] => {
+                        let txid = Txid::from_byte_array(hash);
+                        let mempool_sequence = <[u8; 8]>::try_from(sequence)
+                            .map(u64::from_le_bytes)
+                            .map_err(|_| invalid())?;
+                        if *label == b'A' {
+                            SequenceEvent::TxAdded { txid, mempool_sequence }
+                        } else {
+                            SequenceEvent::TxRemoved { txid, mempool_sequence }
+                        }
+                    }
+                    _ => return Err(invalid()),
+                };
+                Event::Sequence(event)
+            }
+        };
+        Ok(event)
+    }
+}
+
+/// Returns the bytes of a hash published in the reversed (as displayed) byte order.
+fn hash(body: &[u8]) -> Option<[u8; 32]> {
+    let mut hash = <[u8; 32]>::try_from(body).ok()?;
+    hash.reverse();
+    Some(hash)
+}
+
+/// Returns true if `e` is the error returned when a read timed out.
+fn is_timeout(e: &Error) -> bool {
+    matches!(e, Error::Io(e) if matches!(e.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut))
+}
+
+/// Returns the start of the body of a command, its length prefixed name.
+fn command_name(name: &str) -> Vec<u8> {
+    let mut body = vec![name.len() as u8];
+    body.extend_from_slice(name.as_bytes());
+    body
+}
+
+/// An error returned by a [`Subscriber`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// Connecting to, reading from or writing to the node failed.
+    Io(io::Error),
+    /// The node did not follow the ZMTP protocol.
+    Protocol(String),
+    /// A notification was published on a topic this module does not know.
+    UnknownTopic(String),
+    /// The body of a notification published on the topic is malformed.
+    InvalidBody(Topic),
+    /// Decoding the block or transaction in a notification failed.
+    Decode(encode::Error),
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self { Error::Io(e) }
+}
+
+impl From<encode::Error> for Error {
+    fn from(e: encode::Error) -> Self { Error::Decode(e) }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "I/O error: {}", e),
+            Error::Protocol(e) => write!(f, "ZMTP protocol error: {}", e),
+            Error::UnknownTopic(topic) => write!(f, "unknown topic: {}", topic),
+            Error::InvalidBody(topic) => write!(f, "malformed {} notification", topic),
+            Error::Decode(e) => write!(f, "decoding notification failed: {}", e),
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Decode(e) => Some(e),
+            Error::Protocol(_) | Error::UnknownTopic(_) | Error::InvalidBody(_) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::TcpListener;
+    use std::thread;
+
+    use super::*;
+
+    /// Accepts a subscriber on `listener` and completes the handshake, returning the socket.
+    fn accept(listener: &TcpListener) -> TcpStream {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut greeting = [0; 64];
+        stream.read_exact(&mut greeting).unwrap();
+        stream.write_all(&greeting).unwrap();
+        // The READY command of the subscriber: flags, size and a body of 25 bytes.
+        stream.read_exact(&mut [0; 2 + 25]).unwrap();
+        let mut ready = vec![FLAG_COMMAND, 25];
+        ready.extend_from_slice(&command_name("READY"));
+        ready.push(11);
+        ready.extend_from_slice(b"Socket-Type");
+        ready.extend_from_slice(&3u32.to_be_bytes());
+        ready.extend_from_slice(b"PUB");
+        stream.write_all(&ready).unwrap();
+        stream
+    }
+
+    /// Returns the frames of a `hashblock` notification of `hash` with sequence number `sequence`.
+    fn hash_block_message(hash: [u8; 32], sequence: u32) -> Vec<u8> {
+        let mut message = vec![FLAG_MORE, 9];
+        message.extend_from_slice(b"hashblock");
+        message.extend_from_slice(&[FLAG_MORE, 32]);
+        message.extend_from_slice(&hash);
+        message.extend_from_slice(&[0, 4]);
+        message.extend_from_slice(&sequence.to_le_bytes());
+        message
+    }
+
+    #[test]
+    fn receive_after_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let publisher = thread::spawn(move || accept(&listener));
+        let mut subscriber = Subscriber::connect(addr, &[]).unwrap();
+        let mut stream = publisher.join().unwrap();
+
+        let message = hash_block_message([1; 32], 7);
+        let (first, rest) = message.split_at(20);
+        stream.write_all(first).unwrap();
+        subscriber.set_read_timeout(Some(Duration::from_millis(100))).unwrap();
+        let err = subscriber.next().unwrap().unwrap_err();
+        assert!(is_timeout(&err), "unexpected error: {}", err);
+
+        stream.write_all(rest).unwrap();
+        let notification = subscriber.next().unwrap().unwrap();
+        assert_eq!(notification.event, Event::HashBlock(BlockHash::from_byte_array([1; 32])));
+        assert_eq!(notification.sequence, 7);
+    }
+
+    #[test]
+    fn iteration_ends_after_fatal_error() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let publisher = thread::spawn(move || accept(&listener));
+        let mut subscriber = Subscriber::connect(addr, &[]).unwrap();
+        let mut stream = publisher.join().unwrap();
+
+        let message = hash_block_message([1; 32], 0);
+        stream.write_all(&message[..20]).unwrap();
+        drop(stream);
+
+        assert!(matches!(subscriber.next(), Some(Err(Error::Io(_)))));
+        assert!(subscriber.next().is_none());
+        assert!(subscriber.receive().is_err());
+    }
+}
@@ -1,10 +1,12 @@
 //! Provides a macro that implements the tests.
 
+use std::borrow::Borrow;
 use std::path::PathBuf;
+use std::time::{Duration, Instant};
 
 use bitcoin::bip32::{Fingerprint, Xpriv, Xpub};
-use bitcoin::secp256k1::{Secp256k1, XOnlyPublicKey};
-use bitcoin::Network;
+use bitcoin::secp256k1::{Secp256k1, SecretKey, XOnlyPublicKey};
+use bitcoin::{Address, BlockHash, CompressedPublicKey, Network};
 use bitcoind::{Conf, P2P};
 use rand::distributions::Alphanumeric;
 use rand::Rng;
@@ -55,6 +57,18 @@ pub trait BitcoinDExt {
 
     /// Returns the number of peers connected to this node.
     fn peers_connected(&self) -> usize;
+
+    /// Reorgs out the last `depth` blocks without any other node.
+    ///
+    /// The block `depth` blocks below the tip is invalidated and `depth + 1` blocks are mined on top
+    /// of the fork point. The stale blocks stay invalid until passed to `reconsiderblock`.
+    fn force_reorg(&self, depth: usize) -> Reorg;
+
+    /// Moves the clock of the node `secs` seconds past the later of now and the time of the tip.
+    ///
+    /// Blocks mined afterwards use the mock time, so calls alternated with mining keep moving
+    /// forward. Returns the new mock time.
+    fn advance_time(&self, secs: u64) -> u64;
 }
 
 impl BitcoinDExt for BitcoinD {
@@ -114,6 +128,37 @@ impl BitcoinDExt for BitcoinD {
         let json = self.client.get_peer_info().expect("get_peer_info");
         json.0.len()
     }
+
+    fn force_reorg(&self, depth: usize) -> Reorg {
+        assert!(depth > 0, "reorg depth must be at least one block");
+
+        let height = self.client.get_block_count().expect("getblockcount").0;
+        let fork_height = height.checked_sub(depth as u64).expect("chain shorter than reorg depth");
+        let block_hash = |h| {
+            self.client.get_block_hash(h).expect("getblockhash").block_hash().expect("block hash")
+        };
+        let fork_point = block_hash(fork_height);
+        let stale = (fork_height + 1..=height).map(block_hash).collect::<Vec<_>>();
+
+        self.client.invalidate_block(stale[0]).expect("invalidateblock");
+        // Vary the coinbase output with the height so a new block can never equal an invalid one.
+        let new = mine(self, depth + 1, &mining_address(3 + (height % 200) as u8));
+
+        Reorg { fork_point, stale, new }
+    }
+
+    fn advance_time(&self, secs: u64) -> u64 {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .expect("time went backwards")
+            .as_secs();
+        let tip = self.client.best_block_hash().expect("best_block_hash");
+        let tip_time = u64::from(self.client.get_block(tip).expect("getblock").header.time);
+
+        let time = now.max(tip_time) + secs;
+        self.client.set_mock_time(time).expect("setmocktime");
+        time
+    }
 }
 
 /// Return a temporary file path.
@@ -152,6 +197,197 @@ pub fn three_node_network() -> (BitcoinD, BitcoinD, BitcoinD) {
     (node1, node2, node3)
 }
 
+/// A multisig descriptor wallet shared by several connected harness nodes.
+#[cfg(not(feature = "v20_and_below"))]
+pub struct MultisigNetwork {
+    /// The nodes, each one holding one of the multisig keys.
+    pub nodes: Vec<BitcoinD>,
+    /// A client per node connected to the multisig wallet on that node.
+    pub wallets: Vec<bitcoind::Client>,
+    /// The public multisig descriptor (including checksum) imported on every node.
+    pub descriptor: String,
+    /// The multisig receive address.
+    pub address: bitcoin::Address,
+}
+
+/// Creates `n` connected nodes sharing a `required`-of-`n` `wsh(multi(...))` wallet.
+///
+/// Each node gets a descriptor wallet called `multisig` holding its own extended private key and
+/// the extended public keys of all other nodes. The multisig address is funded with `amount` from
+/// the default wallet of the first node and the transaction is mined.
+#[cfg(not(feature = "v20_and_below"))]
+pub fn multisig_network(required: usize, n: usize, amount: bitcoin::Amount) -> MultisigNetwork {
+    use bitcoin::bip32::DerivationPath;
+    use bitcoind::ImportDescriptorsRequest;
+
+    const WALLET: &str = "multisig";
+    assert!(required >= 1 && required <= n, "invalid multisig threshold");
+
+    let exe = bitcoind::exe_path().expect("failed to get bitcoind executable");
+    let mut nodes: Vec<BitcoinD> = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut conf = Conf::default();
+        conf.p2p = match nodes.last() {
+            Some(prev) => prev.p2p_connect(true).unwrap(),
+            None => P2P::Yes,
+        };
+        nodes.push(BitcoinD::with_conf(&exe, &conf).expect("failed to create node"));
+    }
+
+    // Deterministic keys, one per node, derived at a fixed (unranged) path.
+    let secp = Secp256k1::new();
+    let path: DerivationPath = "m/0/0".parse().unwrap();
+    let xprvs = (0..n)
+        .map(|i| {
+            let seed = [i as u8 + 1; 32];
+            let master = Xpriv::new_master(Network::Regtest, &seed).unwrap();
+            master.derive_priv(&secp, &path).unwrap()
+        })
+        .collect::<Vec<_>>();
+    let xpubs = xprvs.iter().map(|xprv| Xpub::from_priv(&secp, xprv)).collect::<Vec<_>>();
+
+    let multi = |keys: Vec<String>| format!("wsh(multi({},{}))", required, keys.join(","));
+
+    let mut wallets = Vec::with_capacity(n);
+    for (i, node) in nodes.iter().enumerate() {
+        #[cfg(feature = "v22_and_below")]
+        node.client.create_descriptor_wallet(WALLET).expect("createwallet");
+        #[cfg(not(feature = "v22_and_below"))]
+        node.client.create_wallet(WALLET).expect("createwallet");
+
+        let wallet = node.wallet_client(WALLET).expect("failed to create wallet client");
+
+        // Our own private key, everyone else's public key.
+        let keys = (0..n)
+            .map(|j| if i == j { xprvs[j].to_string() } else { xpubs[j].to_string() })
+            .collect();
+        let desc = multi(keys);
+        let checksum = node.client.get_descriptor_info(&desc).expect("getdescriptorinfo").checksum;
+        let request = ImportDescriptorsRequest::new(format!("{}#{}", desc, checksum), "now");
+        let imported = wallet.import_descriptors(&[request]).expect("importdescriptors");
+        assert!(imported.0.iter().all(|r| r.success), "failed to import multisig descriptor");
+
+        wallets.push(wallet);
+    }
+
+    let public = multi(xpubs.iter().map(ToString::to_string).collect());
+    let descriptor =
+        nodes[0].client.get_descriptor_info(&public).expect("getdescriptorinfo").descriptor;
+    let derived = nodes[0].client.derive_addresses(&descriptor).expect("deriveaddresses");
+    let address = derived.0[0]
+        .parse::<bitcoin::Address<bitcoin::address::NetworkUnchecked>>()
+        .expect("failed to parse address")
+        .assume_checked();
+
+    nodes[0].fund_wallet();
+    nodes[0].client.send_to_address(&address, amount).expect("failed to send to multisig");
+    nodes[0].mine_a_block();
+    wait_for_sync(&nodes);
+
+    MultisigNetwork { nodes, wallets, descriptor, address }
+}
+
+/// Waits until all `nodes` have the same chain tip.
+fn wait_for_sync<N: Borrow<BitcoinD>>(nodes: &[N]) {
+    wait_for(|| {
+        let tips = nodes
+            .iter()
+            .map(|node| node.borrow().client.best_block_hash().expect("best_block_hash"))
+            .collect::<Vec<_>>();
+        tips.windows(2).all(|w| w[0] == w[1])
+    });
+}
+
+/// Builds a chain reorganisation between two connected nodes.
+///
+/// Both nodes are disconnected, `node` mines the stale branch and `other` mines the (longer) new
+/// branch. Once reconnected `node` reorgs onto the chain of `other`.
+pub struct ReorgBuilder<'a> {
+    node: &'a BitcoinD,
+    other: &'a BitcoinD,
+    stale_blocks: usize,
+    new_blocks: usize,
+}
+
+/// The block hashes involved in a reorg created using [`ReorgBuilder`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reorg {
+    /// The last block common to both branches.
+    pub fork_point: BlockHash,
+    /// The blocks that were reorged out, in chain order.
+    pub stale: Vec<BlockHash>,
+    /// The blocks that replaced them, in chain order.
+    pub new: Vec<BlockHash>,
+}
+
+impl<'a> ReorgBuilder<'a> {
+    /// Creates a builder for a one block reorg of `node` by `other`.
+    ///
+    /// The two nodes are expected to be connected to each other, as in [`three_node_network`].
+    pub fn new(node: &'a BitcoinD, other: &'a BitcoinD) -> Self {
+        ReorgBuilder { node, other, stale_blocks: 1, new_blocks: 2 }
+    }
+
+    /// Sets the number of blocks mined by `node` that get reorged out.
+    pub fn stale_blocks(mut self, n: usize) -> Self {
+        self.stale_blocks = n;
+        self
+    }
+
+    /// Sets the number of blocks mined by `other`, must be more than the stale blocks.
+    pub fn new_blocks(mut self, n: usize) -> Self {
+        self.new_blocks = n;
+        self
+    }
+
+    /// Creates the competing branches and waits for the reorg to happen.
+    pub fn build(self) -> Reorg {
+        assert!(self.new_blocks > self.stale_blocks, "new branch must be longer than stale branch");
+
+        wait_for_sync(&[self.node, self.other]);
+        let fork_point = self.node.client.best_block_hash().expect("best_block_hash");
+
+        self.node.client.set_network_active(false).expect("setnetworkactive false");
+        wait_for(|| self.node.peers_connected() == 0);
+
+        // Different coinbase outputs so the branches don't contain identical blocks.
+        let stale = mine(self.node, self.stale_blocks, &mining_address(1));
+        let new = mine(self.other, self.new_blocks, &mining_address(2));
+
+        self.node.client.set_network_active(true).expect("setnetworkactive true");
+        wait_for_sync(&[self.node, self.other]);
+        assert_eq!(
+            self.node.client.best_block_hash().expect("best_block_hash"),
+            new[new.len() - 1]
+        );
+
+        Reorg { fork_point, stale, new }
+    }
+}
+
+/// Mines `n` blocks to `address` returning their hashes.
+fn mine(node: &BitcoinD, n: usize, address: &Address) -> Vec<BlockHash> {
+    let json = node.client.generate_to_address(n, address).expect("generatetoaddress");
+    json.into_model().expect("GenerateToAddress into model").0
+}
+
+/// Returns a regtest address that is not controlled by any wallet.
+fn mining_address(seed: u8) -> Address {
+    let secp = Secp256k1::new();
+    let sk = SecretKey::from_slice(&[seed; 32]).expect("valid secret key");
+    let pk = CompressedPublicKey(sk.public_key(&secp));
+    Address::p2wpkh(&pk, Network::Regtest)
+}
+
+/// Polls `f` until it returns true, panics after 30 seconds.
+fn wait_for(f: impl Fn() -> bool) {
+    let timeout = Instant::now() + Duration::from_secs(30);
+    while !f() {
+        assert!(Instant::now() < timeout, "timed out waiting for condition");
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
 /// BIP32 key set for testing.
 pub struct TestKeys {
     pub xprv: Xpriv,
@@ -7,7 +7,7 @@
 use bitcoin::consensus::encode;
 use bitcoin::hex;
 use bitcoind::vtype::*; // All the version specific types.
-use bitcoind::{mtype, Input, Output};
+use bitcoind::{mtype, HashOrHeight, Input, Output, StatField};
 use integration_test::{BitcoinD, BitcoinDExt as _, Wallet};
 
 #[test]
@@ -34,6 +34,30 @@ fn blockchain__dump_tx_out_set__modelled() {
     assert!(dump.coins_written.to_sat() > 0);
 }
 
+#[test]
+#[cfg(not(feature = "v28_and_below"))]
+fn blockchain__dump_tx_out_set_with_rollback__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let tip = node.client.best_block_hash().expect("best_block_hash");
+    let rollback_hash =
+        node.client.get_block_hash(100).expect("getblockhash").block_hash().unwrap();
+
+    let temp_path = integration_test::random_tmp_file();
+    let path = temp_path.to_str().expect("temp path should be valid UTF-8");
+    let json: DumpTxOutSet = node
+        .client
+        .dump_tx_out_set_with_rollback(path, HashOrHeight::Height(100))
+        .expect("dumptxoutset rollback");
+    let model: Result<mtype::DumpTxOutSet, DumpTxOutSetError> = json.into_model();
+    let dump = model.unwrap();
+
+    assert_eq!(dump.base_height, 100);
+    assert_eq!(dump.base_hash, rollback_hash);
+    assert_eq!(dump.n_chain_tx, 101);
+    assert_eq!(node.client.best_block_hash().expect("best_block_hash"), tip);
+}
+
 #[test]
 #[cfg(not(feature = "v25_and_below"))]
 fn blockchain__load_tx_out_set__modelled() {
@@ -68,10 +92,7 @@ fn blockchain__load_tx_out_set__modelled() {
     const MOCK_TIME_START: u64 = 1598887952;
     for i in 0..snapshot_height {
         let mock_time = MOCK_TIME_START + i;
-        let _: bitcoind::serde_json::Value = node_a
-            .client
-            .call("setmocktime", &[bitcoind::serde_json::json!(mock_time)])
-            .expect("setmocktime");
+        node_a.client.set_mock_time(mock_time).expect("setmocktime");
         node_a.client.generate_to_descriptor(1, coinbase_descriptor).expect("generatetodescriptor");
     }
 
@@ -128,6 +149,14 @@ fn blockchain__load_tx_out_set__modelled() {
     assert_eq!(model.base_height, snapshot_height as u32);
     assert_eq!(model.tip_hash, hash_at_height);
     assert_eq!(model.coins_loaded, bitcoin::Amount::from_btc(110.0).unwrap());
+
+    let json: GetChainStates = node_b.client.get_chain_states().expect("getchainstates");
+    let model: Result<mtype::GetChainStates, GetChainStatesError> = json.into_model();
+    let chain_states = model.unwrap();
+    let active = chain_states.active().expect("snapshot chainstate");
+    assert_eq!(active.snapshot_block_hash, Some(hash_at_height));
+    assert!(!active.validated);
+    assert!(chain_states.background().expect("background chainstate").validated);
 }
 
 #[test]
@@ -157,30 +186,35 @@ fn blockchain__get_block__modelled() {
     assert!(block_v1.coinbase_tx.is_some());
     let _ = block_v1;
 
-    #[cfg(not(feature = "v28_and_below"))]
-    {
-        let node = BitcoinD::with_wallet(Wallet::Default, &[]);
-        node.fund_wallet();
-        let (_address, mined_tx) = node.create_mined_transaction();
-        let block_hash = node.client.best_block_hash().expect("best_block_hash failed");
-
-        let json: GetBlockVerboseTwo =
-            node.client.get_block_verbose_two(block_hash).expect("getblock verbose=2");
-        let model: Result<mtype::GetBlockVerboseTwo, GetBlockVerboseTwoError> = json.into_model();
-        let block_v2 = model.unwrap();
-
-        assert_eq!(block_v2.tx.len(), block_v2.n_tx as usize);
-
-        let mined_txid = mined_tx.compute_txid();
-        let mined_entry = block_v2
-            .tx
-            .iter()
-            .find(|entry| entry.transaction.transaction.compute_txid() == mined_txid)
-            .expect("mined transaction should be present in verbosity=2 results");
-        assert!(mined_entry.fee.is_some());
-        assert!(!mined_entry.transaction.transaction.input.is_empty());
-        assert!(!mined_entry.transaction.transaction.output.is_empty());
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (_address, mined_tx) = node.create_mined_transaction();
+    let block_hash = node.client.best_block_hash().expect("best_block_hash failed");
 
+    let json: GetBlockVerboseTwo =
+        node.client.get_block_verbose_two(block_hash).expect("getblock verbose=2");
+    let model: Result<mtype::GetBlockVerboseTwo, GetBlockVerboseTwoError> = json.into_model();
+    let block_v2 = model.unwrap();
+
+    assert_eq!(block_v2.tx.len(), block_v2.n_tx as usize);
+
+    let block = bitcoin::Block::from(block_v2.clone());
+    assert_eq!(block.block_hash(), block_hash);
+    assert!(block.check_merkle_root());
+
+    let mined_txid = mined_tx.compute_txid();
+    let mined_entry = block_v2
+        .tx
+        .iter()
+        .find(|entry| entry.transaction.transaction.compute_txid() == mined_txid)
+        .expect("mined transaction should be present in verbosity=2 results");
+    #[cfg(not(feature = "v21_and_below"))]
+    assert!(mined_entry.fee.is_some());
+    assert!(!mined_entry.transaction.transaction.input.is_empty());
+    assert!(!mined_entry.transaction.transaction.output.is_empty());
+
+    #[cfg(not(feature = "v24_and_below"))]
+    {
         let json: GetBlockVerboseThree =
             node.client.get_block_verbose_three(block_hash).expect("getblock verbose=3");
         let model: Result<mtype::GetBlockVerboseThree, GetBlockVerboseThreeError> =
@@ -224,16 +258,329 @@ fn blockchain__get_block_count__modelled() {
     let _: mtype::GetBlockCount = json.into_model();
 }
 
+#[test]
+fn blockchain__get_block_count__any_client() {
+    use bitcoind::client::client_sync::{AnyClient, Auth, Error};
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let auth = Auth::CookieFile(node.params.cookie_file.clone());
+
+    let client = AnyClient::detect(&node.rpc_url(), auth).expect("detect");
+    let model: mtype::GetBlockCount = client.get_block_count().expect("getblockcount");
+    assert_eq!(model.0, 0);
+
+    let chain_states = client.get_chain_states();
+    if client.major_version() >= 26 {
+        let active = chain_states.expect("getchainstates").active().cloned();
+        assert!(active.expect("active chainstate").validated);
+    } else {
+        assert!(matches!(chain_states, Err(Error::UnsupportedByServer { .. })));
+    }
+}
+
+#[test]
+fn blockchain__get_deployment_info__any_client() {
+    use bitcoind::client::client_sync::{AnyClient, Auth, Error};
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let auth = Auth::CookieFile(node.params.cookie_file.clone());
+    let client = AnyClient::detect(&node.rpc_url(), auth).expect("detect");
+
+    let deployment_info = client.get_deployment_info();
+    let genesis = client.get_block_hash(0).expect("getblockhash").0;
+    let deployment_info_at = client.get_deployment_info_at(&genesis);
+    if client.major_version() >= 23 {
+        deployment_info.expect("getdeploymentinfo");
+        assert_eq!(deployment_info_at.expect("getdeploymentinfo genesis").hash, genesis);
+    } else {
+        assert!(matches!(deployment_info, Err(Error::UnsupportedByServer { .. })));
+        assert!(matches!(deployment_info_at, Err(Error::UnsupportedByServer { .. })));
+    }
+}
+
+#[test]
+fn blockchain__get_block_count__cookie_reload() {
+    use bitcoind::client::client_sync::Auth;
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    // Build the client with stale credentials, as if the node restarted since.
+    let cookie_file = node.workdir().join("copied.cookie");
+    std::fs::write(&cookie_file, "__cookie__:stale").unwrap();
+    let client = bitcoind::Client::builder(&node.rpc_url())
+        .auth(Auth::CookieFile(cookie_file.clone()))
+        .build()
+        .unwrap();
+
+    std::fs::copy(&node.params.cookie_file, &cookie_file).unwrap();
+    let json: GetBlockCount = client.get_block_count().expect("getblockcount");
+    assert_eq!(json.0, 0);
+}
+
+#[test]
+fn blockchain__get_block_count__jsonrpc_v1() {
+    use bitcoind::client::client_sync::{Auth, Error, JsonRpcVersion};
+    use bitcoind::client::jsonrpc;
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    for version in [JsonRpcVersion::V1, JsonRpcVersion::V2] {
+        let client = bitcoind::Client::builder(&node.rpc_url())
+            .auth(Auth::CookieFile(node.params.cookie_file.clone()))
+            .jsonrpc_version(version)
+            .build()
+            .unwrap();
+        let json: GetBlockCount = client.get_block_count().expect("getblockcount");
+        assert_eq!(json.0, 0);
+
+        // Errors are returned with a different HTTP status depending on the version.
+        match client.call_json("nosuchmethod", &[]) {
+            Err(Error::JsonRpc(jsonrpc::Error::Rpc(e))) => assert_eq!(e.code, -32601),
+            res => panic!("unexpected result for {:?}: {:?}", version, res),
+        }
+    }
+}
+
+#[test]
+fn blockchain__get_block_count__header() {
+    use bitcoind::client::client_sync::{Auth, Error};
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let auth = Auth::CookieFile(node.params.cookie_file.clone());
+
+    for keep_alive in [false, true] {
+        let client = bitcoind::Client::builder(&node.rpc_url())
+            .auth(auth.clone())
+            .header("X-Api-Key", "secret")
+            .keep_alive(keep_alive)
+            .build()
+            .unwrap();
+        let json: GetBlockCount = client.get_block_count().expect("getblockcount");
+        assert_eq!(json.0, 0);
+    }
+
+    let res = bitcoind::Client::builder(&node.rpc_url()).header("X-Api-Key", "a\r\nb").build();
+    assert!(matches!(res, Err(Error::InvalidHeader(_))));
+}
+
+#[test]
+fn blockchain__get_block__max_response_size() {
+    use bitcoind::client::client_sync::{Auth, Error};
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let hash = node.client.best_block_hash().expect("best_block_hash");
+
+    for keep_alive in [false, true] {
+        let client = bitcoind::Client::builder(&node.rpc_url())
+            .auth(Auth::CookieFile(node.params.cookie_file.clone()))
+            .max_response_size(100)
+            .keep_alive(keep_alive)
+            .build()
+            .unwrap();
+        // The genesis block is a few hundred bytes.
+        let res = client.get_block_verbose_zero(hash);
+        assert!(matches!(res, Err(Error::ResponseTooLarge)), "unexpected result: {:?}", res);
+        // Small responses still fit.
+        client.get_block_count().expect("getblockcount");
+    }
+}
+
+#[test]
+fn blockchain__get_block_count__section_trait() {
+    use bitcoind::client::client_sync::{BlockchainRpc, MockTransport, Result};
+    use bitcoind::serde_json::json;
+
+    /// Generic over the client, works with the client for any version.
+    fn block_count(client: &impl BlockchainRpc) -> Result<u64> { Ok(client.get_block_count()?.0) }
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    assert_eq!(block_count(&node.client).unwrap(), 0);
+
+    let mock = MockTransport::new();
+    mock.respond("getblockcount", json!(42));
+    assert_eq!(block_count(&bitcoind::Client::with_transport(mock)).unwrap(), 42);
+}
+
+#[test]
+fn blockchain__get_block__rest() {
+    use bitcoin::hashes::Hash;
+    use bitcoind::client::client_sync::{Error, RestClient, RestError};
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &["-rest", "-txindex"]);
+    node.fund_wallet();
+    let (_, tx) = node.create_mined_transaction();
+    let rest = RestClient::new(&node.rpc_url());
+
+    let best_block_hash = node.client.best_block_hash().expect("best_block_hash");
+    let block = rest.get_block(best_block_hash).expect("block");
+    assert_eq!(block.block_hash(), best_block_hash);
+    assert_eq!(block.txdata[1], tx);
+
+    let genesis = node.client.get_block_hash(0).expect("getblockhash").block_hash().unwrap();
+    let headers = rest.get_block_headers(genesis, 10).expect("headers");
+    assert_eq!(headers.len(), 10);
+    assert_eq!(headers[0].block_hash(), genesis);
+    assert_eq!(headers[1].prev_blockhash, genesis);
+
+    let count = node.client.get_block_count().expect("getblockcount").0;
+    let headers = rest.headers_from(genesis, 5000).expect("headers_from");
+    assert_eq!(headers.len() as u64, count + 1);
+    assert_eq!(headers.last().unwrap().block_hash(), best_block_hash);
+
+    #[cfg(not(feature = "v17"))]
+    assert_eq!(rest.get_block_hash(0).expect("blockhashbyheight").0, genesis);
+
+    let got = rest.get_raw_transaction(tx.compute_txid()).expect("tx");
+    assert_eq!(got.0, tx);
+
+    let info = rest.get_mempool_info().expect("mempool info");
+    assert_eq!(info.size, 0);
+
+    let unknown = bitcoin::BlockHash::from_byte_array([0; 32]);
+    match rest.get_block(unknown) {
+        Err(Error::Rest(RestError::Http { status_code, .. })) => assert_eq!(status_code, 404),
+        other => panic!("expected a 404, got {:?}", other),
+    }
+}
+
+#[test]
+fn blockchain__get_utxos__rest() {
+    use bitcoin::OutPoint;
+    use bitcoind::client::client_sync::RestClient;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &["-rest"]);
+    node.fund_wallet();
+    let (_, tx) = node.create_mined_transaction();
+    let rest = RestClient::new(&node.rpc_url());
+
+    let txid = tx.compute_txid();
+    let outpoints = [OutPoint { txid, vout: 0 }, OutPoint { txid, vout: 99 }];
+    let utxos = rest.get_utxos(&outpoints, false).expect("getutxos");
+    let count = node.client.get_block_count().expect("getblockcount").0;
+    assert_eq!(u64::from(utxos.chain_height), count);
+    assert_eq!(utxos.chain_tip_hash, node.client.best_block_hash().expect("best_block_hash"));
+    let utxo = utxos.utxos[0].as_ref().expect("unspent");
+    assert_eq!(utxo.height, utxos.chain_height);
+    assert_eq!(utxo.value, tx.output[0].value);
+    assert_eq!(utxo.script_pubkey, tx.output[0].script_pubkey);
+    assert!(utxos.utxos[1].is_none());
+
+    // Outputs of mempool transactions are only found when checking the mempool.
+    let (_, txid) = node.create_mempool_transaction();
+    let outpoint = OutPoint { txid, vout: 0 };
+    assert_eq!(rest.get_utxos(&[outpoint], false).expect("getutxos").utxos, [None]);
+    let utxos = rest.get_utxos(&[outpoint], true).expect("getutxos");
+    assert_eq!(utxos.utxos[0].as_ref().expect("in mempool").height, 0x7FFF_FFFF);
+}
+
+#[test]
+fn blockchain__get_best_block_hash__block_watcher() {
+    use bitcoind::client::client_sync::BlockWatcher;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    let mut watcher = BlockWatcher::new(&node.client)
+        .expect("watcher")
+        .interval(std::time::Duration::from_millis(10));
+    let (start, _) = watcher.tip();
+
+    let address = node.client.new_address().expect("new_address");
+    node.client.generate_to_address(2, &address).expect("generatetoaddress");
+    for height in start + 1..=start + 2 {
+        let (got, hash, block) = watcher.next().unwrap().expect("next block");
+        assert_eq!(got, height);
+        assert_eq!(hash, node.client.get_block_hash(height).unwrap().block_hash().unwrap());
+        assert_eq!(block.block_hash(), hash);
+    }
+
+    // Replace the tip with two other blocks, the watcher continues from the fork point.
+    let (_, stale) = watcher.tip();
+    node.client.invalidate_block(stale).expect("invalidateblock");
+    let address = node.client.new_address().expect("new_address");
+    node.client.generate_to_address(2, &address).expect("generatetoaddress");
+    for height in start + 2..=start + 3 {
+        let (got, hash, _) = watcher.next().unwrap().expect("next block");
+        assert_eq!(got, height);
+        assert_ne!(hash, stale);
+        assert_eq!(hash, node.client.get_block_hash(height).unwrap().block_hash().unwrap());
+    }
+}
+
+#[test]
+fn blockchain__get_block_count__call_with_timeout() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let timeout = std::time::Duration::from_secs(5);
+    let json: GetBlockCount = node.client.call_with_timeout("getblockcount", &[], timeout).unwrap();
+    assert_eq!(json.0, node.client.get_block_count().unwrap().0);
+}
+
+#[test]
+fn blockchain__get_block_count__call_with_deadline() {
+    use std::time::{Duration, Instant};
+
+    use bitcoind::client::client_sync::{Error, RetryPolicy};
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let deadline = Duration::from_secs(5);
+    let json: GetBlockCount =
+        node.client.call_with_deadline("getblockcount", &[], deadline).unwrap();
+    assert_eq!(json.0, node.client.get_block_count().unwrap().0);
+
+    let res = node.client.call_with_deadline::<GetBlockCount>("getblockcount", &[], Duration::ZERO);
+    assert!(matches!(res, Err(Error::DeadlineExceeded)));
+
+    // Nothing listens on port 1, the deadline passes before the first retry.
+    let policy = RetryPolicy { initial_backoff: Duration::from_secs(10), ..Default::default() };
+    let client = bitcoind::Client::builder("http://127.0.0.1:1").retry(policy).build().unwrap();
+    let start = Instant::now();
+    let res = client.call_with_deadline::<GetBlockCount>("getblockcount", &[], deadline);
+    assert!(matches!(res, Err(Error::DeadlineExceeded)));
+    assert!(start.elapsed() < deadline);
+}
+
+#[test]
+fn blockchain__get_block_hash__call_named() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let json: GetBlockHash =
+        node.client.call_named("getblockhash", &[("height", 0.into())]).unwrap();
+    assert_eq!(json.0, node.client.get_block_hash(0).unwrap().0);
+}
+
+#[test]
+fn blockchain__get_block_hash__call_json() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let json = node.client.call_json("getblockhash", &[0.into()]).unwrap();
+    assert_eq!(json, node.client.get_block_hash(0).unwrap().0);
+}
+
 #[test]
 #[cfg(not(feature = "v18_and_below"))]
 fn blockchain__get_block_filter__modelled() {
+    use bitcoind::BlockFilterType;
+
     let node = BitcoinD::with_wallet(Wallet::Default, &["-blockfilterindex"]);
     node.mine_a_block();
     let hash = node.client.best_block_hash().expect("best_block_hash failed");
 
     let json: GetBlockFilter = node.client.get_block_filter(hash).expect("getblockfilter");
     let model: Result<mtype::GetBlockFilter, GetBlockFilterError> = json.into_model();
-    model.unwrap();
+    let model = model.unwrap();
+
+    // The header commits to the filter and the header of the previous block's filter.
+    let block = node.client.get_block(hash).expect("getblock");
+    let json = node
+        .client
+        .get_block_filter_with_type(block.header.prev_blockhash, BlockFilterType::Basic)
+        .expect("getblockfilter");
+    let previous = json.into_model().unwrap();
+    let filter = bitcoin::bip158::BlockFilter::from(model.clone());
+    assert_eq!(filter.filter_header(&previous.header), model.header);
+
+    let script = block.txdata[0].output[0].script_pubkey.as_bytes();
+    assert!(filter.match_any(&hash, core::iter::once(script)).expect("match_any"));
 }
 
 #[test]
@@ -284,13 +631,46 @@ fn blockchain__get_block_header__modelled() {
     // verbose = false
     let json: GetBlockHeader = node.client.get_block_header(&block_hash).expect("getblockheader");
     let model: Result<mtype::GetBlockHeader, GetBlockHeaderError> = json.into_model();
-    model.unwrap();
+    let header = model.unwrap();
 
     // verbose = true
     let json: GetBlockHeaderVerbose =
         node.client.get_block_header_verbose(&block_hash).expect("getblockheader");
-    let model: Result<mtype::GetBlockHeaderVerbose, GetBlockHeaderVerboseError> = json.into_model();
+    let model: Result<mtype::GetBlockHeaderVerbose, GetBlockHeaderVerboseError> =
+        json.clone().into_model();
     model.unwrap();
+
+    let from_verbose = bitcoin::block::Header::try_from(json).expect("verbose header");
+    assert_eq!(from_verbose, header.0);
+    assert_eq!(from_verbose.block_hash(), block_hash);
+}
+
+#[test]
+fn blockchain__get_block_header_verbose__chain_fields() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let block_hash = node.client.best_block_hash().expect("best_block_hash failed");
+    let genesis = node.client.get_block_hash(0).expect("getblockhash").block_hash().unwrap();
+
+    let json: GetBlockchainInfo = node.client.get_blockchain_info().expect("getblockchaininfo");
+    let info: mtype::GetBlockchainInfo = json.into_model().unwrap();
+
+    let json: GetBlockHeaderVerbose =
+        node.client.get_block_header_verbose(&block_hash).expect("getblockheader");
+    let model: Result<mtype::GetBlockHeaderVerbose, GetBlockHeaderVerboseError> = json.into_model();
+    let tip = model.unwrap();
+    assert_eq!(tip.chain_work, info.chain_work);
+    assert_eq!(tip.height, 101);
+    assert_eq!(tip.n_tx, 1);
+    assert_eq!(tip.confirmations, 1);
+
+    let json: GetBlockHeaderVerbose =
+        node.client.get_block_header_verbose(&genesis).expect("getblockheader");
+    let genesis = json.into_model().unwrap();
+    assert_eq!(genesis.confirmations, 102);
+    // Each regtest block adds two units of work.
+    assert_eq!(genesis.chain_work.to_be_bytes()[31], 2);
+    assert!(tip.chain_work > genesis.chain_work);
 }
 
 #[test]
@@ -307,6 +687,8 @@ fn blockchain__get_block_stats__modelled() {
     get_block_stats_by_height(&node);
     get_block_stats_by_block_hash(&node);
     get_block_stats_with_stats(&node);
+    get_block_stats_with_stat_fields(&node);
+    get_block_stats_blockchain_rpc(&node);
 }
 
 fn get_block_stats_by_height(node: &BitcoinD) {
@@ -345,6 +727,31 @@ fn get_block_stats_with_stats(node: &BitcoinD) {
     assert!(model.height.is_none());
 }
 
+fn get_block_stats_with_stat_fields(node: &BitcoinD) {
+    let block_hash = node.client.best_block_hash().expect("best_block_hash failed");
+    let stats = [StatField::TotalFee, StatField::Height];
+
+    for block in [HashOrHeight::Height(101), HashOrHeight::from(block_hash)] {
+        let json: GetBlockStats =
+            node.client.get_block_stats(block, Some(&stats)).expect("getblockstats");
+        let model = json.into_model().unwrap(); // Explicit error type already used above.
+
+        assert!(model.total_fee.is_some());
+        assert_eq!(model.height, Some(101));
+        assert!(model.block_hash.is_none());
+        assert!(model.average_fee_rate.is_none());
+    }
+}
+
+fn get_block_stats_blockchain_rpc(node: &BitcoinD) {
+    use bitcoind::client::client_sync::BlockchainRpc;
+
+    let model = BlockchainRpc::get_block_stats(&node.client, HashOrHeight::Height(101), None)
+        .expect("getblockstats");
+    assert_eq!(model.height, Some(101));
+    assert!(model.total_fee.is_some());
+}
+
 #[test]
 #[cfg(not(feature = "v25_and_below"))]
 fn blockchain__get_chain_states__modelled() {
@@ -361,11 +768,26 @@ fn blockchain__get_chain_states__modelled() {
 
 #[test]
 fn blockchain__get_chain_tips__modelled() {
-    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
 
     let json: GetChainTips = node.client.get_chain_tips().expect("getchaintips");
     let model: Result<mtype::GetChainTips, ChainTipsError> = json.into_model();
-    model.unwrap();
+    let tips = model.unwrap();
+    assert_eq!(tips.active().expect("active tip").branch_length, 0);
+    assert_eq!(tips.forks().count(), 0);
+
+    // Invalidating the tip leaves it as an invalid fork of length one.
+    let address = node.client.new_address().expect("new_address");
+    node.client.generate_to_address(2, &address).expect("generatetoaddress");
+    let stale = node.client.best_block_hash().expect("best_block_hash");
+    node.client.invalidate_block(stale).expect("invalidateblock");
+
+    let tips = node.client.get_chain_tips().expect("getchaintips").into_model().unwrap();
+    let fork = tips.forks().next().expect("fork");
+    assert_eq!(fork.hash, stale);
+    assert_eq!(fork.branch_length, 1);
+    assert_eq!(fork.status, mtype::ChainTipsStatus::Invalid);
+    assert_eq!(tips.active().expect("active tip").height, fork.height - 1);
 }
 
 #[test]
@@ -379,6 +801,23 @@ fn blockchain__get_chain_tx_stats__modelled() {
     let chain_tx_stats = model.unwrap();
 
     assert!(chain_tx_stats.tx_rate.unwrap() > 0.0);
+
+    let block_hash = node.client.get_block_hash(1).expect("getblockhash").block_hash().unwrap();
+    let json: GetChainTxStats = node
+        .client
+        .get_chain_tx_stats_with_window(Some(0), Some(block_hash))
+        .expect("getchaintxstats");
+    let model: mtype::GetChainTxStats = json.into_model().unwrap();
+    assert_eq!(model.window_final_block_hash, block_hash);
+    assert_eq!(model.window_block_count, 0);
+    assert!(model.window_tx_count.is_none());
+    assert!(model.tx_rate.is_none());
+
+    let json: GetChainTxStats =
+        node.client.get_chain_tx_stats_with_window(Some(10), None).expect("getchaintxstats");
+    let model: mtype::GetChainTxStats = json.into_model().unwrap();
+    assert_eq!(model.window_block_count, 10);
+    assert!(model.window_tx_count.is_some());
 }
 
 #[test]
@@ -441,6 +880,33 @@ fn blockchain__get_descriptor_activity__modelled() {
     model.unwrap();
 }
 
+#[test]
+#[cfg(not(feature = "v28_and_below"))]
+fn blockchain__get_descriptor_activity_mempool__modelled() {
+    use bitcoind::ScanObject;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (address, txid) = node.create_mempool_transaction();
+
+    let descriptor = format!("addr({})", address);
+    let json: GetDescriptorActivity = node
+        .client
+        .get_descriptor_activity_with_options(
+            &[],
+            &[ScanObject::from(descriptor.as_str())],
+            Some(true),
+        )
+        .expect("getdescriptoractivity");
+    let model: Result<mtype::GetDescriptorActivity, GetDescriptorActivityError> = json.into_model();
+    let activity = model.unwrap().activity;
+
+    assert!(activity.iter().any(|entry| matches!(
+        entry,
+        mtype::ActivityEntry::Receive(receive) if receive.txid == txid && receive.block_hash.is_none()
+    )));
+}
+
 #[test]
 fn blockchain__get_difficulty__modelled() {
     let node = BitcoinD::with_wallet(Wallet::None, &[]);
@@ -477,6 +943,10 @@ fn blockchain__get_mempool_ancestors_verbose__modelled() {
     let ancestors = model.unwrap();
 
     assert!(ancestors.0.contains_key(&parent_txid));
+    let parent = &ancestors.0[&parent_txid];
+    assert!(parent.virtual_size().is_some());
+    assert!(parent.spent_by.contains(&child_txid));
+    assert!(parent.fees.ancestor >= parent.fees.base);
     #[cfg(not(feature = "v30_and_below"))]
     assert!(ancestors.0[&parent_txid].chunk_weight.is_some());
 }
@@ -512,6 +982,10 @@ fn blockchain__get_mempool_descendants_verbose__modelled() {
     let descendants = model.unwrap();
 
     assert!(descendants.0.contains_key(&child_txid));
+    let child = &descendants.0[&child_txid];
+    assert!(child.virtual_size().is_some());
+    assert!(child.depends.contains(&parent_txid));
+    assert_eq!(child.ancestor_count, 2);
     #[cfg(not(feature = "v30_and_below"))]
     assert!(descendants.0[&child_txid].chunk_weight.is_some());
 }
@@ -530,7 +1004,12 @@ fn blockchain__get_mempool_entry__modelled() {
         assert!(entry.0.chunk_weight.is_some());
         assert!(entry.0.fees.chunk.is_some());
     }
-    let _ = entry;
+
+    // The transaction has no in-mempool ancestors or descendants.
+    let fee_rate = entry.0.fee_rate().expect("fee rate");
+    assert!(fee_rate > bitcoin::FeeRate::ZERO);
+    assert_eq!(entry.0.ancestor_fee_rate(), Some(fee_rate));
+    assert_eq!(entry.0.descendant_fee_rate(), Some(fee_rate));
 }
 
 #[test]
@@ -665,6 +1144,69 @@ fn blockchain__get_tx_out_set_info__modelled() {
     model.unwrap();
 }
 
+#[test]
+#[cfg(all(feature = "v21_and_below", not(feature = "v20_and_below")))]
+fn blockchain__get_tx_out_set_info_with_options__modelled() {
+    use bitcoind::TxOutSetHashType;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let json: GetTxOutSetInfo = node
+        .client
+        .get_tx_out_set_info_with_options(TxOutSetHashType::Muhash)
+        .expect("gettxoutsetinfo muhash");
+    let model: Result<mtype::GetTxOutSetInfo, GetTxOutSetInfoError> = json.into_model();
+    let model = model.unwrap();
+    assert!(model.muhash.is_some());
+    assert!(model.hash_serialized_2.is_none());
+
+    let json: GetTxOutSetInfo = node
+        .client
+        .get_tx_out_set_info_with_options(TxOutSetHashType::None)
+        .expect("gettxoutsetinfo none");
+    let model = json.into_model().unwrap();
+    assert!(model.muhash.is_none());
+    assert!(model.hash_serialized_2.is_none());
+}
+
+#[test]
+#[cfg(not(feature = "v21_and_below"))]
+fn blockchain__get_tx_out_set_info_with_options__modelled() {
+    use bitcoind::TxOutSetHashType;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &["-coinstatsindex"]);
+    node.fund_wallet();
+
+    let json: GetTxOutSetInfo = node
+        .client
+        .get_tx_out_set_info_with_options(
+            TxOutSetHashType::Muhash,
+            Some(HashOrHeight::Height(1)),
+            Some(true),
+        )
+        .expect("gettxoutsetinfo muhash");
+    let model: Result<mtype::GetTxOutSetInfo, GetTxOutSetInfoError> = json.into_model();
+    let model = model.unwrap();
+
+    assert_eq!(model.height, 1);
+    assert!(model.muhash.is_some());
+    assert!(model.transactions.is_none());
+    let block_info = model.block_info.expect("block_info with coinstatsindex");
+    assert_eq!(block_info.coinbase, bitcoin::Amount::from_int_btc(50));
+
+    let json: GetTxOutSetInfo = node
+        .client
+        .get_tx_out_set_info_with_options(TxOutSetHashType::None, None, Some(false))
+        .expect("gettxoutsetinfo none");
+    let model = json.into_model().unwrap();
+
+    assert!(model.hash_serialized_2.is_none());
+    assert!(model.hash_serialized_3.is_none());
+    assert!(model.muhash.is_none());
+    assert!(model.block_info.is_none());
+}
+
 #[test]
 #[cfg(not(feature = "v23_and_below"))]
 fn blockchain__get_tx_spending_prevout__modelled() {
@@ -703,14 +1245,9 @@ fn blockchain__get_tx_spending_prevout_spending_tx_and_block_hash__modelled() {
     let (_address, tx) = node.create_mined_transaction();
     let outpoint = tx.input[0].previous_output;
 
-    let outputs = bitcoind::serde_json::json!([{
-        "txid": outpoint.txid.to_string(),
-        "vout": outpoint.vout,
-    }]);
-    let options = bitcoind::serde_json::json!({ "return_spending_tx": true });
     let json: GetTxSpendingPrevout = node
         .client
-        .call("gettxspendingprevout", &[outputs, options])
+        .get_tx_spending_prevout_with_options(&[outpoint], Some(false), Some(true))
         .expect("gettxspendingprevout");
     let item = &json.0[0];
     assert!(item.spending_txid.is_some());
@@ -735,6 +1272,10 @@ fn blockchain__import_mempool() {
     let mempool_path = node.client.save_mempool().expect("savemempool");
 
     let _: () = node.client.import_mempool(&mempool_path.filename).expect("importmempool");
+    let _: () = node
+        .client
+        .import_mempool_with_options(&mempool_path.filename, Some(false), Some(true), Some(true))
+        .expect("importmempool with options");
 }
 
 #[test]
@@ -782,7 +1323,9 @@ fn blockchain__save_mempool() {
 
     #[cfg(not(feature = "v22_and_below"))]
     {
-        let _: Result<SaveMempool, _> = node.client.save_mempool();
+        let json: SaveMempool = node.client.save_mempool().expect("savemempool");
+        let model: mtype::SaveMempool = json.clone().into_model();
+        assert_eq!(model.filename, Some(json.filename));
     }
 }
 
@@ -811,6 +1354,43 @@ fn blockchain__scan_blocks__modelled() {
     }
 }
 
+#[test]
+#[cfg(not(feature = "v24_and_below"))]
+fn blockchain__scan_blocks_with_options__modelled() {
+    use bitcoind::{BlockFilterType, ScanObject};
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &["-blockfilterindex=1"]);
+    node.fund_wallet();
+    let address = node.client.new_address().expect("failed to get new address");
+    node.client.generate_to_address(1, &address).expect("generatetoaddress");
+    let best = node.client.best_block_hash().expect("best_block_hash");
+    let height = node.client.get_block_count().expect("getblockcount").0 as u32;
+
+    let desc = ScanObject::from(format!("addr({})", address).as_str());
+    let scan = |start, stop| {
+        let json: ScanBlocksStart = node
+            .client
+            .scan_blocks_start_with_options(
+                std::slice::from_ref(&desc),
+                start,
+                stop,
+                Some(BlockFilterType::Basic),
+                Some(true),
+            )
+            .expect("scanblocks start");
+        let model: Result<mtype::ScanBlocksStart, ScanBlocksStartError> = json.into_model();
+        model.unwrap()
+    };
+
+    let model = scan(Some(height - 1), None);
+    assert_eq!(model.from_height, height - 1);
+    assert_eq!(model.to_height, height);
+    assert_eq!(model.relevant_blocks, vec![best]);
+
+    let model = scan(Some(1), Some(height - 1));
+    assert!(model.relevant_blocks.is_empty());
+}
+
 #[test]
 fn blockchain__scan_tx_out_set__modelled() {
     let node = match () {
@@ -834,6 +1414,43 @@ fn blockchain__scan_tx_out_set__modelled() {
     let _: ScanTxOutSetAbort = node.client.scan_tx_out_set_abort().expect("scantxoutset abort");
 }
 
+#[test]
+#[cfg(not(feature = "v17"))]
+fn blockchain__scan_tx_out_set_ranged__modelled() {
+    use bitcoin::bip32::{DerivationPath, Xpriv, Xpub};
+    use bitcoin::secp256k1::Secp256k1;
+    use bitcoin::{Address, Amount, CompressedPublicKey, Network};
+    use bitcoind::ScanObject;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+
+    let secp = Secp256k1::new();
+    let xpriv = Xpriv::new_master(Network::Regtest, &[1; 32]).unwrap();
+    let xpub = Xpub::from_priv(&secp, &xpriv);
+    let path: DerivationPath = "0/3".parse().unwrap();
+    let pubkey = CompressedPublicKey(xpub.derive_pub(&secp, &path).unwrap().public_key);
+    let address = Address::p2wpkh(&pubkey, Network::Regtest);
+    node.client.generate_to_address(1, &address).expect("generatetoaddress");
+
+    let desc = format!("wpkh({}/0/*)", xpub);
+    let scan = |range| {
+        let object = ScanObject::Ranged { desc: desc.clone(), range };
+        let json: ScanTxOutSetStart =
+            node.client.scan_tx_out_set_start_with_objects(&[object]).expect("scantxoutset start");
+        let model: Result<mtype::ScanTxOutSetStart, ScanTxOutSetError> = json.into_model();
+        model.unwrap()
+    };
+
+    let model = scan((0, 5));
+    assert_eq!(model.unspents.len(), 1);
+    assert_eq!(model.unspents[0].script_pubkey, address.script_pubkey());
+    assert_eq!(model.unspents[0].height, 1);
+    assert_eq!(model.total_amount, Amount::from_int_btc(50));
+
+    let model = scan((0, 2));
+    assert!(model.unspents.is_empty());
+}
+
 #[test]
 fn blockchain__verify_chain() {
     let node = BitcoinD::with_wallet(Wallet::None, &[]);
@@ -841,6 +1458,20 @@ fn blockchain__verify_chain() {
     let _: Result<VerifyChain, _> = node.client.verify_chain();
 }
 
+#[test]
+fn blockchain__verify_chain_with_options() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let json: VerifyChain =
+        node.client.verify_chain_with_options(Some(4), Some(10)).expect("verifychain");
+    assert!(json.0);
+
+    let json: VerifyChain =
+        node.client.verify_chain_with_options(None, Some(0)).expect("verifychain");
+    assert!(json.0);
+}
+
 #[test]
 fn blockchain__verify_tx_out_proof__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -859,6 +1490,31 @@ fn blockchain__verify_tx_out_proof__modelled() {
     assert_eq!(txids.0.len(), 1);
 }
 
+#[test]
+fn blockchain__verify_tx_out_proof__merkle_block() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let (_address, tx) = node.create_mined_transaction();
+    let txid = tx.compute_txid();
+    let block_hash = node.client.best_block_hash().expect("best_block_hash");
+
+    let proof = node.client.get_tx_out_proof_merkle_block(&[txid]).expect("gettxoutproof");
+    assert_eq!(proof.header.block_hash(), block_hash);
+
+    // The proof can be checked without the node.
+    let mut matches = vec![];
+    let mut indexes = vec![];
+    let root = proof.txn.extract_matches(&mut matches, &mut indexes).expect("valid proof");
+    assert_eq!(root, proof.header.merkle_root);
+    assert_eq!(matches, vec![txid]);
+
+    let json: VerifyTxOutProof =
+        node.client.verify_tx_out_proof_merkle_block(&proof).expect("verifytxoutproof");
+    let model: Result<mtype::VerifyTxOutProof, hex::HexToArrayError> = json.into_model();
+    assert_eq!(model.unwrap().0, vec![txid]);
+}
+
 #[test]
 fn blockchain__wait_for_block__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -914,6 +1570,35 @@ fn blockchain__wait_for_new_block__modelled() {
     assert_ne!(block.hash, prev_hash);
 }
 
+#[test]
+fn blockchain__wait_for_block__timeout() {
+    use bitcoin::hashes::Hash;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.mine_a_block();
+    let height = node.client.get_block_count().expect("getblockcount").0;
+    let block_hash = node.client.best_block_hash().expect("bestblockhash");
+    let timeout = std::time::Duration::from_millis(100);
+
+    // Each call times out waiting and returns the current tip.
+    let unknown = bitcoin::BlockHash::from_byte_array([1; 32]);
+    let json: WaitForBlock =
+        node.client.wait_for_block_with_timeout(&unknown, timeout).expect("waitforblock");
+    assert_eq!(json.into_model().unwrap().hash, block_hash);
+
+    let json: WaitForBlockHeight = node
+        .client
+        .wait_for_block_height_with_timeout(height + 1, timeout)
+        .expect("waitforblockheight");
+    let model = json.into_model().unwrap();
+    assert_eq!(model.height, height as u32);
+    assert_eq!(model.hash, block_hash);
+
+    let json: WaitForNewBlock =
+        node.client.wait_for_new_block_with_timeout(timeout).expect("waitfornewblock");
+    assert_eq!(json.into_model().unwrap().hash, block_hash);
+}
+
 /// Create and broadcast a child transaction spending vout 0 of the given parent mempool txid.
 /// Returns the child's txid.
 fn create_child_spending_parent(node: &BitcoinD, parent_txid: bitcoin::Txid) -> bitcoin::Txid {
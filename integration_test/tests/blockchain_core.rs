@@ -6,7 +6,7 @@
 
 use bitcoind::mtype;
 use bitcoind::vtype::*;
-use integration_test::{BitcoinD, BitcoinDExt as _, Wallet};
+use integration_test::{BitcoinD, BitcoinDExt as _, ReorgBuilder, Wallet};
 
 #[test]
 fn get_blockchain_info_pruned_node_has_prune_fields() {
@@ -20,6 +20,31 @@ fn get_blockchain_info_pruned_node_has_prune_fields() {
     assert!(out.prune_target_size.is_some());
 }
 
+#[test]
+fn get_blockchain_info_prune_status() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &["-prune=550"]);
+    node.fund_wallet();
+
+    let model: mtype::GetBlockchainInfo =
+        node.client.get_blockchain_info().unwrap().into_model().unwrap();
+    let status = model.prune_status().expect("node is pruned");
+    assert!(status.automatic_pruning);
+    assert_eq!(status.prune_target_size, Some(550 * 1024 * 1024));
+
+    // Manual pruning only.
+    let node = BitcoinD::with_wallet(Wallet::None, &["-prune=1"]);
+    let model: mtype::GetBlockchainInfo =
+        node.client.get_blockchain_info().unwrap().into_model().unwrap();
+    let status = model.prune_status().expect("node is pruned");
+    assert!(!status.automatic_pruning);
+    assert_eq!(status.prune_target_size, None);
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let model: mtype::GetBlockchainInfo =
+        node.client.get_blockchain_info().unwrap().into_model().unwrap();
+    assert_eq!(model.prune_status(), None);
+}
+
 #[test]
 fn get_chain_tx_stats_default_window_has_optional_fields() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -251,7 +276,7 @@ fn get_tx_spending_prevout_for_unspent_output() {
 }
 
 #[test]
-#[cfg(not(feature = "v28_and_below"))]
+#[cfg(not(feature = "v21_and_below"))]
 fn get_block_verbose_two_non_coinbase_has_fee() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
@@ -266,7 +291,7 @@ fn get_block_verbose_two_non_coinbase_has_fee() {
 }
 
 #[test]
-#[cfg(not(feature = "v28_and_below"))]
+#[cfg(not(feature = "v24_and_below"))]
 fn get_block_verbose_three_non_coinbase_has_prevouts() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
     node.fund_wallet();
@@ -358,6 +383,49 @@ fn get_chain_tips_after_invalidate_block_has_stale_tip() {
     assert_eq!(stale.branch_length, 1);
 }
 
+#[test]
+fn get_chain_tips_after_reorg_has_valid_fork() {
+    let (node1, node2, _node3) = integration_test::three_node_network();
+    node1.mine_a_block();
+
+    let reorg = ReorgBuilder::new(&node1, &node2).stale_blocks(2).new_blocks(3).build();
+    assert_eq!(reorg.stale.len(), 2);
+    assert_eq!(reorg.new.len(), 3);
+
+    let json: GetChainTips = node1.client.get_chain_tips().unwrap();
+    let stale_tip = reorg.stale.last().unwrap().to_string();
+    let stale = json.0.iter().find(|t| t.hash == stale_tip).unwrap();
+    assert_eq!(stale.status, ChainTipsStatus::ValidFork);
+    assert_eq!(stale.branch_length, 2);
+
+    let fork_point = node1.client.get_block_header_verbose(&reorg.fork_point).unwrap();
+    assert_eq!(fork_point.confirmations, 4);
+}
+
+#[test]
+fn get_chain_tips_after_forced_reorg() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let reorg = node.force_reorg(2);
+    assert_eq!(reorg.stale.len(), 2);
+    assert_eq!(reorg.new.len(), 3);
+    assert_eq!(node.client.best_block_hash().unwrap(), *reorg.new.last().unwrap());
+
+    let stale_tip = reorg.stale.last().unwrap().to_string();
+    let json: GetChainTips = node.client.get_chain_tips().unwrap();
+    let stale = json.0.iter().find(|t| t.hash == stale_tip).unwrap();
+    assert_eq!(stale.status, ChainTipsStatus::Invalid);
+    assert_eq!(stale.branch_length, 2);
+
+    node.client.reconsider_block(reorg.stale[0]).unwrap();
+    assert_eq!(node.client.best_block_hash().unwrap(), *reorg.new.last().unwrap());
+
+    let json: GetChainTips = node.client.get_chain_tips().unwrap();
+    let stale = json.0.iter().find(|t| t.hash == stale_tip).unwrap();
+    assert_eq!(stale.status, ChainTipsStatus::ValidFork);
+}
+
 #[test]
 #[cfg(not(feature = "v25_and_below"))]
 fn dump_tx_out_set_fields_consistent() {
@@ -0,0 +1,241 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Tests for the features of the blocking client, e.g., batching and the builder options.
+
+#![allow(non_snake_case)] // Test names intentionally use double underscore.
+
+use bitcoind::vtype::*; // All the version specific types.
+use integration_test::{BitcoinD, BitcoinDExt as _, Wallet};
+
+#[test]
+fn client__batch() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let mut batch = node.client.batch();
+    for height in 0..5 {
+        batch.push::<GetBlockHash>("getblockhash", &[height.into()]).unwrap();
+    }
+    let hashes: Vec<GetBlockHash> = batch.send().unwrap().into_results().unwrap();
+    assert_eq!(hashes.len(), 5);
+    for (height, hash) in hashes.into_iter().enumerate() {
+        assert_eq!(hash.0, node.client.get_block_hash(height as u64).unwrap().0);
+    }
+
+    let mut batch = node.client.batch();
+    let count = batch.push::<GetBlockCount>("getblockcount", &[]).unwrap();
+    let best = batch.push::<GetBestBlockHash>("getbestblockhash", &[]).unwrap();
+    let mut response = batch.send().unwrap();
+    assert_eq!(response.take(count).unwrap().0, 101);
+    assert_eq!(response.take(best).unwrap().0, node.client.get_best_block_hash().unwrap().0);
+    assert!(response.take(best).is_err());
+}
+
+#[test]
+fn client__builder() {
+    use bitcoind::client::client_sync::Auth;
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let client = bitcoind::Client::builder(&node.rpc_url())
+        .auth(Auth::CookieFile(node.params.cookie_file.clone()))
+        .timeout(std::time::Duration::from_secs(5))
+        .build()
+        .unwrap();
+    let json: GetBlockCount = client.get_block_count().unwrap();
+    assert_eq!(json.0, node.client.get_block_count().unwrap().0);
+}
+
+#[test]
+fn client__retry() {
+    use std::time::{Duration, Instant};
+
+    use bitcoind::client::client_sync::{Auth, RetryPolicy};
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let policy = RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(50),
+        max_backoff: Duration::from_millis(100),
+    };
+
+    let client = bitcoind::Client::builder(&node.rpc_url())
+        .auth(Auth::CookieFile(node.params.cookie_file.clone()))
+        .retry(policy.clone())
+        .build()
+        .unwrap();
+    let _: GetBlockCount = client.get_block_count().unwrap();
+
+    // Nothing listens on the port so every attempt is refused.
+    let port = bitcoind::get_available_port().unwrap();
+    let client = bitcoind::Client::builder(&format!("http://127.0.0.1:{}", port))
+        .retry(policy)
+        .build()
+        .unwrap();
+    let start = Instant::now();
+    assert!(client.get_block_count().is_err());
+    assert!(start.elapsed() >= Duration::from_millis(150)); // Backoff of 50ms then 100ms.
+}
+
+#[test]
+fn client__middleware() {
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use bitcoind::client::client_sync::{Auth, Middleware, Result};
+    use bitcoind::client::jsonrpc;
+    use bitcoind::serde_json::{json, Value};
+
+    /// Queries the genesis block whatever height is asked for, records the methods called.
+    #[derive(Default)]
+    struct Genesis(Arc<Mutex<Vec<String>>>);
+
+    impl Middleware for Genesis {
+        fn on_request(&self, method: &str, params: &mut Value) {
+            if method == "getblockhash" {
+                *params = json!([0]);
+            }
+        }
+
+        fn on_response(&self, method: &str, _: &Result<jsonrpc::Response>, _: Duration) {
+            self.0.lock().unwrap().push(method.to_owned());
+        }
+    }
+
+    // There is no block at height 1, the call only succeeds if the params are rewritten.
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let middleware = Genesis::default();
+    let called = Arc::clone(&middleware.0);
+    let client = bitcoind::Client::new_with_auth(
+        &node.rpc_url(),
+        Auth::CookieFile(node.params.cookie_file.clone()),
+    )
+    .unwrap()
+    .with_middleware(middleware);
+
+    let json: GetBlockHash = client.get_block_hash(1).unwrap();
+    assert_eq!(json.0, node.client.get_block_hash(0).unwrap().0);
+    assert_eq!(*called.lock().unwrap(), vec!["getblockhash".to_owned()]);
+}
+
+#[test]
+fn client__stats() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    node.client.reset_stats();
+    let _ = node.client.get_block_count().unwrap();
+    let _ = node.client.get_block_count().unwrap();
+    assert!(node.client.get_block_hash(1).is_err());
+
+    let stats = node.client.stats();
+    assert_eq!(stats.total_calls(), 3);
+
+    let count = stats.get("getblockcount").unwrap();
+    assert_eq!(count.calls, 2);
+    assert_eq!(count.errors, 0);
+    assert_eq!(count.latency.buckets().map(|(_, n)| n).sum::<u64>(), 2);
+    assert!(count.latency.mean().is_some());
+
+    let hash = stats.get("getblockhash").unwrap();
+    assert_eq!((hash.calls, hash.errors), (1, 1));
+}
+
+#[test]
+fn client__pipeline() {
+    use bitcoind::client::client_sync::Auth;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let auth = Auth::CookieFile(node.params.cookie_file.clone());
+
+    let heights: Vec<_> = (0..=100u64).map(|height| vec![height.into()]).collect();
+    for keep_alive in [false, true] {
+        let client = bitcoind::Client::builder(&node.rpc_url())
+            .auth(auth.clone())
+            .keep_alive(keep_alive)
+            .build()
+            .unwrap();
+        let results = client.pipeline::<GetBlockHash>("getblockhash", &heights, 4);
+        assert_eq!(results.len(), heights.len());
+        for (height, result) in results.into_iter().enumerate() {
+            let json = result.expect("getblockhash");
+            assert_eq!(json.0, node.client.get_block_hash(height as u64).unwrap().0);
+        }
+    }
+}
+
+#[test]
+fn client__wait_for_ready() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    node.client.wait_for_ready(std::time::Duration::from_secs(1)).expect("wait_for_ready");
+}
+
+#[test]
+fn client__wait_for_ready_warmup() {
+    use std::time::{Duration, Instant};
+
+    use bitcoind::client::client_sync::MockTransport;
+
+    let mock = MockTransport::new();
+    mock.respond_error("getblockchaininfo", -28, "Loading block index...");
+    let client = bitcoind::Client::with_transport(mock.clone());
+
+    let start = Instant::now();
+    let err = client.wait_for_ready(Duration::from_millis(500)).expect_err("still warming up");
+    assert!(err.is_warmup());
+    assert!(start.elapsed() >= Duration::from_millis(500));
+    assert!(mock.calls().len() > 1);
+
+    mock.respond("getblockchaininfo", bitcoind::serde_json::json!({}));
+    client.wait_for_ready(Duration::from_millis(500)).expect("wait_for_ready");
+}
+
+#[test]
+fn client__failover() {
+    use bitcoind::client::client_sync::Auth;
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let auth = Auth::CookieFile(node.params.cookie_file.clone());
+
+    for keep_alive in [false, true] {
+        // Nothing listens on port 1, requests fail over to the node.
+        let client = bitcoind::Client::builder("http://127.0.0.1:1")
+            .fallback_url(&node.rpc_url())
+            .auth(auth.clone())
+            .keep_alive(keep_alive)
+            .build()
+            .unwrap();
+        let json: GetBlockCount = client.get_block_count().expect("getblockcount");
+        assert_eq!(json.0, 0);
+
+        // The primary accepts connections but never responds, the request may have been received
+        // so the timeout is returned instead of failing over.
+        let silent = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = bitcoind::Client::builder(&format!("http://{}", silent.local_addr().unwrap()))
+            .fallback_url(&node.rpc_url())
+            .auth(auth.clone())
+            .keep_alive(keep_alive)
+            .timeout(std::time::Duration::from_millis(200))
+            .build()
+            .unwrap();
+        assert!(client.get_block_count().is_err());
+    }
+}
+
+#[test]
+fn client__keep_alive() {
+    use bitcoind::client::client_sync::Auth;
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let client = bitcoind::Client::builder(&node.rpc_url())
+        .auth(Auth::CookieFile(node.params.cookie_file.clone()))
+        .keep_alive(true)
+        .build()
+        .unwrap();
+    for _ in 0..3 {
+        let json: GetBlockCount = client.get_block_count().unwrap();
+        assert_eq!(json.0, 0);
+    }
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: CC0-1.0
+
+//! Tests for the async client, which wraps the same methods as the blocking client.
+
+#![allow(non_snake_case)] // Test names intentionally use double underscore.
+#![allow(unused_imports)] // Because of feature gated tests.
+
+use bitcoin::Amount;
+use bitcoind::vtype::*;
+use bitcoind::{mtype, Input, Output};
+#[cfg(feature = "v17")]
+use corepc_client::client_async::v17::Client as AsyncClient;
+#[cfg(all(feature = "v18_and_below", not(feature = "v17")))]
+use corepc_client::client_async::v18::Client as AsyncClient;
+#[cfg(all(feature = "v19_and_below", not(feature = "v18_and_below")))]
+use corepc_client::client_async::v19::Client as AsyncClient;
+#[cfg(all(feature = "v20_and_below", not(feature = "v19_and_below")))]
+use corepc_client::client_async::v20::Client as AsyncClient;
+#[cfg(all(feature = "v21_and_below", not(feature = "v20_and_below")))]
+use corepc_client::client_async::v21::Client as AsyncClient;
+#[cfg(all(feature = "v22_and_below", not(feature = "v21_and_below")))]
+use corepc_client::client_async::v22::Client as AsyncClient;
+#[cfg(all(feature = "v23_and_below", not(feature = "v22_and_below")))]
+use corepc_client::client_async::v23::Client as AsyncClient;
+#[cfg(all(feature = "v24_and_below", not(feature = "v23_and_below")))]
+use corepc_client::client_async::v24::Client as AsyncClient;
+#[cfg(all(feature = "v25_and_below", not(feature = "v24_and_below")))]
+use corepc_client::client_async::v25::Client as AsyncClient;
+#[cfg(all(feature = "v26_and_below", not(feature = "v25_and_below")))]
+use corepc_client::client_async::v26::Client as AsyncClient;
+#[cfg(all(feature = "v27_and_below", not(feature = "v26_and_below")))]
+use corepc_client::client_async::v27::Client as AsyncClient;
+#[cfg(all(feature = "v28_and_below", not(feature = "v27_and_below")))]
+use corepc_client::client_async::v28::Client as AsyncClient;
+#[cfg(all(feature = "v29_and_below", not(feature = "v28_and_below")))]
+use corepc_client::client_async::v29::Client as AsyncClient;
+#[cfg(all(feature = "v30_and_below", not(feature = "v29_and_below")))]
+use corepc_client::client_async::v30::Client as AsyncClient;
+#[cfg(not(feature = "v30_and_below"))]
+use corepc_client::client_async::v31::Client as AsyncClient;
+use corepc_client::client_async::Auth;
+use integration_test::{BitcoinD, BitcoinDExt as _, Wallet};
+
+/// Returns an async client authenticated with the cookie file of `node`.
+fn async_client(node: &BitcoinD) -> AsyncClient {
+    AsyncClient::new_with_auth(&node.rpc_url(), Auth::CookieFile(node.params.cookie_file.clone()))
+        .expect("failed to create async client")
+}
+
+#[tokio::test]
+async fn client_async__blockchain() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let client = async_client(&node);
+
+    let info: GetBlockchainInfo = client.get_blockchain_info().await.expect("getblockchaininfo");
+    assert_eq!(info.blocks, 101);
+    assert_eq!(client.get_block_count().await.expect("getblockcount").0, 101);
+
+    let best = client.best_block_hash().await.expect("getbestblockhash");
+    assert_eq!(best, node.client.best_block_hash().unwrap());
+    let hash = client.get_block_hash(101).await.expect("getblockhash").block_hash().unwrap();
+    assert_eq!(hash, best);
+
+    let block = client.get_block(best).await.expect("getblock");
+    assert_eq!(block.block_hash(), best);
+    let header = client.get_block_header(&best).await.expect("getblockheader");
+    assert_eq!(header.block_header().unwrap(), block.header);
+
+    let coinbase = block.txdata[0].compute_txid();
+    let json: GetTxOut = client.get_tx_out(coinbase, 0).await.expect("gettxout");
+    let model: mtype::GetTxOut = json.into_model().unwrap();
+    assert_eq!(model.tx_out, block.txdata[0].output[0]);
+}
+
+#[tokio::test]
+async fn client_async__network() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let client = async_client(&node);
+
+    let version = client.server_version().await.expect("getnetworkinfo");
+    assert_eq!(version, node.client.server_version().unwrap());
+}
+
+#[tokio::test]
+async fn client_async__raw_transactions() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &["-txindex"]);
+    node.fund_wallet();
+    let client = async_client(&node);
+
+    let json: ListUnspent = node.client.list_unspent().expect("listunspent");
+    let utxo = json.into_model().unwrap().0.remove(0);
+    let inputs = vec![Input { txid: utxo.txid, vout: u64::from(utxo.vout), sequence: None }];
+    let address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(address, utxo.amount - Amount::from_sat(1_000))];
+    let json: CreateRawTransaction =
+        node.client.create_raw_transaction(&inputs, &outputs).expect("createrawtransaction");
+    let json: SignRawTransactionWithWallet = node
+        .client
+        .sign_raw_transaction_with_wallet(&json.transaction().unwrap())
+        .expect("signrawtransactionwithwallet");
+    let tx = json.into_model().unwrap().tx;
+
+    let json: SendRawTransaction =
+        client.send_raw_transaction(&tx).await.expect("sendrawtransaction");
+    let txid = json.txid().unwrap();
+    assert_eq!(txid, tx.compute_txid());
+
+    let mempool: GetRawMempool = client.get_raw_mempool().await.expect("getrawmempool");
+    assert!(mempool.0.contains(&txid.to_string()));
+    let json: GetRawTransaction =
+        client.get_raw_transaction(txid).await.expect("getrawtransaction");
+    assert_eq!(json.transaction().unwrap(), tx);
+}
+
+#[tokio::test]
+async fn client_async__wallet() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let client = async_client(&node);
+
+    let json: GetBalance = client.get_balance().await.expect("getbalance");
+    assert_eq!(json.balance().unwrap(), node.client.get_balance().unwrap().balance().unwrap());
+
+    let addresses = client.get_new_addresses(2, None, None).await.expect("getnewaddress");
+    assert_eq!(addresses.len(), 2);
+    let address = addresses[0].clone().address().unwrap().assume_checked();
+    let json: GetAddressInfo = client.get_address_info(&address).await.expect("getaddressinfo");
+    assert!(json.is_mine);
+}
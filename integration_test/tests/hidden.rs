@@ -261,6 +261,22 @@ fn hidden__reconsider_block() {
     );
 }
 
+#[test]
+fn hidden__set_mock_time() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let time = node.advance_time(3600);
+    node.mine_a_block();
+    let tip = node.client.best_block_hash().expect("bestblockhash");
+    assert_eq!(u64::from(node.client.get_block(tip).expect("getblock").header.time), time);
+
+    // The next advance continues from the time of the block just mined.
+    assert_eq!(node.advance_time(3600), time + 3600);
+
+    let _: () = node.client.set_mock_time(0).expect("setmocktime");
+}
+
 #[test]
 #[cfg(not(feature = "v19_and_below"))]
 fn hidden__mock_scheduler() {
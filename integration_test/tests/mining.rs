@@ -36,6 +36,35 @@ fn mining__get_block_template__modelled() {
     model.unwrap();
 }
 
+#[test]
+fn mining__get_block_template__longpoll() {
+    // Requires connected nodes otherwise the RPC call errors.
+    let (node1, node2, node3) = integration_test::three_node_network();
+
+    // Use the nodes otherwise they get dropped.
+    node1.mine_a_block();
+    node2.mine_a_block();
+    node3.mine_a_block();
+
+    let options = match () {
+        #[cfg(feature = "v28_and_below")]
+        () => TemplateRequest { rules: vec![TemplateRules::Segwit] },
+        #[cfg(not(feature = "v28_and_below"))]
+        () => TemplateRequest { rules: vec![TemplateRules::Segwit], ..Default::default() },
+    };
+
+    let json: GetBlockTemplate =
+        node1.client.get_block_template(&options).expect("getblocktemplate");
+    let longpollid = json.long_poll_id.expect("longpollid");
+
+    let updated: GetBlockTemplate = std::thread::scope(|s| {
+        let poll = s.spawn(|| node1.client.get_block_template_longpoll(&options, &longpollid));
+        node1.mine_a_block();
+        poll.join().unwrap().expect("getblocktemplate longpoll")
+    });
+    assert_ne!(updated.previous_block_hash, json.previous_block_hash);
+}
+
 #[test]
 fn mining__get_mining_info__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
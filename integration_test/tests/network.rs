@@ -85,6 +85,32 @@ fn network__get_network_info__modelled() {
     node.client.check_expected_server_version().expect("unexpected version");
 }
 
+#[test]
+fn network__get_network_info__detect() {
+    use bitcoind::client::client_sync::{Auth, VersionedClient};
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let auth = Auth::CookieFile(node.params.cookie_file.clone());
+
+    let client = VersionedClient::detect(&node.rpc_url(), auth).expect("detect");
+    let version = node.client.server_version().expect("server_version");
+    assert_eq!(client.major_version() as usize, version / 10_000);
+}
+
+#[test]
+fn network__get_network_info__detect_with() {
+    use bitcoind::client::client_sync::{v17, Auth, VersionedClient};
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    let auth = Auth::CookieFile(node.params.cookie_file.clone());
+
+    // The detected client keeps the options of the builder, here the wallet endpoint.
+    let builder = v17::Client::builder(&node.rpc_url()).auth(auth).wallet("default");
+    let client = VersionedClient::detect_with(builder).expect("detect_with");
+    let version = node.client.server_version().expect("server_version");
+    assert_eq!(client.major_version() as usize, version / 10_000);
+}
+
 #[test]
 #[cfg(not(feature = "v17"))]
 fn network__get_node_addresses() {
@@ -108,7 +134,7 @@ fn network__get_node_addresses() {
 }
 
 #[test]
-fn network__get_peer_info() {
+fn network__get_peer_info__modelled() {
     get_peer_info_one_node_network();
     get_peer_info_three_node_network();
 }
@@ -134,6 +160,19 @@ fn get_peer_info_three_node_network() {
     #[cfg(not(feature = "v30_and_below"))]
     let _ = (json.0[0].inv_to_send, json.0[0].last_inv_sequence);
 
+    let model: mtype::GetPeerInfo = json.into_model();
+    assert_eq!(model.inbound().len() + model.outbound().len(), model.0.len());
+    assert!(model.onion_peers().is_empty());
+    assert!(model.v2_transport_count() <= model.0.len());
+    #[cfg(not(feature = "v20_and_below"))]
+    {
+        let not_routable = mtype::NetworkType::NotPubliclyRoutable;
+        assert_eq!(model.by_network().get(&not_routable), Some(&model.0.len()));
+        // The test nodes connect with `-connect`, which makes manual connections.
+        use mtype::ConnectionType::{Inbound, Manual};
+        assert!(model.0.iter().all(|p| matches!(p.connection_type, Some(Inbound | Manual))));
+    }
+
     // FIXME: Fails if we use equal to 2 ???
     assert!(node1.peers_connected() >= 1);
     assert!(node2.peers_connected() >= 1);
@@ -180,3 +219,12 @@ fn network__set_network_active() {
         node.client.set_network_active(true).expect("setnetworkactive true");
     assert!(json.0);
 }
+
+#[test]
+fn network__set_network_active__notify() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    node.client.notify("setnetworkactive", &[false.into()]).expect("notify setnetworkactive");
+    let json: GetNetworkInfo = node.client.get_network_info().expect("getnetworkinfo");
+    assert!(!json.network_active);
+}
@@ -25,7 +25,29 @@ fn raw_transactions__analyze_psbt__modelled() {
     let psbt = create_a_psbt(&node);
     let json: AnalyzePsbt = node.client.analyze_psbt(&psbt).expect("analyzepsbt");
     let model: Result<mtype::AnalyzePsbt, AnalyzePsbtError> = json.into_model();
-    model.unwrap();
+    let analysis = model.unwrap();
+
+    // A newly created PSBT has no UTXO information.
+    assert_eq!(analysis.next, mtype::PsbtRole::Updater);
+    assert!(!analysis.inputs[0].has_utxo);
+    assert!(!analysis.inputs[0].is_final);
+    assert!(analysis.fee.is_none());
+
+    let signed = node
+        .client
+        .wallet_process_psbt(&psbt)
+        .expect("walletprocesspsbt")
+        .into_model()
+        .expect("WalletProcessPsbt into model")
+        .psbt;
+    let json: AnalyzePsbt = node.client.analyze_psbt(&signed).expect("analyzepsbt");
+    let model: Result<mtype::AnalyzePsbt, AnalyzePsbtError> = json.into_model();
+    let analysis = model.unwrap();
+
+    assert_eq!(analysis.next, mtype::PsbtRole::Extractor);
+    assert!(analysis.inputs.iter().all(|input| input.has_utxo && input.is_final));
+    assert!(analysis.estimated_vsize.is_some());
+    assert!(analysis.fee.is_some());
 }
 
 #[test]
@@ -81,6 +103,38 @@ fn raw_transactions__combine_psbt__modelled() {
     assert_eq!(combined.0, psbt)
 }
 
+#[test]
+fn raw_transactions__combine_psbt_partial_signatures__modelled() {
+    use bitcoin::secp256k1::{Message, Secp256k1, SecretKey};
+    use bitcoin::{ecdsa, EcdsaSighashType, PublicKey};
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let psbt = create_a_psbt(&node);
+
+    // Two signers each add their signature to their own copy, the node does not check them.
+    let secp = Secp256k1::new();
+    let sign = |secret: [u8; 32]| {
+        let sk = SecretKey::from_slice(&secret).unwrap();
+        let sig = secp.sign_ecdsa(&Message::from_digest([1; 32]), &sk);
+        let sig = ecdsa::Signature { signature: sig, sighash_type: EcdsaSighashType::All };
+        let mut psbt = psbt.clone();
+        psbt.inputs[0].partial_sigs.insert(PublicKey::new(sk.public_key(&secp)), sig);
+        psbt
+    };
+    let (psbt_a, psbt_b) = (sign([1; 32]), sign([2; 32]));
+
+    let json: CombinePsbt =
+        node.client.combine_psbt(&[psbt_a.clone(), psbt_b.clone()]).expect("combinepsbt");
+    let model: Result<mtype::CombinePsbt, psbt::PsbtParseError> = json.into_model();
+    let combined = model.unwrap().0;
+
+    let mut want = psbt_a.inputs[0].partial_sigs.clone();
+    want.extend(psbt_b.inputs[0].partial_sigs.clone());
+    assert_eq!(combined.inputs[0].partial_sigs, want);
+    assert_eq!(combined.unsigned_tx, psbt.unsigned_tx);
+}
+
 #[test]
 fn raw_transactions__combine_raw_transaction__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &["-txindex"]);
@@ -102,6 +156,30 @@ fn raw_transactions__combine_raw_transaction__modelled() {
     assert_eq!(combined.0, tx)
 }
 
+#[test]
+fn raw_transactions__combine_raw_transaction_hex__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let unsigned = create_a_raw_transaction(&node);
+    let signed = node
+        .client
+        .sign_raw_transaction_with_wallet(&unsigned)
+        .expect("signrawtransactionwithwallet")
+        .into_model()
+        .expect("SignRawTransactionWithWallet into model")
+        .tx;
+
+    let hexes = [encode::serialize_hex(&unsigned), encode::serialize_hex(&signed)];
+    let hexes = hexes.iter().map(String::as_str).collect::<Vec<_>>();
+    let json: CombineRawTransaction =
+        node.client.combine_raw_transaction_hex(&hexes).expect("combinerawtransaction");
+    let model: Result<mtype::CombineRawTransaction, encode::FromHexError> = json.into_model();
+
+    // The signatures of the signed transaction are added to the unsigned one.
+    assert_eq!(model.unwrap().0, signed);
+}
+
 #[test]
 fn raw_transactions__convert_to_psbt__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &["-txindex"]);
@@ -114,6 +192,31 @@ fn raw_transactions__convert_to_psbt__modelled() {
     model.unwrap();
 }
 
+#[test]
+fn raw_transactions__convert_to_psbt_with_options__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let unsigned = create_a_raw_transaction(&node);
+    let signed = node
+        .client
+        .sign_raw_transaction_with_wallet(&unsigned)
+        .expect("signrawtransactionwithwallet")
+        .into_model()
+        .expect("SignRawTransactionWithWallet into model")
+        .tx;
+
+    // A signed transaction is only converted if the signatures may be dropped.
+    assert!(node.client.convert_to_psbt_with_options(&signed, Some(false), None).is_err());
+
+    let json: ConvertToPsbt = node
+        .client
+        .convert_to_psbt_with_options(&signed, Some(true), Some(true))
+        .expect("converttopsbt");
+    let model: Result<mtype::ConvertToPsbt, psbt::PsbtParseError> = json.into_model();
+    assert_eq!(model.unwrap().0.unsigned_tx, unsigned);
+}
+
 #[test]
 fn raw_transactions__create_psbt__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &["-txindex"]);
@@ -121,6 +224,29 @@ fn raw_transactions__create_psbt__modelled() {
     let _ = create_a_psbt(&node);
 }
 
+#[test]
+fn raw_transactions__create_psbt_with_options__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (_addr, _tx, txid, tx_out, vout) = create_utxo(&node);
+
+    let inputs = vec![Input { txid, vout, sequence: None }];
+    let address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(address, tx_out.value - Amount::from_sat(1000))];
+    let lock_time = absolute::LockTime::from_height(100).unwrap();
+
+    let json: CreatePsbt = node
+        .client
+        .create_psbt_with_options(&inputs, &outputs, Some(lock_time), Some(true))
+        .expect("createpsbt");
+    let model: Result<mtype::CreatePsbt, psbt::PsbtParseError> = json.into_model();
+    let psbt = model.unwrap().0;
+
+    assert_eq!(psbt.unsigned_tx.lock_time, lock_time);
+    assert!(psbt.unsigned_tx.input[0].sequence.is_rbf());
+    assert_eq!(psbt.inputs.len(), 1);
+}
+
 #[test]
 fn raw_transactions__create_raw_transaction__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &["-txindex"]);
@@ -128,6 +254,30 @@ fn raw_transactions__create_raw_transaction__modelled() {
     create_sign_send(&node);
 }
 
+#[test]
+fn raw_transactions__create_raw_transaction_with_options__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (_addr, _tx, txid, tx_out, vout) = create_utxo(&node);
+
+    let inputs = vec![Input { txid, vout, sequence: None }];
+    let address = node.client.new_address().expect("failed to create new address");
+    let outputs =
+        vec![Output::new(address, tx_out.value - Amount::from_sat(1000)), Output::data(b"corepc")];
+    let lock_time = absolute::LockTime::from_height(100).unwrap();
+
+    let json: CreateRawTransaction = node
+        .client
+        .create_raw_transaction_with_options(&inputs, &outputs, Some(lock_time), Some(true))
+        .expect("createrawtransaction");
+    let tx = json.transaction().unwrap();
+
+    assert_eq!(tx.lock_time, lock_time);
+    assert!(tx.input[0].sequence.is_rbf());
+    assert!(tx.output[1].script_pubkey.is_op_return());
+    assert_eq!(tx.output[1].value, Amount::ZERO);
+}
+
 // Tests PSBT decoding across Bitcoin Core versions.
 // Version-specific assertions are gated below.
 #[test]
@@ -172,7 +322,12 @@ fn raw_transactions__decode_psbt__modelled() {
 
     // v23+: dedicated xpub field; earlier versions store in `unknown`.
     #[cfg(feature = "v22_and_below")]
-    assert_eq!(decoded.psbt.unknown.len(), 1);
+    {
+        assert_eq!(decoded.psbt.unknown.len(), 1);
+        let key = decoded.psbt.unknown.keys().next().unwrap();
+        assert_eq!(key.type_value, 0x01); // PSBT_GLOBAL_XPUB
+        assert_eq!(key.key.len(), 78); // The serialized xpub.
+    }
 
     #[cfg(not(feature = "v22_and_below"))]
     assert_eq!(decoded.psbt.xpub.len(), 1);
@@ -181,6 +336,44 @@ fn raw_transactions__decode_psbt__modelled() {
     assert_eq!(decoded.psbt.inputs[0].tap_internal_key, Some(keys.x_only_public_key));
 }
 
+#[test]
+fn raw_transactions__decode_psbt_input_fields__modelled() {
+    use bitcoin::secp256k1::{Message, Secp256k1};
+    use bitcoin::{ecdsa, EcdsaSighashType, PublicKey};
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let mut psbt = create_a_psbt(&node);
+
+    // The node does not check the signature when decoding.
+    let secp = Secp256k1::new();
+    let keys = test_keys();
+    let pubkey = PublicKey::new(keys.xprv.private_key.public_key(&secp));
+    let sig = secp.sign_ecdsa(&Message::from_digest([1; 32]), &keys.xprv.private_key);
+    let sig = ecdsa::Signature { signature: sig, sighash_type: EcdsaSighashType::All };
+    let path: DerivationPath = "m/84'/1'/0'/0/0".parse().expect("valid derivation path");
+
+    let input = &mut psbt.inputs[0];
+    input.partial_sigs.insert(pubkey, sig);
+    input.bip32_derivation.insert(pubkey.inner, (keys.fingerprint, path.clone()));
+    let unknown = psbt::raw::Key { type_value: 0x30, key: vec![1, 2, 3] };
+    input.unknown.insert(unknown.clone(), vec![4, 5, 6]);
+    psbt.outputs[0].bip32_derivation.insert(pubkey.inner, (keys.fingerprint, path));
+    psbt.outputs[0].unknown.insert(unknown, vec![7, 8]);
+
+    let json: DecodePsbt = node.client.decode_psbt(&psbt.to_string()).expect("decodepsbt");
+    let model: Result<mtype::DecodePsbt, DecodePsbtError> = json.into_model();
+    let decoded = model.unwrap().psbt;
+
+    let (got, want) = (&decoded.inputs[0], &psbt.inputs[0]);
+    assert_eq!(got.partial_sigs, want.partial_sigs);
+    assert_eq!(got.bip32_derivation, want.bip32_derivation);
+    assert_eq!(got.unknown, want.unknown);
+    let (got, want) = (&decoded.outputs[0], &psbt.outputs[0]);
+    assert_eq!(got.bip32_derivation, want.bip32_derivation);
+    assert_eq!(got.unknown, want.unknown);
+}
+
 #[test]
 fn raw_transactions__decode_raw_transaction__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &["-txindex"]);
@@ -193,7 +386,12 @@ fn raw_transactions__decode_raw_transaction__modelled() {
     let json: DecodeRawTransaction =
         node.client.decode_raw_transaction(&tx).expect("decoderawtransaction");
     let model: Result<mtype::DecodeRawTransaction, RawTransactionError> = json.into_model();
-    model.unwrap();
+    assert_eq!(model.unwrap().0, tx);
+
+    let hex = encode::serialize_hex(&tx);
+    let json: DecodeRawTransaction =
+        node.client.decode_raw_transaction_hex(&hex, Some(true)).expect("decoderawtransaction");
+    assert_eq!(json.transaction().unwrap(), tx);
 }
 
 #[test]
@@ -210,7 +408,9 @@ fn raw_transactions__decode_script__modelled() {
 
         let json: DecodeScript = node.client.decode_script(&hex).expect("decodescript");
         let model: Result<mtype::DecodeScript, DecodeScriptError> = json.into_model();
-        model.unwrap();
+        let segwit = model.unwrap().segwit.expect("segwit");
+        assert!(segwit.script_pubkey.is_witness_program());
+        assert!(segwit.p2sh_segwit.is_some());
     }
 }
 
@@ -246,6 +446,67 @@ fn arbitrary_multisig_script() -> ScriptBuf {
         .into_script()
 }
 
+#[test]
+#[cfg(not(feature = "v25_and_below"))] // descriptorprocesspsbt was added in v26.
+fn raw_transactions__descriptor_process_psbt__modelled() {
+    use bitcoin::secp256k1::Secp256k1;
+    use bitcoin::{Address, CompressedPublicKey, Network};
+    use bitcoind::{ScanObject, SighashType};
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // Pay to a key derived from the test xprv, the wallet knows nothing about it.
+    let secp = Secp256k1::new();
+    let keys = test_keys();
+    let path: DerivationPath = "0/0".parse().unwrap();
+    let pubkey = keys.xpub.derive_pub(&secp, &path).unwrap().public_key;
+    let address = Address::p2wpkh(&CompressedPublicKey(pubkey), Network::Regtest);
+    let amount = Amount::from_sat(1_000_000);
+    let txid =
+        node.client.send_to_address(&address, amount).expect("sendtoaddress").txid().unwrap();
+    let funding =
+        node.client.get_raw_transaction(txid).expect("getrawtransaction").transaction().unwrap();
+    let vout = funding
+        .output
+        .iter()
+        .position(|out| out.script_pubkey == address.script_pubkey())
+        .expect("output to key") as u64;
+
+    let inputs = vec![Input { txid, vout, sequence: None }];
+    let spend_address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(spend_address, amount - Amount::from_sat(1000))];
+    let psbt = node.client.create_psbt(&inputs, &outputs).expect("createpsbt").psbt().unwrap();
+    let descriptors =
+        [ScanObject::Ranged { desc: format!("wpkh({}/0/*)", keys.xprv), range: (0, 5) }];
+
+    // Sign without finalizing, the signature is left in the PSBT.
+    let json: DescriptorProcessPsbt = node
+        .client
+        .descriptor_process_psbt_with_options(
+            &psbt,
+            &descriptors,
+            Some(SighashType::All),
+            Some(true),
+            Some(false),
+        )
+        .expect("descriptorprocesspsbt");
+    let model: Result<mtype::DescriptorProcessPsbt, DescriptorProcessPsbtError> = json.into_model();
+    let processed = model.unwrap();
+    assert!(processed.tx.is_none());
+    assert!(processed.psbt.inputs[0].partial_sigs.contains_key(&bitcoin::PublicKey::new(pubkey)));
+    assert!(processed.psbt.inputs[0].bip32_derivation.contains_key(&pubkey));
+
+    let json: DescriptorProcessPsbt =
+        node.client.descriptor_process_psbt(&psbt, &descriptors).expect("descriptorprocesspsbt");
+    let model: Result<mtype::DescriptorProcessPsbt, DescriptorProcessPsbtError> = json.into_model();
+    let processed = model.unwrap();
+    assert!(processed.complete);
+
+    let tx = processed.tx.expect("complete transaction");
+    node.client.send_raw_transaction(&tx).expect("sendrawtransaction");
+}
+
 #[test]
 fn raw_transactions__finalize_psbt__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -262,6 +523,41 @@ fn raw_transactions__finalize_psbt__modelled() {
     assert_eq!(finalized.psbt, Some(psbt));
 }
 
+#[test]
+fn raw_transactions__finalize_psbt_with_options__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let psbt = create_a_psbt(&node);
+    let finalize = |psbt: &bitcoin::Psbt, extract| {
+        let json: FinalizePsbt =
+            node.client.finalize_psbt_with_options(psbt, extract).expect("finalizepsbt");
+        let model: Result<mtype::FinalizePsbt, FinalizePsbtError> = json.into_model();
+        model.unwrap().into_finalized().expect("psbt or transaction")
+    };
+
+    assert_eq!(finalize(&psbt, None), mtype::FinalizedPsbt::Incomplete(psbt.clone()));
+
+    let signed = node
+        .client
+        .wallet_process_psbt(&psbt)
+        .expect("walletprocesspsbt")
+        .into_model()
+        .expect("WalletProcessPsbt into model")
+        .psbt;
+
+    match finalize(&signed, Some(false)) {
+        mtype::FinalizedPsbt::Complete(psbt) => assert_eq!(psbt.unsigned_tx, signed.unsigned_tx),
+        other => panic!("expected a complete PSBT, got {:?}", other),
+    }
+    let tx = match finalize(&signed, None) {
+        mtype::FinalizedPsbt::Extracted(tx) => tx,
+        other => panic!("expected a transaction, got {:?}", other),
+    };
+    assert_eq!(tx.compute_txid(), signed.unsigned_tx.compute_txid());
+    node.client.send_raw_transaction(&tx).expect("sendrawtransaction");
+}
+
 #[test]
 fn raw_transactions__fund_raw_transaction__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -269,6 +565,43 @@ fn raw_transactions__fund_raw_transaction__modelled() {
     create_fund_sign_send(&node);
 }
 
+#[test]
+fn raw_transactions__fund_raw_transaction_with_options__modelled() {
+    use bitcoind::FundRawTransactionOptions;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    let (_addr, _tx, txid, _tx_out, vout) = create_utxo(&node);
+
+    let inputs = vec![Input { txid, vout, sequence: None }];
+    let spend_address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(spend_address, Amount::from_int_btc(50))];
+    let tx = node
+        .client
+        .create_raw_transaction(&inputs, &outputs)
+        .expect("createrawtransaction")
+        .transaction()
+        .unwrap();
+
+    let change_address = node.client.new_address().expect("failed to create new address");
+    let fee_rate = bitcoin::FeeRate::from_sat_per_vb(10).unwrap();
+    let options = FundRawTransactionOptions {
+        change_address: Some(change_address.clone()),
+        change_position: Some(0),
+        lock_unspents: Some(true),
+        fee_rate: Some(fee_rate),
+        ..Default::default()
+    };
+    let json: FundRawTransaction =
+        node.client.fund_raw_transaction_with_options(&tx, &options).expect("fundrawtransaction");
+    let model: Result<mtype::FundRawTransaction, FundRawTransactionError> = json.into_model();
+    let funded = model.unwrap();
+
+    assert_eq!(funded.change_position, 0);
+    assert_eq!(funded.tx.output[0].script_pubkey, change_address.script_pubkey());
+    assert!(funded.fee >= fee_rate.fee_vb(funded.tx.vsize() as u64).unwrap());
+}
+
 #[test]
 fn raw_transactions__send_raw_transaction__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -276,6 +609,53 @@ fn raw_transactions__send_raw_transaction__modelled() {
     create_sign_send(&node); // Calls `sendrawtransaction`.
 }
 
+#[test]
+#[cfg(not(feature = "v18_and_below"))] // maxfeerate was added in v0.19.
+fn raw_transactions__send_raw_transaction_with_options__modelled() {
+    use bitcoin::FeeRate;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // A fee of 100,000 sats is several hundred sat/vB.
+    let tx = create_signed_spend(&node, Amount::from_sat(100_000), None);
+    let max_fee_rate = FeeRate::from_sat_per_vb(100).unwrap();
+
+    #[cfg(feature = "v24_and_below")]
+    let send = |max_fee_rate| node.client.send_raw_transaction_with_options(&tx, max_fee_rate);
+    #[cfg(not(feature = "v24_and_below"))]
+    let send =
+        |max_fee_rate| node.client.send_raw_transaction_with_options(&tx, max_fee_rate, None);
+
+    assert!(send(Some(max_fee_rate)).is_err());
+    // A limit below 1 sat/vB is still a limit, not rounded down to 0 which means no limit.
+    assert!(send(Some(FeeRate::from_sat_per_kwu(100))).is_err());
+
+    let json: SendRawTransaction = send(None).expect("sendrawtransaction");
+    let model: Result<mtype::SendRawTransaction, hex::HexToArrayError> = json.into_model();
+    assert_eq!(model.unwrap().0, tx.compute_txid());
+}
+
+#[test]
+#[cfg(not(feature = "v24_and_below"))] // maxburnamount was added in v25.
+fn raw_transactions__send_raw_transaction_max_burn_amount__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let burn =
+        TxOut { value: Amount::from_sat(1000), script_pubkey: ScriptBuf::new_op_return([0xab; 4]) };
+    let tx = create_signed_spend(&node, Amount::from_sat(1000), Some(burn));
+
+    // By default sending value to an unspendable output is rejected.
+    assert!(node.client.send_raw_transaction_with_options(&tx, None, None).is_err());
+
+    let json: SendRawTransaction = node
+        .client
+        .send_raw_transaction_with_options(&tx, None, Some(Amount::from_sat(1000)))
+        .expect("sendrawtransaction");
+    assert_eq!(json.txid().unwrap(), tx.compute_txid());
+}
+
 #[test]
 fn raw_transactions__get_raw_transaction__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &["-txindex"]);
@@ -308,6 +688,142 @@ fn raw_transactions__get_raw_transaction__modelled() {
         .unwrap();
 }
 
+#[test]
+fn raw_transactions__get_raw_transaction_in_block__modelled() {
+    // Without `-txindex` a confirmed transaction is only found by giving the block.
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let (_, tx) = node.create_mined_transaction();
+    let txid = tx.compute_txid();
+    let block_hash = node.client.best_block_hash().expect("best_block_hash");
+    assert!(node.client.get_raw_transaction_verbose(txid).is_err());
+
+    let json: GetRawTransaction =
+        node.client.get_raw_transaction_in_block(txid, block_hash).expect("getrawtransaction");
+    let model: Result<mtype::GetRawTransaction, encode::FromHexError> = json.into_model();
+    assert_eq!(model.unwrap().0, tx);
+
+    let json: GetRawTransactionVerbose = node
+        .client
+        .get_raw_transaction_verbose_in_block(txid, block_hash)
+        .expect("getrawtransaction verbose");
+    let model: Result<mtype::GetRawTransactionVerbose, GetRawTransactionVerboseError> =
+        json.into_model();
+    let verbose = model.unwrap();
+
+    assert_eq!(verbose.transaction, tx);
+    assert_eq!(verbose.in_active_chain, Some(true));
+    assert_eq!(verbose.block_hash, Some(block_hash));
+    assert_eq!(verbose.confirmations, Some(1));
+    assert!(verbose.block_time.is_some());
+}
+
+#[test]
+#[cfg(not(feature = "v24_and_below"))]
+fn raw_transactions__get_raw_transaction_verbose_two__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &["-txindex"]);
+    node.fund_wallet();
+
+    // A mempool transaction has the prevouts and fee.
+    let (_, txid) = node.create_mempool_transaction();
+    let json: GetRawTransactionVerboseTwo =
+        node.client.get_raw_transaction_verbose_two(txid).expect("getrawtransaction verbosity 2");
+    let model: Result<mtype::GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError> =
+        json.into_model();
+    let mempool = model.unwrap();
+
+    assert_eq!(mempool.transaction.transaction.compute_txid(), txid);
+    assert_eq!(mempool.transaction.block_hash, None);
+    assert_eq!(mempool.prevouts.len(), mempool.transaction.transaction.input.len());
+    let spent: Amount = mempool.prevouts.iter().map(|p| p.as_ref().expect("prevout").value).sum();
+    let sent: Amount = mempool.transaction.transaction.output.iter().map(|o| o.value).sum();
+    assert_eq!(mempool.fee, Some(spent - sent));
+
+    // So does a mined transaction, looked up with and without the block hash.
+    node.mine_a_block();
+    let block_hash = node.client.best_block_hash().expect("best_block_hash");
+    let json: GetRawTransactionVerboseTwo = node
+        .client
+        .get_raw_transaction_verbose_two_in_block(txid, block_hash)
+        .expect("getrawtransaction verbosity 2");
+    let mined = json.into_model().unwrap();
+
+    assert_eq!(mined.transaction.block_hash, Some(block_hash));
+    assert_eq!(mined.transaction.in_active_chain, Some(true));
+    assert_eq!(mined.prevouts, mempool.prevouts);
+    assert_eq!(mined.fee, mempool.fee);
+
+    let json: GetRawTransactionVerboseTwo =
+        node.client.get_raw_transaction_verbose_two(txid).expect("getrawtransaction verbosity 2");
+    assert_eq!(json.into_model().unwrap().fee, mempool.fee);
+}
+
+#[test]
+fn raw_transactions__get_raw_transaction__build_replacement() {
+    use bitcoin::{FeeRate, Sequence};
+    use bitcoind::client::client_sync::{build_replacement, ReplacementError};
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // A replaceable transaction paying 1000 sat, with the change as the second output.
+    let (_addr, _tx, txid, tx_out, vout) = create_utxo(&node);
+    let spend = Amount::from_sat(100_000);
+    let fee = Amount::from_sat(1000);
+    let inputs = vec![Input { txid, vout, sequence: Some(Sequence::ENABLE_RBF_NO_LOCKTIME) }];
+    let outputs = vec![
+        Output::new(node.client.new_address().expect("new_address"), spend),
+        Output::new(node.client.new_address().expect("new_address"), tx_out.value - spend - fee),
+    ];
+    let tx = node
+        .client
+        .create_raw_transaction(&inputs, &outputs)
+        .expect("createrawtransaction")
+        .transaction()
+        .unwrap();
+    let original = node.client.sign_raw_transaction_with_wallet(&tx).unwrap().into_model().unwrap();
+    assert!(original.complete);
+    node.client.send_raw_transaction(&original.tx).expect("sendrawtransaction");
+
+    let fee_rate = FeeRate::from_sat_per_vb_unchecked(20);
+    let replacement = build_replacement(&node.client, original.tx.compute_txid(), 1, fee_rate)
+        .expect("build_replacement");
+
+    assert_eq!(replacement.replaced_fee, fee);
+    assert!(replacement.fee > fee);
+    assert_eq!(replacement.tx.input[0].previous_output, original.tx.input[0].previous_output);
+    assert!(replacement.tx.input[0].witness.is_empty());
+    assert_eq!(replacement.tx.output[0], original.tx.output[0]);
+    assert_eq!(
+        replacement.tx.output[1].value,
+        original.tx.output[1].value - (replacement.fee - fee)
+    );
+
+    let signed = node
+        .client
+        .sign_raw_transaction_with_wallet(&replacement.tx)
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert!(signed.complete);
+    node.client.send_raw_transaction(&signed.tx).expect("sendrawtransaction replacement");
+
+    let mempool = node.client.get_raw_mempool().expect("getrawmempool").into_model().unwrap();
+    assert!(mempool.0.contains(&signed.tx.compute_txid()));
+    assert!(!mempool.0.contains(&original.tx.compute_txid()));
+
+    let txid = signed.tx.compute_txid();
+    assert!(matches!(
+        build_replacement(&node.client, txid, 2, fee_rate),
+        Err(ReplacementError::NoChange(2))
+    ));
+    assert!(matches!(
+        build_replacement(&node.client, txid, 0, FeeRate::from_sat_per_vb_unchecked(10_000)),
+        Err(ReplacementError::InsufficientChange { .. })
+    ));
+}
+
 #[test]
 #[cfg(not(feature = "v17"))]
 fn raw_transactions__join_psbts__modelled() {
@@ -323,6 +839,7 @@ fn raw_transactions__join_psbts__modelled() {
     let join_psbts = model.unwrap();
 
     assert_eq!(join_psbts.0.inputs.len(), psbt1.inputs.len() + psbt2.inputs.len());
+    assert_eq!(join_psbts.0.outputs.len(), psbt1.outputs.len() + psbt2.outputs.len());
 }
 
 #[test]
@@ -341,6 +858,77 @@ fn raw_transactions__sign_raw_transaction_with_key__modelled() {
     create_sign_with_key_send(&node)
 }
 
+#[test]
+fn raw_transactions__sign_raw_transaction_with_key_with_options__modelled() {
+    use bitcoin::secp256k1::{Secp256k1, SecretKey};
+    use bitcoin::{Address, CompressedPublicKey, Network, PrivateKey};
+    use bitcoind::{PrevTx, SighashType};
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // A key the wallet does not know, so the node can only sign with the key and `prevtxs`.
+    let secp = Secp256k1::new();
+    let key = PrivateKey::new(test_keys().xprv.private_key, Network::Regtest);
+    let pubkey = CompressedPublicKey::from_private_key(&secp, &key).unwrap();
+    let address = Address::p2wpkh(&pubkey, Network::Regtest);
+    let amount = Amount::from_sat(1_000_000);
+    let txid =
+        node.client.send_to_address(&address, amount).expect("sendtoaddress").txid().unwrap();
+    let funding =
+        node.client.get_raw_transaction(txid).expect("getrawtransaction").transaction().unwrap();
+    let vout = funding
+        .output
+        .iter()
+        .position(|out| out.script_pubkey == address.script_pubkey())
+        .expect("output to key") as u32;
+
+    let inputs = vec![Input { txid, vout: vout.into(), sequence: None }];
+    let spend_address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(spend_address, amount - Amount::from_sat(1000))];
+    let tx = node
+        .client
+        .create_raw_transaction(&inputs, &outputs)
+        .expect("createrawtransaction")
+        .transaction()
+        .unwrap();
+
+    let prevtxs = [PrevTx {
+        txid,
+        vout,
+        script_pubkey: address.script_pubkey(),
+        redeem_script: None,
+        witness_script: None,
+        amount: Some(amount),
+    }];
+
+    // Signing with some other key leaves the input unsigned and reports it.
+    let other = PrivateKey::new(SecretKey::from_slice(&[2; 32]).unwrap(), Network::Regtest);
+    let json: SignRawTransactionWithKey = node
+        .client
+        .sign_raw_transaction_with_key_with_options(&tx, &[other], &prevtxs, None)
+        .expect("signrawtransactionwithkey");
+    let model: Result<mtype::SignRawTransactionWithKey, SignRawTransactionError> =
+        json.into_model();
+    let unsigned = model.unwrap();
+    assert!(!unsigned.complete);
+    assert_eq!(unsigned.errors.len(), 1);
+    assert_eq!(unsigned.errors[0].txid, txid);
+    assert_eq!(unsigned.errors[0].vout, u64::from(vout));
+
+    let json: SignRawTransactionWithKey = node
+        .client
+        .sign_raw_transaction_with_key_with_options(&tx, &[key], &prevtxs, Some(SighashType::All))
+        .expect("signrawtransactionwithkey");
+    let model: Result<mtype::SignRawTransactionWithKey, SignRawTransactionError> =
+        json.into_model();
+    let signed = model.unwrap();
+    assert!(signed.complete);
+    assert!(signed.errors.is_empty());
+
+    node.client.send_raw_transaction(&signed.tx).expect("sendrawtransaction");
+}
+
 // FIXME: Doesn't work for v26 for some reason.
 #[test]
 #[cfg(all(feature = "v27_and_below", not(feature = "v26_and_below")))]
@@ -393,6 +981,80 @@ fn raw_transactions__submit_package__modelled() {
     assert!(submit_package.replaced_transactions.is_empty());
 }
 
+#[test]
+#[cfg(not(feature = "v27_and_below"))]
+fn raw_transactions__submit_package_child_with_parent__modelled() {
+    use bitcoin::secp256k1::Secp256k1;
+    use bitcoin::{Address, CompressedPublicKey, Network, PrivateKey};
+    use bitcoind::PrevTx;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // The parent pays to a key outside the wallet so the child can be signed before the parent
+    // is in the mempool.
+    let secp = Secp256k1::new();
+    let key = PrivateKey::new(test_keys().xprv.private_key, Network::Regtest);
+    let pubkey = CompressedPublicKey::from_private_key(&secp, &key).unwrap();
+    let address = Address::p2wpkh(&pubkey, Network::Regtest);
+    let fee = Amount::from_sat(1000);
+
+    let mut parent = create_signed_spend(&node, fee, None);
+    parent.output[0].script_pubkey = address.script_pubkey();
+    let json: SignRawTransactionWithWallet = node
+        .client
+        .sign_raw_transaction_with_wallet(&parent)
+        .expect("signrawtransactionwithwallet");
+    let model: mtype::SignRawTransactionWithWallet =
+        json.into_model().expect("SignRawTransactionWithWallet into model");
+    let parent = model.tx;
+
+    let amount = parent.output[0].value;
+    let inputs = vec![Input { txid: parent.compute_txid(), vout: 0, sequence: None }];
+    let spend_address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(spend_address, amount - fee)];
+    let child = node
+        .client
+        .create_raw_transaction(&inputs, &outputs)
+        .expect("createrawtransaction")
+        .transaction()
+        .unwrap();
+    let prevtxs = [PrevTx {
+        txid: parent.compute_txid(),
+        vout: 0,
+        script_pubkey: address.script_pubkey(),
+        redeem_script: None,
+        witness_script: None,
+        amount: Some(amount),
+    }];
+    let json: SignRawTransactionWithKey = node
+        .client
+        .sign_raw_transaction_with_key_with_options(&child, &[key], &prevtxs, None)
+        .expect("signrawtransactionwithkey");
+    let model: Result<mtype::SignRawTransactionWithKey, SignRawTransactionError> =
+        json.into_model();
+    let child = model.unwrap().tx;
+
+    let json: SubmitPackage = node
+        .client
+        .submit_package(&[parent.clone(), child.clone()], None, None)
+        .expect("submitpackage");
+    let model: Result<mtype::SubmitPackage, SubmitPackageError> = json.into_model();
+    let submit_package = model.unwrap();
+
+    assert_eq!(submit_package.package_msg, "success");
+    assert_eq!(submit_package.tx_results.len(), 2);
+    for tx in [&parent, &child] {
+        let result = &submit_package.tx_results[&tx.compute_wtxid()];
+        assert_eq!(result.txid, tx.compute_txid());
+        assert!(result.error.is_none());
+        assert_eq!(result.fees.as_ref().expect("fees").base_fee, fee);
+    }
+
+    let mempool = node.client.get_raw_mempool().expect("getrawmempool").into_model().unwrap();
+    assert!(mempool.0.contains(&child.compute_txid()));
+}
+
 #[test]
 fn raw_transactions__test_mempool_accept__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -422,6 +1084,44 @@ fn raw_transactions__test_mempool_accept__modelled() {
     assert!(res.allowed, "fresh signed tx should be allowed");
 }
 
+#[test]
+#[cfg(not(feature = "v18_and_below"))] // maxfeerate was added in v0.19.
+fn raw_transactions__test_mempool_accept_with_options__modelled() {
+    use bitcoin::FeeRate;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // A fee of 100,000 sats is several hundred sat/vB.
+    let fee = Amount::from_sat(100_000);
+    let tx = create_signed_spend(&node, fee, None);
+    let test = |max_fee_rate| {
+        let json: TestMempoolAccept = node
+            .client
+            .test_mempool_accept_with_options(std::slice::from_ref(&tx), max_fee_rate)
+            .expect("testmempoolaccept");
+        #[cfg(feature = "v20_and_below")]
+        type TestMempoolAcceptError = hex::HexToArrayError;
+        let model: Result<mtype::TestMempoolAccept, TestMempoolAcceptError> = json.into_model();
+        let mut model = model.unwrap();
+        assert_eq!(model.results.len(), 1);
+        model.results.remove(0)
+    };
+
+    let rejected = test(Some(FeeRate::from_sat_per_vb(100).unwrap()));
+    assert!(!rejected.allowed);
+    assert!(rejected.reject_reason.is_some());
+    // A limit below 1 sat/vB is still a limit, not rounded down to 0 which means no limit.
+    assert!(!test(Some(FeeRate::from_sat_per_kwu(100))).allowed);
+
+    let accepted = test(None);
+    assert!(accepted.allowed);
+    assert_eq!(accepted.txid, tx.compute_txid());
+    assert_eq!(accepted.vsize, Some(tx.vsize() as u32));
+    #[cfg(not(feature = "v20_and_below"))] // The fees were added in v21.
+    assert_eq!(accepted.fees.expect("fees").base, fee);
+}
+
 #[test]
 #[cfg(not(feature = "v17"))]
 fn raw_transactions__utxo_update_psbt__modelled() {
@@ -436,6 +1136,50 @@ fn raw_transactions__utxo_update_psbt__modelled() {
     assert!(update_psbts.0.inputs.len() >= psbt.inputs.len());
 }
 
+#[test]
+#[cfg(not(feature = "v18_and_below"))] // The descriptors argument was added in v0.19.
+fn raw_transactions__utxo_update_psbt_with_descriptors__modelled() {
+    use bitcoin::secp256k1::Secp256k1;
+    use bitcoin::{Address, CompressedPublicKey, Network};
+    use bitcoind::ScanObject;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    // Pay to a key derived from the test xpub, the wallet knows nothing about it.
+    let secp = Secp256k1::new();
+    let keys = test_keys();
+    let path: DerivationPath = "0/0".parse().unwrap();
+    let pubkey = keys.xpub.derive_pub(&secp, &path).unwrap().public_key;
+    let address = Address::p2wpkh(&CompressedPublicKey(pubkey), Network::Regtest);
+    let amount = Amount::from_sat(1_000_000);
+    let txid =
+        node.client.send_to_address(&address, amount).expect("sendtoaddress").txid().unwrap();
+    let funding =
+        node.client.get_raw_transaction(txid).expect("getrawtransaction").transaction().unwrap();
+    let vout = funding
+        .output
+        .iter()
+        .position(|out| out.script_pubkey == address.script_pubkey())
+        .expect("output to key") as u64;
+
+    let inputs = vec![Input { txid, vout, sequence: None }];
+    let spend_address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(spend_address, amount - Amount::from_sat(1000))];
+    let psbt = node.client.create_psbt(&inputs, &outputs).expect("createpsbt").psbt().unwrap();
+
+    let descriptor = ScanObject::Ranged { desc: format!("wpkh({}/0/*)", keys.xpub), range: (0, 5) };
+    let json: UtxoUpdatePsbt = node
+        .client
+        .utxo_update_psbt_with_descriptors(&psbt, &[descriptor])
+        .expect("utxoupdatepsbt");
+    let model: Result<mtype::UtxoUpdatePsbt, psbt::PsbtParseError> = json.into_model();
+    let updated = model.unwrap().0;
+
+    let derivation = updated.inputs[0].bip32_derivation.get(&pubkey).expect("key derivation");
+    assert_eq!(derivation, &(keys.fingerprint, path));
+}
+
 // Manipulates raw transactions.
 //
 // Calls the following RPC methods:
@@ -667,6 +1411,35 @@ fn create_utxo(
     (addr, tx, txid, tx_out, vout)
 }
 
+// Creates a wallet signed transaction, not broadcast, spending a million sat UTXO back to the
+// wallet with the given `fee`, and with the `extra` output if there is one.
+#[cfg(not(feature = "v18_and_below"))]
+fn create_signed_spend(node: &BitcoinD, fee: Amount, extra: Option<TxOut>) -> Transaction {
+    let (_addr, _tx, txid, tx_out, vout) = create_utxo(node);
+
+    let mut value = tx_out.value - fee;
+    if let Some(ref extra) = extra {
+        value -= extra.value;
+    }
+    let inputs = vec![Input { txid, vout, sequence: None }];
+    let address = node.client.new_address().expect("failed to create new address");
+    let outputs = vec![Output::new(address, value)];
+    let mut tx = node
+        .client
+        .create_raw_transaction(&inputs, &outputs)
+        .expect("createrawtransaction")
+        .transaction()
+        .unwrap();
+    tx.output.extend(extra);
+
+    let json: SignRawTransactionWithWallet =
+        node.client.sign_raw_transaction_with_wallet(&tx).expect("signrawtransactionwithwallet");
+    let model: mtype::SignRawTransactionWithWallet =
+        json.into_model().expect("SignRawTransactionWithWallet into model");
+    assert!(model.complete);
+    model.tx
+}
+
 // Creates a PSBT using client to do RPC call `create_psbt`.
 fn create_a_psbt(node: &BitcoinD) -> bitcoin::Psbt {
     let (_addr, _tx, txid, tx_out, vout) = create_utxo(node);
@@ -110,6 +110,15 @@ fn util__get_index_info() {
         txindex_info.best_block_height < u32::MAX,
         "best_block_height should be a valid block height"
     );
+
+    let txindex: GetIndexInfo =
+        node.client.get_index_info_by_name("txindex").expect("getindexinfo txindex");
+    assert_eq!(txindex.0.len(), 1);
+    assert!(txindex.0.contains_key("txindex"));
+
+    let disabled: GetIndexInfo =
+        node.client.get_index_info_by_name("coinstatsindex").expect("getindexinfo coinstatsindex");
+    assert!(disabled.0.is_empty());
 }
 
 #[test]
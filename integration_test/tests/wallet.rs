@@ -20,7 +20,7 @@ use bitcoind::vtype::*; // All the version specific types.
 use bitcoind::ImportDescriptorsRequest;
 use bitcoind::{
     mtype, AddressType, ImportMultiRequest, ImportMultiScriptPubKey, ImportMultiTimestamp,
-    WalletCreateFundedPsbtInput,
+    SighashType, WalletCreateFundedPsbtInput,
 };
 use integration_test::{BitcoinD, BitcoinDExt as _, Wallet};
 
@@ -79,11 +79,30 @@ fn wallet__add_multisig_address__modelled() {
 
     let json: AddMultisigAddress = node
         .client
-        .add_multisig_address_with_addresses(nrequired, vec![addr1, addr2])
+        .add_multisig_address_with_addresses(nrequired, vec![addr1.clone(), addr2.clone()])
         .expect("addmultisigaddress");
 
     let model: Result<mtype::AddMultisigAddress, AddMultisigAddressError> = json.into_model();
     model.unwrap();
+
+    let pubkey = node.client.get_address_info(&addr1).expect("getaddressinfo").pubkey.unwrap();
+    let addr2 = addr2.to_string();
+    let json: AddMultisigAddress = node
+        .client
+        .add_multisig_address(
+            nrequired,
+            &[pubkey.as_str(), addr2.as_str()],
+            Some("multisig"),
+            Some(AddressType::Bech32),
+        )
+        .expect("addmultisigaddress");
+    let model: Result<mtype::AddMultisigAddress, AddMultisigAddressError> = json.into_model();
+    let multisig = model.unwrap();
+    assert!(multisig.address.is_valid_for_network(Network::Regtest));
+
+    // The descriptor field was added in v0.20.
+    #[cfg(not(feature = "v19_and_below"))]
+    assert!(multisig.descriptor.unwrap().starts_with("wsh(multi(2,"));
 }
 
 #[test]
@@ -278,7 +297,21 @@ fn wallet__get_address_info__modelled() {
         node.client.get_address_info(&addr_bech32).expect("getaddressinfo bech32");
     let model: Result<mtype::GetAddressInfo, GetAddressInfoError> = json.into_model();
     let address_info = model.unwrap();
+    assert!(!address_info.is_taproot());
     assert_eq!(address_info.address.assume_checked(), addr_bech32);
+
+    // Test a Bech32m (taproot) address, descriptor wallets are the default from v23.
+    #[cfg(not(feature = "v22_and_below"))]
+    {
+        let addr_bech32m = node.client.new_address_with_type(AddressType::Bech32m).unwrap();
+        let json: GetAddressInfo =
+            node.client.get_address_info(&addr_bech32m).expect("getaddressinfo bech32m");
+        let model: Result<mtype::GetAddressInfo, GetAddressInfoError> = json.into_model();
+        let address_info = model.unwrap();
+        assert!(address_info.is_taproot());
+        assert!(address_info.has_taproot_parent_descriptor());
+        assert_eq!(address_info.address.assume_checked(), addr_bech32m);
+    }
 }
 
 #[test]
@@ -333,6 +366,24 @@ fn wallet__get_new_address__modelled() {
     let _ = node.client.new_address_with_type(AddressType::Bech32).unwrap();
 }
 
+#[test]
+fn wallet__get_new_addresses() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+
+    let addresses = node
+        .client
+        .get_new_addresses(5, Some("deposits"), Some(AddressType::Bech32))
+        .expect("getnewaddress batch");
+    assert_eq!(addresses.len(), 5);
+
+    let mut unique = addresses.iter().map(|a| a.0.clone()).collect::<Vec<_>>();
+    unique.sort();
+    unique.dedup();
+    assert_eq!(unique.len(), 5);
+
+    assert!(node.client.get_new_addresses(0, None, None).unwrap().is_empty());
+}
+
 #[test]
 fn wallet__get_raw_change_address__modelled() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -504,6 +555,40 @@ fn wallet__import_descriptors() {
         node.client.import_descriptors(&[request]).expect("importdescriptors");
     assert_eq!(result.0.len(), 1, "should have exactly one import result");
     assert!(result.0[0].success);
+
+    let model: mtype::ImportDescriptors = result.into_model();
+    assert!(model.0[0].success);
+    assert!(model.0[0].error.is_none());
+}
+
+#[test]
+#[cfg(not(feature = "v20_and_below"))]
+fn wallet__setup_watch_only() {
+    use bitcoind::client::client_sync::wallet::setup_watch_only;
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let address = node.client.new_address().expect("failed to get new address");
+    let _txid =
+        node.client.send_to_address(&address, Amount::from_sat(10_000)).expect("sendtoaddress");
+    node.mine_a_block();
+
+    let info =
+        node.client.get_descriptor_info(&format!("addr({})", address)).expect("getdescriptorinfo");
+    let descriptor = format!("addr({})#{}", address, info.checksum);
+
+    let mut last_progress = 0.0;
+    let watch_only = setup_watch_only(&node.client, "watch-only", &[&descriptor], 0, |p| {
+        last_progress = p;
+    })
+    .expect("setup_watch_only");
+    assert_eq!(last_progress, 1.0);
+
+    let json: ListUnspent = watch_only.list_unspent().expect("listunspent");
+    let model: mtype::ListUnspent = json.into_model().unwrap();
+    assert!(!model.0.is_empty());
+    assert!(model.0.iter().all(|utxo| !utxo.spendable));
 }
 
 #[test]
@@ -517,8 +602,10 @@ fn wallet__import_pruned_funds() {
     let raw_tx = node.client.get_raw_transaction(txid).expect("getrawtransaction");
     let tx_out_proof = node.client.get_tx_out_proof(&[txid]).expect("gettxoutproof");
 
-    let _: () =
-        node.client.import_pruned_funds(&raw_tx.0, &tx_out_proof).expect("importprunedfunds");
+    let _: () = node
+        .client
+        .import_pruned_funds(raw_tx.0.as_str(), &tx_out_proof)
+        .expect("importprunedfunds");
 }
 
 #[test]
@@ -810,7 +897,11 @@ fn wallet__list_unspent__modelled() {
 
     let json: ListUnspent = node.client.list_unspent().expect("listunspent");
     let model: Result<mtype::ListUnspent, ListUnspentItemError> = json.into_model();
-    model.unwrap();
+    let model = model.unwrap();
+
+    let spendable = model.spendable();
+    assert!(!spendable.is_empty());
+    assert_eq!(spendable[0], model.0[0].clone().into());
 }
 
 #[test]
@@ -842,6 +933,67 @@ fn wallet__list_wallets__modelled() {
     assert!(model.0.iter().any(|w| w == wallet_2));
 }
 
+#[test]
+fn wallet__get_wallet_info__for_wallet() {
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let wallet_1 = "test_wallet_1";
+    let wallet_2 = "test wallet 2";
+    node.client.create_wallet(wallet_1).expect("createwallet w1");
+    node.client.create_wallet(wallet_2).expect("createwallet w2");
+
+    for wallet in [wallet_1, wallet_2] {
+        let client = node.client.for_wallet(wallet).expect("for_wallet");
+        let json: GetWalletInfo = client.get_wallet_info().expect("getwalletinfo");
+        assert_eq!(json.wallet_name, wallet);
+    }
+}
+
+#[test]
+fn wallet__get_balance__section_trait() {
+    use bitcoind::client::client_sync::{Result, WalletRpc};
+
+    /// Generic over the client, works with the client for any version.
+    fn balance(client: &impl WalletRpc) -> Result<Amount> {
+        let _ = client.get_new_address()?;
+        Ok(client.get_balance()?.0)
+    }
+
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+    assert!(balance(&node.client).unwrap() > Amount::ZERO);
+}
+
+#[test]
+fn wallet__get_wallet_info__wallet_not_found() {
+    use bitcoind::client::client_sync::RpcErrorCode;
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+
+    let client = node.client.for_wallet("missing_wallet").expect("for_wallet");
+    let err = client.get_wallet_info().expect_err("getwalletinfo");
+    assert_eq!(err.rpc_code(), Some(RpcErrorCode::WalletNotFound));
+    assert!(err.is_wallet_not_found());
+}
+
+#[test]
+fn wallet__get_wallet_info__builder_wallet() {
+    use bitcoind::client::client_sync::Auth;
+
+    let node = BitcoinD::with_wallet(Wallet::None, &[]);
+    let wallet = "test_wallet";
+    node.client.create_wallet(wallet).expect("createwallet");
+    node.client.create_wallet("other_wallet").expect("createwallet other");
+
+    let client = bitcoind::Client::builder(&node.rpc_url())
+        .auth(Auth::CookieFile(node.params.cookie_file.clone()))
+        .wallet(wallet)
+        .build()
+        .unwrap();
+    let json: GetWalletInfo = client.get_wallet_info().expect("getwalletinfo");
+    assert_eq!(json.wallet_name, wallet);
+}
+
 #[test]
 fn wallet__load_wallet__modelled() { create_load_unload_wallet(); }
 
@@ -914,8 +1066,10 @@ fn wallet__remove_pruned_funds() {
     let raw_tx = node.client.get_raw_transaction(txid).expect("getrawtransaction");
     let tx_out_proof = node.client.get_tx_out_proof(&[txid]).expect("gettxoutproof");
 
-    let _: () =
-        node.client.import_pruned_funds(&raw_tx.0, &tx_out_proof).expect("importprunedfunds");
+    let _: () = node
+        .client
+        .import_pruned_funds(raw_tx.0.as_str(), &tx_out_proof)
+        .expect("importprunedfunds");
 
     let _: () = node.client.remove_pruned_funds(txid).expect("removeprunedfunds");
 }
@@ -1013,6 +1167,50 @@ fn wallet__send_to_address__modelled() {
     model.unwrap();
 }
 
+#[test]
+fn wallet__send_to_address__not_retried_after_timeout() {
+    use std::io::Read as _;
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use bitcoind::client::client_sync::RetryPolicy;
+
+    // A server that receives requests but never responds.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let server_received = Arc::clone(&received);
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            let received = Arc::clone(&server_received);
+            std::thread::spawn(move || {
+                let mut buf = [0; 1024];
+                while let Ok(n @ 1..) = stream.read(&mut buf) {
+                    received.lock().unwrap().extend_from_slice(&buf[..n]);
+                }
+            });
+        }
+    });
+
+    let policy = RetryPolicy { initial_backoff: Duration::from_millis(10), ..Default::default() };
+    let client = bitcoind::Client::builder(&url)
+        .timeout(Duration::from_millis(200))
+        .retry(policy)
+        .build()
+        .unwrap();
+    let address = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080"
+        .parse::<Address<_>>()
+        .unwrap()
+        .assume_checked();
+    assert!(client.send_to_address(&address, Amount::from_sat(10_000)).is_err());
+
+    // Give the server time to read any retried request.
+    std::thread::sleep(Duration::from_millis(200));
+    let received = String::from_utf8_lossy(&received.lock().unwrap()).into_owned();
+    assert_eq!(received.matches("sendtoaddress").count(), 1);
+}
+
 #[test]
 #[cfg(feature = "v30_and_below")]
 fn wallet__set_tx_fee() {
@@ -1088,7 +1286,7 @@ fn wallet__simulate_raw_transaction__modelled() {
     let raw_tx2 = node.client.get_raw_transaction(txid2).expect("getrawtransaction");
 
     // Simulate raw transaction with the 2 transactions
-    let rawtxs = vec![raw_tx1.0, raw_tx2.0];
+    let rawtxs = vec![raw_tx1.0.into_string(), raw_tx2.0.into_string()];
     let json: SimulateRawTransaction =
         node.client.simulate_raw_transaction(&rawtxs).expect("simulaterawtransaction");
 
@@ -1144,6 +1342,58 @@ fn wallet__wallet_process_psbt__modelled() {
     assert_eq!(processed.psbt.inputs.len(), funded_psbt_model.psbt.inputs.len());
 }
 
+#[test]
+#[cfg(not(feature = "v20_and_below"))]
+fn wallet__wallet_process_psbt__multisig() {
+    let multisig = integration_test::multisig_network(2, 3, Amount::from_btc(1.0).unwrap());
+    let (alice, bob) = (&multisig.wallets[0], &multisig.wallets[1]);
+
+    let addr = multisig.nodes[2].client.new_address().expect("newaddress");
+    let outputs = BTreeMap::from([(addr, Amount::from_sat(50_000))]);
+    let funded_psbt: WalletCreateFundedPsbt =
+        alice.wallet_create_funded_psbt(vec![], vec![outputs]).expect("walletcreatefundedpsbt");
+    let psbt = funded_psbt.into_model().unwrap().psbt;
+
+    let processed = alice.wallet_process_psbt(&psbt).expect("walletprocesspsbt");
+    let processed = processed.into_model().unwrap();
+    assert!(!processed.complete);
+
+    let processed = bob.wallet_process_psbt(&processed.psbt).expect("walletprocesspsbt");
+    let processed = processed.into_model().unwrap();
+    assert!(processed.complete);
+
+    let tx = processed.psbt.extract_tx_unchecked_fee_rate();
+    let _ = multisig.nodes[0].client.send_raw_transaction(&tx).expect("sendrawtransaction");
+}
+
+#[test]
+fn wallet__wallet_process_psbt_with_sighash__modelled() {
+    let node = BitcoinD::with_wallet(Wallet::Default, &[]);
+    node.fund_wallet();
+
+    let addr = node.client.new_address().expect("newaddress");
+    let outputs = BTreeMap::from([(addr, Amount::from_sat(50_000))]);
+    let funded_psbt: WalletCreateFundedPsbt = node
+        .client
+        .wallet_create_funded_psbt(vec![], vec![outputs])
+        .expect("walletcreatefundedpsbt");
+    let model: Result<mtype::WalletCreateFundedPsbt, WalletCreateFundedPsbtError> =
+        funded_psbt.into_model();
+    let funded_psbt_model = model.unwrap();
+
+    let json: WalletProcessPsbt = node
+        .client
+        .wallet_process_psbt_with_sighash(&funded_psbt_model.psbt, SighashType::AllPlusAnyoneCanPay)
+        .expect("walletprocesspsbt");
+    #[cfg(feature = "v25_and_below")]
+    type WalletProcessPsbtError = psbt::PsbtParseError;
+
+    let model: Result<mtype::WalletProcessPsbt, WalletProcessPsbtError> = json.into_model();
+    let processed = model.unwrap();
+
+    assert!(processed.complete);
+}
+
 #[test]
 fn wallet__wallet_lock() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
@@ -1165,6 +1415,72 @@ fn wallet__wallet_passphrase() {
     let _: () = node.client.wallet_passphrase("passphrase", timeout).expect("walletpassphrase");
 }
 
+#[test]
+fn wallet__wallet_passphrase__redacted_log() {
+    use std::sync::Mutex;
+
+    use bitcoind::client::client_sync::MockTransport;
+
+    /// Collects the debug messages logged by the client.
+    struct Capture(Mutex<Vec<String>>);
+
+    impl log::Log for Capture {
+        fn enabled(&self, _: &log::Metadata) -> bool { true }
+        fn log(&self, record: &log::Record) {
+            if record.target() == "corepc" {
+                self.0.lock().unwrap().push(record.args().to_string());
+            }
+        }
+        fn flush(&self) {}
+    }
+
+    static CAPTURE: Capture = Capture(Mutex::new(Vec::new()));
+    log::set_logger(&CAPTURE).expect("no other logger");
+    log::set_max_level(log::LevelFilter::Debug);
+
+    let mock = MockTransport::new();
+    mock.respond("walletpassphrase", bitcoind::serde_json::Value::Null);
+    let client = bitcoind::Client::with_transport(mock);
+    client.wallet_passphrase("hunter2", 60).expect("walletpassphrase");
+
+    let logs = CAPTURE.0.lock().unwrap();
+    let request = logs.iter().find(|msg| msg.contains("walletpassphrase")).expect("request logged");
+    assert!(request.contains("60"));
+    assert!(!request.contains("hunter2"));
+}
+
+#[test]
+fn wallet__wallet_passphrase__redacted_record() {
+    use bitcoind::client::client_sync::{MockTransport, RecordReplayTransport};
+    use bitcoind::serde_json::{json, Value};
+
+    let path =
+        std::env::temp_dir().join(format!("corepc-record-redacted-{}.jsonl", std::process::id()));
+    let wif = "cVt4o7BGAig1UXywgGSmARhxMdzP5qvQsxKkSsc1XEkw3tDTQFpy";
+
+    let mock = MockTransport::new();
+    mock.respond("walletpassphrase", Value::Null);
+    mock.respond("dumpprivkey", json!(wif));
+    let recorder = RecordReplayTransport::record(mock, &path).expect("record");
+    let client = bitcoind::Client::with_transport(recorder);
+    client.wallet_passphrase("hunter2", 60).expect("walletpassphrase");
+    let key: String = client.call("dumpprivkey", &[json!("address")]).expect("dumpprivkey");
+    assert_eq!(key, wif);
+
+    let recording = std::fs::read_to_string(&path).unwrap();
+    assert!(!recording.contains("hunter2"));
+    assert!(!recording.contains(wif));
+
+    // Calls passing a secret are matched by their redacted params.
+    let replay = RecordReplayTransport::replay(&path).expect("replay");
+    let client = bitcoind::Client::with_transport(replay);
+    client.wallet_passphrase("hunter2", 60).expect("walletpassphrase replayed");
+    let key: String = client.call("dumpprivkey", &[json!("address")]).expect("dumpprivkey");
+    assert_ne!(key, wif);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
 #[test]
 fn wallet__wallet_passphrase_change() {
     let node = BitcoinD::with_wallet(Wallet::Default, &[]);
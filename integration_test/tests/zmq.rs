@@ -5,6 +5,7 @@
 #![allow(non_snake_case)] // Test names intentionally use double underscore.
 #![allow(unused_imports)] // Because of feature gated tests.
 
+use bitcoind::mtype;
 use bitcoind::vtype::*;
 use integration_test::{BitcoinD, BitcoinDExt as _, Wallet}; // All the version specific types.
 
@@ -26,5 +27,104 @@ fn zmq__get_zmq_notifications__modelled() {
         let zmq_notification = &list[0];
         assert_eq!(zmq_notification.type_, "pubhashblock");
         assert_eq!(zmq_notification.address, "tcp://127.0.0.1:29000");
+
+        let model: mtype::GetZmqNotifications = list[0].clone().into_model();
+        assert_eq!(model.address, "tcp://127.0.0.1:29000");
+        assert!(model.hwm.is_some());
+    }
+}
+
+#[test]
+fn zmq__subscriber__blocks() {
+    use bitcoind::client::zmq::{Event, Subscriber, Topic};
+
+    let port = bitcoind::get_available_port().expect("available port");
+    let addr = format!("tcp://127.0.0.1:{}", port);
+    let node = BitcoinD::with_wallet(
+        Wallet::Default,
+        &[&format!("-zmqpubhashblock={}", addr), &format!("-zmqpubrawblock={}", addr)],
+    );
+
+    let mut subscriber =
+        Subscriber::connect(("127.0.0.1", port), &[Topic::HashBlock, Topic::RawBlock])
+            .expect("connect");
+    subscriber.set_read_timeout(Some(std::time::Duration::from_secs(10))).expect("timeout");
+    // Give the node time to process the subscriptions.
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    node.mine_a_block();
+    let best_block_hash = node.client.best_block_hash().expect("best_block_hash");
+
+    let mut received = (false, false);
+    for _ in 0..2 {
+        match subscriber.receive().expect("receive").event {
+            Event::HashBlock(hash) => {
+                assert_eq!(hash, best_block_hash);
+                received.0 = true;
+            }
+            Event::RawBlock(block) => {
+                assert_eq!(block.block_hash(), best_block_hash);
+                received.1 = true;
+            }
+            event => panic!("unexpected event: {:?}", event),
+        }
     }
+    assert_eq!(received, (true, true));
+}
+
+#[test]
+#[cfg(not(feature = "v20_and_below"))]
+fn zmq__subscriber__sequence() {
+    use bitcoind::client::zmq::{Event, SequenceEvent, Subscriber, Topic};
+
+    let port = bitcoind::get_available_port().expect("available port");
+    let node = BitcoinD::with_wallet(
+        Wallet::Default,
+        &[&format!("-zmqpubsequence=tcp://127.0.0.1:{}", port)],
+    );
+    node.fund_wallet();
+
+    let mut subscriber =
+        Subscriber::connect(("127.0.0.1", port), &[Topic::Sequence]).expect("connect");
+    subscriber.set_read_timeout(Some(std::time::Duration::from_secs(10))).expect("timeout");
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let (_, txid) = node.create_mempool_transaction();
+    match subscriber.receive().expect("receive").event {
+        Event::Sequence(SequenceEvent::TxAdded { txid: added, .. }) => assert_eq!(added, txid),
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
+
+#[test]
+#[cfg(not(feature = "v20_and_below"))]
+fn zmq__mempool_tracker() {
+    use bitcoind::client::client_sync::{AnyClient, Auth, MempoolEvent, MempoolTracker};
+
+    let port = bitcoind::get_available_port().expect("available port");
+    let node = BitcoinD::with_wallet(
+        Wallet::Default,
+        &[&format!("-zmqpubsequence=tcp://127.0.0.1:{}", port)],
+    );
+    node.fund_wallet();
+    let (_, before) = node.create_mempool_transaction();
+
+    let auth = Auth::CookieFile(node.params.cookie_file.clone());
+    let client = AnyClient::detect(&node.rpc_url(), auth).expect("detect");
+    let mut tracker = MempoolTracker::connect(&client, ("127.0.0.1", port)).expect("connect");
+    tracker.set_read_timeout(Some(std::time::Duration::from_secs(10))).expect("timeout");
+    assert!(tracker.mempool().contains(&before));
+    std::thread::sleep(std::time::Duration::from_millis(500));
+
+    let (_, added) = node.create_mempool_transaction();
+    assert_eq!(tracker.next_event().expect("event"), MempoolEvent::Added(added));
+    assert!(tracker.mempool().contains(&added));
+
+    // Both transactions leave the mempool when mined.
+    node.mine_a_block();
+    let mut removed =
+        vec![tracker.next_event().expect("event"), tracker.next_event().expect("event")];
+    removed.sort_by_key(|event| *event != MempoolEvent::Removed(before));
+    assert_eq!(removed, [MempoolEvent::Removed(before), MempoolEvent::Removed(added)]);
+    assert!(tracker.mempool().is_empty());
 }
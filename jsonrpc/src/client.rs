@@ -23,6 +23,13 @@ pub trait Transport: Send + Sync + 'static {
     fn send_request(&self, _: Request) -> Result<Response, Error>;
     /// Sends a batch of RPC requests over the transport.
     fn send_batch(&self, _: &[Request]) -> Result<Vec<Response>, Error>;
+    /// Sends an RPC notification, a JSON-RPC 2.0 request without an id, over the transport.
+    ///
+    /// The server does not respond to a notification. The default implementation sends it as a
+    /// request and ignores the response.
+    fn send_notification(&self, req: Request) -> Result<(), Error> {
+        self.send_request(req).map(|_| ())
+    }
     /// Formats the target of this transport. I.e. the URL/socket/...
     fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result;
 }
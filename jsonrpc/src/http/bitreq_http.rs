@@ -7,6 +7,7 @@
 use std::io::{self, Read, Write};
 #[cfg(jsonrpc_fuzz)]
 use std::sync::Mutex;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use std::{error, fmt};
 
@@ -18,6 +19,8 @@ use crate::{Request, Response};
 
 const DEFAULT_URL: &str = "http://localhost";
 const DEFAULT_PORT: u16 = 8332; // the default RPC port for bitcoind.
+/// Default maximum size of a response body, same as the `bitreq` default.
+const DEFAULT_MAX_RESPONSE_SIZE: usize = 1024 * 1024 * 1024;
 #[cfg(not(jsonrpc_fuzz))]
 const DEFAULT_TIMEOUT_SECONDS: u64 = 15;
 #[cfg(jsonrpc_fuzz)]
@@ -31,7 +34,19 @@ pub struct BitreqHttpTransport {
     /// Timeout only supports second granularity.
     timeout: Duration,
     /// The value of the `Authorization` HTTP header, i.e., a base64 encoding of 'user:password'.
-    basic_auth: Option<String>,
+    ///
+    /// Shared with all clones, see [`BitreqHttpTransport::set_basic_auth`].
+    basic_auth: Arc<RwLock<Option<String>>>,
+    /// Additional HTTP headers sent with each request.
+    headers: Vec<(String, String)>,
+    /// Maximum size of a response body in bytes.
+    max_response_size: usize,
+    /// DER encoded certificates to trust as roots, in addition to the defaults.
+    #[cfg(feature = "bitreq_https")]
+    root_certificates: Vec<Vec<u8>>,
+    /// Proxy to route requests through.
+    #[cfg(feature = "bitreq_proxy")]
+    proxy: Option<bitreq::Proxy>,
 }
 
 impl Default for BitreqHttpTransport {
@@ -39,7 +54,13 @@ impl Default for BitreqHttpTransport {
         BitreqHttpTransport {
             url: format!("{}:{}", DEFAULT_URL, DEFAULT_PORT),
             timeout: Duration::from_secs(DEFAULT_TIMEOUT_SECONDS),
-            basic_auth: None,
+            basic_auth: Arc::new(RwLock::new(None)),
+            headers: Vec::new(),
+            max_response_size: DEFAULT_MAX_RESPONSE_SIZE,
+            #[cfg(feature = "bitreq_https")]
+            root_certificates: Vec::new(),
+            #[cfg(feature = "bitreq_proxy")]
+            proxy: None,
         }
     }
 }
@@ -51,6 +72,29 @@ impl BitreqHttpTransport {
     /// Returns a builder for [`BitreqHttpTransport`].
     pub fn builder() -> Builder { Builder::new() }
 
+    /// Returns the timeout after which requests are aborted.
+    pub fn timeout(&self) -> Duration { self.timeout }
+
+    /// Returns a copy of this transport that aborts requests after `timeout` instead.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        BitreqHttpTransport { timeout, ..self.clone() }
+    }
+
+    /// Returns a copy of this transport that sends requests to `path` on the same server instead.
+    ///
+    /// `path` replaces any path in the URL, e.g., `/wallet/alice`.
+    pub fn with_url_path(&self, path: &str) -> Self {
+        let start = self.url.find("://").map_or(0, |i| i + 3);
+        let end = self.url[start..].find('/').map_or(self.url.len(), |i| start + i);
+        let url = format!("{}{}", &self.url[..end], path);
+        BitreqHttpTransport { url, ..self.clone() }
+    }
+
+    /// Replaces the authentication information, for this transport and all its clones.
+    pub fn set_basic_auth(&self, user: String, pass: Option<String>) {
+        *self.basic_auth.write().expect("poisoned lock") = Some(basic_auth_header(user, pass));
+    }
+
     /// Returns the timeout in whole seconds, rounding positive sub-second values up to one.
     fn timeout_secs(&self) -> u64 {
         let secs = self.timeout.as_secs();
@@ -65,22 +109,10 @@ impl BitreqHttpTransport {
     where
         R: for<'a> serde::de::Deserialize<'a>,
     {
-        let timeout_secs = self.timeout_secs();
-
-        let req = match &self.basic_auth {
-            Some(auth) => bitreq::Request::new(bitreq::Method::Post, &self.url)
-                .with_timeout(timeout_secs)
-                .with_header("Authorization", auth)
-                .with_json(&req)?,
-            None => bitreq::Request::new(bitreq::Method::Post, &self.url)
-                .with_timeout(timeout_secs)
-                .with_json(&req)?,
-        };
-
         // Send the request and parse the response. If the response is an error that does not
         // contain valid JSON in its body (for instance if the bitcoind HTTP server work queue
         // depth is exceeded), return the raw HTTP error so users can match against it.
-        let resp = req.send()?;
+        let resp = self.send(req)?;
         match resp.json() {
             Ok(json) => Ok(json),
             Err(bitreq_err) =>
@@ -94,6 +126,42 @@ impl BitreqHttpTransport {
                 },
         }
     }
+
+    /// Sends a notification, accepting either no response (HTTP 204) or any JSON response.
+    ///
+    /// Servers that do not support JSON-RPC 2.0 notifications respond as to a request.
+    fn notify(&self, req: impl serde::Serialize) -> Result<(), Error> {
+        let resp = self.send(req)?;
+        if resp.status_code == 204 || resp.json::<serde_json::Value>().is_ok() {
+            return Ok(());
+        }
+        Err(Error::Http(HttpError {
+            status_code: resp.status_code,
+            body: resp.as_str().unwrap_or("").to_string(),
+        }))
+    }
+
+    /// Sends `req` as the JSON body of a POST request.
+    fn send(&self, req: impl serde::Serialize) -> Result<bitreq::Response, Error> {
+        let mut http = bitreq::Request::new(bitreq::Method::Post, &self.url)
+            .with_timeout(self.timeout_secs())
+            .with_max_body_size(self.max_response_size);
+        if let Some(auth) = &*self.basic_auth.read().expect("poisoned lock") {
+            http = http.with_header("Authorization", auth);
+        }
+        for (name, value) in &self.headers {
+            http = http.with_header(name, value);
+        }
+        #[cfg(feature = "bitreq_https")]
+        for der in &self.root_certificates {
+            http = http.with_root_certificate(der.clone());
+        }
+        #[cfg(feature = "bitreq_proxy")]
+        if let Some(proxy) = &self.proxy {
+            http = http.with_proxy(proxy.clone());
+        }
+        Ok(http.with_json(&req)?.send()?)
+    }
 }
 
 impl Transport for BitreqHttpTransport {
@@ -105,6 +173,8 @@ impl Transport for BitreqHttpTransport {
         Ok(self.request(reqs)?)
     }
 
+    fn send_notification(&self, req: Request) -> Result<(), crate::Error> { Ok(self.notify(req)?) }
+
     fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result { write!(f, "{}", self.url) }
 }
 
@@ -133,12 +203,7 @@ impl Builder {
 
     /// Adds authentication information to the transport.
     pub fn basic_auth(mut self, user: String, pass: Option<String>) -> Self {
-        let mut s = user;
-        s.push(':');
-        if let Some(ref pass) = pass {
-            s.push_str(pass.as_ref());
-        }
-        self.tp.basic_auth = Some(format!("Basic {}", &BASE64.encode(s.as_bytes())));
+        self.tp.basic_auth = Arc::new(RwLock::new(Some(basic_auth_header(user, pass))));
         self
     }
 
@@ -159,7 +224,38 @@ impl Builder {
     /// let client = BitreqHttpTransport::builder().cookie_auth(cookie);
     /// ```
     pub fn cookie_auth<S: AsRef<str>>(mut self, cookie: S) -> Self {
-        self.tp.basic_auth = Some(format!("Basic {}", &BASE64.encode(cookie.as_ref().as_bytes())));
+        let auth = format!("Basic {}", &BASE64.encode(cookie.as_ref().as_bytes()));
+        self.tp.basic_auth = Arc::new(RwLock::new(Some(auth)));
+        self
+    }
+
+    /// Sets the maximum size of a response body in bytes, defaults to 1 GiB.
+    ///
+    /// Larger responses fail with [`bitreq::Error::BodyOverflow`].
+    pub fn max_response_size(mut self, max: usize) -> Self {
+        self.tp.max_response_size = max;
+        self
+    }
+
+    /// Adds an HTTP header sent with each request.
+    pub fn header(mut self, name: String, value: String) -> Self {
+        self.tp.headers.push((name, value));
+        self
+    }
+
+    /// Trusts the DER encoded certificate `der` as a root when connecting over HTTPS.
+    ///
+    /// Useful for servers behind a reverse proxy using a self-signed or private CA certificate.
+    #[cfg(feature = "bitreq_https")]
+    pub fn root_certificate(mut self, der: Vec<u8>) -> Self {
+        self.tp.root_certificates.push(der);
+        self
+    }
+
+    /// Routes requests through `proxy`, e.g., a Tor SOCKS5 proxy to reach an onion service.
+    #[cfg(feature = "bitreq_proxy")]
+    pub fn proxy(mut self, proxy: bitreq::Proxy) -> Self {
+        self.tp.proxy = Some(proxy);
         self
     }
 
@@ -167,6 +263,16 @@ impl Builder {
     pub fn build(self) -> BitreqHttpTransport { self.tp }
 }
 
+/// Returns the value of the `Authorization` header for `user` and `pass`.
+fn basic_auth_header(user: String, pass: Option<String>) -> String {
+    let mut s = user;
+    s.push(':');
+    if let Some(ref pass) = pass {
+        s.push_str(pass.as_ref());
+    }
+    format!("Basic {}", &BASE64.encode(s.as_bytes()))
+}
+
 impl Default for Builder {
     fn default() -> Self { Builder::new() }
 }
@@ -283,4 +389,26 @@ mod tests {
             .build();
         let _ = Client::with_transport(tp);
     }
+
+    #[test]
+    fn header() {
+        let tp = Builder::new()
+            .header("X-Api-Key".to_owned(), "secret".to_owned())
+            .header("X-Tenant".to_owned(), "alice".to_owned())
+            .build();
+        assert_eq!(tp.headers.len(), 2);
+        assert_eq!(tp.headers[1], ("X-Tenant".to_owned(), "alice".to_owned()));
+    }
+
+    #[test]
+    fn with_url_path() {
+        let tp = Builder::new().url("http://localhost:22").unwrap().build();
+        let tp = tp.with_url_path("/wallet/alice");
+        assert_eq!(tp.url, "http://localhost:22/wallet/alice");
+        let tp = tp.with_url_path("/wallet/bob");
+        assert_eq!(tp.url, "http://localhost:22/wallet/bob");
+
+        let tp = Builder::new().url("localhost:22").unwrap().build();
+        assert_eq!(tp.with_url_path("/").url, "localhost:22/");
+    }
 }
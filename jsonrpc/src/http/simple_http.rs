@@ -10,7 +10,7 @@ use std::io::{BufRead, BufReader, Read, Write};
 #[cfg(not(jsonrpc_fuzz))]
 use std::net::TcpStream;
 use std::net::{SocketAddr, ToSocketAddrs};
-use std::sync::{Arc, Mutex, MutexGuard};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
 use std::time::Duration;
 use std::{error, fmt, io, net, num};
 
@@ -25,7 +25,7 @@ use crate::http::DEFAULT_PORT;
 use crate::http::DEFAULT_PROXY_PORT;
 use crate::{Request, Response};
 
-/// Absolute maximum content length allowed before cutting off the response.
+/// Default maximum content length allowed before cutting off the response.
 const FINAL_RESP_ALLOC: u64 = 1024 * 1024 * 1024;
 
 #[cfg(not(jsonrpc_fuzz))]
@@ -43,7 +43,13 @@ pub struct SimpleHttpTransport {
     path: String,
     timeout: Duration,
     /// The value of the `Authorization` HTTP header.
-    basic_auth: Option<String>,
+    ///
+    /// Shared with all clones, see [`SimpleHttpTransport::set_basic_auth`].
+    basic_auth: Arc<RwLock<Option<String>>>,
+    /// Additional HTTP headers sent with each request.
+    headers: Vec<(String, String)>,
+    /// Maximum size of a response body in bytes.
+    max_response_size: u64,
     #[cfg(feature = "proxy")]
     proxy_addr: net::SocketAddr,
     #[cfg(feature = "proxy")]
@@ -61,7 +67,9 @@ impl Default for SimpleHttpTransport {
             host_header: format!("127.0.0.1:{}", DEFAULT_PORT),
             path: "/".to_owned(),
             timeout: DEFAULT_TIMEOUT,
-            basic_auth: None,
+            basic_auth: Arc::new(RwLock::new(None)),
+            headers: Vec::new(),
+            max_response_size: FINAL_RESP_ALLOC,
             #[cfg(feature = "proxy")]
             proxy_addr: net::SocketAddr::new(
                 net::IpAddr::V4(net::Ipv4Addr::new(127, 0, 0, 1)),
@@ -81,6 +89,21 @@ impl SimpleHttpTransport {
     /// Returns a builder for [`SimpleHttpTransport`].
     pub fn builder() -> Builder { Builder::new() }
 
+    /// Returns the timeout after which requests are aborted.
+    pub fn timeout(&self) -> Duration { self.timeout }
+
+    /// Returns a copy of this transport that aborts requests after `timeout` instead.
+    ///
+    /// The copy shares the connection with this transport.
+    pub fn with_timeout(&self, timeout: Duration) -> Self {
+        SimpleHttpTransport { timeout, ..self.clone() }
+    }
+
+    /// Returns a copy of this transport that opens its own connection instead of sharing this one.
+    pub fn with_new_connection(&self) -> Self {
+        SimpleHttpTransport { sock: Arc::new(Mutex::new(None)), ..self.clone() }
+    }
+
     /// Replaces the URL of the transport.
     pub fn set_url(&mut self, url: &str) -> Result<(), Error> {
         let url = check_url(url)?;
@@ -93,6 +116,11 @@ impl SimpleHttpTransport {
     /// Replaces only the path part of the URL.
     pub fn set_url_path(&mut self, path: String) { self.path = path; }
 
+    /// Replaces the authentication information, for this transport and all its clones.
+    pub fn set_basic_auth<S: AsRef<str>>(&self, user: S, pass: Option<S>) {
+        *self.basic_auth.write().expect("poisoned lock") = Some(basic_auth_header(user, pass));
+    }
+
     fn request<R>(&self, req: impl serde::Serialize) -> Result<R, Error>
     where
         R: for<'a> serde::de::Deserialize<'a>,
@@ -142,6 +170,9 @@ impl SimpleHttpTransport {
         // In the immediately preceding block, we made sure that `sock` is non-`None`,
         // so unwrapping here is fine.
         let sock: &mut BufReader<_> = sock_lock.as_mut().unwrap();
+        // The socket may have been opened by a copy of this transport with a different timeout.
+        sock.get_ref().set_read_timeout(Some(self.timeout))?;
+        sock.get_ref().set_write_timeout(Some(self.timeout))?;
 
         // Serialize the body first so we can set the Content-Length header.
         let body = serde_json::to_vec(&req)?;
@@ -159,11 +190,17 @@ impl SimpleHttpTransport {
         request_bytes.write_all(b"Content-Length: ")?;
         request_bytes.write_all(body.len().to_string().as_bytes())?;
         request_bytes.write_all(b"\r\n")?;
-        if let Some(ref auth) = self.basic_auth {
+        if let Some(ref auth) = *self.basic_auth.read().expect("poisoned lock") {
             request_bytes.write_all(b"Authorization: ")?;
             request_bytes.write_all(auth.as_ref())?;
             request_bytes.write_all(b"\r\n")?;
         }
+        for (name, value) in &self.headers {
+            request_bytes.write_all(name.as_bytes())?;
+            request_bytes.write_all(b": ")?;
+            request_bytes.write_all(value.as_bytes())?;
+            request_bytes.write_all(b"\r\n")?;
+        }
         // Write body
         request_bytes.write_all(b"\r\n")?;
         request_bytes.write_all(&body)?;
@@ -242,16 +279,20 @@ impl SimpleHttpTransport {
             // There is no body in a 401 response, so don't try to read it
             return Err(Error::HttpErrorCode(response_code));
         }
+        if response_code == 204 {
+            // There is no body in a 204 response either, e.g., the reply to a notification.
+            return Ok(serde_json::from_value(serde_json::Value::Null)?);
+        }
 
         // Read up to `content_length` bytes. Note that if there is no content-length
         // header, we will assume an effectively infinite content length, i.e. we will
         // just keep reading from the socket until it is closed.
         let mut reader = match content_length {
-            None => sock.take(FINAL_RESP_ALLOC),
-            Some(n) if n > FINAL_RESP_ALLOC => {
+            None => sock.take(self.max_response_size),
+            Some(n) if n > self.max_response_size => {
                 return Err(Error::HttpResponseContentLengthTooLarge {
                     length: n,
-                    max: FINAL_RESP_ALLOC,
+                    max: self.max_response_size,
                 });
             }
             Some(n) => sock.take(n),
@@ -268,6 +309,10 @@ impl SimpleHttpTransport {
                 Ok(s)
             }
             Err(e) => {
+                // Without a content-length the body is cut off at our maximum, which fails the parse
+                if content_length.is_none() && reader.limit() == 0 {
+                    return Err(Error::HttpResponseTooLarge { max: self.max_response_size });
+                }
                 // If the response was not 200, assume the parse failed because of that
                 if response_code != 200 {
                     Err(Error::HttpErrorCode(response_code))
@@ -353,6 +398,14 @@ impl Transport for SimpleHttpTransport {
         Ok(self.request(reqs)?)
     }
 
+    /// Sends a notification, accepting either no response (HTTP 204) or any JSON response.
+    ///
+    /// Servers that do not support JSON-RPC 2.0 notifications respond as to a request.
+    fn send_notification(&self, req: Request) -> Result<(), crate::Error> {
+        self.request::<serde_json::Value>(req)?;
+        Ok(())
+    }
+
     fn fmt_target(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "http://{}:{}{}", self.addr.ip(), self.addr.port(), self.path)
     }
@@ -382,18 +435,28 @@ impl Builder {
 
     /// Adds authentication information to the transport.
     pub fn auth<S: AsRef<str>>(mut self, user: S, pass: Option<S>) -> Self {
-        let mut auth = user.as_ref().to_owned();
-        auth.push(':');
-        if let Some(ref pass) = pass {
-            auth.push_str(pass.as_ref());
-        }
-        self.tp.basic_auth = Some(format!("Basic {}", &BASE64.encode(auth.as_bytes())));
+        self.tp.basic_auth = Arc::new(RwLock::new(Some(basic_auth_header(user, pass))));
         self
     }
 
     /// Adds authentication information to the transport using a cookie string ('user:pass').
     pub fn cookie_auth<S: AsRef<str>>(mut self, cookie: S) -> Self {
-        self.tp.basic_auth = Some(format!("Basic {}", &BASE64.encode(cookie.as_ref().as_bytes())));
+        let auth = format!("Basic {}", &BASE64.encode(cookie.as_ref().as_bytes()));
+        self.tp.basic_auth = Arc::new(RwLock::new(Some(auth)));
+        self
+    }
+
+    /// Sets the maximum size of a response body in bytes, defaults to 1 GiB.
+    pub fn max_response_size(mut self, max: u64) -> Self {
+        self.tp.max_response_size = max;
+        self
+    }
+
+    /// Adds an HTTP header sent with each request.
+    ///
+    /// Neither `name` nor `value` may contain a line break.
+    pub fn header<S: AsRef<str>>(mut self, name: S, value: S) -> Self {
+        self.tp.headers.push((name.as_ref().to_owned(), value.as_ref().to_owned()));
         self
     }
 
@@ -417,6 +480,16 @@ impl Builder {
     pub fn build(self) -> SimpleHttpTransport { self.tp }
 }
 
+/// Returns the value of the `Authorization` header for `user` and `pass`.
+fn basic_auth_header<S: AsRef<str>>(user: S, pass: Option<S>) -> String {
+    let mut auth = user.as_ref().to_owned();
+    auth.push(':');
+    if let Some(ref pass) = pass {
+        auth.push_str(pass.as_ref());
+    }
+    format!("Basic {}", &BASE64.encode(auth.as_bytes()))
+}
+
 impl Default for Builder {
     fn default() -> Self { Builder::new() }
 }
@@ -496,6 +569,11 @@ pub enum Error {
         /// Our hard maximum on number of bytes we'll try to read.
         max: u64,
     },
+    /// The response without a content-length header exceeded our maximum.
+    HttpResponseTooLarge {
+        /// Our maximum on number of bytes we'll read.
+        max: u64,
+    },
     /// The server is replying with chunked encoding which is not supported.
     HttpResponseChunked,
     /// Unexpected HTTP error code (non-200).
@@ -543,6 +621,7 @@ impl fmt::Display for Error {
             HttpResponseContentLengthTooLarge { length, max } => {
                 write!(f, "HTTP response content length {} exceeds our max {}.", length, max)
             }
+            HttpResponseTooLarge { max } => write!(f, "HTTP response exceeds our max {}.", max),
             HttpErrorCode(c) => write!(f, "unexpected HTTP code: {}", c),
             IncompleteResponse { content_length, n_read } => {
                 write!(
@@ -571,6 +650,7 @@ impl error::Error for Error {
             | HttpResponseBadStatus(..)
             | HttpResponseBadContentLength(..)
             | HttpResponseContentLengthTooLarge { .. }
+            | HttpResponseTooLarge { .. }
             | HttpErrorCode(_)
             | IncompleteResponse { .. }
             | HttpResponseChunked => None,
@@ -679,7 +759,7 @@ mod tests {
             assert_eq!(builder.tp.host_header, host_header);
             assert_eq!(builder.tp.path, path);
             assert_eq!(builder.tp.timeout, DEFAULT_TIMEOUT);
-            assert_eq!(builder.tp.basic_auth, None);
+            assert_eq!(*builder.tp.basic_auth.read().unwrap(), None);
             #[cfg(feature = "proxy")]
             assert_eq!(builder.tp.proxy_addr, SocketAddr::from_str("127.0.0.1:9050").unwrap());
         }
@@ -74,8 +74,11 @@ pub struct Request<'a> {
     /// Parameters to the RPC call.
     pub params: Option<&'a RawValue>,
     /// Identifier for this request, which should appear in the response.
+    ///
+    /// Omitted when `null`, which makes a JSON-RPC 2.0 request a notification.
+    #[serde(skip_serializing_if = "serde_json::Value::is_null")]
     pub id: serde_json::Value,
-    /// jsonrpc field, MUST be "2.0".
+    /// jsonrpc field, "2.0" for JSON-RPC 2.0.
     pub jsonrpc: Option<&'a str>,
 }
 
@@ -191,6 +194,21 @@ mod tests {
         assert_eq!(batch_response.len(), 5);
     }
 
+    #[test]
+    fn v2_response() {
+        // JSON-RPC 2.0 responses only include one of result and error.
+        let s = r#"{"jsonrpc": "2.0", "result": 7, "id": 1}"#;
+        let response: Response = serde_json::from_str(s).unwrap();
+        assert_eq!(response.result::<u64>().unwrap(), 7);
+
+        let s = r#"{"jsonrpc": "2.0", "error": {"code": -32601, "message": "Method not found"}, "id": 1}"#;
+        let response: Response = serde_json::from_str(s).unwrap();
+        match response.result::<u64>() {
+            Err(Error::Rpc(e)) => assert_eq!(e.code, -32601),
+            res => panic!("unexpected result: {:?}", res),
+        }
+    }
+
     #[test]
     fn test_arg() {
         macro_rules! test_arg {
@@ -249,4 +267,18 @@ mod tests {
             r#"{"method":"object","params":{"height":0},"id":2,"jsonrpc":"2.0"}"#
         );
     }
+
+    #[test]
+    fn test_request_notification() {
+        let request = Request {
+            method: "ping",
+            params: None,
+            id: serde_json::Value::Null,
+            jsonrpc: Some("2.0"),
+        };
+        assert_eq!(
+            serde_json::to_string(&request).unwrap(),
+            r#"{"method":"ping","params":null,"jsonrpc":"2.0"}"#
+        );
+    }
 }
@@ -40,8 +40,9 @@ use core::fmt;
 
 use bitcoin::address::{self, Address, NetworkUnchecked};
 use bitcoin::amount::ParseAmountError;
+use bitcoin::consensus::encode::{self, Decodable};
 use bitcoin::hex::{self, FromHex as _};
-use bitcoin::{Amount, FeeRate, ScriptBuf, Witness};
+use bitcoin::{Amount, Block, FeeRate, ScriptBuf, Transaction, Witness};
 use serde::{Deserialize, Serialize};
 
 use crate::error::write_err;
@@ -176,6 +177,60 @@ pub fn compact_size_decode(slice: &mut &[u8]) -> u64 {
     }
 }
 
+/// Hex encoded data returned by Core (e.g. a raw block, transaction, or filter).
+///
+/// The hex string is stored as is and only decoded when asked for. Callers that just forward the
+/// data (e.g. to another node) can do so without paying for the decoding.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct HexBytes(String);
+
+impl HexBytes {
+    /// Creates a new `HexBytes` from a hex string, the string is not validated.
+    pub fn new(hex: String) -> Self { Self(hex) }
+
+    /// Returns the hex string.
+    pub fn as_str(&self) -> &str { &self.0 }
+
+    /// Returns the inner hex string.
+    pub fn into_string(self) -> String { self.0 }
+
+    /// Returns the length of the decoded data in bytes.
+    pub fn byte_len(&self) -> usize { self.0.len() / 2 }
+
+    /// Decodes the hex string into bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, hex::HexToBytesError> { Vec::from_hex(&self.0) }
+
+    /// Decodes the hex string directly into a consensus encodable type.
+    ///
+    /// Decodes straight from the hex string without first allocating an intermediate byte vector.
+    pub fn decode<T: Decodable>(&self) -> Result<T, encode::FromHexError> {
+        encode::deserialize_hex(&self.0)
+    }
+
+    /// Decodes the hex string into a `bitcoin::Block`.
+    pub fn block(&self) -> Result<Block, encode::FromHexError> { self.decode() }
+
+    /// Decodes the hex string into a `bitcoin::Transaction`.
+    pub fn transaction(&self) -> Result<Transaction, encode::FromHexError> { self.decode() }
+}
+
+impl fmt::Display for HexBytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { fmt::Display::fmt(&self.0, f) }
+}
+
+impl AsRef<str> for HexBytes {
+    fn as_ref(&self) -> &str { &self.0 }
+}
+
+impl From<String> for HexBytes {
+    fn from(hex: String) -> Self { Self(hex) }
+}
+
+impl From<HexBytes> for String {
+    fn from(hex: HexBytes) -> Self { hex.0 }
+}
+
 /// Data returned by Core for a script pubkey.
 ///
 /// This is used by methods in the blockchain section and in the raw transaction section (i.e raw
@@ -306,4 +361,21 @@ mod tests {
         let got = btc_per_kb(f).unwrap();
         assert_eq!(got, Some(FeeRate::from_sat_per_kwu(25)))
     }
+
+    #[test]
+    fn hex_bytes_decode() {
+        use bitcoin::{absolute, transaction};
+
+        let tx = Transaction {
+            version: transaction::Version::TWO,
+            lock_time: absolute::LockTime::ZERO,
+            input: vec![],
+            output: vec![],
+        };
+        let hex = HexBytes::new(encode::serialize_hex(&tx));
+
+        assert_eq!(hex.byte_len(), encode::serialize(&tx).len());
+        assert_eq!(hex.to_bytes().unwrap(), encode::serialize(&tx));
+        assert_eq!(hex.transaction().unwrap(), tx);
+    }
 }
@@ -8,11 +8,11 @@
 use alloc::collections::BTreeMap;
 
 use bitcoin::address::NetworkUnchecked;
-use bitcoin::hashes::sha256;
+use bitcoin::hashes::{sha256, Hash as _};
 use bitcoin::{
-    absolute, block, transaction, Address, Amount, Block, BlockHash, CompactTarget, FeeRate,
-    Network, OutPoint, ScriptBuf, Sequence, Target, Transaction, TxMerkleNode, TxOut, Txid, Weight,
-    Witness, Work, Wtxid,
+    absolute, bip158, block, transaction, Address, Amount, Block, BlockHash, CompactTarget,
+    FeeRate, Network, OutPoint, ScriptBuf, Sequence, Target, Transaction, TxMerkleNode, TxOut,
+    Txid, Weight, Witness, Work, Wtxid,
 };
 use serde::{Deserialize, Serialize};
 
@@ -43,6 +43,10 @@ pub struct GetBestBlockHash(pub BlockHash);
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GetBlockVerboseZero(pub Block);
 
+impl From<GetBlockVerboseZero> for Block {
+    fn from(block: GetBlockVerboseZero) -> Self { block.0 }
+}
+
 /// Models the result of JSON-RPC method `getblock` with verbosity set to 1.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBlockVerboseOne {
@@ -133,6 +137,28 @@ pub struct GetBlockVerboseTwo {
     pub next_block_hash: Option<BlockHash>,
 }
 
+impl GetBlockVerboseTwo {
+    /// Returns the block header.
+    pub fn block_header(&self) -> block::Header {
+        block_header(
+            self.version,
+            self.previous_block_hash,
+            self.merkle_root,
+            self.time,
+            self.bits,
+            self.nonce,
+        )
+    }
+}
+
+impl From<GetBlockVerboseTwo> for Block {
+    fn from(block: GetBlockVerboseTwo) -> Self {
+        let header = block.block_header();
+        let txdata = block.tx.into_iter().map(|tx| tx.transaction.transaction).collect();
+        Block { header, txdata }
+    }
+}
+
 /// A transaction entry for `getblock` verbosity 2.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBlockVerboseTwoTransaction {
@@ -187,6 +213,28 @@ pub struct GetBlockVerboseThree {
     pub next_block_hash: Option<BlockHash>,
 }
 
+impl GetBlockVerboseThree {
+    /// Returns the block header.
+    pub fn block_header(&self) -> block::Header {
+        block_header(
+            self.version,
+            self.previous_block_hash,
+            self.merkle_root,
+            self.time,
+            self.bits,
+            self.nonce,
+        )
+    }
+}
+
+impl From<GetBlockVerboseThree> for Block {
+    fn from(block: GetBlockVerboseThree) -> Self {
+        let header = block.block_header();
+        let txdata = block.tx.into_iter().map(|tx| tx.transaction.transaction).collect();
+        Block { header, txdata }
+    }
+}
+
 /// Coinbase transaction metadata. Part of `getblock`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct CoinbaseTransaction {
@@ -213,8 +261,9 @@ pub struct GetBlockVerboseThreeTransaction {
     pub fee: Option<Amount>,
 }
 
-/// The prevout information for a transaction input (verbosity 3 only).
-/// TODO: This type adding prevouts is exactly the same as the getrawtransaction's type with verbosity set to 2, which is not implemented yet. Consider reusing that structure when implemented.
+/// The prevout information for a transaction input.
+///
+/// Part of `getblock` with verbosity 3 and `getrawtransaction` with verbosity 2.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBlockVerboseThreePrevout {
     /// Coinbase or not.
@@ -272,6 +321,31 @@ pub struct GetBlockchainInfo {
     pub warnings: Vec<String>,
 }
 
+impl GetBlockchainInfo {
+    /// Returns the pruning state of the node, `None` if the node is not pruned.
+    pub fn prune_status(&self) -> Option<PruneStatus> {
+        if !self.pruned {
+            return None;
+        }
+        Some(PruneStatus {
+            prune_height: self.prune_height.unwrap_or(0),
+            automatic_pruning: self.automatic_pruning.unwrap_or(false),
+            prune_target_size: self.prune_target_size,
+        })
+    }
+}
+
+/// The pruning state of a pruned node. Part of `getblockchaininfo`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct PruneStatus {
+    /// Lowest-height complete block stored.
+    pub prune_height: u32,
+    /// Whether automatic pruning is enabled, if not blocks are only pruned by `pruneblockchain`.
+    pub automatic_pruning: bool,
+    /// The target size in bytes used by automatic pruning.
+    pub prune_target_size: Option<u64>,
+}
+
 /// Softfork status. Part of `getblockchaininfo`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct Softfork {
@@ -358,8 +432,12 @@ pub struct GetBlockCount(pub u64);
 pub struct GetBlockFilter {
     /// The filter data.
     pub filter: Vec<u8>,
-    /// The hex-encoded filter header.
-    pub header: bitcoin::bip158::FilterHash,
+    /// The filter header, committing to this filter and the headers of all previous filters.
+    pub header: bip158::FilterHeader,
+}
+
+impl From<GetBlockFilter> for bip158::BlockFilter {
+    fn from(filter: GetBlockFilter) -> Self { bip158::BlockFilter { content: filter.filter } }
 }
 
 /// Models the result of JSON-RPC method `getblockhash`.
@@ -370,6 +448,10 @@ pub struct GetBlockHash(pub BlockHash);
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct GetBlockHeader(pub block::Header);
 
+impl From<GetBlockHeader> for block::Header {
+    fn from(header: GetBlockHeader) -> Self { header.0 }
+}
+
 /// Models the result of JSON-RPC method `getblockheader`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetBlockHeaderVerbose {
@@ -405,6 +487,45 @@ pub struct GetBlockHeaderVerbose {
     pub next_block_hash: Option<BlockHash>,
 }
 
+impl GetBlockHeaderVerbose {
+    /// Returns the block header.
+    pub fn block_header(&self) -> block::Header {
+        block_header(
+            self.version,
+            self.previous_block_hash,
+            self.merkle_root,
+            self.time,
+            self.bits,
+            self.nonce,
+        )
+    }
+}
+
+impl From<GetBlockHeaderVerbose> for block::Header {
+    fn from(header: GetBlockHeaderVerbose) -> Self { header.block_header() }
+}
+
+/// Builds a block header from the fields returned by the verbose `getblock`/`getblockheader` calls.
+///
+/// Core omits `previousblockhash` for the genesis block, the header commits to all zeros.
+fn block_header(
+    version: block::Version,
+    previous_block_hash: Option<BlockHash>,
+    merkle_root: TxMerkleNode,
+    time: u32,
+    bits: CompactTarget,
+    nonce: u32,
+) -> block::Header {
+    block::Header {
+        version,
+        prev_blockhash: previous_block_hash.unwrap_or_else(BlockHash::all_zeros),
+        merkle_root,
+        time,
+        bits,
+        nonce,
+    }
+}
+
 /// Models the result of JSON-RPC method `getblockstats`.
 ///
 /// All fields are optional because the caller can select which stats to compute.
@@ -485,6 +606,21 @@ pub struct GetChainStates {
     pub chain_states: Vec<ChainState>,
 }
 
+impl GetChainStates {
+    /// Returns the active chainstate, the one with the most work.
+    pub fn active(&self) -> Option<&ChainState> { self.chain_states.last() }
+
+    /// Returns the chainstate validating the blocks below a loaded snapshot, if any.
+    ///
+    /// This is `None` when no snapshot is loaded or once background validation has finished.
+    pub fn background(&self) -> Option<&ChainState> {
+        match self.chain_states.as_slice() {
+            [background, _snapshot] => Some(background),
+            _ => None,
+        }
+    }
+}
+
 /// A single chainstate. Part of `getchainstates`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct ChainState {
@@ -514,6 +650,18 @@ pub struct ChainState {
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetChainTips(pub Vec<ChainTips>);
 
+impl GetChainTips {
+    /// Returns the tip of the active chain.
+    pub fn active(&self) -> Option<&ChainTips> {
+        self.0.iter().find(|tip| tip.status == ChainTipsStatus::Active)
+    }
+
+    /// Returns the tips of all branches that are not part of the active chain.
+    pub fn forks(&self) -> impl Iterator<Item = &ChainTips> {
+        self.0.iter().filter(|tip| tip.status != ChainTipsStatus::Active)
+    }
+}
+
 /// An individual list item from the result of JSON-RPC method `getchaintips`.
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct ChainTips {
@@ -774,6 +922,32 @@ pub struct MempoolEntry {
     pub unbroadcast: Option<bool>,
 }
 
+impl MempoolEntry {
+    /// Returns the virtual transaction size, reported as `size` before v0.19 and `vsize` after.
+    pub fn virtual_size(&self) -> Option<u32> { self.vsize.or(self.size) }
+
+    /// Returns the fee rate of the transaction, using the base fee.
+    pub fn fee_rate(&self) -> Option<FeeRate> { fee_rate(self.fees.base, self.virtual_size()?) }
+
+    /// Returns the fee rate of the transaction and its in-mempool ancestors, using modified fees.
+    pub fn ancestor_fee_rate(&self) -> Option<FeeRate> {
+        fee_rate(self.fees.ancestor, self.ancestor_size)
+    }
+
+    /// Returns the fee rate of the transaction and its in-mempool descendants, using modified fees.
+    pub fn descendant_fee_rate(&self) -> Option<FeeRate> {
+        fee_rate(self.fees.descendant, self.descendant_size)
+    }
+}
+
+/// Returns the fee rate of paying `fee` for `vsize` virtual bytes, rounded down.
+fn fee_rate(fee: Amount, vsize: u32) -> Option<FeeRate> {
+    match Weight::from_vb(vsize.into())? {
+        Weight::ZERO => None,
+        weight => Some(fee / weight),
+    }
+}
+
 /// Fee object. Part of `getmempoolentry`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct MempoolEntryFees {
@@ -973,6 +1147,13 @@ pub struct LoadTxOutSet {
     pub path: String,
 }
 
+/// Models the result of JSON-RPC method `savemempool`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SaveMempool {
+    /// The directory and file where the mempool was saved. v23 and later only.
+    pub filename: Option<String>,
+}
+
 /// Models the result of the JSON-RPC method `scanblocks` whan `action = start`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct ScanBlocksStart {
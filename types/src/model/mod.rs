@@ -38,9 +38,10 @@ pub use self::{
         GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolFeerateDiagram, GetMempoolInfo,
         GetRawMempool, GetRawMempoolSequence, GetRawMempoolVerbose, GetTxOut, GetTxOutSetInfo,
         GetTxOutSetInfoBlockInfo, GetTxOutSetInfoUnspendables, GetTxSpendingPrevout,
-        GetTxSpendingPrevoutItem, LoadTxOutSet, MempoolEntry, MempoolEntryFees, ReceiveActivity,
-        ScanBlocksStart, ScanTxOutSetStart, ScanTxOutSetUnspent, Softfork, SoftforkType,
-        SpendActivity, VerifyTxOutProof, WaitForBlock, WaitForBlockHeight, WaitForNewBlock,
+        GetTxSpendingPrevoutItem, LoadTxOutSet, MempoolEntry, MempoolEntryFees, PruneStatus,
+        ReceiveActivity, SaveMempool, ScanBlocksStart, ScanTxOutSetStart, ScanTxOutSetUnspent,
+        Softfork, SoftforkType, SpendActivity, VerifyTxOutProof, WaitForBlock, WaitForBlockHeight,
+        WaitForNewBlock,
     },
     generating::{Generate, GenerateBlock, GenerateToAddress, GenerateToDescriptor},
     hidden::{
@@ -51,13 +52,17 @@ pub use self::{
         BlockTemplateTransaction, GetBlockTemplate, GetMiningInfo, GetPrioritisedTransactions,
         NextBlockInfo, PrioritisedTransaction,
     },
-    network::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork},
+    network::{
+        ConnectionType, GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, GetPeerInfo,
+        NetworkType, PeerInfo, TransportProtocolType,
+    },
     raw_transactions::{
         AbortPrivateBroadcast, AnalyzePsbt, AnalyzePsbtInput, AnalyzePsbtInputMissing, CombinePsbt,
         CombineRawTransaction, ConvertToPsbt, CreatePsbt, CreateRawTransaction, DecodePsbt,
-        DecodeRawTransaction, DecodeScript, DescriptorProcessPsbt, FinalizePsbt,
-        FundRawTransaction, GetPrivateBroadcastInfo, GetRawTransaction, GetRawTransactionVerbose,
-        JoinPsbts, MempoolAcceptance, MempoolAcceptanceFees, SendRawTransaction, SignFail,
+        DecodeRawTransaction, DecodeScript, DecodeScriptSegwit, DescriptorProcessPsbt,
+        FinalizePsbt, FinalizedPsbt, FundRawTransaction, GetPrivateBroadcastInfo,
+        GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseTwo, JoinPsbts,
+        MempoolAcceptance, MempoolAcceptanceFees, PsbtRole, SendRawTransaction, SignFail,
         SignRawTransaction, SignRawTransactionWithKey, SubmitPackage, SubmitPackageTxResult,
         SubmitPackageTxResultFees, TestMempoolAccept, UtxoUpdatePsbt,
     },
@@ -71,14 +76,16 @@ pub use self::{
         GetBalance, GetBalances, GetBalancesMine, GetBalancesWatchOnly, GetHdKeys, GetNewAddress,
         GetRawChangeAddress, GetReceivedByAddress, GetReceivedByLabel, GetTransaction,
         GetTransactionDetail, GetUnconfirmedBalance, GetWalletInfo, GetWalletInfoScanning, HdKey,
-        HdKeyDescriptor, LastProcessedBlock, ListAddressGroupings, ListAddressGroupingsItem,
-        ListLockUnspent, ListLockUnspentItem, ListReceivedByAddress, ListReceivedByAddressItem,
-        ListReceivedByLabel, ListReceivedByLabelItem, ListSinceBlock, ListTransactions,
-        ListUnspent, ListUnspentItem, ListWallets, LoadWallet, PsbtBumpFee, RescanBlockchain,
-        ScriptType, Send, SendAll, SendMany, SendManyVerbose, SendToAddress, SignMessage,
-        SignRawTransactionWithWallet, SimulateRawTransaction, TransactionCategory, TransactionItem,
-        UnloadWallet, WalletCreateFundedPsbt, WalletDisplayAddress, WalletProcessPsbt,
+        HdKeyDescriptor, ImportDescriptors, ImportDescriptorsResult, LastProcessedBlock,
+        ListAddressGroupings, ListAddressGroupingsItem, ListLockUnspent, ListLockUnspentItem,
+        ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
+        ListReceivedByLabelItem, ListSinceBlock, ListTransactions, ListUnspent, ListUnspentItem,
+        ListWallets, LoadWallet, PsbtBumpFee, RescanBlockchain, ScriptType, Send, SendAll,
+        SendMany, SendManyVerbose, SendToAddress, SignMessage, SignRawTransactionWithWallet,
+        SimulateRawTransaction, TransactionCategory, TransactionItem, UnloadWallet,
+        WalletCreateFundedPsbt, WalletDisplayAddress, WalletProcessPsbt,
     },
+    zmq::GetZmqNotifications,
 };
 
 /// Models the data returned by Core for a scriptPubKey.
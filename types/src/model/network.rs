@@ -5,6 +5,8 @@
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
 
+use alloc::collections::BTreeMap;
+
 use bitcoin::FeeRate;
 use serde::{Deserialize, Serialize};
 
@@ -70,3 +72,163 @@ pub struct GetNetworkInfoAddress {
     /// Relative score.
     pub score: u32,
 }
+
+/// Models the result of JSON-RPC method `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetPeerInfo(pub Vec<PeerInfo>);
+
+impl GetPeerInfo {
+    /// Returns the peers that connected to us.
+    pub fn inbound(&self) -> Vec<&PeerInfo> { self.0.iter().filter(|p| p.inbound).collect() }
+
+    /// Returns the peers we connected to.
+    pub fn outbound(&self) -> Vec<&PeerInfo> { self.0.iter().filter(|p| !p.inbound).collect() }
+
+    /// Returns the number of peers per network (ipv4, ipv6, onion, i2p, cjdns, ...).
+    ///
+    /// Peers without a `network` field (Core v0.20 and earlier) are not counted.
+    pub fn by_network(&self) -> BTreeMap<NetworkType, usize> {
+        let mut map = BTreeMap::new();
+        for network in self.0.iter().filter_map(|p| p.network) {
+            *map.entry(network).or_insert(0) += 1;
+        }
+        map
+    }
+
+    /// Returns the peers connected over Tor.
+    pub fn onion_peers(&self) -> Vec<&PeerInfo> {
+        self.0.iter().filter(|p| p.network == Some(NetworkType::Onion)).collect()
+    }
+
+    /// Returns the median of the last ping time (in seconds) of all peers that have one.
+    pub fn median_ping(&self) -> Option<f64> {
+        let mut pings = self.0.iter().filter_map(|p| p.ping_time).collect::<Vec<_>>();
+        if pings.is_empty() {
+            return None;
+        }
+        pings.sort_by(|a, b| a.total_cmp(b));
+
+        let mid = pings.len() / 2;
+        if pings.len() % 2 == 0 {
+            Some((pings[mid - 1] + pings[mid]) / 2.0)
+        } else {
+            Some(pings[mid])
+        }
+    }
+
+    /// Returns the number of peers using the BIP-324 encrypted (v2) transport protocol.
+    pub fn v2_transport_count(&self) -> usize {
+        self.0
+            .iter()
+            .filter(|p| p.transport_protocol_type == Some(TransportProtocolType::V2))
+            .count()
+    }
+}
+
+/// A peer info item. Part of `getpeerinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PeerInfo {
+    /// Peer index.
+    pub id: u32,
+    /// The IP address and port of the peer ("host:port").
+    pub address: String,
+    /// Bind address of the connection to the peer ("ip:port").
+    pub address_bind: Option<String>,
+    /// Local address as reported by the peer.
+    pub address_local: Option<String>,
+    /// Network (ipv4, ipv6, onion, i2p, cjdns, not_publicly_routable). v0.21 and later only.
+    pub network: Option<NetworkType>,
+    /// The services offered.
+    pub services: String,
+    /// The services offered, in human-readable form. v0.19 and later only.
+    pub services_names: Option<Vec<String>>,
+    /// Whether peer has asked us to relay transactions to it.
+    pub relay_transactions: bool,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last send.
+    pub last_send: i64,
+    /// The time in seconds since epoch (Jan 1 1970 GMT) of the last receive.
+    pub last_received: i64,
+    /// The total bytes sent.
+    pub bytes_sent: u64,
+    /// The total bytes received.
+    pub bytes_received: u64,
+    /// The connection time in seconds since epoch (Jan 1 1970 GMT).
+    pub connection_time: i64,
+    /// The time offset in seconds.
+    pub time_offset: i64,
+    /// Ping time in seconds (if available).
+    pub ping_time: Option<f64>,
+    /// Minimum observed ping time in seconds (if any at all).
+    pub minimum_ping: Option<f64>,
+    /// Ping wait in seconds (if non-zero).
+    pub ping_wait: Option<f64>,
+    /// The peer version, such as 70001.
+    pub version: u32,
+    /// The string version (e.g. "/Satoshi:0.8.5/").
+    pub subversion: String,
+    /// Inbound (true) or Outbound (false).
+    pub inbound: bool,
+    /// The starting height (block) of the peer.
+    pub starting_height: Option<i64>,
+    /// The last header we have in common with this peer.
+    pub synced_headers: Option<i64>,
+    /// The last block we have in common with this peer.
+    pub synced_blocks: Option<i64>,
+    /// Any special permissions that have been granted to this peer. v0.19 and later only.
+    pub permissions: Option<Vec<String>>,
+    /// The total bytes sent aggregated by message type.
+    pub bytes_sent_per_message: BTreeMap<String, u64>,
+    /// The total bytes received aggregated by message type.
+    pub bytes_received_per_message: BTreeMap<String, u64>,
+    /// Type of connection. v0.21 and later only.
+    pub connection_type: Option<ConnectionType>,
+    /// Type of transport protocol (detecting, v1, v2). v26 and later only.
+    pub transport_protocol_type: Option<TransportProtocolType>,
+}
+
+/// The network a peer is connected through. Part of `getpeerinfo`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub enum NetworkType {
+    /// IPv4.
+    Ipv4,
+    /// IPv6.
+    Ipv6,
+    /// Tor onion service.
+    Onion,
+    /// I2P.
+    I2p,
+    /// CJDNS.
+    Cjdns,
+    /// Addresses not publicly routable e.g., localhost.
+    NotPubliclyRoutable,
+}
+
+/// Type of connection. Part of `getpeerinfo`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum ConnectionType {
+    /// Default automatic connections.
+    OutboundFullRelay,
+    /// Does not relay transactions or addresses.
+    BlockRelayOnly,
+    /// Initiated by the peer.
+    Inbound,
+    /// Added via addnode RPC or -addnode/-connect configuration options.
+    Manual,
+    /// Short-lived automatic connection for soliciting addresses.
+    AddrFetch,
+    /// Short-lived automatic connection for testing addresses.
+    Feeler,
+    /// Short-lived automatic connection for broadcasting privacy-sensitive transactions.
+    PrivateBroadcast,
+}
+
+/// Type of transport protocol. Part of `getpeerinfo`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum TransportProtocolType {
+    /// Peer could be v1 or v2.
+    Detecting,
+    /// Plaintext transport protocol.
+    V1,
+    /// BIP324 encrypted transport protocol.
+    V2,
+}
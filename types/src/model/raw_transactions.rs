@@ -12,6 +12,8 @@ use bitcoin::hashes::{hash160, sha256};
 use bitcoin::{Amount, BlockHash, FeeRate, Psbt, ScriptBuf, Sequence, Transaction, Txid, Wtxid};
 use serde::{Deserialize, Serialize};
 
+use super::GetBlockVerboseThreePrevout;
+
 /// Models the result of JSON-RPC method `abortprivatebroadcast`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct AbortPrivateBroadcast {
@@ -33,7 +35,7 @@ pub struct AnalyzePsbt {
     /// The transaction fee paid. Shown only if all UTXO slots in the PSBT have been filled.
     pub fee: Option<Amount>,
     /// Role of the next person that this psbt needs to go to.
-    pub next: String,
+    pub next: PsbtRole,
 }
 
 /// An input in a PSBT operation. Part of `analyzepsbt`.
@@ -46,7 +48,7 @@ pub struct AnalyzePsbtInput {
     /// Things that are missing that are required to complete this input.
     pub missing: Option<AnalyzePsbtInputMissing>,
     /// Role of the next person that this input needs to go to.
-    pub next: Option<String>,
+    pub next: Option<PsbtRole>,
 }
 
 /// Missing elements required to complete an input. Part of `analyzepsbt`.
@@ -62,6 +64,21 @@ pub struct AnalyzePsbtInputMissing {
     pub witness_script: Option<sha256::Hash>,
 }
 
+/// The role of the next person a PSBT or an input needs to go to. Part of `analyzepsbt`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+pub enum PsbtRole {
+    /// Creates the PSBT.
+    Creator,
+    /// Adds the information needed to sign e.g., UTXOs, scripts and key derivations.
+    Updater,
+    /// Signs the inputs.
+    Signer,
+    /// Builds the final scriptSig and witness of the inputs.
+    Finalizer,
+    /// Extracts the signed transaction.
+    Extractor,
+}
+
 /// Models the result of JSON-RPC method `combinepsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct CombinePsbt(pub Psbt);
@@ -95,6 +112,10 @@ pub struct DecodePsbt {
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct DecodeRawTransaction(pub Transaction);
 
+impl From<DecodeRawTransaction> for Transaction {
+    fn from(tx: DecodeRawTransaction) -> Self { tx.0 }
+}
+
 /// Models the result of JSON-RPC method `decodescript`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct DecodeScript {
@@ -112,10 +133,31 @@ pub struct DecodeScript {
     pub addresses: Vec<Address<NetworkUnchecked>>,
     /// Address of P2SH script wrapping this redeem script (not returned if the script is already a P2SH).
     pub p2sh: Option<Address<NetworkUnchecked>>,
+    /// The script wrapped in a segwit v0 output (not returned for segwit scripts).
+    pub segwit: Option<DecodeScriptSegwit>,
     /// Address of the P2SH script wrapping this witness redeem script
     pub p2sh_segwit: Option<String>,
 }
 
+/// The script wrapped in a segwit v0 output. Part of `decodescript`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct DecodeScriptSegwit {
+    /// The `scriptPubKey` of the segwit output.
+    pub script_pubkey: ScriptBuf,
+    /// The output type.
+    pub type_: String,
+    /// Bitcoin address of the segwit output. v22 and later only.
+    pub address: Option<Address<NetworkUnchecked>>,
+    /// The required signatures.
+    pub required_signatures: Option<u64>,
+    /// List of bitcoin addresses.
+    pub addresses: Vec<Address<NetworkUnchecked>>,
+    /// Inferred descriptor for the segwit output. v23 and later only.
+    pub descriptor: Option<String>,
+    /// Address of the P2SH script wrapping the segwit output.
+    pub p2sh_segwit: Option<Address<NetworkUnchecked>>,
+}
+
 /// Models the result of JSON-RPC method `descriptorprocesspsbt`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct DescriptorProcessPsbt {
@@ -138,6 +180,30 @@ pub struct FinalizePsbt {
     pub complete: bool,
 }
 
+impl FinalizePsbt {
+    /// Returns the outcome of finalizing, `None` if the node returned neither a PSBT nor a
+    /// transaction.
+    pub fn into_finalized(self) -> Option<FinalizedPsbt> {
+        match (self.complete, self.tx, self.psbt) {
+            (true, Some(tx), _) => Some(FinalizedPsbt::Extracted(tx)),
+            (true, None, Some(psbt)) => Some(FinalizedPsbt::Complete(psbt)),
+            (false, _, Some(psbt)) => Some(FinalizedPsbt::Incomplete(psbt)),
+            _ => None,
+        }
+    }
+}
+
+/// The outcome of JSON-RPC method `finalizepsbt`, see [`FinalizePsbt::into_finalized`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum FinalizedPsbt {
+    /// All inputs are finalized and the network transaction was extracted.
+    Extracted(Transaction),
+    /// All inputs are finalized, the transaction was not extracted.
+    Complete(Psbt),
+    /// Some inputs could not be finalized e.g., signatures are missing.
+    Incomplete(Psbt),
+}
+
 /// Models the result of JSON-RPC method `fundrawtransaction`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct FundRawTransaction {
@@ -161,6 +227,10 @@ pub struct GetPrivateBroadcastInfo {
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetRawTransaction(pub Transaction);
 
+impl From<GetRawTransaction> for Transaction {
+    fn from(tx: GetRawTransaction) -> Self { tx.0 }
+}
+
 /// Models the result of JSON-RPC method `getrawtransaction` with verbose set to `true`.
 /// Result of JSON-RPC method `getrawtransaction`
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
@@ -179,6 +249,21 @@ pub struct GetRawTransactionVerbose {
     pub block_time: Option<u64>,
 }
 
+impl From<GetRawTransactionVerbose> for Transaction {
+    fn from(tx: GetRawTransactionVerbose) -> Self { tx.transaction }
+}
+
+/// Models the result of JSON-RPC method `getrawtransaction` with verbosity set to 2.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetRawTransactionVerboseTwo {
+    /// The transaction data (same as `getrawtransaction` verbose output).
+    pub transaction: GetRawTransactionVerbose,
+    /// The prevout data aligned with the transaction input order.
+    pub prevouts: Vec<Option<GetBlockVerboseThreePrevout>>,
+    /// The transaction fee, omitted if block undo data is not available.
+    pub fee: Option<Amount>,
+}
+
 /// Models the result of JSON-RPC method `joinpsbts`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct JoinPsbts(pub Psbt);
@@ -11,8 +11,8 @@ use bitcoin::address::NetworkUnchecked;
 use bitcoin::bip32::{Xpriv, Xpub};
 use bitcoin::hashes::hash160;
 use bitcoin::{
-    bip32, sign_message, Address, Amount, BlockHash, FeeRate, PrivateKey, Psbt, PublicKey,
-    ScriptBuf, SignedAmount, Transaction, Txid, WitnessProgram, WitnessVersion,
+    bip32, sign_message, Address, Amount, BlockHash, FeeRate, OutPoint, PrivateKey, Psbt,
+    PublicKey, ScriptBuf, SignedAmount, Transaction, TxOut, Txid, WitnessProgram, WitnessVersion,
 };
 use serde::{Deserialize, Serialize};
 
@@ -166,6 +166,20 @@ pub struct GetAddressInfo {
     pub labels: Vec<String>,
 }
 
+impl GetAddressInfo {
+    /// Returns true if this is a taproot (witness version 1) address.
+    pub fn is_taproot(&self) -> bool {
+        self.witness_program.as_ref().is_some_and(|program| program.is_p2tr())
+    }
+
+    /// Returns true if the address was derived from a `tr()` descriptor.
+    ///
+    /// Only descriptor wallets report a parent descriptor.
+    pub fn has_taproot_parent_descriptor(&self) -> bool {
+        self.parent_descriptor.as_deref().is_some_and(|desc| desc.starts_with("tr("))
+    }
+}
+
 /// The script field. Part of `getaddressinfo` and `getaddressinfoembedded`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum ScriptType {
@@ -185,6 +199,8 @@ pub enum ScriptType {
     WitnessV0KeyHash,
     /// Witness version 0 script hash output script.
     WitnessV0ScriptHash,
+    /// Witness version 1 taproot output script.
+    WitnessV1Taproot,
     /// Witness unknown for output script.
     WitnessUnknown,
 }
@@ -429,6 +445,23 @@ pub struct LastProcessedBlock {
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetUnconfirmedBalance(pub Amount);
 
+/// Models the result of JSON-RPC method `importdescriptors`.
+///
+/// Core version v0.21 onwards.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptors(pub Vec<ImportDescriptorsResult>);
+
+/// Result of the import of a single descriptor. Part of `importdescriptors`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ImportDescriptorsResult {
+    /// Whether the import was successful.
+    pub success: bool,
+    /// Warning messages, if any.
+    pub warnings: Vec<String>,
+    /// The error message if the import failed.
+    pub error: Option<String>,
+}
+
 /// Models the result of JSON-RPC method `getwalletinfo`.
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct GetWalletInfo {
@@ -702,6 +735,38 @@ pub struct ListUnspentItem {
     pub parent_descriptors: Option<Vec<String>>,
 }
 
+impl ListUnspent {
+    /// Returns the outpoint and output of all coins we have the private keys to spend.
+    pub fn spendable(&self) -> Vec<(OutPoint, TxOut)> {
+        self.0.iter().filter(|u| u.spendable).map(ListUnspentItem::to_outpoint_and_tx_out).collect()
+    }
+
+    /// Returns the outpoint and output of all coins we know how to spend, ignoring the lack of keys.
+    pub fn solvable(&self) -> Vec<(OutPoint, TxOut)> {
+        self.0.iter().filter(|u| u.solvable).map(ListUnspentItem::to_outpoint_and_tx_out).collect()
+    }
+}
+
+impl ListUnspentItem {
+    /// Returns the outpoint of this unspent output.
+    pub fn outpoint(&self) -> OutPoint { OutPoint { txid: self.txid, vout: self.vout } }
+
+    /// Returns the unspent output itself.
+    pub fn tx_out(&self) -> TxOut {
+        TxOut { value: self.amount, script_pubkey: self.script_pubkey.clone() }
+    }
+
+    /// Returns the outpoint and output pair, as used for coin selection and PSBT inputs.
+    pub fn to_outpoint_and_tx_out(&self) -> (OutPoint, TxOut) { (self.outpoint(), self.tx_out()) }
+}
+
+impl From<ListUnspentItem> for (OutPoint, TxOut) {
+    fn from(u: ListUnspentItem) -> Self {
+        let outpoint = u.outpoint();
+        (outpoint, TxOut { value: u.amount, script_pubkey: u.script_pubkey })
+    }
+}
+
 /// Models the result of JSON-RPC method `listwallets`.
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub struct ListWallets(pub Vec<String>);
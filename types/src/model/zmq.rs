@@ -4,3 +4,16 @@
 //!
 //! These structs model the types returned by the JSON-RPC API but have concrete types
 //! and are not specific to a specific version of Bitcoin Core.
+
+use serde::{Deserialize, Serialize};
+
+/// Models an item in the result of JSON-RPC method `getzmqnotifications`.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct GetZmqNotifications {
+    /// Type of notification e.g., `pubhashblock`.
+    pub type_: String,
+    /// Address of the publisher e.g., `tcp://127.0.0.1:28332`.
+    pub address: String,
+    /// Outbound message high water mark. v0.18 and later only.
+    pub hwm: Option<u64>,
+}
@@ -251,21 +251,20 @@ pub fn into_unknown(
 ) -> Result<BTreeMap<psbt::raw::Key, Vec<u8>>, hex::HexToBytesError> {
     let mut map = BTreeMap::default();
     for (k, v) in hash_map.iter() {
-        // FIXME: This is best guess, I (Tobin) don't actually know what
-        // is in the hex string returned by Core.
+        // Core returns the key without the keylen prefix i.e., the key type followed by the key
+        // data, and the value without the valuelen prefix.
         let key = Vec::from_hex(k)?;
         let value = Vec::from_hex(v)?;
 
         // rust-bitcoin separates out the key type.
         let mut p = key.as_slice();
-        let _ = crate::compact_size_decode(&mut p); // Moves p past the keylen integer.
         let type_value = crate::compact_size_decode(&mut p);
 
         // In the next release of rust-bitcoin this is changed to a u64.
         // Yes this looses data - c'est la vie.
         let type_value: u8 = type_value as u8;
 
-        let key = psbt::raw::Key { type_value, key };
+        let key = psbt::raw::Key { type_value, key: p.to_vec() };
         map.insert(key, value);
     }
     Ok(map)
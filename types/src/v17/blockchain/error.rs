@@ -8,6 +8,7 @@ use bitcoin::error::UnprefixedHexError;
 use bitcoin::{address, hex, network};
 
 use crate::error::write_err;
+use crate::v17::GetRawTransactionVerboseError;
 use crate::NumericError;
 
 /// Error when converting a `GetBlockVerboseOne` type into the model type.
@@ -70,6 +71,71 @@ impl From<NumericError> for GetBlockVerboseOneError {
     fn from(e: NumericError) -> Self { Self::Numeric(e) }
 }
 
+/// Error when converting a `GetBlockVerboseTwo` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockVerboseTwoError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the transaction `hash` field failed.
+    Hash(hex::HexToArrayError),
+    /// Conversion of the transaction `merkle_root` field failed.
+    MerkleRoot(hex::HexToArrayError),
+    /// Conversion of the transaction `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the transaction `chain_work` field failed.
+    ChainWork(UnprefixedHexError),
+    /// Conversion of the transaction `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the transaction `next_block_hash` field failed.
+    NextBlockHash(hex::HexToArrayError),
+    /// Conversion of a transaction entry failed.
+    Transaction(GetRawTransactionVerboseError),
+    /// Conversion of the transaction `fee` field failed.
+    Fee(ParseAmountError),
+}
+
+impl fmt::Display for GetBlockVerboseTwoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Numeric(ref e) => write_err!(f, "numeric"; e),
+            Self::Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+            Self::MerkleRoot(ref e) =>
+                write_err!(f, "conversion of the `merkle_root` field failed"; e),
+            Self::Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            Self::ChainWork(ref e) =>
+                write_err!(f, "conversion of the `chain_work` field failed"; e),
+            Self::PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            Self::NextBlockHash(ref e) =>
+                write_err!(f, "conversion of the `next_block_hash` field failed"; e),
+            Self::Transaction(ref e) =>
+                write_err!(f, "conversion of a transaction entry failed"; e),
+            Self::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetBlockVerboseTwoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Self::Numeric(ref e) => Some(e),
+            Self::Hash(ref e) => Some(e),
+            Self::MerkleRoot(ref e) => Some(e),
+            Self::Bits(ref e) => Some(e),
+            Self::ChainWork(ref e) => Some(e),
+            Self::PreviousBlockHash(ref e) => Some(e),
+            Self::NextBlockHash(ref e) => Some(e),
+            Self::Transaction(ref e) => Some(e),
+            Self::Fee(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetBlockVerboseTwoError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
+
 /// Error when converting a `GetBlockchainInfo` type into the model type.
 #[derive(Debug)]
 pub enum GetBlockchainInfoError {
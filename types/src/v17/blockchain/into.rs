@@ -24,7 +24,7 @@ impl GetBestBlockHash {
 impl GetBlockVerboseZero {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockVerboseZero, encode::FromHexError> {
-        let block = encode::deserialize_hex(&self.0)?;
+        let block = self.0.block()?;
         Ok(model::GetBlockVerboseZero(block))
     }
 
@@ -32,6 +32,12 @@ impl GetBlockVerboseZero {
     pub fn block(self) -> Result<Block, encode::FromHexError> { Ok(self.into_model()?.0) }
 }
 
+impl TryFrom<GetBlockVerboseZero> for Block {
+    type Error = encode::FromHexError;
+
+    fn try_from(json: GetBlockVerboseZero) -> Result<Self, Self::Error> { json.block() }
+}
+
 impl GetBlockVerboseOne {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockVerboseOne, GetBlockVerboseOneError> {
@@ -87,6 +93,73 @@ impl GetBlockVerboseOne {
     }
 }
 
+impl GetBlockVerboseTwo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockVerboseTwo, GetBlockVerboseTwoError> {
+        use GetBlockVerboseTwoError as E;
+
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let stripped_size =
+            self.stripped_size.map(|size| crate::to_u32(size, "stripped_size")).transpose()?;
+        let weight = Weight::from_wu(self.weight);
+        let version = block::Version::from_consensus(self.version);
+        let merkle_root = self.merkle_root.parse::<TxMerkleNode>().map_err(E::MerkleRoot)?;
+        let tx = self
+            .tx
+            .into_iter()
+            .map(|entry| {
+                let transaction = entry.transaction.into_model().map_err(E::Transaction)?;
+                let fee = entry.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+                Ok(model::GetBlockVerboseTwoTransaction { transaction, fee })
+            })
+            .collect::<Result<Vec<_>, E>>()?;
+        let median_time = self.median_time.map(|t| crate::to_u32(t, "median_time")).transpose()?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let previous_block_hash = self
+            .previous_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::PreviousBlockHash)?;
+        let next_block_hash = self
+            .next_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::NextBlockHash)?;
+
+        Ok(model::GetBlockVerboseTwo {
+            hash,
+            confirmations: self.confirmations,
+            size: crate::to_u32(self.size, "size")?,
+            stripped_size,
+            weight,
+            coinbase_tx: None,
+            height: crate::to_u32(self.height, "height")?,
+            version,
+            merkle_root,
+            tx,
+            time: crate::to_u32(self.time, "time")?,
+            median_time,
+            nonce: crate::to_u32(self.nonce, "nonce")?,
+            bits,
+            target: None,
+            difficulty: self.difficulty,
+            chain_work,
+            n_tx: crate::to_u32(self.n_tx, "n_tx")?,
+            previous_block_hash,
+            next_block_hash,
+        })
+    }
+}
+
+impl TryFrom<GetBlockVerboseTwo> for Block {
+    type Error = GetBlockVerboseTwoError;
+
+    fn try_from(json: GetBlockVerboseTwo) -> Result<Self, Self::Error> {
+        Ok(json.into_model()?.into())
+    }
+}
+
 impl GetBlockchainInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockchainInfo, GetBlockchainInfoError> {
@@ -175,6 +248,12 @@ impl GetBlockHeader {
     }
 }
 
+impl TryFrom<GetBlockHeader> for block::Header {
+    type Error = GetBlockHeaderError;
+
+    fn try_from(json: GetBlockHeader) -> Result<Self, Self::Error> { json.block_header() }
+}
+
 impl GetBlockHeaderVerbose {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockHeaderVerbose, GetBlockHeaderVerboseError> {
@@ -184,7 +263,7 @@ impl GetBlockHeaderVerbose {
         let version = block::Version::from_consensus(self.version);
         let merkle_root = self.merkle_root.parse::<TxMerkleNode>().map_err(E::MerkleRoot)?;
         let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
-        let chain_work = Work::from_unprefixed_hex(&self.bits).map_err(E::ChainWork)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
         let previous_block_hash = self
             .previous_block_hash
             .map(|s| s.parse::<BlockHash>().map_err(E::PreviousBlockHash))
@@ -214,7 +293,15 @@ impl GetBlockHeaderVerbose {
     }
 
     /// Converts json straight to a `bitcoin::BlockHeader`.
-    pub fn block_header(self) -> Result<block::Header, hex::HexToArrayError> { todo!() }
+    pub fn block_header(self) -> Result<block::Header, GetBlockHeaderVerboseError> {
+        Ok(self.into_model()?.block_header())
+    }
+}
+
+impl TryFrom<GetBlockHeaderVerbose> for block::Header {
+    type Error = GetBlockHeaderVerboseError;
+
+    fn try_from(json: GetBlockHeaderVerbose) -> Result<Self, Self::Error> { json.block_header() }
 }
 
 impl GetBlockStats {
@@ -13,7 +13,8 @@ use serde::{Deserialize, Serialize};
 
 // TODO: Remove wildcard, use explicit types.
 pub use self::error::*;
-use crate::{model, ScriptPubKey};
+use crate::v17::GetRawTransactionVerbose;
+use crate::{model, HexBytes, ScriptPubKey};
 
 /// Result of JSON-RPC method `getbestblockhash`.
 ///
@@ -39,7 +40,7 @@ pub struct GetBestBlockHash(pub String);
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockVerboseZero(
     /// A string that is serialized, hex-encoded data for block 'hash'.
-    pub String,
+    pub HexBytes,
 );
 
 /// Result of JSON-RPC method `getblock` with verbosity set to 1.
@@ -94,6 +95,69 @@ pub struct GetBlockVerboseOne {
     pub next_block_hash: Option<String>,
 }
 
+/// Result of JSON-RPC method `getblock` with verbosity set to 2.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct GetBlockVerboseTwo {
+    /// The block hash (same as provided) in RPC call.
+    pub hash: String,
+    /// The number of confirmations, or -1 if the block is not on the main chain.
+    pub confirmations: i64,
+    /// The block size.
+    pub size: i64,
+    /// The block size excluding witness data.
+    #[serde(rename = "strippedsize")]
+    pub stripped_size: Option<i64>,
+    /// The block weight as defined in BIP-141.
+    pub weight: u64,
+    /// The block height or index.
+    pub height: i64,
+    /// The block version.
+    pub version: i32,
+    /// The block version formatted in hexadecimal.
+    #[serde(rename = "versionHex")]
+    pub version_hex: String,
+    /// The merkle root
+    #[serde(rename = "merkleroot")]
+    pub merkle_root: String,
+    /// The transactions.
+    pub tx: Vec<GetBlockVerboseTwoTransaction>,
+    /// The block time expressed in UNIX epoch time.
+    pub time: i64,
+    /// The median block time expressed in UNIX epoch time.
+    #[serde(rename = "mediantime")]
+    pub median_time: Option<i64>,
+    /// The nonce (this should be only 4 bytes).
+    pub nonce: i64,
+    /// The bits.
+    pub bits: String,
+    /// The difficulty.
+    pub difficulty: f64,
+    /// Expected number of hashes required to produce the chain up to this block (in hex).
+    #[serde(rename = "chainwork")]
+    pub chain_work: String,
+    /// The number of transactions in the block.
+    #[serde(rename = "nTx")]
+    pub n_tx: i64,
+    /// The hash of the previous block (if available).
+    #[serde(rename = "previousblockhash")]
+    pub previous_block_hash: Option<String>,
+    /// The hash of the next block (if available).
+    #[serde(rename = "nextblockhash")]
+    pub next_block_hash: Option<String>,
+}
+
+/// A transaction entry for `getblock` verbosity 2.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct GetBlockVerboseTwoTransaction {
+    /// The transaction data (same as `getrawtransaction` verbose output).
+    #[serde(flatten)]
+    pub transaction: GetRawTransactionVerbose,
+    /// The transaction fee in BTC (v22 and later, omitted if block undo data is not available).
+    pub fee: Option<f64>,
+}
+
 /// Result of JSON-RPC method `getblockchaininfo`.
 ///
 /// > getblockchaininfo
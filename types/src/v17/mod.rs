@@ -101,7 +101,7 @@
 //! | getconnectioncount                 | version         |                                        |
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -216,7 +216,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -238,7 +238,8 @@ pub use self::{
         Bip9Softfork, Bip9SoftforkStatus, ChainTips, ChainTipsError, ChainTipsStatus,
         GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
         GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError,
-        GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseZero, GetBlockchainInfo,
+        GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseTwo, GetBlockVerboseTwoError,
+        GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetBlockchainInfo,
         GetBlockchainInfoError, GetChainTips, GetChainTxStats, GetChainTxStatsError, GetDifficulty,
         GetMempoolAncestors, GetMempoolAncestorsVerbose, GetMempoolDescendants,
         GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo, GetMempoolInfoError,
@@ -267,8 +268,8 @@ pub use self::{
     raw_transactions::{
         CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreatePsbt, CreateRawTransaction,
         DecodePsbt, DecodePsbtError, DecodeRawTransaction, DecodeScript, DecodeScriptError,
-        DecodeScriptSegwit, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
-        FundRawTransactionError, GetRawTransaction, GetRawTransactionVerbose,
+        DecodeScriptSegwit, DecodeScriptSegwitError, FinalizePsbt, FinalizePsbtError,
+        FundRawTransaction, FundRawTransactionError, GetRawTransaction, GetRawTransactionVerbose,
         GetRawTransactionVerboseError, MempoolAcceptance, PsbtInput, PsbtInputError, PsbtOutput,
         PsbtOutputError, SendRawTransaction, SignFail, SignFailError, SignRawTransaction,
         SignRawTransactionError, SignRawTransactionWithKey, TestMempoolAccept,
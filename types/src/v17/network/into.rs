@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: CC0-1.0
 
 use super::error::GetNetworkInfoError;
-use super::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork};
+use super::{GetNetworkInfo, GetNetworkInfoAddress, GetNetworkInfoNetwork, GetPeerInfo, PeerInfo};
 use crate::model;
 
 impl GetNetworkInfo {
@@ -52,3 +52,46 @@ impl GetNetworkInfoAddress {
         model::GetNetworkInfoAddress { address: self.address, port: self.port, score: self.score }
     }
 }
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetPeerInfo {
+        model::GetPeerInfo(self.0.into_iter().map(|p| p.into_model()).collect())
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::PeerInfo {
+        model::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: Some(self.address_bind),
+            address_local: self.address_local,
+            network: None,
+            services: self.services,
+            services_names: None,
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            starting_height: Some(self.starting_height),
+            synced_headers: Some(self.synced_headers),
+            synced_blocks: Some(self.synced_blocks),
+            permissions: None,
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: None,
+            transport_protocol_type: None,
+        }
+    }
+}
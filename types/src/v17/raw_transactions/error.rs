@@ -172,6 +172,8 @@ pub enum DecodeScriptError {
     Addresses(address::ParseError),
     /// Conversion of the transaction `p2sh` field failed.
     P2sh(address::ParseError),
+    /// Conversion of the transaction `segwit` field failed.
+    Segwit(DecodeScriptSegwitError),
 }
 
 impl fmt::Display for DecodeScriptError {
@@ -181,6 +183,7 @@ impl fmt::Display for DecodeScriptError {
             Self::Addresses(ref e) =>
                 write_err!(f, "conversion of the `addresses` field failed"; e),
             Self::P2sh(ref e) => write_err!(f, "conversion of the `p2sh` field failed"; e),
+            Self::Segwit(ref e) => write_err!(f, "conversion of the `segwit` field failed"; e),
         }
     }
 }
@@ -192,6 +195,49 @@ impl std::error::Error for DecodeScriptError {
             Self::Hex(ref e) => Some(e),
             Self::Addresses(ref e) => Some(e),
             Self::P2sh(ref e) => Some(e),
+            Self::Segwit(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<DecodeScriptSegwitError> for DecodeScriptError {
+    fn from(e: DecodeScriptSegwitError) -> Self { Self::Segwit(e) }
+}
+
+/// Error when converting a `DecodeScriptSegwit` type into the model type.
+#[derive(Debug)]
+pub enum DecodeScriptSegwitError {
+    /// Conversion of the transaction `hex` field failed.
+    Hex(hex::HexToBytesError),
+    /// Conversion of the transaction `address` field failed.
+    Address(address::ParseError),
+    /// Conversion of the transaction `addresses` field failed.
+    Addresses(address::ParseError),
+    /// Conversion of the transaction `p2sh-segwit` field failed.
+    P2shSegwit(address::ParseError),
+}
+
+impl fmt::Display for DecodeScriptSegwitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Hex(ref e) => write_err!(f, "conversion of the `hex` field failed"; e),
+            Self::Address(ref e) => write_err!(f, "conversion of the `address` field failed"; e),
+            Self::Addresses(ref e) =>
+                write_err!(f, "conversion of the `addresses` field failed"; e),
+            Self::P2shSegwit(ref e) =>
+                write_err!(f, "conversion of the `p2sh-segwit` field failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeScriptSegwitError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Self::Hex(ref e) => Some(e),
+            Self::Address(ref e) => Some(e),
+            Self::Addresses(ref e) => Some(e),
+            Self::P2shSegwit(ref e) => Some(e),
         }
     }
 }
@@ -11,10 +11,11 @@ use bitcoin::{
 use super::{
     CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreatePsbt, CreateRawTransaction,
     DecodePsbt, DecodePsbtError, DecodeRawTransaction, DecodeScript, DecodeScriptError,
-    FinalizePsbt, FinalizePsbtError, FundRawTransaction, FundRawTransactionError,
-    GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError, MempoolAcceptance,
-    PsbtInput, PsbtInputError, PsbtOutput, PsbtOutputError, SendRawTransaction, SignFail,
-    SignFailError, SignRawTransaction, SignRawTransactionError, TestMempoolAccept,
+    DecodeScriptSegwit, DecodeScriptSegwitError, FinalizePsbt, FinalizePsbtError,
+    FundRawTransaction, FundRawTransactionError, GetRawTransaction, GetRawTransactionVerbose,
+    GetRawTransactionVerboseError, MempoolAcceptance, PsbtInput, PsbtInputError, PsbtOutput,
+    PsbtOutputError, SendRawTransaction, SignFail, SignFailError, SignRawTransaction,
+    SignRawTransactionError, TestMempoolAccept,
 };
 use crate::model;
 use crate::psbt::RawTransactionError;
@@ -282,6 +283,12 @@ impl DecodeRawTransaction {
     }
 }
 
+impl TryFrom<DecodeRawTransaction> for Transaction {
+    type Error = RawTransactionError;
+
+    fn try_from(json: DecodeRawTransaction) -> Result<Self, Self::Error> { json.transaction() }
+}
+
 impl DecodeScript {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::DecodeScript, DecodeScriptError> {
@@ -300,6 +307,7 @@ impl DecodeScript {
             None => vec![],
         };
         let p2sh = self.p2sh.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::P2sh)?;
+        let segwit = self.segwit.map(|s| s.into_model()).transpose()?;
 
         Ok(model::DecodeScript {
             script_pubkey,
@@ -309,11 +317,41 @@ impl DecodeScript {
             required_signatures: self.required_signatures,
             addresses,
             p2sh,
+            segwit,
             p2sh_segwit: self.p2sh_segwit,
         })
     }
 }
 
+impl DecodeScriptSegwit {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeScriptSegwit, DecodeScriptSegwitError> {
+        use DecodeScriptSegwitError as E;
+
+        let script_pubkey = ScriptBuf::from_hex(&self.hex).map_err(E::Hex)?;
+        let addresses = match self.addresses {
+            Some(addresses) => addresses
+                .iter()
+                .map(|s| s.parse::<Address<_>>())
+                .collect::<Result<_, _>>()
+                .map_err(E::Addresses)?,
+            None => vec![],
+        };
+        let p2sh_segwit =
+            self.p2sh_segwit.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::P2shSegwit)?;
+
+        Ok(model::DecodeScriptSegwit {
+            script_pubkey,
+            type_: self.type_,
+            address: None,
+            required_signatures: self.required_signatures,
+            addresses,
+            descriptor: None,
+            p2sh_segwit,
+        })
+    }
+}
+
 impl FinalizePsbt {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::FinalizePsbt, FinalizePsbtError> {
@@ -350,7 +388,7 @@ impl FundRawTransaction {
 impl GetRawTransaction {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetRawTransaction, consensus::encode::FromHexError> {
-        let tx: Transaction = consensus::encode::deserialize_hex(&self.0)?;
+        let tx = self.0.transaction()?;
         Ok(model::GetRawTransaction(tx))
     }
 
@@ -361,6 +399,12 @@ impl GetRawTransaction {
     }
 }
 
+impl TryFrom<GetRawTransaction> for Transaction {
+    type Error = consensus::encode::FromHexError;
+
+    fn try_from(json: GetRawTransaction) -> Result<Self, Self::Error> { json.transaction() }
+}
+
 impl GetRawTransactionVerbose {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(
@@ -397,6 +441,17 @@ impl GetRawTransactionVerbose {
             block_time: self.block_time,
         })
     }
+
+    /// Converts json straight to a `bitcoin::Transaction`.
+    pub fn transaction(self) -> Result<Transaction, GetRawTransactionVerboseError> {
+        Ok(self.into_model()?.transaction)
+    }
+}
+
+impl TryFrom<GetRawTransactionVerbose> for Transaction {
+    type Error = GetRawTransactionVerboseError;
+
+    fn try_from(json: GetRawTransactionVerbose) -> Result<Self, Self::Error> { json.transaction() }
 }
 
 impl SendRawTransaction {
@@ -11,11 +11,11 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use crate::ScriptSig;
+use crate::{HexBytes, ScriptSig};
 
 #[rustfmt::skip]                // Keep public re-exports separate.
 pub use self::error::{
-    DecodePsbtError, DecodeScriptError, FundRawTransactionError, GetRawTransactionVerboseError,
+    DecodePsbtError, DecodeScriptError, DecodeScriptSegwitError, FundRawTransactionError, GetRawTransactionVerboseError,
     PsbtInputError, PsbtOutputError, SignFailError, SignRawTransactionError, FinalizePsbtError,
 };
 // Re-export types that appear in the public API of this module.
@@ -332,7 +332,7 @@ pub struct FundRawTransaction {
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetRawTransaction(
     /// The serialized, hex-encoded data for 'txid'.
-    pub String,
+    pub HexBytes,
 );
 
 /// Result of JSON-RPC method `getrawtransaction` with verbose set to `true`.
@@ -221,6 +221,7 @@ impl ScriptType {
             V::NullData => M::NullData,
             V::WitnessV0KeyHash => M::WitnessV0KeyHash,
             V::WitnessV0ScriptHash => M::WitnessV0ScriptHash,
+            V::WitnessV1Taproot => M::WitnessV1Taproot,
             V::WitnessUnknown => M::WitnessUnknown,
         }
     }
@@ -284,6 +284,9 @@ pub enum ScriptType {
     /// Witness version 0 script hash output script.
     #[serde(rename = "witness_v0_scripthash")]
     WitnessV0ScriptHash,
+    /// Witness version 1 taproot output script. v22 and later only.
+    #[serde(rename = "witness_v1_taproot")]
+    WitnessV1Taproot,
     /// Witness unknown for output script.
     #[serde(rename = "witness_unknown")]
     WitnessUnknown,
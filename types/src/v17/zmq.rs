@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::model;
+
 /// Result of JSON-RPC method `getzmqnotifications`.
 ///
 ///> getzmqnotifications
@@ -16,7 +18,14 @@ use serde::{Deserialize, Serialize};
 pub struct GetZmqNotifications {
     /// Type of notification.
     #[serde(rename = "type")]
-    type_: String,
+    pub type_: String,
     /// Address of the publisher.
-    address: String,
+    pub address: String,
+}
+
+impl GetZmqNotifications {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetZmqNotifications {
+        model::GetZmqNotifications { type_: self.type_, address: self.address, hwm: None }
+    }
 }
@@ -104,7 +104,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -218,7 +218,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -242,7 +242,7 @@ pub use self::{
     network::{GetNodeAddresses, GetPeerInfo, NodeAddress, PeerInfo},
     raw_transactions::{
         AnalyzePsbt, AnalyzePsbtError, AnalyzePsbtInput, AnalyzePsbtInputMissing,
-        AnalyzePsbtInputMissingError, JoinPsbts, UtxoUpdatePsbt,
+        AnalyzePsbtInputMissingError, JoinPsbts, PsbtRole, UtxoUpdatePsbt,
     },
     util::{DeriveAddresses, GetDescriptorInfo},
     wallet::{
@@ -261,13 +261,14 @@ pub use crate::v17::{
     BumpFee, BumpFeeError, ChainTips, ChainTipsError, ChainTipsStatus, CombinePsbt,
     CombineRawTransaction, ConvertToPsbt, CreateMultisig, CreateMultisigError, CreatePsbt,
     CreateRawTransaction, CreateWallet, DecodePsbt, DecodePsbtError, DecodeRawTransaction,
-    DecodeScript, DecodeScriptError, DecodeScriptSegwit, DumpPrivKey, DumpWallet, EncryptWallet,
-    EstimateRawFee, EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError,
-    FundRawTransaction, FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
-    GetAddressInfoEmbeddedError, GetAddressInfoLabel, GetAddressesByLabel, GetBalance,
-    GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
+    DecodeScript, DecodeScriptError, DecodeScriptSegwit, DecodeScriptSegwitError, DumpPrivKey,
+    DumpWallet, EncryptWallet, EstimateRawFee, EstimateRawFeeError, EstimateSmartFee, FinalizePsbt,
+    FinalizePsbtError, FundRawTransaction, FundRawTransactionError, Generate, GenerateToAddress,
+    GetAddedNodeInfo, GetAddressInfoEmbeddedError, GetAddressInfoLabel, GetAddressesByLabel,
+    GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
     GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError,
     GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError,
+    GetBlockVerboseTwo, GetBlockVerboseTwoError, GetBlockVerboseTwoTransaction,
     GetBlockVerboseZero, GetBlockchainInfo, GetBlockchainInfoError, GetChainTips, GetChainTxStats,
     GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats, GetMempoolInfo,
     GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfo, GetNetworkInfoAddress,
@@ -6,7 +6,7 @@ use bitcoin::Amount;
 
 use super::{
     AnalyzePsbt, AnalyzePsbtError, AnalyzePsbtInput, AnalyzePsbtInputMissing,
-    AnalyzePsbtInputMissingError, JoinPsbts, UtxoUpdatePsbt,
+    AnalyzePsbtInputMissingError, JoinPsbts, PsbtRole, UtxoUpdatePsbt,
 };
 use crate::model;
 
@@ -34,7 +34,7 @@ impl AnalyzePsbt {
             estimated_vsize: self.estimated_vsize,
             estimated_fee_rate,
             fee,
-            next: self.next,
+            next: self.next.into_model(),
         })
     }
 }
@@ -48,11 +48,26 @@ impl AnalyzePsbtInput {
             has_utxo: self.has_utxo,
             is_final: self.is_final,
             missing,
-            next: self.next,
+            next: self.next.map(PsbtRole::into_model),
         })
     }
 }
 
+impl PsbtRole {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::PsbtRole {
+        use model::PsbtRole::*;
+
+        match self {
+            Self::Creator => Creator,
+            Self::Updater => Updater,
+            Self::Signer => Signer,
+            Self::Finalizer => Finalizer,
+            Self::Extractor => Extractor,
+        }
+    }
+}
+
 impl AnalyzePsbtInputMissing {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(
@@ -34,7 +34,7 @@ pub struct AnalyzePsbt {
     /// The transaction fee paid. Shown only if all UTXO slots in the PSBT have been filled.
     pub fee: Option<f64>,
     /// Role of the next person that this psbt needs to go to.
-    pub next: String,
+    pub next: PsbtRole,
 }
 
 /// Represents an input in a PSBT operation. Part of `analyzepsbt`.
@@ -48,7 +48,7 @@ pub struct AnalyzePsbtInput {
     /// Things that are missing that are required to complete this input.
     pub missing: Option<AnalyzePsbtInputMissing>,
     /// Role of the next person that this input needs to go to.
-    pub next: Option<String>,
+    pub next: Option<PsbtRole>,
 }
 
 /// Represents missing elements required to complete an input. Part of `analyzepsbt`.
@@ -67,6 +67,22 @@ pub struct AnalyzePsbtInputMissing {
     pub witness_script: Option<String>,
 }
 
+/// The role of the next person a PSBT or an input needs to go to. Part of `analyzepsbt`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PsbtRole {
+    /// Creates the PSBT.
+    Creator,
+    /// Adds the information needed to sign e.g., UTXOs, scripts and key derivations.
+    Updater,
+    /// Signs the inputs.
+    Signer,
+    /// Builds the final scriptSig and witness of the inputs.
+    Finalizer,
+    /// Extracts the signed transaction.
+    Extractor,
+}
+
 /// Result of JSON-RPC method `joinpsbts`.
 ///
 /// > joinpsbts ["psbt",...]
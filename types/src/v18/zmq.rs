@@ -6,6 +6,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::model;
+
 /// Result of JSON-RPC method `getzmqnotifications`.
 ///
 ///> getzmqnotifications
@@ -22,3 +24,10 @@ pub struct GetZmqNotifications {
     /// Outbound message high water mark.
     pub hwm: u64,
 }
+
+impl GetZmqNotifications {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetZmqNotifications {
+        model::GetZmqNotifications { type_: self.type_, address: self.address, hwm: Some(self.hwm) }
+    }
+}
@@ -2,8 +2,7 @@
 
 use std::collections::BTreeMap;
 
-use bitcoin::hex::{self, FromHex as _};
-use bitcoin::{bip158, Amount, BlockHash, Network, Txid, Work, Wtxid};
+use bitcoin::{bip158, hex, Amount, BlockHash, Network, Txid, Work, Wtxid};
 
 use super::error::{
     GetBlockFilterError, GetBlockchainInfoError, MapMempoolEntryError, MempoolEntryError,
@@ -62,8 +61,8 @@ impl GetBlockFilter {
     pub fn into_model(self) -> Result<model::GetBlockFilter, GetBlockFilterError> {
         use GetBlockFilterError as E;
 
-        let filter = Vec::from_hex(&self.filter).map_err(E::Filter)?;
-        let header = self.header.parse::<bip158::FilterHash>().map_err(E::Header)?;
+        let filter = self.filter.to_bytes().map_err(E::Filter)?;
+        let header = self.header.parse::<bip158::FilterHeader>().map_err(E::Header)?;
         Ok(model::GetBlockFilter { filter, header })
     }
 }
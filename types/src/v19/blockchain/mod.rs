@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 // TODO: Remove wildcard, use explicit types.
 pub use self::error::*;
 use super::{GetChainTxStatsError, GetMempoolInfoError, ScanTxOutSetError, ScanTxOutSetUnspent};
+use crate::HexBytes;
 
 /// Result of JSON-RPC method `getblockchaininfo`.
 ///
@@ -156,7 +157,7 @@ pub struct Bip9SoftforkStatistics {
 #[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
 pub struct GetBlockFilter {
     /// The hex-encoded filter data.
-    pub filter: String,
+    pub filter: HexBytes,
     /// The hex-encoded filter header.
     pub header: String,
 }
@@ -104,7 +104,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -220,7 +220,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -257,13 +257,14 @@ pub use crate::v17::{
     ChainTipsError, ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt,
     CreateMultisig, CreateMultisigError, CreatePsbt, CreateRawTransaction, CreateWallet,
     DecodePsbt, DecodePsbtError, DecodeRawTransaction, DecodeScript, DecodeScriptError,
-    DecodeScriptSegwit, DumpPrivKey, DumpWallet, EncryptWallet, EstimateRawFee,
-    EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
-    FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
+    DecodeScriptSegwit, DecodeScriptSegwitError, DumpPrivKey, DumpWallet, EncryptWallet,
+    EstimateRawFee, EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError,
+    FundRawTransaction, FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
     GetAddressInfoEmbeddedError, GetAddressInfoLabel, GetAddressesByLabel, GetBalance,
     GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError,
     GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError,
     GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError,
+    GetBlockVerboseTwo, GetBlockVerboseTwoError, GetBlockVerboseTwoTransaction,
     GetBlockVerboseZero, GetChainTips, GetChainTxStatsError, GetConnectionCount, GetDifficulty,
     GetMemoryInfoStats, GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfoAddress,
     GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress,
@@ -293,6 +294,6 @@ pub use crate::v18::{
     GetAddressInfoError, GetNodeAddresses, GetReceivedByLabel, GetZmqNotifications, ImportMulti,
     ImportMultiEntry, JoinPsbts, JsonRpcError, ListReceivedByAddress, ListReceivedByAddressItem,
     ListReceivedByLabel, ListReceivedByLabelError, ListReceivedByLabelItem, ListUnspent,
-    ListUnspentItem, ListWalletDir, ListWalletDirWallet, NodeAddress, ScanTxOutSetUnspent,
-    UtxoUpdatePsbt,
+    ListUnspentItem, ListWalletDir, ListWalletDirWallet, NodeAddress, PsbtRole,
+    ScanTxOutSetUnspent, UtxoUpdatePsbt,
 };
@@ -105,7 +105,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -221,7 +221,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -252,20 +252,22 @@ pub use crate::{
         BlockTemplateTransactionError, BumpFee, BumpFeeError, ChainTips, ChainTipsError,
         ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreateMultisigError,
         CreatePsbt, CreateRawTransaction, CreateWallet, DecodePsbt, DecodePsbtError,
-        DecodeRawTransaction, DecodeScript, DecodeScriptError, DecodeScriptSegwit, DumpPrivKey,
-        DumpWallet, EncryptWallet, EstimateRawFee, EstimateRawFeeError, EstimateSmartFee,
-        FinalizePsbt, FinalizePsbtError, FundRawTransaction, FundRawTransactionError, Generate,
-        GenerateToAddress, GetAddedNodeInfo, GetAddressInfoEmbeddedError, GetAddressesByLabel,
-        GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader,
-        GetBlockHeaderError, GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats,
-        GetBlockStatsError, GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne,
-        GetBlockVerboseOneError, GetBlockVerboseZero, GetChainTips, GetChainTxStatsError,
-        GetConnectionCount, GetDifficulty, GetMemoryInfoStats, GetMempoolInfoError, GetMiningInfo,
-        GetNetTotals, GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork,
-        GetNewAddress, GetRawChangeAddress, GetRawTransaction, GetRawTransactionVerbose,
-        GetRawTransactionVerboseError, GetReceivedByAddress, GetTransactionDetailError,
-        GetTransactionError, GetTxOut, GetTxOutError, GetTxOutSetInfo, GetTxOutSetInfoError,
-        GetUnconfirmedBalance, GetWalletInfoError, ListAddressGroupings, ListAddressGroupingsError,
+        DecodeRawTransaction, DecodeScript, DecodeScriptError, DecodeScriptSegwit,
+        DecodeScriptSegwitError, DumpPrivKey, DumpWallet, EncryptWallet, EstimateRawFee,
+        EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
+        FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
+        GetAddressInfoEmbeddedError, GetAddressesByLabel, GetBalance, GetBestBlockHash,
+        GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError, GetBlockHeaderVerbose,
+        GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError, GetBlockTemplate,
+        GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseTwo,
+        GetBlockVerboseTwoError, GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetChainTips,
+        GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats,
+        GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfoAddress,
+        GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress,
+        GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
+        GetReceivedByAddress, GetTransactionDetailError, GetTransactionError, GetTxOut,
+        GetTxOutError, GetTxOutSetInfo, GetTxOutSetInfoError, GetUnconfirmedBalance,
+        GetWalletInfoError, ListAddressGroupings, ListAddressGroupingsError,
         ListAddressGroupingsItem, ListLabels, ListLockUnspent, ListLockUnspentItem,
         ListLockUnspentItemError, ListReceivedByAddressError, ListUnspentItemError, ListWallets,
         LoadWallet, LockUnspent, Locked, MempoolAcceptance, NumericError, PartialSignatureError,
@@ -287,7 +289,7 @@ pub use crate::{
         GetReceivedByLabel, GetZmqNotifications, ImportMulti, ImportMultiEntry, JoinPsbts,
         JsonRpcError, ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
         ListReceivedByLabelError, ListReceivedByLabelItem, ListUnspent, ListUnspentItem,
-        ListWalletDir, ListWalletDirWallet, NodeAddress, UtxoUpdatePsbt,
+        ListWalletDir, ListWalletDirWallet, NodeAddress, PsbtRole, UtxoUpdatePsbt,
     },
     v19::{
         Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalances,
@@ -2,13 +2,13 @@
 
 use alloc::collections::BTreeMap;
 
-use bitcoin::{hex, BlockHash, Network, Txid, Work, Wtxid};
+use bitcoin::{hex, Amount, BlockHash, Network, Txid, Work, Wtxid};
 
 use super::{
     GetBlockchainInfo, GetBlockchainInfoError, GetMempoolAncestors, GetMempoolAncestorsVerbose,
     GetMempoolDescendants, GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo,
     GetMempoolInfoError, GetRawMempool, GetRawMempoolSequence, GetRawMempoolVerbose,
-    MapMempoolEntryError, MempoolEntry, MempoolEntryError,
+    GetTxOutSetInfo, GetTxOutSetInfoError, MapMempoolEntryError, MempoolEntry, MempoolEntryError,
 };
 use crate::model;
 
@@ -217,3 +217,33 @@ impl GetRawMempoolSequence {
         Ok(model::GetRawMempoolSequence { txids, mempool_sequence: self.mempool_sequence })
     }
 }
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let transactions = Some(crate::to_u32(self.transactions, "transactions")?);
+        let tx_outs = crate::to_u32(self.tx_outs, "tx_outs")?;
+        let bogo_size = crate::to_u32(self.bogo_size, "bogo_size")?;
+        let disk_size = Some(crate::to_u32(self.disk_size, "disk_size")?);
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+
+        Ok(model::GetTxOutSetInfo {
+            height,
+            best_block,
+            transactions,
+            tx_outs,
+            bogo_size,
+            hash_serialized_2: self.hash_serialized_2, // TODO: Convert this to a hash type.
+            hash_serialized_3: None,                   // v26 and later only.
+            disk_size,
+            total_amount,
+            muhash: self.muhash,
+            total_unspendable_amount: None, // v22 and later only.
+            block_info: None,               // v22 and later only.
+        })
+    }
+}
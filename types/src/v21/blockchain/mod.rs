@@ -12,7 +12,7 @@ use serde::{Deserialize, Serialize};
 
 pub use super::{
     Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBlockchainInfoError, GetMempoolInfoError,
-    MapMempoolEntryError, MempoolEntryError, MempoolEntryFees,
+    GetTxOutSetInfoError, MapMempoolEntryError, MempoolEntryError, MempoolEntryFees,
 };
 
 /// Result of JSON-RPC method `getblockchaininfo`.
@@ -276,3 +276,38 @@ pub struct GetRawMempoolSequence {
     /// The mempool sequence value.
     pub mempool_sequence: u64,
 }
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo ( "hash_type" )
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time.
+/// >
+/// > Arguments:
+/// > 1. hash_type    (string, optional, default=hash_serialized_2) Which UTXO set hash should be calculated. Options: 'hash_serialized_2' (the legacy algorithm), 'muhash', 'none'.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct GetTxOutSetInfo {
+    /// The current block height (index).
+    pub height: i64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of transactions with unspent outputs.
+    pub transactions: i64,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: i64,
+    /// A meaningless metric for UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: i64,
+    /// The serialized hash (only present if 'hash_serialized_2' hash_type is chosen).
+    pub hash_serialized_2: Option<String>,
+    /// The serialized hash (only present if 'muhash' hash_type is chosen).
+    pub muhash: Option<String>,
+    /// The estimated size of the chainstate on disk.
+    pub disk_size: i64,
+    /// The total amount.
+    pub total_amount: f64,
+}
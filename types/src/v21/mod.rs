@@ -106,7 +106,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -181,7 +181,7 @@
 //! | getunconfirmedbalance              | version + model |                                        |
 //! | getwalletinfo                      | version + model |                                        |
 //! | importaddress                      | returns nothing |                                        |
-//! | importdescriptors                  | version         |                                        |
+//! | importdescriptors                  | version + model |                                        |
 //! | importmulti                        | version         |                                        |
 //! | importprivkey                      | returns nothing |                                        |
 //! | importprunedfunds                  | returns nothing |                                        |
@@ -227,7 +227,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -245,12 +245,12 @@ pub use self::{
     blockchain::{
         Bip9SoftforkInfo, GetBlockchainInfo, GetMempoolAncestors, GetMempoolAncestorsVerbose,
         GetMempoolDescendants, GetMempoolDescendantsVerbose, GetMempoolEntry, GetMempoolInfo,
-        GetRawMempool, GetRawMempoolSequence, GetRawMempoolVerbose, MempoolEntry, Softfork,
-        SoftforkType,
+        GetRawMempool, GetRawMempoolSequence, GetRawMempoolVerbose, GetTxOutSetInfo, MempoolEntry,
+        Softfork, SoftforkType,
     },
     generating::GenerateBlock,
     hidden::AddPeerAddress,
-    network::{GetNetworkInfo, GetPeerInfo, PeerInfo},
+    network::{ConnectionType, GetNetworkInfo, GetPeerInfo, NetworkType, PeerInfo},
     raw_transactions::{
         MempoolAcceptance, MempoolAcceptanceError, MempoolAcceptanceFees, TestMempoolAccept,
         TestMempoolAcceptError,
@@ -270,33 +270,35 @@ pub use crate::{
         BlockTemplateTransactionError, BumpFee, BumpFeeError, ChainTips, ChainTipsError,
         ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreateMultisigError,
         CreatePsbt, CreateRawTransaction, CreateWallet, DecodePsbt, DecodePsbtError,
-        DecodeRawTransaction, DecodeScript, DecodeScriptError, DecodeScriptSegwit, DumpPrivKey,
-        DumpWallet, EncryptWallet, EstimateRawFee, EstimateRawFeeError, EstimateSmartFee,
-        FinalizePsbt, FinalizePsbtError, FundRawTransaction, FundRawTransactionError, Generate,
-        GenerateToAddress, GetAddedNodeInfo, GetAddressInfoEmbeddedError, GetAddressesByLabel,
-        GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader,
-        GetBlockHeaderError, GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats,
-        GetBlockStatsError, GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne,
-        GetBlockVerboseOneError, GetBlockVerboseZero, GetChainTips, GetChainTxStatsError,
-        GetConnectionCount, GetDifficulty, GetMemoryInfoStats, GetMempoolInfoError, GetMiningInfo,
-        GetNetTotals, GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork,
-        GetNewAddress, GetRawChangeAddress, GetRawTransaction, GetRawTransactionVerbose,
-        GetRawTransactionVerboseError, GetReceivedByAddress, GetTransactionDetailError,
-        GetTransactionError, GetTxOut, GetTxOutError, GetTxOutSetInfo, GetTxOutSetInfoError,
-        GetUnconfirmedBalance, GetWalletInfoError, ListAddressGroupings, ListAddressGroupingsError,
-        ListAddressGroupingsItem, ListLabels, ListLockUnspent, ListLockUnspentItem,
-        ListLockUnspentItemError, ListReceivedByAddressError, ListUnspentItemError, ListWallets,
-        LoadWallet, LockUnspent, Locked, NumericError, PartialSignatureError, PruneBlockchain,
-        PsbtInput, PsbtInputError, PsbtOutput, PsbtOutputError, RawFeeDetail, RawFeeRange,
-        RawTransactionError, RawTransactionInput, RawTransactionOutput, RescanBlockchain,
-        ScanTxOutSetAbort, ScanTxOutSetError, ScanTxOutSetStatus, ScriptType, SendRawTransaction,
-        SendToAddress, SetNetworkActive, SetTxFee, SignFail, SignFailError, SignMessage,
-        SignMessageWithPrivKey, SignRawTransaction, SignRawTransactionError,
-        SignRawTransactionWithKey, SignRawTransactionWithWallet, SoftforkReject,
-        TransactionCategory, UploadTarget, ValidateAddress, ValidateAddressError, VerifyChain,
-        VerifyMessage, VerifyTxOutProof, WaitForBlock, WaitForBlockError, WaitForBlockHeight,
-        WaitForBlockHeightError, WaitForNewBlock, WaitForNewBlockError, WalletCreateFundedPsbt,
-        WalletCreateFundedPsbtError, WalletProcessPsbt, WitnessUtxo, WitnessUtxoError,
+        DecodeRawTransaction, DecodeScript, DecodeScriptError, DecodeScriptSegwit,
+        DecodeScriptSegwitError, DumpPrivKey, DumpWallet, EncryptWallet, EstimateRawFee,
+        EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
+        FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
+        GetAddressInfoEmbeddedError, GetAddressesByLabel, GetBalance, GetBestBlockHash,
+        GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError, GetBlockHeaderVerbose,
+        GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError, GetBlockTemplate,
+        GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseTwo,
+        GetBlockVerboseTwoError, GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetChainTips,
+        GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats,
+        GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfoAddress,
+        GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress,
+        GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
+        GetReceivedByAddress, GetTransactionDetailError, GetTransactionError, GetTxOut,
+        GetTxOutError, GetTxOutSetInfoError, GetUnconfirmedBalance, GetWalletInfoError,
+        ListAddressGroupings, ListAddressGroupingsError, ListAddressGroupingsItem, ListLabels,
+        ListLockUnspent, ListLockUnspentItem, ListLockUnspentItemError, ListReceivedByAddressError,
+        ListUnspentItemError, ListWallets, LoadWallet, LockUnspent, Locked, NumericError,
+        PartialSignatureError, PruneBlockchain, PsbtInput, PsbtInputError, PsbtOutput,
+        PsbtOutputError, RawFeeDetail, RawFeeRange, RawTransactionError, RawTransactionInput,
+        RawTransactionOutput, RescanBlockchain, ScanTxOutSetAbort, ScanTxOutSetError,
+        ScanTxOutSetStatus, ScriptType, SendRawTransaction, SendToAddress, SetNetworkActive,
+        SetTxFee, SignFail, SignFailError, SignMessage, SignMessageWithPrivKey, SignRawTransaction,
+        SignRawTransactionError, SignRawTransactionWithKey, SignRawTransactionWithWallet,
+        SoftforkReject, TransactionCategory, UploadTarget, ValidateAddress, ValidateAddressError,
+        VerifyChain, VerifyMessage, VerifyTxOutProof, WaitForBlock, WaitForBlockError,
+        WaitForBlockHeight, WaitForBlockHeightError, WaitForNewBlock, WaitForNewBlockError,
+        WalletCreateFundedPsbt, WalletCreateFundedPsbtError, WalletProcessPsbt, WitnessUtxo,
+        WitnessUtxoError,
     },
     v18::{
         ActiveCommand, AnalyzePsbt, AnalyzePsbtError, AnalyzePsbtInput, AnalyzePsbtInputMissing,
@@ -304,7 +306,7 @@ pub use crate::{
         GetReceivedByLabel, GetZmqNotifications, ImportMulti, ImportMultiEntry, JoinPsbts,
         JsonRpcError, ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
         ListReceivedByLabelError, ListReceivedByLabelItem, ListUnspent, ListUnspentItem,
-        ListWalletDir, ListWalletDirWallet, NodeAddress, UtxoUpdatePsbt,
+        ListWalletDir, ListWalletDirWallet, NodeAddress, PsbtRole, UtxoUpdatePsbt,
     },
     v19::{
         Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalances, GetBalancesError, GetBalancesMine,
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use super::{GetNetworkInfo, GetNetworkInfoError};
+use super::{
+    ConnectionType, GetNetworkInfo, GetNetworkInfoError, GetPeerInfo, NetworkType, PeerInfo,
+};
 use crate::model;
 
 impl GetNetworkInfo {
@@ -31,3 +33,80 @@ impl GetNetworkInfo {
         })
     }
 }
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetPeerInfo {
+        model::GetPeerInfo(self.0.into_iter().map(|p| p.into_model()).collect())
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::PeerInfo {
+        model::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: Some(self.address_bind),
+            address_local: self.address_local,
+            network: self.network.map(|n| n.into_model()),
+            services: self.services,
+            services_names: Some(self.services_names),
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            starting_height: Some(self.starting_height),
+            synced_headers: Some(self.synced_headers),
+            synced_blocks: Some(self.synced_blocks),
+            permissions: Some(self.permissions),
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: self.connection_type.map(|t| t.into_model()),
+            transport_protocol_type: None,
+        }
+    }
+}
+
+impl NetworkType {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::NetworkType {
+        use model::NetworkType as M; // M for model.
+        use NetworkType as V; // V for version specific.
+
+        match self {
+            V::Ipv4 => M::Ipv4,
+            V::Ipv6 => M::Ipv6,
+            V::Onion => M::Onion,
+            V::I2p => M::I2p,
+            V::Cjdns => M::Cjdns,
+            V::NotPubliclyRoutable => M::NotPubliclyRoutable,
+        }
+    }
+}
+
+impl ConnectionType {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ConnectionType {
+        use model::ConnectionType as M; // M for model.
+        use ConnectionType as V; // V for version specific.
+
+        match self {
+            V::OutboundFullRelay => M::OutboundFullRelay,
+            V::BlockRelayOnly => M::BlockRelayOnly,
+            V::Inbound => M::Inbound,
+            V::Manual => M::Manual,
+            V::AddrFetch => M::AddrFetch,
+            V::Feeler => M::Feeler,
+        }
+    }
+}
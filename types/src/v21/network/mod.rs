@@ -88,7 +88,7 @@ pub struct PeerInfo {
     #[serde(rename = "addrlocal")]
     pub address_local: Option<String>,
     /// Network (ipv4, ipv6, or onion) the peer connected through.
-    pub network: Option<String>,
+    pub network: Option<NetworkType>,
     /// The services offered.
     pub services: String,
     /// The services offered, in human-readable form.
@@ -167,5 +167,41 @@ pub struct PeerInfo {
     #[serde(rename = "bytesrecv_per_msg")]
     pub bytes_received_per_message: BTreeMap<String, u64>,
     /// Type of connection.
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
+}
+
+/// The network a peer is connected through. Part of `getpeerinfo`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkType {
+    /// IPv4.
+    Ipv4,
+    /// IPv6.
+    Ipv6,
+    /// Tor onion service.
+    Onion,
+    /// I2P, v22 and later only.
+    I2p,
+    /// CJDNS, v23 and later only.
+    Cjdns,
+    /// Addresses not publicly routable e.g., localhost.
+    NotPubliclyRoutable,
+}
+
+/// Type of connection. Part of `getpeerinfo`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ConnectionType {
+    /// Default automatic connections.
+    OutboundFullRelay,
+    /// Does not relay transactions or addresses.
+    BlockRelayOnly,
+    /// Initiated by the peer.
+    Inbound,
+    /// Added via addnode RPC or -addnode/-connect configuration options.
+    Manual,
+    /// Short-lived automatic connection for soliciting addresses.
+    AddrFetch,
+    /// Short-lived automatic connection for testing addresses.
+    Feeler,
 }
@@ -3,8 +3,9 @@
 use bitcoin::{hex, Txid};
 
 use super::{
-    GetWalletInfo, GetWalletInfoError, GetWalletInfoScanning, PsbtBumpFee, PsbtBumpFeeError, Send,
-    SendError, SendMany, SendManyVerbose, UnloadWallet,
+    GetWalletInfo, GetWalletInfoError, GetWalletInfoScanning, ImportDescriptors,
+    ImportDescriptorsResult, PsbtBumpFee, PsbtBumpFeeError, Send, SendError, SendMany,
+    SendManyVerbose, UnloadWallet,
 };
 use crate::model;
 
@@ -15,6 +16,29 @@ impl UnloadWallet {
     }
 }
 
+impl ImportDescriptors {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportDescriptors {
+        model::ImportDescriptors(self.0.into_iter().map(|r| r.into_model()).collect())
+    }
+}
+
+impl ImportDescriptorsResult {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ImportDescriptorsResult {
+        // The error is a JSON-RPC error object, keep its message.
+        let error = self.error.map(|e| match e.get("message").and_then(|m| m.as_str()) {
+            Some(message) => message.to_string(),
+            None => e.to_string(),
+        });
+        model::ImportDescriptorsResult {
+            success: self.success,
+            warnings: self.warnings.unwrap_or_default(),
+            error,
+        }
+    }
+}
+
 impl PsbtBumpFee {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::PsbtBumpFee, PsbtBumpFeeError> {
@@ -1,6 +1,11 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use super::{GetMempoolInfo, GetMempoolInfoError};
+use bitcoin::{Amount, BlockHash};
+
+use super::{
+    GetMempoolInfo, GetMempoolInfoError, GetTxOutSetInfo, GetTxOutSetInfoBlockInfo,
+    GetTxOutSetInfoError,
+};
 use crate::model;
 
 impl GetMempoolInfo {
@@ -34,3 +39,68 @@ impl GetMempoolInfo {
         })
     }
 }
+
+impl GetTxOutSetInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let height = crate::to_u32(self.height, "height")?;
+        let best_block = self.best_block.parse::<BlockHash>().map_err(E::BestBlock)?;
+        let transactions =
+            self.transactions.map(|v| crate::to_u32(v, "transactions")).transpose()?;
+        let tx_outs = crate::to_u32(self.tx_outs, "tx_outs")?;
+        let bogo_size = crate::to_u32(self.bogo_size, "bogo_size")?;
+        let disk_size = self.disk_size.map(|v| crate::to_u32(v, "disk_size")).transpose()?;
+        let total_amount = Amount::from_btc(self.total_amount).map_err(E::TotalAmount)?;
+        let total_unspendable_amount = self
+            .total_unspendable_amount
+            .map(|v| Amount::from_btc(v).map_err(E::TotalUnspendableAmount))
+            .transpose()?;
+        let block_info = self.block_info.map(|b| b.into_model()).transpose()?;
+
+        Ok(model::GetTxOutSetInfo {
+            height,
+            best_block,
+            transactions,
+            tx_outs,
+            bogo_size,
+            hash_serialized_2: self.hash_serialized_2, // TODO: Convert this to a hash type.
+            hash_serialized_3: None,                   // v26 and later only.
+            disk_size,
+            total_amount,
+            muhash: self.muhash,
+            total_unspendable_amount,
+            block_info,
+        })
+    }
+}
+
+impl GetTxOutSetInfoBlockInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetTxOutSetInfoBlockInfo, GetTxOutSetInfoError> {
+        use GetTxOutSetInfoError as E;
+
+        let prevout_spent = Amount::from_btc(self.prevout_spent).map_err(E::PrevoutSpent)?;
+        let coinbase = Amount::from_btc(self.coinbase).map_err(E::Coinbase)?;
+        let new_outputs_ex_coinbase =
+            Amount::from_btc(self.new_outputs_ex_coinbase).map_err(E::NewOutputsExCoinbase)?;
+        let unspendable = Amount::from_btc(self.unspendable).map_err(E::Unspendable)?;
+        let unspendables = model::GetTxOutSetInfoUnspendables {
+            genesis_block: Amount::from_btc(self.unspendables.genesis_block)
+                .map_err(E::UnspendablesGenesisBlock)?,
+            bip30: Amount::from_btc(self.unspendables.bip30).map_err(E::UnspendablesBip30)?,
+            scripts: Amount::from_btc(self.unspendables.scripts).map_err(E::UnspendablesScripts)?,
+            unclaimed_rewards: Amount::from_btc(self.unspendables.unclaimed_rewards)
+                .map_err(E::UnspendablesUnclaimedRewards)?,
+        };
+
+        Ok(model::GetTxOutSetInfoBlockInfo {
+            prevout_spent,
+            coinbase,
+            new_outputs_ex_coinbase,
+            unspendable,
+            unspendables,
+        })
+    }
+}
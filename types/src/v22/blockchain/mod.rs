@@ -4,10 +4,12 @@
 //!
 //! Types for methods found under the `== Blockchain ==` section of the API docs.
 
+mod error;
 mod into;
 
 use serde::{Deserialize, Serialize};
 
+pub use self::error::GetTxOutSetInfoError;
 pub use super::GetMempoolInfoError;
 
 /// Result of JSON-RPC method `getmempoolinfo` with verbose set to `true`.
@@ -45,3 +47,76 @@ pub struct GetMempoolInfo {
     #[serde(rename = "unbroadcastcount")]
     pub unbroadcast_count: i64,
 }
+
+/// Result of JSON-RPC method `gettxoutsetinfo`.
+///
+/// > gettxoutsetinfo ( "hash_type" hash_or_height use_index )
+/// >
+/// > Returns statistics about the unspent transaction output set.
+/// > Note this call may take some time.
+/// >
+/// > Arguments:
+/// > 1. hash_type         (string, optional, default=hash_serialized_2) Which UTXO set hash should be calculated. Options: 'hash_serialized_2' (the legacy algorithm), 'muhash', 'none'.
+/// > 2. hash_or_height    (string or numeric, optional) The block hash or height of the target height (only available with coinstatsindex).
+/// > 3. use_index         (boolean, optional, default=true) Use coinstatsindex, if available.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct GetTxOutSetInfo {
+    /// The current block height (index).
+    pub height: i64,
+    /// The hash of the block at the tip of the chain.
+    #[serde(rename = "bestblock")]
+    pub best_block: String,
+    /// The number of transactions with unspent outputs (not available when coinstatsindex is used).
+    pub transactions: Option<i64>,
+    /// The number of unspent transaction outputs.
+    #[serde(rename = "txouts")]
+    pub tx_outs: i64,
+    /// A meaningless metric for UTXO set size.
+    #[serde(rename = "bogosize")]
+    pub bogo_size: i64,
+    /// The serialized hash (only present if 'hash_serialized_2' hash_type is chosen).
+    pub hash_serialized_2: Option<String>,
+    /// The serialized hash (only present if 'muhash' hash_type is chosen).
+    pub muhash: Option<String>,
+    /// The estimated size of the chainstate on disk (not available when coinstatsindex is used).
+    pub disk_size: Option<i64>,
+    /// The total amount.
+    pub total_amount: f64,
+    /// The total amount of coins permanently excluded from the UTXO set (only available if coinstatsindex is used).
+    pub total_unspendable_amount: Option<f64>,
+    /// Info on amounts in the block at this block height (only available if coinstatsindex is used).
+    pub block_info: Option<GetTxOutSetInfoBlockInfo>,
+}
+
+/// Detailed block-level info. Part of `gettxoutsetinfo`, only returned when coinstatsindex is used.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct GetTxOutSetInfoBlockInfo {
+    /// Total amount of all prevouts spent in this block.
+    #[serde(rename = "prevout_spent")]
+    pub prevout_spent: f64,
+    /// Coinbase subsidy amount of this block.
+    pub coinbase: f64,
+    /// Total amount of new outputs created by this block.
+    #[serde(rename = "new_outputs_ex_coinbase")]
+    pub new_outputs_ex_coinbase: f64,
+    /// Total amount of unspendable outputs created in this block.
+    pub unspendable: f64,
+    /// Detailed view of unspendable categories.
+    pub unspendables: GetTxOutSetInfoUnspendables,
+}
+
+/// Categories of unspendable amounts. Part of `gettxoutsetinfo`.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct GetTxOutSetInfoUnspendables {
+    /// The unspendable amount of the Genesis block subsidy.
+    pub genesis_block: f64,
+    /// Transactions overridden by duplicates (no longer possible with BIP30).
+    pub bip30: f64,
+    /// Amounts sent to scripts that are unspendable (for example OP_RETURN outputs).
+    pub scripts: f64,
+    /// Fee rewards that miners did not claim in their coinbase transaction.
+    pub unclaimed_rewards: f64,
+}
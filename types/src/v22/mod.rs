@@ -106,7 +106,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -190,7 +190,7 @@
 //! | getunconfirmedbalance              | version + model |                                        |
 //! | getwalletinfo                      | version + model |                                        |
 //! | importaddress                      | returns nothing |                                        |
-//! | importdescriptors                  | version         |                                        |
+//! | importdescriptors                  | version + model |                                        |
 //! | importmulti                        | version         |                                        |
 //! | importprivkey                      | returns nothing |                                        |
 //! | importprunedfunds                  | returns nothing |                                        |
@@ -238,7 +238,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -253,7 +253,10 @@ mod wallet;
 
 #[doc(inline)]
 pub use self::{
-    blockchain::GetMempoolInfo,
+    blockchain::{
+        GetMempoolInfo, GetTxOutSetInfo, GetTxOutSetInfoBlockInfo, GetTxOutSetInfoError,
+        GetTxOutSetInfoUnspendables,
+    },
     control::Logging,
     hidden::AddConnection,
     network::{Banned, GetNodeAddresses, GetPeerInfo, ListBanned, NodeAddress, PeerInfo},
@@ -275,33 +278,34 @@ pub use crate::{
         BlockTemplateTransactionError, BumpFee, BumpFeeError, ChainTips, ChainTipsError,
         ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreateMultisigError,
         CreatePsbt, CreateRawTransaction, CreateWallet, DecodePsbt, DecodePsbtError,
-        DecodeRawTransaction, DumpPrivKey, DumpWallet, EncryptWallet, EstimateRawFee,
-        EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
-        FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
+        DecodeRawTransaction, DecodeScriptSegwitError, DumpPrivKey, DumpWallet, EncryptWallet,
+        EstimateRawFee, EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError,
+        FundRawTransaction, FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
         GetAddressInfoEmbeddedError, GetAddressesByLabel, GetBalance, GetBestBlockHash,
         GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError, GetBlockHeaderVerbose,
         GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError, GetBlockTemplate,
-        GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseZero,
-        GetChainTips, GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats,
+        GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseTwo,
+        GetBlockVerboseTwoError, GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetChainTips,
+        GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats,
         GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfoAddress,
         GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress,
         GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
         GetReceivedByAddress, GetTransactionDetailError, GetTransactionError, GetTxOut,
-        GetTxOutError, GetTxOutSetInfo, GetTxOutSetInfoError, GetUnconfirmedBalance,
-        GetWalletInfoError, ListAddressGroupings, ListAddressGroupingsError,
-        ListAddressGroupingsItem, ListLabels, ListLockUnspent, ListLockUnspentItem,
-        ListLockUnspentItemError, ListReceivedByAddressError, ListUnspentItemError, ListWallets,
-        LoadWallet, LockUnspent, Locked, NumericError, PartialSignatureError, PruneBlockchain,
-        PsbtInput, PsbtInputError, PsbtOutput, PsbtOutputError, RawFeeDetail, RawFeeRange,
-        RawTransactionError, RawTransactionInput, RawTransactionOutput, RescanBlockchain,
-        ScanTxOutSetAbort, ScanTxOutSetError, ScanTxOutSetStatus, ScriptType, SendRawTransaction,
-        SendToAddress, SetNetworkActive, SetTxFee, SignFail, SignFailError, SignMessage,
-        SignMessageWithPrivKey, SignRawTransaction, SignRawTransactionError,
-        SignRawTransactionWithKey, SignRawTransactionWithWallet, SoftforkReject,
-        TransactionCategory, UploadTarget, ValidateAddress, ValidateAddressError, VerifyChain,
-        VerifyMessage, VerifyTxOutProof, WaitForBlock, WaitForBlockError, WaitForBlockHeight,
-        WaitForBlockHeightError, WaitForNewBlock, WaitForNewBlockError, WalletCreateFundedPsbt,
-        WalletCreateFundedPsbtError, WalletProcessPsbt, WitnessUtxo, WitnessUtxoError,
+        GetTxOutError, GetUnconfirmedBalance, GetWalletInfoError, ListAddressGroupings,
+        ListAddressGroupingsError, ListAddressGroupingsItem, ListLabels, ListLockUnspent,
+        ListLockUnspentItem, ListLockUnspentItemError, ListReceivedByAddressError,
+        ListUnspentItemError, ListWallets, LoadWallet, LockUnspent, Locked, NumericError,
+        PartialSignatureError, PruneBlockchain, PsbtInput, PsbtInputError, PsbtOutput,
+        PsbtOutputError, RawFeeDetail, RawFeeRange, RawTransactionError, RawTransactionInput,
+        RawTransactionOutput, RescanBlockchain, ScanTxOutSetAbort, ScanTxOutSetError,
+        ScanTxOutSetStatus, ScriptType, SendRawTransaction, SendToAddress, SetNetworkActive,
+        SetTxFee, SignFail, SignFailError, SignMessage, SignMessageWithPrivKey, SignRawTransaction,
+        SignRawTransactionError, SignRawTransactionWithKey, SignRawTransactionWithWallet,
+        SoftforkReject, TransactionCategory, UploadTarget, ValidateAddress, ValidateAddressError,
+        VerifyChain, VerifyMessage, VerifyTxOutProof, WaitForBlock, WaitForBlockError,
+        WaitForBlockHeight, WaitForBlockHeightError, WaitForNewBlock, WaitForNewBlockError,
+        WalletCreateFundedPsbt, WalletCreateFundedPsbtError, WalletProcessPsbt, WitnessUtxo,
+        WitnessUtxoError,
     },
     v18::{
         ActiveCommand, AnalyzePsbt, AnalyzePsbtError, AnalyzePsbtInput, AnalyzePsbtInputMissing,
@@ -309,7 +313,7 @@ pub use crate::{
         GetZmqNotifications, ImportMulti, ImportMultiEntry, JoinPsbts, JsonRpcError,
         ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
         ListReceivedByLabelError, ListReceivedByLabelItem, ListUnspent, ListUnspentItem,
-        ListWalletDir, ListWalletDirWallet, UtxoUpdatePsbt,
+        ListWalletDir, ListWalletDirWallet, PsbtRole, UtxoUpdatePsbt,
     },
     v19::{
         Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalances, GetBalancesError, GetBalancesMine,
@@ -323,13 +327,13 @@ pub use crate::{
         ScanTxOutSetStart, ScanTxOutSetUnspent, TransactionItem, TransactionItemError,
     },
     v21::{
-        AddPeerAddress, Bip9SoftforkInfo, GenerateBlock, GetBlockchainInfo, GetIndexInfo,
-        GetIndexInfoName, GetMempoolAncestors, GetMempoolAncestorsVerbose, GetMempoolDescendants,
-        GetMempoolDescendantsVerbose, GetMempoolEntry, GetNetworkInfo, GetRawMempool,
-        GetRawMempoolSequence, GetRawMempoolVerbose, GetWalletInfo, GetWalletInfoScanning,
-        ImportDescriptors, ImportDescriptorsResult, MempoolEntry, PsbtBumpFee, PsbtBumpFeeError,
-        Send, SendError, SendMany, SendManyVerbose, Softfork, SoftforkType, UnloadWallet,
-        UpgradeWallet,
+        AddPeerAddress, Bip9SoftforkInfo, ConnectionType, GenerateBlock, GetBlockchainInfo,
+        GetIndexInfo, GetIndexInfoName, GetMempoolAncestors, GetMempoolAncestorsVerbose,
+        GetMempoolDescendants, GetMempoolDescendantsVerbose, GetMempoolEntry, GetNetworkInfo,
+        GetRawMempool, GetRawMempoolSequence, GetRawMempoolVerbose, GetWalletInfo,
+        GetWalletInfoScanning, ImportDescriptors, ImportDescriptorsResult, MempoolEntry,
+        NetworkType, PsbtBumpFee, PsbtBumpFeeError, Send, SendError, SendMany, SendManyVerbose,
+        Softfork, SoftforkType, UnloadWallet, UpgradeWallet,
     },
     ScriptPubKey,
 };
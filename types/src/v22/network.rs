@@ -8,6 +8,9 @@ use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::{ConnectionType, NetworkType};
+use crate::model;
+
 /// Result of JSON-RPC method `getnodeaddresses`.
 ///
 /// > getnodeaddresses ( count "network" )
@@ -60,7 +63,7 @@ pub struct PeerInfo {
     #[serde(rename = "addrlocal")]
     pub address_local: Option<String>,
     /// Network (ipv4, ipv6, or onion) the peer connected through.
-    pub network: Option<String>,
+    pub network: Option<NetworkType>,
     /// The services offered.
     pub services: String,
     /// The services offered, in human-readable form.
@@ -141,7 +144,7 @@ pub struct PeerInfo {
     #[serde(rename = "bytesrecv_per_msg")]
     pub bytes_received_per_message: BTreeMap<String, u64>,
     /// Type of connection.
-    pub connection_type: Option<String>,
+    pub connection_type: Option<ConnectionType>,
 }
 
 /// Result of JSON-RPC method `listbanned`.
@@ -168,3 +171,46 @@ pub struct Banned {
     /// The time remaining until the ban expires, in seconds.
     pub time_remaining: u32,
 }
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetPeerInfo {
+        model::GetPeerInfo(self.0.into_iter().map(|p| p.into_model()).collect())
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::PeerInfo {
+        model::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: Some(self.address_bind),
+            address_local: self.address_local,
+            network: self.network.map(|n| n.into_model()),
+            services: self.services,
+            services_names: Some(self.services_names),
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            starting_height: Some(self.starting_height),
+            synced_headers: Some(self.synced_headers),
+            synced_blocks: Some(self.synced_blocks),
+            permissions: Some(self.permissions),
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: self.connection_type.map(|t| t.into_model()),
+            transport_protocol_type: None,
+        }
+    }
+}
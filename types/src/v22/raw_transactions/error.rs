@@ -6,6 +6,7 @@ use bitcoin::amount::ParseAmountError;
 use bitcoin::{address, hex};
 
 use crate::error::write_err;
+use crate::v17::DecodeScriptSegwitError;
 use crate::NumericError;
 
 /// Error when converting a `DecodeScript` type into the model type.
@@ -19,6 +20,8 @@ pub enum DecodeScriptError {
     Addresses(address::ParseError),
     /// Conversion of the transaction `p2sh` field failed.
     P2sh(address::ParseError),
+    /// Conversion of the transaction `segwit` field failed.
+    Segwit(DecodeScriptSegwitError),
 }
 
 impl fmt::Display for DecodeScriptError {
@@ -29,6 +32,7 @@ impl fmt::Display for DecodeScriptError {
             Self::Addresses(ref e) =>
                 write_err!(f, "conversion of the `addresses` field failed"; e),
             Self::P2sh(ref e) => write_err!(f, "conversion of the `p2sh` field failed"; e),
+            Self::Segwit(ref e) => write_err!(f, "conversion of the `segwit` field failed"; e),
         }
     }
 }
@@ -41,10 +45,15 @@ impl std::error::Error for DecodeScriptError {
             Self::Address(ref e) => Some(e),
             Self::Addresses(ref e) => Some(e),
             Self::P2sh(ref e) => Some(e),
+            Self::Segwit(ref e) => Some(e),
         }
     }
 }
 
+impl From<DecodeScriptSegwitError> for DecodeScriptError {
+    fn from(e: DecodeScriptSegwitError) -> Self { Self::Segwit(e) }
+}
+
 /// Error when converting a `TestMempoolAccept` type into the model type.
 #[derive(Debug)]
 pub enum TestMempoolAcceptError {
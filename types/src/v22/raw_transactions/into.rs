@@ -1,12 +1,13 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use bitcoin::{Address, Amount, Txid, Wtxid};
+use bitcoin::{Address, Amount, ScriptBuf, Txid, Wtxid};
 
 use super::{
-    DecodeScript, DecodeScriptError, MempoolAcceptance, MempoolAcceptanceError, TestMempoolAccept,
-    TestMempoolAcceptError,
+    DecodeScript, DecodeScriptError, DecodeScriptSegwit, MempoolAcceptance, MempoolAcceptanceError,
+    TestMempoolAccept, TestMempoolAcceptError,
 };
 use crate::model;
+use crate::v17::DecodeScriptSegwitError;
 
 impl DecodeScript {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -26,6 +27,7 @@ impl DecodeScript {
             None => vec![],
         };
         let p2sh = self.p2sh.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::P2sh)?;
+        let segwit = self.segwit.map(|s| s.into_model()).transpose()?;
 
         Ok(model::DecodeScript {
             script_pubkey: None,
@@ -35,11 +37,43 @@ impl DecodeScript {
             required_signatures: self.required_signatures,
             addresses,
             p2sh,
+            segwit,
             p2sh_segwit: self.p2sh_segwit,
         })
     }
 }
 
+impl DecodeScriptSegwit {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeScriptSegwit, DecodeScriptSegwitError> {
+        use DecodeScriptSegwitError as E;
+
+        let script_pubkey = ScriptBuf::from_hex(&self.hex).map_err(E::Hex)?;
+        let address =
+            self.address.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::Address)?;
+        let addresses = match self.addresses {
+            Some(addresses) => addresses
+                .iter()
+                .map(|s| s.parse::<Address<_>>())
+                .collect::<Result<_, _>>()
+                .map_err(E::Addresses)?,
+            None => vec![],
+        };
+        let p2sh_segwit =
+            self.p2sh_segwit.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::P2shSegwit)?;
+
+        Ok(model::DecodeScriptSegwit {
+            script_pubkey,
+            type_: self.type_,
+            address,
+            required_signatures: self.required_signatures,
+            addresses,
+            descriptor: None,
+            p2sh_segwit,
+        })
+    }
+}
+
 impl TestMempoolAccept {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::TestMempoolAccept, TestMempoolAcceptError> {
@@ -8,7 +8,7 @@ use super::{
     Bip9Info, Bip9Statistics, DeploymentInfo, GetBlockchainInfo, GetBlockchainInfoError,
     GetDeploymentInfo, GetDeploymentInfoError, GetMempoolAncestors, GetMempoolAncestorsVerbose,
     GetMempoolDescendants, GetMempoolDescendantsVerbose, GetMempoolEntry, GetRawMempool,
-    GetRawMempoolVerbose, MapMempoolEntryError, MempoolEntry, MempoolEntryError,
+    GetRawMempoolVerbose, MapMempoolEntryError, MempoolEntry, MempoolEntryError, SaveMempool,
 };
 use crate::model;
 
@@ -235,3 +235,10 @@ impl GetRawMempoolVerbose {
         Ok(model::GetRawMempoolVerbose(map))
     }
 }
+
+impl SaveMempool {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::SaveMempool {
+        model::SaveMempool { filename: Some(self.filename) }
+    }
+}
@@ -49,7 +49,7 @@
 //! | gettxoutsetinfo                    | version + model |                                        |
 //! | preciousblock                      | returns nothing |                                        |
 //! | pruneblockchain                    | version         |                                        |
-//! | savemempool                        | version         |                                        |
+//! | savemempool                        | version + model |                                        |
 //! | scantxoutset                       | version + model | API marked as experimental             |
 //! | verifychain                        | version         |                                        |
 //! | verifytxoutproof                   | version + model |                                        |
@@ -97,7 +97,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -124,7 +124,7 @@
 //! | getrawtransaction                  | version + model | Includes additional 'verbose' type     |
 //! | joinpsbts                          | version + model |                                        |
 //! | sendrawtransaction                 | version + model |                                        |
-//! | signrawtransactionwithkey          | version + model | UNTESTED                               |
+//! | signrawtransactionwithkey          | version + model |                                        |
 //! | testmempoolaccept                  | version + model |                                        |
 //! | utxoupdatepsbt                     | version + model |                                        |
 //!
@@ -181,7 +181,7 @@
 //! | getunconfirmedbalance              | version + model |                                        |
 //! | getwalletinfo                      | version + model |                                        |
 //! | importaddress                      | returns nothing |                                        |
-//! | importdescriptors                  | version         |                                        |
+//! | importdescriptors                  | version + model |                                        |
 //! | importmulti                        | version         |                                        |
 //! | importprivkey                      | returns nothing |                                        |
 //! | importprunedfunds                  | returns nothing |                                        |
@@ -231,7 +231,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -272,20 +272,21 @@ pub use crate::{
         AddressPurpose, Bip125Replaceable, Bip32DerivError, BlockTemplateTransaction,
         BlockTemplateTransactionError, BumpFee, BumpFeeError, ChainTips, ChainTipsError,
         ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreateMultisigError,
-        CreatePsbt, CreateRawTransaction, CreateWallet, DecodeRawTransaction, DumpPrivKey,
-        DumpWallet, EncryptWallet, EstimateRawFee, EstimateRawFeeError, EstimateSmartFee,
-        FinalizePsbt, FinalizePsbtError, FundRawTransaction, FundRawTransactionError, Generate,
-        GenerateToAddress, GetAddedNodeInfo, GetAddressInfoEmbeddedError, GetAddressesByLabel,
-        GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader,
-        GetBlockHeaderError, GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStats,
-        GetBlockStatsError, GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne,
-        GetBlockVerboseOneError, GetBlockVerboseZero, GetChainTips, GetChainTxStatsError,
-        GetConnectionCount, GetDifficulty, GetMemoryInfoStats, GetMempoolInfoError, GetMiningInfo,
-        GetNetTotals, GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork,
-        GetNewAddress, GetRawChangeAddress, GetRawTransaction, GetRawTransactionVerbose,
-        GetRawTransactionVerboseError, GetReceivedByAddress, GetTransactionDetailError, GetTxOut,
-        GetTxOutError, GetTxOutSetInfo, GetTxOutSetInfoError, GetUnconfirmedBalance,
-        GetWalletInfoError, ListAddressGroupings, ListAddressGroupingsError,
+        CreatePsbt, CreateRawTransaction, CreateWallet, DecodeRawTransaction,
+        DecodeScriptSegwitError, DumpPrivKey, DumpWallet, EncryptWallet, EstimateRawFee,
+        EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
+        FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
+        GetAddressInfoEmbeddedError, GetAddressesByLabel, GetBalance, GetBestBlockHash,
+        GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError, GetBlockHeaderVerbose,
+        GetBlockHeaderVerboseError, GetBlockStats, GetBlockStatsError, GetBlockTemplate,
+        GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseTwo,
+        GetBlockVerboseTwoError, GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetChainTips,
+        GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats,
+        GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfoAddress,
+        GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress,
+        GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
+        GetReceivedByAddress, GetTransactionDetailError, GetTxOut, GetTxOutError,
+        GetUnconfirmedBalance, GetWalletInfoError, ListAddressGroupings, ListAddressGroupingsError,
         ListAddressGroupingsItem, ListLabels, ListLockUnspent, ListLockUnspentItem,
         ListLockUnspentItemError, ListReceivedByAddressError, ListUnspentItemError, ListWallets,
         LoadWallet, LockUnspent, Locked, NumericError, PartialSignatureError, PruneBlockchain,
@@ -305,7 +306,7 @@ pub use crate::{
         GetZmqNotifications, ImportMulti, ImportMultiEntry, JoinPsbts, JsonRpcError,
         ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
         ListReceivedByLabelError, ListReceivedByLabelItem, ListUnspent, ListUnspentItem,
-        ListWalletDir, ListWalletDirWallet, UtxoUpdatePsbt,
+        ListWalletDir, ListWalletDirWallet, PsbtRole, UtxoUpdatePsbt,
     },
     v19::{
         Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalances,
@@ -317,14 +318,17 @@ pub use crate::{
     },
     v20::{GenerateToDescriptor, GetTransactionDetail},
     v21::{
-        AddPeerAddress, GenerateBlock, GetIndexInfo, GetIndexInfoName, GetNetworkInfo,
-        GetRawMempoolSequence, ImportDescriptors, ImportDescriptorsResult, PsbtBumpFee,
-        PsbtBumpFeeError, Send, SendError, SendMany, SendManyVerbose, UnloadWallet, UpgradeWallet,
+        AddPeerAddress, ConnectionType, GenerateBlock, GetIndexInfo, GetIndexInfoName,
+        GetNetworkInfo, GetRawMempoolSequence, ImportDescriptors, ImportDescriptorsResult,
+        NetworkType, PsbtBumpFee, PsbtBumpFeeError, Send, SendError, SendMany, SendManyVerbose,
+        UnloadWallet, UpgradeWallet,
     },
     v22::{
         AddConnection, Banned, DescriptorInfo, EnumerateSigners, GetAddressInfo,
-        GetAddressInfoEmbedded, GetMempoolInfo, GetNodeAddresses, ListBanned, ListDescriptors,
-        MempoolAcceptance, MempoolAcceptanceError, MempoolAcceptanceFees, NodeAddress,
-        ScriptPubKey, Signers, TestMempoolAccept, TestMempoolAcceptError, WalletDisplayAddress,
+        GetAddressInfoEmbedded, GetMempoolInfo, GetNodeAddresses, GetTxOutSetInfo,
+        GetTxOutSetInfoBlockInfo, GetTxOutSetInfoError, GetTxOutSetInfoUnspendables, ListBanned,
+        ListDescriptors, MempoolAcceptance, MempoolAcceptanceError, MempoolAcceptanceFees,
+        NodeAddress, ScriptPubKey, Signers, TestMempoolAccept, TestMempoolAcceptError,
+        WalletDisplayAddress,
     },
 };
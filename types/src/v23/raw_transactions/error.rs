@@ -7,6 +7,7 @@ use bitcoin::{address, bip32, hex, sighash};
 
 use super::{Bip32DerivError, PartialSignatureError, RawTransactionError, WitnessUtxoError};
 use crate::error::write_err;
+use crate::v17::DecodeScriptSegwitError;
 
 /// Error when converting a `DecodePsbt` type into the model type.
 #[derive(Debug)]
@@ -258,6 +259,8 @@ pub enum DecodeScriptError {
     Addresses(address::ParseError),
     /// Conversion of the transaction `p2sh` field failed.
     P2sh(address::ParseError),
+    /// Conversion of the transaction `segwit` field failed.
+    Segwit(DecodeScriptSegwitError),
 }
 
 impl fmt::Display for DecodeScriptError {
@@ -268,6 +271,7 @@ impl fmt::Display for DecodeScriptError {
             Self::Addresses(ref e) =>
                 write_err!(f, "conversion of the `addresses` field failed"; e),
             Self::P2sh(ref e) => write_err!(f, "conversion of the `p2sh` field failed"; e),
+            Self::Segwit(ref e) => write_err!(f, "conversion of the `segwit` field failed"; e),
         }
     }
 }
@@ -280,6 +284,11 @@ impl std::error::Error for DecodeScriptError {
             Self::Address(ref e) => Some(e),
             Self::Addresses(ref e) => Some(e),
             Self::P2sh(ref e) => Some(e),
+            Self::Segwit(ref e) => Some(e),
         }
     }
 }
+
+impl From<DecodeScriptSegwitError> for DecodeScriptError {
+    fn from(e: DecodeScriptSegwitError) -> Self { Self::Segwit(e) }
+}
@@ -6,13 +6,14 @@ use bitcoin::bip32::{DerivationPath, Fingerprint, KeySource, Xpub};
 use bitcoin::hashes::{hash160, ripemd160, sha256, sha256d};
 use bitcoin::hex::{self, FromHex as _};
 use bitcoin::psbt::{self, raw, PsbtSighashType};
-use bitcoin::{Address, Amount};
+use bitcoin::{Address, Amount, ScriptBuf};
 
 use super::{
-    DecodePsbt, DecodePsbtError, DecodeScript, DecodeScriptError, GlobalXpub, GlobalXpubError,
-    Proprietary, PsbtInput, PsbtInputError, PsbtOutput, PsbtOutputError,
+    DecodePsbt, DecodePsbtError, DecodeScript, DecodeScriptError, DecodeScriptSegwit, GlobalXpub,
+    GlobalXpubError, Proprietary, PsbtInput, PsbtInputError, PsbtOutput, PsbtOutputError,
 };
 use crate::model;
+use crate::v17::DecodeScriptSegwitError;
 
 impl DecodePsbt {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
@@ -325,6 +326,7 @@ impl DecodeScript {
             None => vec![],
         };
         let p2sh = self.p2sh.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::P2sh)?;
+        let segwit = self.segwit.map(|s| s.into_model()).transpose()?;
 
         Ok(model::DecodeScript {
             script_pubkey: None,
@@ -334,7 +336,39 @@ impl DecodeScript {
             required_signatures: self.required_signatures,
             addresses,
             p2sh,
+            segwit,
             p2sh_segwit: self.p2sh_segwit,
         })
     }
 }
+
+impl DecodeScriptSegwit {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::DecodeScriptSegwit, DecodeScriptSegwitError> {
+        use DecodeScriptSegwitError as E;
+
+        let script_pubkey = ScriptBuf::from_hex(&self.hex).map_err(E::Hex)?;
+        let address =
+            self.address.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::Address)?;
+        let addresses = match self.addresses {
+            Some(addresses) => addresses
+                .iter()
+                .map(|s| s.parse::<Address<_>>())
+                .collect::<Result<_, _>>()
+                .map_err(E::Addresses)?,
+            None => vec![],
+        };
+        let p2sh_segwit =
+            self.p2sh_segwit.map(|s| s.parse::<Address<_>>()).transpose().map_err(E::P2shSegwit)?;
+
+        Ok(model::DecodeScriptSegwit {
+            script_pubkey,
+            type_: self.type_,
+            address,
+            required_signatures: self.required_signatures,
+            addresses,
+            descriptor: self.descriptor,
+            p2sh_segwit,
+        })
+    }
+}
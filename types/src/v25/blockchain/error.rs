@@ -2,10 +2,102 @@
 
 use core::fmt;
 
-use bitcoin::hex;
+use bitcoin::error::UnprefixedHexError;
+use bitcoin::{amount, hex};
 
 use crate::error::write_err;
-use crate::NumericError;
+use crate::psbt::{RawTransactionInputError, RawTransactionOutputError};
+use crate::{NumericError, ScriptPubKeyError};
+
+/// Error when converting a `GetBlockVerboseThree` type into the model type.
+#[derive(Debug)]
+pub enum GetBlockVerboseThreeError {
+    /// Conversion of numeric type to expected type failed.
+    Numeric(NumericError),
+    /// Conversion of the transaction `hash` field failed.
+    Hash(hex::HexToArrayError),
+    /// Conversion of the transaction `merkle_root` field failed.
+    MerkleRoot(hex::HexToArrayError),
+    /// Conversion of the transaction `bits` field failed.
+    Bits(UnprefixedHexError),
+    /// Conversion of the transaction `chain_work` field failed.
+    ChainWork(UnprefixedHexError),
+    /// Conversion of the transaction `previous_block_hash` field failed.
+    PreviousBlockHash(hex::HexToArrayError),
+    /// Conversion of the transaction `next_block_hash` field failed.
+    NextBlockHash(hex::HexToArrayError),
+    /// Conversion of one of the transaction inputs failed.
+    Inputs(RawTransactionInputError),
+    /// Conversion of one of the transaction outputs failed.
+    Outputs(RawTransactionOutputError),
+    /// Conversion of the transaction `block_hash` field failed.
+    TransactionBlockHash(hex::HexToArrayError),
+    /// Conversion of the transaction `fee` field failed.
+    Fee(amount::ParseAmountError),
+    /// Conversion of a prevout height failed.
+    PrevoutHeight(NumericError),
+    /// Conversion of a prevout value failed.
+    PrevoutValue(amount::ParseAmountError),
+    /// Conversion of a prevout script_pubkey failed.
+    PrevoutScriptPubKey(ScriptPubKeyError),
+}
+
+impl fmt::Display for GetBlockVerboseThreeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::Numeric(ref e) => write_err!(f, "numeric"; e),
+            Self::Hash(ref e) => write_err!(f, "conversion of the `hash` field failed"; e),
+            Self::MerkleRoot(ref e) =>
+                write_err!(f, "conversion of the `merkle_root` field failed"; e),
+            Self::Bits(ref e) => write_err!(f, "conversion of the `bits` field failed"; e),
+            Self::ChainWork(ref e) =>
+                write_err!(f, "conversion of the `chain_work` field failed"; e),
+            Self::PreviousBlockHash(ref e) =>
+                write_err!(f, "conversion of the `previous_block_hash` field failed"; e),
+            Self::NextBlockHash(ref e) =>
+                write_err!(f, "conversion of the `next_block_hash` field failed"; e),
+            Self::Inputs(ref e) =>
+                write_err!(f, "conversion of one of the transaction inputs failed"; e),
+            Self::Outputs(ref e) =>
+                write_err!(f, "conversion of one of the transaction outputs failed"; e),
+            Self::TransactionBlockHash(ref e) =>
+                write_err!(f, "conversion of the `block_hash` field failed"; e),
+            Self::Fee(ref e) => write_err!(f, "conversion of the `fee` field failed"; e),
+            Self::PrevoutHeight(ref e) =>
+                write_err!(f, "conversion of a prevout `height` field failed"; e),
+            Self::PrevoutValue(ref e) =>
+                write_err!(f, "conversion of a prevout `value` field failed"; e),
+            Self::PrevoutScriptPubKey(ref e) =>
+                write_err!(f, "conversion of a prevout `script_pubkey` field failed"; e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GetBlockVerboseThreeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Self::Numeric(ref e) => Some(e),
+            Self::Hash(ref e) => Some(e),
+            Self::MerkleRoot(ref e) => Some(e),
+            Self::Bits(ref e) => Some(e),
+            Self::ChainWork(ref e) => Some(e),
+            Self::PreviousBlockHash(ref e) => Some(e),
+            Self::NextBlockHash(ref e) => Some(e),
+            Self::Inputs(ref e) => Some(e),
+            Self::Outputs(ref e) => Some(e),
+            Self::TransactionBlockHash(ref e) => Some(e),
+            Self::Fee(ref e) => Some(e),
+            Self::PrevoutHeight(ref e) => Some(e),
+            Self::PrevoutValue(ref e) => Some(e),
+            Self::PrevoutScriptPubKey(ref e) => Some(e),
+        }
+    }
+}
+
+impl From<NumericError> for GetBlockVerboseThreeError {
+    fn from(e: NumericError) -> Self { Self::Numeric(e) }
+}
 
 /// Error when converting a `ScanBlocksStart` type into the model type.
 #[derive(Debug)]
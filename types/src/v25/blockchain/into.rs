@@ -1,11 +1,14 @@
 // SPDX-License-Identifier: CC0-1.0
 
-use bitcoin::{Amount, BlockHash, FeeRate, ScriptBuf, Txid, Weight};
+use bitcoin::{
+    absolute, block, transaction, Amount, Block, BlockHash, CompactTarget, FeeRate, ScriptBuf,
+    Transaction, TxMerkleNode, Txid, Weight, Work,
+};
 
-use super::error::ScanBlocksStartError;
+use super::error::{GetBlockVerboseThreeError, ScanBlocksStartError};
 use super::{
-    GetBlockStats, GetBlockStatsError, ScanBlocksStart, ScanTxOutSetError, ScanTxOutSetStart,
-    ScanTxOutSetUnspent,
+    GetBlockStats, GetBlockStatsError, GetBlockVerboseThree, GetRawTransactionVerboseWithPrevout,
+    ScanBlocksStart, ScanTxOutSetError, ScanTxOutSetStart, ScanTxOutSetUnspent,
 };
 use crate::model;
 
@@ -76,6 +79,141 @@ impl GetBlockStats {
     }
 }
 
+impl GetBlockVerboseThree {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> Result<model::GetBlockVerboseThree, GetBlockVerboseThreeError> {
+        use GetBlockVerboseThreeError as E;
+
+        let hash = self.hash.parse::<BlockHash>().map_err(E::Hash)?;
+        let stripped_size =
+            self.stripped_size.map(|size| crate::to_u32(size, "stripped_size")).transpose()?;
+        let weight = Weight::from_wu(self.weight);
+        let version = block::Version::from_consensus(self.version);
+        let merkle_root = self.merkle_root.parse::<TxMerkleNode>().map_err(E::MerkleRoot)?;
+        let tx = self
+            .tx
+            .into_iter()
+            .map(|entry| {
+                let (transaction, prevouts) = entry.transaction.into_model_with_prevouts()?;
+                let fee = entry.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+                Ok(model::GetBlockVerboseThreeTransaction { transaction, prevouts, fee })
+            })
+            .collect::<Result<Vec<_>, E>>()?;
+        let median_time = self.median_time.map(|t| crate::to_u32(t, "median_time")).transpose()?;
+        let bits = CompactTarget::from_unprefixed_hex(&self.bits).map_err(E::Bits)?;
+        let chain_work = Work::from_unprefixed_hex(&self.chain_work).map_err(E::ChainWork)?;
+        let previous_block_hash = self
+            .previous_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::PreviousBlockHash)?;
+        let next_block_hash = self
+            .next_block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::NextBlockHash)?;
+
+        Ok(model::GetBlockVerboseThree {
+            hash,
+            confirmations: self.confirmations,
+            size: crate::to_u32(self.size, "size")?,
+            stripped_size,
+            weight,
+            coinbase_tx: None,
+            height: crate::to_u32(self.height, "height")?,
+            version,
+            merkle_root,
+            tx,
+            time: crate::to_u32(self.time, "time")?,
+            median_time,
+            nonce: crate::to_u32(self.nonce, "nonce")?,
+            bits,
+            target: None,
+            difficulty: self.difficulty,
+            chain_work,
+            n_tx: crate::to_u32(self.n_tx, "n_tx")?,
+            previous_block_hash,
+            next_block_hash,
+        })
+    }
+}
+
+impl TryFrom<GetBlockVerboseThree> for Block {
+    type Error = GetBlockVerboseThreeError;
+
+    fn try_from(json: GetBlockVerboseThree) -> Result<Self, Self::Error> {
+        Ok(json.into_model()?.into())
+    }
+}
+
+impl GetRawTransactionVerboseWithPrevout {
+    /// Converts to the model transaction along with its parsed prevouts.
+    pub fn into_model_with_prevouts(
+        self,
+    ) -> Result<
+        (model::GetRawTransactionVerbose, Vec<Option<model::GetBlockVerboseThreePrevout>>),
+        GetBlockVerboseThreeError,
+    > {
+        use GetBlockVerboseThreeError as E;
+
+        let version = transaction::Version::non_standard(self.version);
+        let lock_time = absolute::LockTime::from_consensus(self.lock_time);
+
+        let mut input = Vec::with_capacity(self.inputs.len());
+        let mut prevouts = Vec::with_capacity(self.inputs.len());
+        for item in self.inputs {
+            let prevout = item
+                .prevout
+                .map(|prevout| {
+                    let height = crate::to_u32(prevout.height, "prevout.height")
+                        .map_err(E::PrevoutHeight)?;
+                    let value = Amount::from_btc(prevout.value).map_err(E::PrevoutValue)?;
+                    let script_pubkey =
+                        prevout.script_pubkey.into_model().map_err(E::PrevoutScriptPubKey)?;
+                    Ok::<model::GetBlockVerboseThreePrevout, GetBlockVerboseThreeError>(
+                        model::GetBlockVerboseThreePrevout {
+                            generated: prevout.generated,
+                            height,
+                            value,
+                            script_pubkey,
+                        },
+                    )
+                })
+                .transpose()?;
+
+            prevouts.push(prevout);
+            let txin = item.input.to_input().map_err(E::Inputs)?;
+            input.push(txin);
+        }
+
+        let output = self
+            .outputs
+            .into_iter()
+            .map(|output| output.to_output())
+            .collect::<Result<_, _>>()
+            .map_err(E::Outputs)?;
+
+        let transaction = Transaction { version, lock_time, input, output };
+        let block_hash = self
+            .block_hash
+            .map(|s| s.parse::<BlockHash>())
+            .transpose()
+            .map_err(E::TransactionBlockHash)?;
+
+        Ok((
+            model::GetRawTransactionVerbose {
+                in_active_chain: self.in_active_chain,
+                transaction,
+                block_hash,
+                confirmations: self.confirmations,
+                transaction_time: self.transaction_time,
+                block_time: self.block_time,
+            },
+            prevouts,
+        ))
+    }
+}
+
 impl ScanBlocksStart {
     pub fn into_model(self) -> Result<model::ScanBlocksStart, ScanBlocksStartError> {
         use ScanBlocksStartError as E;
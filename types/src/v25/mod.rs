@@ -50,7 +50,7 @@
 //! | gettxspendingprevout               | version + model |                                        |
 //! | preciousblock                      | returns nothing |                                        |
 //! | pruneblockchain                    | version         |                                        |
-//! | savemempool                        | version         |                                        |
+//! | savemempool                        | version + model |                                        |
 //! | scanblocks                         | version + model |                                        |
 //! | scantxoutset                       | version + model | API marked as experimental             |
 //! | verifychain                        | version         |                                        |
@@ -99,7 +99,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -126,7 +126,7 @@
 //! | getrawtransaction                  | version + model | Includes additional 'verbose' type     |
 //! | joinpsbts                          | version + model |                                        |
 //! | sendrawtransaction                 | version + model |                                        |
-//! | signrawtransactionwithkey          | version + model | UNTESTED                               |
+//! | signrawtransactionwithkey          | version + model |                                        |
 //! | testmempoolaccept                  | version + model |                                        |
 //! | utxoupdatepsbt                     | version + model |                                        |
 //!
@@ -183,7 +183,7 @@
 //! | getunconfirmedbalance              | version + model |                                        |
 //! | getwalletinfo                      | version + model |                                        |
 //! | importaddress                      | returns nothing |                                        |
-//! | importdescriptors                  | version         |                                        |
+//! | importdescriptors                  | version + model |                                        |
 //! | importmulti                        | version         |                                        |
 //! | importprivkey                      | returns nothing |                                        |
 //! | importprunedfunds                  | returns nothing |                                        |
@@ -236,7 +236,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -249,14 +249,17 @@ mod wallet;
 #[doc(inline)]
 pub use self::{
     blockchain::{
-        GetBlockStats, ScanBlocksAbort, ScanBlocksStart, ScanBlocksStartError, ScanBlocksStatus,
-        ScanTxOutSetStart, ScanTxOutSetUnspent,
+        GetBlockStats, GetBlockVerboseThree, GetBlockVerboseThreeError,
+        GetBlockVerboseThreePrevout, GetBlockVerboseThreeTransaction,
+        GetRawTransactionVerboseWithPrevout, RawTransactionInputWithPrevout, ScanBlocksAbort,
+        ScanBlocksStart, ScanBlocksStartError, ScanBlocksStatus, ScanTxOutSetStart,
+        ScanTxOutSetUnspent,
     },
     control::Logging,
     generating::{GenerateBlock, GenerateBlockError},
     raw_transactions::{
-        MempoolAcceptance, MempoolAcceptanceError, MempoolAcceptanceFees, TestMempoolAccept,
-        TestMempoolAcceptError,
+        GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError, MempoolAcceptance,
+        MempoolAcceptanceError, MempoolAcceptanceFees, TestMempoolAccept, TestMempoolAcceptError,
     },
     wallet::{CreateWallet, DescriptorInfo, ListDescriptors, LoadWallet, UnloadWallet},
 };
@@ -267,19 +270,20 @@ pub use crate::{
         AddressPurpose, Bip32DerivError, BlockTemplateTransaction, BlockTemplateTransactionError,
         BumpFee, BumpFeeError, ChainTips, ChainTipsError, ChainTipsStatus, CombinePsbt,
         CombineRawTransaction, ConvertToPsbt, CreateMultisigError, CreatePsbt,
-        CreateRawTransaction, DecodeRawTransaction, DumpPrivKey, DumpWallet, EncryptWallet,
-        EstimateRawFee, EstimateRawFeeError, EstimateSmartFee, FinalizePsbt, FinalizePsbtError,
-        FundRawTransaction, FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
-        GetAddressInfoEmbeddedError, GetAddressesByLabel, GetBalance, GetBestBlockHash,
-        GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError, GetBlockHeaderVerbose,
-        GetBlockHeaderVerboseError, GetBlockStatsError, GetBlockTemplate, GetBlockTemplateError,
-        GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseZero, GetChainTips,
-        GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats,
-        GetMempoolInfoError, GetMiningInfo, GetNetTotals, GetNetworkInfoAddress,
-        GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress,
-        GetRawMempool, GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
-        GetReceivedByAddress, GetTransactionDetailError, GetTxOut, GetTxOutError, GetTxOutSetInfo,
-        GetTxOutSetInfoError, GetUnconfirmedBalance, GetWalletInfoError, ListAddressGroupings,
+        CreateRawTransaction, DecodeRawTransaction, DecodeScriptSegwitError, DumpPrivKey,
+        DumpWallet, EncryptWallet, EstimateRawFee, EstimateRawFeeError, EstimateSmartFee,
+        FinalizePsbt, FinalizePsbtError, FundRawTransaction, FundRawTransactionError, Generate,
+        GenerateToAddress, GetAddedNodeInfo, GetAddressInfoEmbeddedError, GetAddressesByLabel,
+        GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader,
+        GetBlockHeaderError, GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStatsError,
+        GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError,
+        GetBlockVerboseTwo, GetBlockVerboseTwoError, GetBlockVerboseTwoTransaction,
+        GetBlockVerboseZero, GetChainTips, GetChainTxStatsError, GetConnectionCount, GetDifficulty,
+        GetMemoryInfoStats, GetMempoolInfoError, GetMiningInfo, GetNetTotals,
+        GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress,
+        GetRawChangeAddress, GetRawMempool, GetRawTransaction, GetRawTransactionVerbose,
+        GetRawTransactionVerboseError, GetReceivedByAddress, GetTransactionDetailError, GetTxOut,
+        GetTxOutError, GetUnconfirmedBalance, GetWalletInfoError, ListAddressGroupings,
         ListAddressGroupingsError, ListAddressGroupingsItem, ListLabels, ListLockUnspent,
         ListLockUnspentItem, ListLockUnspentItemError, ListReceivedByAddressError,
         ListUnspentItemError, ListWallets, LockUnspent, Locked, NumericError,
@@ -300,7 +304,7 @@ pub use crate::{
         GetZmqNotifications, ImportMulti, ImportMultiEntry, JoinPsbts, JsonRpcError,
         ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
         ListReceivedByLabelError, ListReceivedByLabelItem, ListWalletDir, ListWalletDirWallet,
-        UtxoUpdatePsbt,
+        PsbtRole, UtxoUpdatePsbt,
     },
     v19::{
         Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalances,
@@ -311,13 +315,15 @@ pub use crate::{
     },
     v20::GenerateToDescriptor,
     v21::{
-        AddPeerAddress, GetIndexInfo, GetIndexInfoName, GetNetworkInfo, GetRawMempoolSequence,
-        ImportDescriptors, ImportDescriptorsResult, PsbtBumpFee, PsbtBumpFeeError, Send, SendError,
-        SendMany, SendManyVerbose, UpgradeWallet,
+        AddPeerAddress, ConnectionType, GetIndexInfo, GetIndexInfoName, GetNetworkInfo,
+        GetRawMempoolSequence, ImportDescriptors, ImportDescriptorsResult, NetworkType,
+        PsbtBumpFee, PsbtBumpFeeError, Send, SendError, SendMany, SendManyVerbose, UpgradeWallet,
     },
     v22::{
         AddConnection, Banned, EnumerateSigners, GetAddressInfo, GetAddressInfoEmbedded,
-        GetNodeAddresses, ListBanned, NodeAddress, ScriptPubKey, Signers, WalletDisplayAddress,
+        GetNodeAddresses, GetTxOutSetInfo, GetTxOutSetInfoBlockInfo, GetTxOutSetInfoError,
+        GetTxOutSetInfoUnspendables, ListBanned, NodeAddress, ScriptPubKey, Signers,
+        WalletDisplayAddress,
     },
     v23::{
         AddMultisigAddress, Bip9Info, Bip9Statistics, CreateMultisig, DecodeScript,
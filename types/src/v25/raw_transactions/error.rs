@@ -8,6 +8,11 @@ use bitcoin::hex;
 use crate::error::write_err;
 use crate::NumericError;
 
+/// Error when converting a `GetRawTransactionVerboseTwo` type into the model type.
+///
+/// The result is the same as a transaction of `getblock` with verbosity 3.
+pub type GetRawTransactionVerboseTwoError = crate::v25::GetBlockVerboseThreeError;
+
 /// Error when converting a `TestMempoolAccept` type into the model type.
 #[derive(Debug)]
 pub enum TestMempoolAcceptError {
@@ -2,9 +2,26 @@
 
 use bitcoin::{Amount, Txid, Wtxid};
 
-use super::{MempoolAcceptance, MempoolAcceptanceError, TestMempoolAccept, TestMempoolAcceptError};
+use super::{
+    GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError, MempoolAcceptance,
+    MempoolAcceptanceError, TestMempoolAccept, TestMempoolAcceptError,
+};
 use crate::model;
 
+impl GetRawTransactionVerboseTwo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(
+        self,
+    ) -> Result<model::GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError> {
+        use GetRawTransactionVerboseTwoError as E;
+
+        let (transaction, prevouts) = self.transaction.into_model_with_prevouts()?;
+        let fee = self.fee.map(Amount::from_btc).transpose().map_err(E::Fee)?;
+
+        Ok(model::GetRawTransactionVerboseTwo { transaction, prevouts, fee })
+    }
+}
+
 impl TestMempoolAccept {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::TestMempoolAccept, TestMempoolAcceptError> {
@@ -5,9 +5,10 @@ use bitcoin::{Amount, BlockHash};
 
 use super::{
     DumpTxOutSet, DumpTxOutSetError, GetChainStates, GetChainStatesError, GetTxOutSetInfo,
-    GetTxOutSetInfoError, LoadTxOutSet, LoadTxOutSetError, ScanBlocksStart,
+    LoadTxOutSet, LoadTxOutSetError, ScanBlocksStart,
 };
 use crate::model;
+use crate::v22::GetTxOutSetInfoError;
 use crate::v25::ScanBlocksStartError;
 
 impl GetChainStates {
@@ -82,33 +83,7 @@ impl GetTxOutSetInfo {
             .total_unspendable_amount
             .map(|v| Amount::from_btc(v).map_err(E::TotalUnspendableAmount))
             .transpose()?;
-        let block_info = match self.block_info {
-            Some(b) => {
-                let prevout_spent = Amount::from_btc(b.prevout_spent).map_err(E::PrevoutSpent)?;
-                let coinbase = Amount::from_btc(b.coinbase).map_err(E::Coinbase)?;
-                let new_outputs_ex_coinbase =
-                    Amount::from_btc(b.new_outputs_ex_coinbase).map_err(E::NewOutputsExCoinbase)?;
-                let unspendable = Amount::from_btc(b.unspendable).map_err(E::Unspendable)?;
-                let unspendables = model::GetTxOutSetInfoUnspendables {
-                    genesis_block: Amount::from_btc(b.unspendables.genesis_block)
-                        .map_err(E::UnspendablesGenesisBlock)?,
-                    bip30: Amount::from_btc(b.unspendables.bip30).map_err(E::UnspendablesBip30)?,
-                    scripts: Amount::from_btc(b.unspendables.scripts)
-                        .map_err(E::UnspendablesScripts)?,
-                    unclaimed_rewards: Amount::from_btc(b.unspendables.unclaimed_rewards)
-                        .map_err(E::UnspendablesUnclaimedRewards)?,
-                };
-
-                Some(model::GetTxOutSetInfoBlockInfo {
-                    prevout_spent,
-                    coinbase,
-                    new_outputs_ex_coinbase,
-                    unspendable,
-                    unspendables,
-                })
-            }
-            None => None,
-        };
+        let block_info = self.block_info.map(|b| b.into_model()).transpose()?;
 
         Ok(model::GetTxOutSetInfo {
             height,
@@ -9,9 +9,8 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
-pub use self::error::{
-    DumpTxOutSetError, GetChainStatesError, GetTxOutSetInfoError, LoadTxOutSetError,
-};
+pub use self::error::{DumpTxOutSetError, GetChainStatesError, LoadTxOutSetError};
+use crate::v22::GetTxOutSetInfoBlockInfo;
 
 /// Result of JSON-RPC method `dumptxoutset`.
 ///
@@ -121,38 +120,6 @@ pub struct GetTxOutSetInfo {
     pub block_info: Option<GetTxOutSetInfoBlockInfo>,
 }
 
-/// Detailed block-level info returned by `gettxoutsetinfo` when coinstatsindex is enabled.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
-pub struct GetTxOutSetInfoBlockInfo {
-    /// Total amount of all prevouts spent in this block.
-    #[serde(rename = "prevout_spent")]
-    pub prevout_spent: f64,
-    /// Coinbase subsidy amount of this block.
-    pub coinbase: f64,
-    /// Total amount of new outputs created by this block.
-    #[serde(rename = "new_outputs_ex_coinbase")]
-    pub new_outputs_ex_coinbase: f64,
-    /// Total amount of unspendable outputs created in this block.
-    pub unspendable: f64,
-    /// Detailed view of unspendable categories.
-    pub unspendables: GetTxOutSetInfoUnspendables,
-}
-
-/// Categories of unspendable amounts returned inside `BlockInfo`.
-#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
-#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
-pub struct GetTxOutSetInfoUnspendables {
-    /// The unspendable amount of the Genesis block subsidy.
-    pub genesis_block: f64,
-    /// Transactions overridden by duplicates (no longer possible with BIP30).
-    pub bip30: f64,
-    /// Amounts sent to scripts that are unspendable (for example OP_RETURN outputs).
-    pub scripts: f64,
-    /// Fee rewards that miners did not claim in their coinbase transaction.
-    pub unclaimed_rewards: f64,
-}
-
 /// Result of JSON-RPC method `loadtxoutset`.
 ///
 /// > loadtxoutset "path"
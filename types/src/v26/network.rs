@@ -8,6 +8,9 @@ use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::{ConnectionType, NetworkType};
+use crate::model;
+
 /// Result of JSON-RPC method `getaddrmaninfo`.
 ///
 /// > getaddrmaninfo
@@ -54,7 +57,7 @@ pub struct PeerInfo {
     #[serde(rename = "addrlocal")]
     pub address_local: Option<String>,
     /// Network (ipv4, ipv6, onion, i2p, cjdns, not_publicly_routable) the peer connected through.
-    pub network: String,
+    pub network: NetworkType,
     /// Mapped AS (Autonomous System) number at the end of the BGP route to the peer, used for diversifying peer selection (only displayed if the -asmap config option is set).
     pub mapped_as: Option<u32>,
     /// The services offered.
@@ -146,12 +149,78 @@ pub struct PeerInfo {
     #[serde(rename = "bytesrecv_per_msg")]
     pub bytes_received_per_message: BTreeMap<String, u64>,
     /// Type of connection.
-    pub connection_type: Option<String>,
-    /// Type of transport protocol:
-    /// detecting (peer could be v1 or v2),
-    /// v1 (plaintext transport protocol),
-    /// v2 (BIP324 encrypted transport protocol).
-    pub transport_protocol_type: String,
+    pub connection_type: Option<ConnectionType>,
+    /// Type of transport protocol.
+    pub transport_protocol_type: TransportProtocolType,
     /// The session ID for this connection, or "" if there is none ("v2" transport protocol only).
     pub session_id: String,
 }
+
+/// Type of transport protocol. Part of `getpeerinfo`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TransportProtocolType {
+    /// Peer could be v1 or v2.
+    Detecting,
+    /// Plaintext transport protocol.
+    V1,
+    /// BIP324 encrypted transport protocol.
+    V2,
+}
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetPeerInfo {
+        model::GetPeerInfo(self.0.into_iter().map(|p| p.into_model()).collect())
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::PeerInfo {
+        model::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            network: Some(self.network.into_model()),
+            services: self.services,
+            services_names: Some(self.services_names),
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            starting_height: self.starting_height,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            permissions: Some(self.permissions),
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: self.connection_type.map(|t| t.into_model()),
+            transport_protocol_type: Some(self.transport_protocol_type.into_model()),
+        }
+    }
+}
+
+impl TransportProtocolType {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::TransportProtocolType {
+        use model::TransportProtocolType as M; // M for model.
+        use TransportProtocolType as V; // V for version specific.
+
+        match self {
+            V::Detecting => M::Detecting,
+            V::V1 => M::V1,
+            V::V2 => M::V2,
+        }
+    }
+}
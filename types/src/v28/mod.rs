@@ -54,7 +54,7 @@
 //! | loadtxoutset                       | version + model |                                        |
 //! | preciousblock                      | returns nothing |                                        |
 //! | pruneblockchain                    | version         |                                        |
-//! | savemempool                        | version         |                                        |
+//! | savemempool                        | version + model |                                        |
 //! | scanblocks                         | version + model |                                        |
 //! | scantxoutset                       | version + model | API marked as experimental             |
 //! | verifychain                        | version         |                                        |
@@ -105,7 +105,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -125,7 +125,7 @@
 //! | createpsbt                         | version + model |                                        |
 //! | createrawtransaction               | version + model |                                        |
 //! | decodepsbt                         | version + model |                                        |
-//! | descriptorprocesspsbt              | version + model | UNTESTED                               |
+//! | descriptorprocesspsbt              | version + model |                                        |
 //! | decoderawtransaction               | version + model |                                        |
 //! | decodescript                       | version + model |                                        |
 //! | finalizepsbt                       | version + model |                                        |
@@ -133,7 +133,7 @@
 //! | getrawtransaction                  | version + model | Includes additional 'verbose' type     |
 //! | joinpsbts                          | version + model |                                        |
 //! | sendrawtransaction                 | version + model |                                        |
-//! | signrawtransactionwithkey          | version + model | UNTESTED                               |
+//! | signrawtransactionwithkey          | version + model |                                        |
 //! | submitpackage                      | version + model |                                        |
 //! | testmempoolaccept                  | version + model |                                        |
 //! | utxoupdatepsbt                     | version + model |                                        |
@@ -193,7 +193,7 @@
 //! | getunconfirmedbalance              | version + model |                                        |
 //! | getwalletinfo                      | version + model |                                        |
 //! | importaddress                      | returns nothing |                                        |
-//! | importdescriptors                  | version         |                                        |
+//! | importdescriptors                  | version + model |                                        |
 //! | importmulti                        | version         |                                        |
 //! | importprivkey                      | returns nothing |                                        |
 //! | importprunedfunds                  | returns nothing |                                        |
@@ -246,7 +246,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -282,19 +282,20 @@ pub use crate::{
         AddressPurpose, Bip125Replaceable, Bip32DerivError, BlockTemplateTransaction,
         BlockTemplateTransactionError, BumpFee, BumpFeeError, ChainTips, ChainTipsError,
         ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreateMultisigError,
-        CreatePsbt, CreateRawTransaction, DecodeRawTransaction, DumpPrivKey, DumpWallet,
-        EncryptWallet, EstimateRawFee, EstimateRawFeeError, EstimateSmartFee, FinalizePsbt,
-        FinalizePsbtError, FundRawTransaction, FundRawTransactionError, Generate,
-        GenerateToAddress, GetAddedNodeInfo, GetAddressInfoEmbeddedError, GetAddressesByLabel,
-        GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockHeader,
-        GetBlockHeaderError, GetBlockHeaderVerbose, GetBlockHeaderVerboseError, GetBlockStatsError,
-        GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseOne, GetBlockVerboseOneError,
-        GetBlockVerboseZero, GetChainTips, GetChainTxStatsError, GetConnectionCount, GetDifficulty,
-        GetMemoryInfoStats, GetMempoolInfoError, GetNetTotals, GetNetworkInfoAddress,
-        GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress,
-        GetRawMempool, GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
-        GetReceivedByAddress, GetTransactionDetailError, GetTxOut, GetTxOutError,
-        GetUnconfirmedBalance, ListAddressGroupings, ListAddressGroupingsError,
+        CreatePsbt, CreateRawTransaction, DecodeRawTransaction, DecodeScriptSegwitError,
+        DumpPrivKey, DumpWallet, EncryptWallet, EstimateRawFee, EstimateRawFeeError,
+        EstimateSmartFee, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
+        FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
+        GetAddressInfoEmbeddedError, GetAddressesByLabel, GetBalance, GetBestBlockHash,
+        GetBlockCount, GetBlockHash, GetBlockHeader, GetBlockHeaderError, GetBlockHeaderVerbose,
+        GetBlockHeaderVerboseError, GetBlockStatsError, GetBlockTemplate, GetBlockTemplateError,
+        GetBlockVerboseOne, GetBlockVerboseOneError, GetBlockVerboseTwo, GetBlockVerboseTwoError,
+        GetBlockVerboseTwoTransaction, GetBlockVerboseZero, GetChainTips, GetChainTxStatsError,
+        GetConnectionCount, GetDifficulty, GetMemoryInfoStats, GetMempoolInfoError, GetNetTotals,
+        GetNetworkInfoAddress, GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress,
+        GetRawChangeAddress, GetRawMempool, GetRawTransaction, GetRawTransactionVerbose,
+        GetRawTransactionVerboseError, GetReceivedByAddress, GetTransactionDetailError, GetTxOut,
+        GetTxOutError, GetUnconfirmedBalance, ListAddressGroupings, ListAddressGroupingsError,
         ListAddressGroupingsItem, ListLabels, ListLockUnspent, ListLockUnspentItem,
         ListLockUnspentItemError, ListReceivedByAddressError, ListUnspentItemError, ListWallets,
         LockUnspent, Locked, NumericError, PartialSignatureError, PruneBlockchain, RawFeeDetail,
@@ -314,7 +315,7 @@ pub use crate::{
         GetZmqNotifications, ImportMulti, ImportMultiEntry, JoinPsbts, JsonRpcError,
         ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
         ListReceivedByLabelError, ListReceivedByLabelItem, ListWalletDir, ListWalletDirWallet,
-        UtxoUpdatePsbt,
+        PsbtRole, UtxoUpdatePsbt,
     },
     v19::{
         Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalancesMine,
@@ -324,13 +325,14 @@ pub use crate::{
     },
     v20::GenerateToDescriptor,
     v21::{
-        AddPeerAddress, GetIndexInfo, GetIndexInfoName, GetRawMempoolSequence, ImportDescriptors,
-        ImportDescriptorsResult, PsbtBumpFee, PsbtBumpFeeError, Send, SendError, SendMany,
-        SendManyVerbose, UpgradeWallet,
+        AddPeerAddress, ConnectionType, GetIndexInfo, GetIndexInfoName, GetRawMempoolSequence,
+        ImportDescriptors, ImportDescriptorsResult, NetworkType, PsbtBumpFee, PsbtBumpFeeError,
+        Send, SendError, SendMany, SendManyVerbose, UpgradeWallet,
     },
     v22::{
-        AddConnection, Banned, EnumerateSigners, GetNodeAddresses, ListBanned, NodeAddress,
-        ScriptPubKey, Signers, WalletDisplayAddress,
+        AddConnection, Banned, EnumerateSigners, GetNodeAddresses, GetTxOutSetInfoBlockInfo,
+        GetTxOutSetInfoError, GetTxOutSetInfoUnspendables, ListBanned, NodeAddress, ScriptPubKey,
+        Signers, WalletDisplayAddress,
     },
     v23::{
         AddMultisigAddress, Bip9Info, Bip9Statistics, CreateMultisig, DecodeScript,
@@ -349,18 +351,22 @@ pub use crate::{
         TaprootScriptPathSigError,
     },
     v25::{
-        DescriptorInfo, GenerateBlock, GenerateBlockError, GetBlockStats, ListDescriptors,
-        MempoolAcceptance, MempoolAcceptanceError, MempoolAcceptanceFees, ScanBlocksAbort,
-        ScanBlocksStartError, ScanBlocksStatus, TestMempoolAccept, TestMempoolAcceptError,
+        DescriptorInfo, GenerateBlock, GenerateBlockError, GetBlockStats, GetBlockVerboseThree,
+        GetBlockVerboseThreeError, GetBlockVerboseThreePrevout, GetBlockVerboseThreeTransaction,
+        GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError,
+        GetRawTransactionVerboseWithPrevout, ListDescriptors, MempoolAcceptance,
+        MempoolAcceptanceError, MempoolAcceptanceFees, RawTransactionInputWithPrevout,
+        ScanBlocksAbort, ScanBlocksStartError, ScanBlocksStatus, TestMempoolAccept,
+        TestMempoolAcceptError,
     },
     v26::{
         AddrManInfoNetwork, ChainState, CreateWallet, DescriptorProcessPsbt,
         DescriptorProcessPsbtError, DumpTxOutSet, DumpTxOutSetError, GetAddrManInfo, GetBalances,
         GetBalancesError, GetChainStates, GetChainStatesError, GetPeerInfo, GetTransactionError,
-        GetTxOutSetInfo, GetTxOutSetInfoBlockInfo, GetTxOutSetInfoError,
-        GetTxOutSetInfoUnspendables, GetWalletInfo, GetWalletInfoError, GetWalletInfoScanning,
+        GetTxOutSetInfo, GetWalletInfo, GetWalletInfoError, GetWalletInfoScanning,
         LastProcessedBlock, LastProcessedBlockError, LoadTxOutSet, LoadTxOutSetError, LoadWallet,
-        PeerInfo, ScanBlocksStart, UnloadWallet, WalletProcessPsbt, WalletProcessPsbtError,
+        PeerInfo, ScanBlocksStart, TransportProtocolType, UnloadWallet, WalletProcessPsbt,
+        WalletProcessPsbtError,
     },
     v27::{GetPrioritisedTransactions, PrioritisedTransaction},
 };
@@ -5,7 +5,7 @@ use alloc::collections::BTreeMap;
 use bitcoin::consensus::encode;
 use bitcoin::hashes::hex::FromHex;
 use bitcoin::{
-    absolute, block, hex, transaction, Amount, BlockHash, CompactTarget, Network, ScriptBuf,
+    absolute, block, transaction, Amount, Block, BlockHash, CompactTarget, Network, ScriptBuf,
     Target, Transaction, TxMerkleNode, Txid, Weight, Work,
 };
 
@@ -128,6 +128,14 @@ impl GetBlockVerboseTwo {
     }
 }
 
+impl TryFrom<GetBlockVerboseTwo> for Block {
+    type Error = GetBlockVerboseTwoError;
+
+    fn try_from(json: GetBlockVerboseTwo) -> Result<Self, Self::Error> {
+        Ok(json.into_model()?.into())
+    }
+}
+
 impl GetRawTransactionVerboseWithPrevout {
     /// Converts to the model transaction along with its parsed prevouts.
     pub fn into_model_with_prevouts(
@@ -256,6 +264,14 @@ impl GetBlockVerboseThree {
     }
 }
 
+impl TryFrom<GetBlockVerboseThree> for Block {
+    type Error = GetBlockVerboseThreeError;
+
+    fn try_from(json: GetBlockVerboseThree) -> Result<Self, Self::Error> {
+        Ok(json.into_model()?.into())
+    }
+}
+
 impl GetBlockchainInfo {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockchainInfo, GetBlockchainInfoError> {
@@ -317,6 +333,12 @@ impl GetBlockHeader {
     }
 }
 
+impl TryFrom<GetBlockHeader> for block::Header {
+    type Error = GetBlockHeaderError;
+
+    fn try_from(json: GetBlockHeader) -> Result<Self, Self::Error> { json.block_header() }
+}
+
 impl GetBlockHeaderVerbose {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockHeaderVerbose, GetBlockHeaderVerboseError> {
@@ -357,7 +379,15 @@ impl GetBlockHeaderVerbose {
     }
 
     /// Converts json straight to a `bitcoin::BlockHeader`.
-    pub fn block_header(self) -> Result<block::Header, hex::HexToArrayError> { todo!() }
+    pub fn block_header(self) -> Result<block::Header, GetBlockHeaderVerboseError> {
+        Ok(self.into_model()?.block_header())
+    }
+}
+
+impl TryFrom<GetBlockHeaderVerbose> for block::Header {
+    type Error = GetBlockHeaderVerboseError;
+
+    fn try_from(json: GetBlockHeaderVerbose) -> Result<Self, Self::Error> { json.block_header() }
 }
 
 impl GetChainStates {
@@ -55,7 +55,7 @@
 //! | loadtxoutset                       | version + model |                                        |
 //! | preciousblock                      | returns nothing |                                        |
 //! | pruneblockchain                    | version         |                                        |
-//! | savemempool                        | version         |                                        |
+//! | savemempool                        | version + model |                                        |
 //! | scanblocks                         | version + model |                                        |
 //! | scantxoutset                       | version + model | API marked as experimental             |
 //! | verifychain                        | version         |                                        |
@@ -106,7 +106,7 @@
 //! | getnettotals                       | version         |                                        |
 //! | getnetworkinfo                     | version + model |                                        |
 //! | getnodeaddresses                   | version         |                                        |
-//! | getpeerinfo                        | version         |                                        |
+//! | getpeerinfo                        | version + model |                                        |
 //! | listbanned                         | version         |                                        |
 //! | ping                               | returns nothing |                                        |
 //! | setban                             | returns nothing |                                        |
@@ -126,7 +126,7 @@
 //! | createpsbt                         | version + model |                                        |
 //! | createrawtransaction               | version + model |                                        |
 //! | decodepsbt                         | version + model |                                        |
-//! | descriptorprocesspsbt              | version + model | UNTESTED                               |
+//! | descriptorprocesspsbt              | version + model |                                        |
 //! | decoderawtransaction               | version + model |                                        |
 //! | decodescript                       | version + model |                                        |
 //! | finalizepsbt                       | version + model |                                        |
@@ -134,7 +134,7 @@
 //! | getrawtransaction                  | version + model | Includes additional 'verbose' type     |
 //! | joinpsbts                          | version + model |                                        |
 //! | sendrawtransaction                 | version + model |                                        |
-//! | signrawtransactionwithkey          | version + model | UNTESTED                               |
+//! | signrawtransactionwithkey          | version + model |                                        |
 //! | submitpackage                      | version + model |                                        |
 //! | testmempoolaccept                  | version + model |                                        |
 //! | utxoupdatepsbt                     | version + model |                                        |
@@ -194,7 +194,7 @@
 //! | getunconfirmedbalance              | version + model |                                        |
 //! | getwalletinfo                      | version + model |                                        |
 //! | importaddress                      | returns nothing |                                        |
-//! | importdescriptors                  | version         |                                        |
+//! | importdescriptors                  | version + model |                                        |
 //! | importmulti                        | version         |                                        |
 //! | importprivkey                      | returns nothing |                                        |
 //! | importprunedfunds                  | returns nothing |                                        |
@@ -247,7 +247,7 @@
 //!
 //! | JSON-RPC Method Name               | Returns         | Notes                                  |
 //! |:-----------------------------------|:---------------:|:--------------------------------------:|
-//! | getzmqnotifications                | version         |                                        |
+//! | getzmqnotifications                | version + model |                                        |
 //!
 //! </details>
 
@@ -275,7 +275,10 @@ pub use self::{
         GetOrphanTxsVerboseTwoEntryError,
     },
     mining::{GetMiningInfo, GetMiningInfoError, NextBlockInfo, NextBlockInfoError},
-    raw_transactions::{MempoolAcceptance, MempoolAcceptanceFees, TestMempoolAccept},
+    raw_transactions::{
+        GetRawTransactionVerboseTwo, GetRawTransactionVerboseTwoError, MempoolAcceptance,
+        MempoolAcceptanceFees, TestMempoolAccept,
+    },
     util::{DeriveAddressesMultipath, GetDescriptorInfo},
 };
 #[doc(inline)]
@@ -285,16 +288,16 @@ pub use crate::{
         AddressPurpose, Bip125Replaceable, Bip32DerivError, BlockTemplateTransaction,
         BlockTemplateTransactionError, BumpFee, BumpFeeError, ChainTips, ChainTipsError,
         ChainTipsStatus, CombinePsbt, CombineRawTransaction, ConvertToPsbt, CreateMultisigError,
-        CreatePsbt, CreateRawTransaction, DecodeRawTransaction, DecodeScriptSegwit, DumpPrivKey,
-        DumpWallet, EncryptWallet, EstimateRawFee, EstimateRawFeeError, EstimateSmartFee,
-        FinalizePsbt, FinalizePsbtError, FundRawTransaction, FundRawTransactionError, Generate,
-        GenerateToAddress, GetAddedNodeInfo, GetAddressInfoEmbeddedError, GetAddressesByLabel,
-        GetBalance, GetBestBlockHash, GetBlockCount, GetBlockHash, GetBlockStatsError,
-        GetBlockTemplate, GetBlockTemplateError, GetBlockVerboseZero, GetChainTips,
-        GetChainTxStatsError, GetConnectionCount, GetDifficulty, GetMemoryInfoStats,
-        GetMempoolInfoError, GetNetTotals, GetNetworkInfoAddress, GetNetworkInfoError,
-        GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress, GetRawMempool,
-        GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
+        CreatePsbt, CreateRawTransaction, DecodeRawTransaction, DecodeScriptSegwitError,
+        DumpPrivKey, DumpWallet, EncryptWallet, EstimateRawFee, EstimateRawFeeError,
+        EstimateSmartFee, FinalizePsbt, FinalizePsbtError, FundRawTransaction,
+        FundRawTransactionError, Generate, GenerateToAddress, GetAddedNodeInfo,
+        GetAddressInfoEmbeddedError, GetAddressesByLabel, GetBalance, GetBestBlockHash,
+        GetBlockCount, GetBlockHash, GetBlockStatsError, GetBlockTemplate, GetBlockTemplateError,
+        GetBlockVerboseZero, GetChainTips, GetChainTxStatsError, GetConnectionCount, GetDifficulty,
+        GetMemoryInfoStats, GetMempoolInfoError, GetNetTotals, GetNetworkInfoAddress,
+        GetNetworkInfoError, GetNetworkInfoNetwork, GetNewAddress, GetRawChangeAddress,
+        GetRawMempool, GetRawTransaction, GetRawTransactionVerbose, GetRawTransactionVerboseError,
         GetReceivedByAddress, GetTransactionDetailError, GetTxOut, GetTxOutError,
         GetUnconfirmedBalance, ListAddressGroupings, ListAddressGroupingsError,
         ListAddressGroupingsItem, ListLabels, ListLockUnspent, ListLockUnspentItem,
@@ -316,7 +319,7 @@ pub use crate::{
         GetZmqNotifications, ImportMulti, ImportMultiEntry, JoinPsbts, JsonRpcError,
         ListReceivedByAddress, ListReceivedByAddressItem, ListReceivedByLabel,
         ListReceivedByLabelError, ListReceivedByLabelItem, ListWalletDir, ListWalletDirWallet,
-        UtxoUpdatePsbt,
+        PsbtRole, UtxoUpdatePsbt,
     },
     v19::{
         Bip9SoftforkInfo, Bip9SoftforkStatistics, Bip9SoftforkStatus, GetBalancesMine,
@@ -326,18 +329,19 @@ pub use crate::{
     },
     v20::GenerateToDescriptor,
     v21::{
-        AddPeerAddress, GetIndexInfo, GetIndexInfoName, GetRawMempoolSequence, ImportDescriptors,
-        ImportDescriptorsResult, PsbtBumpFee, PsbtBumpFeeError, Send, SendError, SendMany,
-        SendManyVerbose, UpgradeWallet,
+        AddPeerAddress, ConnectionType, GetIndexInfo, GetIndexInfoName, GetRawMempoolSequence,
+        ImportDescriptors, ImportDescriptorsResult, NetworkType, PsbtBumpFee, PsbtBumpFeeError,
+        Send, SendError, SendMany, SendManyVerbose, UpgradeWallet,
     },
     v22::{
-        AddConnection, Banned, EnumerateSigners, GetNodeAddresses, ListBanned, NodeAddress,
-        ScriptPubKey, Signers, WalletDisplayAddress,
+        AddConnection, Banned, EnumerateSigners, GetNodeAddresses, GetTxOutSetInfoBlockInfo,
+        GetTxOutSetInfoError, GetTxOutSetInfoUnspendables, ListBanned, NodeAddress, ScriptPubKey,
+        Signers, WalletDisplayAddress,
     },
     v23::{
         AddMultisigAddress, Bip9Info, Bip9Statistics, CreateMultisig, DecodeScript,
-        DecodeScriptError, DeploymentInfo, GetDeploymentInfo, GetDeploymentInfoError,
-        RestoreWallet, SaveMempool,
+        DecodeScriptError, DecodeScriptSegwit, DeploymentInfo, GetDeploymentInfo,
+        GetDeploymentInfoError, RestoreWallet, SaveMempool,
     },
     v24::{
         ControlBlocksError, DecodePsbt, DecodePsbtError, GetMempoolAncestors,
@@ -358,11 +362,10 @@ pub use crate::{
     v26::{
         AddrManInfoNetwork, CreateWallet, DescriptorProcessPsbt, DescriptorProcessPsbtError,
         DumpTxOutSet, DumpTxOutSetError, GetAddrManInfo, GetBalances, GetBalancesError,
-        GetPeerInfo, GetTransactionError, GetTxOutSetInfo, GetTxOutSetInfoBlockInfo,
-        GetTxOutSetInfoError, GetTxOutSetInfoUnspendables, GetWalletInfo, GetWalletInfoError,
+        GetPeerInfo, GetTransactionError, GetTxOutSetInfo, GetWalletInfo, GetWalletInfoError,
         GetWalletInfoScanning, LastProcessedBlock, LastProcessedBlockError, LoadTxOutSet,
-        LoadTxOutSetError, LoadWallet, PeerInfo, ScanBlocksStart, UnloadWallet, WalletProcessPsbt,
-        WalletProcessPsbtError,
+        LoadTxOutSetError, LoadWallet, PeerInfo, ScanBlocksStart, TransportProtocolType,
+        UnloadWallet, WalletProcessPsbt, WalletProcessPsbtError,
     },
     v27::{GetPrioritisedTransactions, PrioritisedTransaction},
     v28::{
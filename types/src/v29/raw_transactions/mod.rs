@@ -8,8 +8,35 @@ mod into;
 
 use serde::{Deserialize, Serialize};
 
+use super::GetRawTransactionVerboseWithPrevout;
 pub use super::{MempoolAcceptanceError, TestMempoolAcceptError};
 
+/// Error when converting a `GetRawTransactionVerboseTwo` type into the model type.
+///
+/// The result is the same as a transaction of `getblock` with verbosity 3.
+pub type GetRawTransactionVerboseTwoError = super::GetBlockVerboseThreeError;
+
+/// Result of JSON-RPC method `getrawtransaction` with verbosity set to 2.
+///
+/// > getrawtransaction "txid" ( verbosity "blockhash" )
+/// >
+/// > If verbosity is 2, returns a JSON Object with information about the transaction, including
+/// > fee and prevout information.
+/// >
+/// > Arguments:
+/// > 1. txid         (string, required) The transaction id
+/// > 2. verbosity    (numeric, optional, default=0) 0 for hex-encoded data, 1 for a JSON object, and 2 for JSON object with fee and prevout
+/// > 3. blockhash    (string, optional) The block in which to look for the transaction
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+#[cfg_attr(feature = "serde-deny-unknown-fields", serde(deny_unknown_fields))]
+pub struct GetRawTransactionVerboseTwo {
+    /// The transaction data, including the prevout of each input.
+    #[serde(flatten)]
+    pub transaction: GetRawTransactionVerboseWithPrevout,
+    /// The transaction fee in BTC (omitted if block undo data is not available).
+    pub fee: Option<f64>,
+}
+
 /// Result of JSON-RPC method `testmempoolaccept`.
 ///
 /// > testmempoolaccept ["rawtxs"] ( allowhighfees )
@@ -4,8 +4,8 @@ use alloc::collections::BTreeMap;
 
 use bitcoin::consensus::encode;
 use bitcoin::{
-    absolute, block, transaction, Amount, BlockHash, CompactTarget, OutPoint, ScriptBuf, Sequence,
-    Target, Transaction, TxMerkleNode, Txid, Weight, Work, Wtxid,
+    absolute, block, transaction, Amount, Block, BlockHash, CompactTarget, OutPoint, ScriptBuf,
+    Sequence, Target, Transaction, TxMerkleNode, Txid, Weight, Work, Wtxid,
 };
 
 use super::{
@@ -415,6 +415,14 @@ impl GetBlockVerboseTwo {
     }
 }
 
+impl TryFrom<GetBlockVerboseTwo> for Block {
+    type Error = GetBlockVerboseTwoError;
+
+    fn try_from(json: GetBlockVerboseTwo) -> Result<Self, Self::Error> {
+        Ok(json.into_model()?.into())
+    }
+}
+
 impl GetBlockVerboseThree {
     /// Converts version specific type to a version nonspecific, more strongly typed type.
     pub fn into_model(self) -> Result<model::GetBlockVerboseThree, GetBlockVerboseThreeError> {
@@ -481,3 +489,11 @@ impl GetBlockVerboseThree {
         })
     }
 }
+
+impl TryFrom<GetBlockVerboseThree> for Block {
+    type Error = GetBlockVerboseThreeError;
+
+    fn try_from(json: GetBlockVerboseThree) -> Result<Self, Self::Error> {
+        Ok(json.into_model()?.into())
+    }
+}
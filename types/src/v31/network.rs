@@ -8,6 +8,9 @@ use alloc::collections::BTreeMap;
 
 use serde::{Deserialize, Serialize};
 
+use super::NetworkType;
+use crate::model;
+
 /// Result of JSON-RPC method `getpeerinfo`.
 ///
 /// > getpeerinfo
@@ -33,7 +36,7 @@ pub struct PeerInfo {
     #[serde(rename = "addrlocal")]
     pub address_local: Option<String>,
     /// Network (ipv4, ipv6, onion, i2p, cjdns, not_publicly_routable).
-    pub network: String,
+    pub network: NetworkType,
     /// Mapped AS (Autonomous System) number at the end of the BGP route to the peer, used for diversifying peer selection (only displayed if the -asmap config option is set).
     pub mapped_as: Option<u32>,
     /// The services offered.
@@ -161,3 +164,78 @@ pub enum TransportProtocolType {
     /// BIP324 encrypted transport protocol.
     V2,
 }
+
+impl GetPeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::GetPeerInfo {
+        model::GetPeerInfo(self.0.into_iter().map(|p| p.into_model()).collect())
+    }
+}
+
+impl PeerInfo {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::PeerInfo {
+        model::PeerInfo {
+            id: self.id,
+            address: self.address,
+            address_bind: self.address_bind,
+            address_local: self.address_local,
+            network: Some(self.network.into_model()),
+            services: self.services,
+            services_names: Some(self.services_names),
+            relay_transactions: self.relay_transactions,
+            last_send: self.last_send,
+            last_received: self.last_received,
+            bytes_sent: self.bytes_sent,
+            bytes_received: self.bytes_received,
+            connection_time: self.connection_time,
+            time_offset: self.time_offset,
+            ping_time: self.ping_time,
+            minimum_ping: self.minimum_ping,
+            ping_wait: self.ping_wait,
+            version: self.version,
+            subversion: self.subversion,
+            inbound: self.inbound,
+            starting_height: self.starting_height,
+            synced_headers: self.synced_headers,
+            synced_blocks: self.synced_blocks,
+            permissions: Some(self.permissions),
+            bytes_sent_per_message: self.bytes_sent_per_message,
+            bytes_received_per_message: self.bytes_received_per_message,
+            connection_type: self.connection_type.map(|t| t.into_model()),
+            transport_protocol_type: Some(self.transport_protocol_type.into_model()),
+        }
+    }
+}
+
+impl ConnectionType {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::ConnectionType {
+        use model::ConnectionType as M; // M for model.
+        use ConnectionType as V; // V for version specific.
+
+        match self {
+            V::OutboundFullRelay => M::OutboundFullRelay,
+            V::BlockRelayOnly => M::BlockRelayOnly,
+            V::Inbound => M::Inbound,
+            V::Manual => M::Manual,
+            V::AddrFetch => M::AddrFetch,
+            V::Feeler => M::Feeler,
+            V::PrivateBroadcast => M::PrivateBroadcast,
+        }
+    }
+}
+
+impl TransportProtocolType {
+    /// Converts version specific type to a version nonspecific, more strongly typed type.
+    pub fn into_model(self) -> model::TransportProtocolType {
+        use model::TransportProtocolType as M; // M for model.
+        use TransportProtocolType as V; // V for version specific.
+
+        match self {
+            V::Detecting => M::Detecting,
+            V::V1 => M::V1,
+            V::V2 => M::V2,
+        }
+    }
+}
@@ -61,7 +61,7 @@ pub const METHODS: &[Method] = &[
     Method::new_no_model("getnettotals", "GetNetTotals", "get_net_totals"),
     Method::new_modelled("getnetworkinfo", "GetNetworkInfo", "get_network_info"),
     Method::new_no_model("getnodeaddresses", "GetNodeAddresses", "get_node_addresses"),
-    Method::new_no_model("getpeerinfo", "GetPeerInfo", "get_peer_info"),
+    Method::new_modelled("getpeerinfo", "GetPeerInfo", "get_peer_info"),
     Method::new_no_model("listbanned", "ListBanned", "list_banned"),
     Method::new_no_type("ping", "ping"),
     Method::new_no_type("setban", "set_ban"),
@@ -175,5 +175,5 @@ pub const METHODS: &[Method] = &[
     Method::new_no_type("walletpassphrasechange", "wallet_passphrase_change"),
     Method::new_modelled("walletprocesspsbt", "WalletProcessPsbt", "wallet_process_psbt"),
     // zmq
-    Method::new_no_model("getzmqnotifications", "GetZmqNotifications", "get_zmq_notifications"),
+    Method::new_modelled("getzmqnotifications", "GetZmqNotifications", "get_zmq_notifications"),
 ];